@@ -54,6 +54,7 @@ vi.mock("@/data/resume-data.json", () => ({
             name: "Senior Engineer",
             dateStart: "2020-01",
             dateEnd: "2023-12",
+            priority: 8,
             children: [
               {
                 id: "bullet-1",
@@ -83,6 +84,7 @@ vi.mock("@/data/resume-data.json", () => ({
             name: "Engineer",
             dateStart: "2018-01",
             dateEnd: "2020-01",
+            priority: 7,
             children: [
               {
                 id: "bullet-3",
@@ -95,6 +97,14 @@ vi.mock("@/data/resume-data.json", () => ({
         ],
       },
     ],
+    roleProfiles: [
+      {
+        id: "engineering-leader",
+        name: "Engineering Leader",
+        tagWeights: { leadership: 1.0 },
+        scoringWeights: { tagRelevance: 0.6, priority: 0.4 },
+      },
+    ],
   },
 }));
 
@@ -638,6 +648,57 @@ describe("/api/resume/ai-select", () => {
       expect(data.selected[2].bullet.id).toBe("bullet-3"); // score 0.5 (company2)
     });
 
+    it("blends AI scores with the role profile's heuristic scores", async () => {
+      mockTurnstileSuccess();
+      mockSelectBulletsWithAI.mockResolvedValue({
+        bullets: [
+          { id: "bullet-1", score: 0.6 },
+          { id: "bullet-2", score: 0.5 },
+        ],
+        reasoning: "Test",
+        jobTitle: null,
+        salary: null,
+        provider: "cerebras-qwen",
+      });
+
+      const request = createRequest({
+        jobDescription: validJobDescription,
+        turnstileToken: getUniqueToken(),
+        roleProfileId: "engineering-leader",
+        ensemble: { heuristic: 1, external: 1 },
+        config: { minPerCompany: 1 },
+      });
+
+      const response = await POST(request);
+      const data = (await response.json()) as any;
+
+      // The leadership heuristic lifts bullet-2 past the AI's favourite, and
+      // bullet-3 (skipped by the AI) keeps its heuristic score
+      expect(response.status).toBe(200);
+      expect(data.selected.map((s: any) => s.bullet.id)).toEqual([
+        "bullet-2",
+        "bullet-1",
+        "bullet-3",
+      ]);
+    });
+
+    it("returns 404 for an unknown role profile to blend with", async () => {
+      mockTurnstileSuccess();
+
+      const request = createRequest({
+        jobDescription: validJobDescription,
+        turnstileToken: getUniqueToken(),
+        roleProfileId: "nonexistent-profile",
+      });
+
+      const response = await POST(request);
+      const data = (await response.json()) as any;
+
+      expect(response.status).toBe(404);
+      expect(data.error).toContain("Role profile not found");
+      expect(mockSelectBulletsWithAI).not.toHaveBeenCalled();
+    });
+
     it("filters out non-existent bullet IDs gracefully", async () => {
       mockTurnstileSuccess();
       mockSelectBulletsWithAI.mockResolvedValue({
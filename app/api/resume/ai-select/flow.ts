@@ -3,7 +3,8 @@
  *
  * Extracted from `route.ts` so the HTTP handler stays focused on request
  * parsing, rate limiting, and Turnstile verification while this module owns
- * the AI call + diversity constraint + chronology reordering flow.
+ * the AI call + score blending + diversity constraint + chronology
+ * reordering flow.
  *
  * @module app/api/resume/ai-select/flow
  */
@@ -12,11 +13,14 @@ import { selectBulletsWithAI } from "@/lib/ai/providers";
 import type { AIProvider } from "@/lib/ai/providers/types";
 import {
   selectBulletsWithConstraints,
+  type RequirementCoverage,
   type SelectedBullet,
   type SelectionConfig,
 } from "@/lib/selection";
 import { reorderByCompanyChronology } from "@/lib/selection-diversity";
-import type { ResumeData } from "@/types/resume";
+import { refineScores, type EnsembleWeights } from "@/lib/selection-ensemble";
+import { scoreBullets } from "@/app/api/resume/select/scoring";
+import type { ResumeData, RoleProfile } from "@/types/resume";
 
 /**
  * Result of an AI-driven selection pipeline run.
@@ -24,6 +28,8 @@ import type { ResumeData } from "@/types/resume";
 export interface AISelectionPipelineResult {
   /** Bullets chosen after applying diversity constraints and chronology reordering. */
   selected: SelectedBullet[];
+  /** One entry per `mustCoverTags` requirement, with the gap reason if uncovered. */
+  coverage: RequirementCoverage[];
   /** Raw AI provider response details (prompt, tokens, reasoning, etc.). */
   aiResult: Awaited<ReturnType<typeof selectBulletsWithAI>>;
   /** Milliseconds spent in the AI provider call. */
@@ -32,20 +38,26 @@ export interface AISelectionPipelineResult {
 
 /**
  * Call the AI provider, then apply diversity constraints and chronology
- * reordering to the scored bullets.
+ * reordering to the scored bullets. With a role profile, the AI scores refine
+ * that profile's heuristic scores instead of replacing them.
  *
  * @param params.jobDescription - Raw JD text from the user.
  * @param params.resumeData - Full compendium to score against.
  * @param params.provider - Chosen AI provider identifier.
- * @param params.selectionConfig - Diversity + ceiling constraints.
+ * @param params.selectionConfig - Diversity + ceiling constraints, overrides,
+ *   `mustCoverTags` and selection mode.
+ * @param params.roleProfile - Profile to score heuristically alongside the AI.
+ * @param params.ensembleWeights - Heuristic vs AI weights when `roleProfile` is set.
  */
 export async function runAISelectionPipeline(params: {
   jobDescription: string;
   resumeData: ResumeData;
   provider: AIProvider;
   selectionConfig: SelectionConfig;
+  roleProfile?: RoleProfile;
+  ensembleWeights?: EnsembleWeights;
 }): Promise<AISelectionPipelineResult> {
-  const { jobDescription, resumeData, provider, selectionConfig } = params;
+  const { jobDescription, resumeData, provider, selectionConfig, roleProfile } = params;
 
   const startTime = Date.now();
   const aiResult = await selectBulletsWithAI(
@@ -59,18 +71,27 @@ export async function runAISelectionPipeline(params: {
   const aiDuration = Date.now() - startTime;
 
   // Build score map from AI response
-  const scoreMap = new Map<string, number>();
+  const aiScores = new Map<string, number>();
   for (const b of aiResult.bullets) {
-    scoreMap.set(b.id, b.score);
+    aiScores.set(b.id, b.score);
   }
 
+  // Let the AI refine the heuristic when a role profile was given
+  const scoreMap = roleProfile
+    ? refineScores(
+        new Map(scoreBullets(resumeData, roleProfile).map((s) => [s.bullet.id, s.score])),
+        aiScores,
+        params.ensembleWeights,
+      )
+    : aiScores;
+
   // Apply diversity constraints server-side (ported from Rust)
-  const selectedRaw = selectBulletsWithConstraints(resumeData, scoreMap, selectionConfig);
+  const { bullets, coverage } = selectBulletsWithConstraints(resumeData, scoreMap, selectionConfig);
 
   // Reorder to maintain company chronology (companies in resume order, bullets by score)
-  const selected = reorderByCompanyChronology(selectedRaw, resumeData);
+  const selected = reorderByCompanyChronology(bullets, resumeData);
 
-  return { selected, aiResult, aiDuration };
+  return { selected, coverage, aiResult, aiDuration };
 }
 
 /**
@@ -3,6 +3,9 @@
  *
  * AI-powered bullet selection from job description.
  * Uses LLM to score bullets, then applies diversity constraints server-side.
+ * With an optional `roleProfileId`, the LLM scores are blended with that
+ * profile's heuristic scores, weighted by `ensemble: { heuristic, external }`
+ * (default 0.3 / 0.7, see `lib/selection-ensemble.ts`).
  *
  * Rate limit: 5 requests per hour per IP (stricter due to AI costs)
  */
@@ -15,7 +18,13 @@ import type { AIProvider } from "@/lib/ai/providers/types";
 import { AISelectionError } from "@/lib/ai/errors";
 import { captureEvent, flushEvents } from "@/lib/posthog-server";
 import { ANALYTICS_EVENTS } from "@/lib/analytics/events";
-import { DEFAULT_SELECTION_CONFIG } from "@/lib/selection";
+import {
+  DEFAULT_SELECTION_CONFIG,
+  parseSelectionOptions,
+  type SelectionConfig,
+} from "@/lib/selection";
+import { validateSelectionOverrides } from "@/lib/selection-caps";
+import { parseEnsembleWeights } from "@/lib/selection-ensemble";
 import { loadResumeData, runAISelectionPipeline } from "./flow";
 
 /**
@@ -62,7 +71,10 @@ export async function POST(request: NextRequest) {
         maxPerCompany?: number;
         maxPerPosition?: number;
         minPerCompany?: number;
+        [key: string]: unknown; // mode, overrides, mustCoverTags (see parseSelectionOptions)
       };
+      roleProfileId?: string;
+      ensemble?: { heuristic?: number; external?: number };
       email?: string;
       linkedin?: string;
       sessionId?: string;
@@ -72,6 +84,8 @@ export async function POST(request: NextRequest) {
       turnstileToken,
       provider = FALLBACK_ORDER[0], // Default to first in fallback order
       config,
+      roleProfileId,
+      ensemble,
       email,
       linkedin,
       sessionId,
@@ -163,19 +177,38 @@ export async function POST(request: NextRequest) {
     }
 
     // Selection config with defaults (matches Rust SelectionConfig)
-    const selectionConfig = {
+    const selectionConfig: SelectionConfig = {
       maxBullets: config?.maxBullets ?? DEFAULT_SELECTION_CONFIG.maxBullets,
       maxPerCompany: config?.maxPerCompany ?? DEFAULT_SELECTION_CONFIG.maxPerCompany,
       maxPerPosition: config?.maxPerPosition ?? DEFAULT_SELECTION_CONFIG.maxPerPosition,
       minPerCompany: config?.minPerCompany ?? DEFAULT_SELECTION_CONFIG.minPerCompany,
+      ...parseSelectionOptions(config),
     };
 
+    const overridesError = validateSelectionOverrides(selectionConfig, resumeData);
+    if (overridesError) {
+      return NextResponse.json({ error: overridesError }, { status: 400 });
+    }
+
+    // Optional heuristic profile for the AI scores to refine
+    const roleProfile = roleProfileId
+      ? resumeData.roleProfiles?.find((p) => p.id === roleProfileId)
+      : undefined;
+    if (roleProfileId && !roleProfile) {
+      return NextResponse.json(
+        { error: `Role profile not found: ${roleProfileId}` },
+        { status: 404 },
+      );
+    }
+
     // Call AI provider, apply diversity constraints, and reorder by chronology
-    const { selected, aiResult, aiDuration } = await runAISelectionPipeline({
+    const { selected, coverage, aiResult, aiDuration } = await runAISelectionPipeline({
       jobDescription,
       resumeData,
       provider: provider as AIProvider,
       selectionConfig,
+      roleProfile,
+      ensembleWeights: parseEnsembleWeights(ensemble),
     });
 
     // Build analytics data (snake_case per spec)
@@ -243,6 +276,7 @@ export async function POST(request: NextRequest) {
       {
         success: true,
         selected,
+        coverage,
         count: selected.length,
         reasoning: aiResult.reasoning,
         jobTitle: aiResult.jobTitle,
@@ -568,6 +568,36 @@ describe("/api/resume/select - Security (Integration Tests)", () => {
     expect(data.error).toContain("Role profile not found");
   });
 
+  it("selects for a blend and echoes its sources", async () => {
+    mockTurnstileSuccess();
+
+    const request = createRequest({
+      blend: [{ roleProfileId: "developer-relations-lead", weight: 2 }],
+      turnstileToken: getUniqueToken(),
+    });
+
+    const response = await POST(request);
+    const data = (await response.json()) as any;
+
+    expect(response.status).toBe(200);
+    expect(data.blend.sources).toEqual([{ profileId: "developer-relations-lead", weight: 1 }]);
+  });
+
+  it("returns 404 for an unknown profile in a blend", async () => {
+    mockTurnstileSuccess();
+
+    const request = createRequest({
+      blend: [{ roleProfileId: "nonexistent-profile", weight: 1 }],
+      turnstileToken: getUniqueToken(),
+    });
+
+    const response = await POST(request);
+    const data = (await response.json()) as any;
+
+    expect(response.status).toBe(404);
+    expect(data.error).toContain("Role profile not found");
+  });
+
   it("returns 500 if TURNSTILE_SECRET_KEY not configured", async () => {
     delete process.env.TURNSTILE_SECRET_KEY;
 
@@ -2,6 +2,14 @@ import { NextRequest, NextResponse } from "next/server";
 import { checkRateLimit, getClientIP } from "@/lib/rate-limit";
 import { captureEvent, flushEvents } from "@/lib/posthog-server";
 import { ANALYTICS_EVENTS } from "@/lib/analytics/events";
+import { parseSelectionOptions, type SelectionConfig } from "@/lib/selection";
+import { validateSelectionOverrides } from "@/lib/selection-caps";
+import {
+  blendRoleProfiles,
+  type BlendedRoleProfile,
+  type BlendWeight,
+} from "@/lib/role-profile-blend";
+import type { RoleProfile } from "@/types/resume";
 import { loadResumeData, selectBullets } from "./scoring";
 
 // In-memory store for used tokens (prevents replay attacks within function instance lifetime)
@@ -16,19 +24,29 @@ const usedTokens = new Set<string>();
  *
  * Request body:
  * - roleProfileId: string (e.g., "developer-relations-lead")
+ * - blend?: Array<{ roleProfileId: string, weight: number }> — select for a hybrid of
+ *   several profiles instead of `roleProfileId` (see `lib/role-profile-blend.ts`)
  * - turnstileToken: string
- * - config?: { maxBullets?: number, maxPerCompany?: number, maxPerPosition?: number }
+ * - config?: { maxBullets?: number, maxPerCompany?: number, maxPerPosition?: number,
+ *   mode?, pinnedBulletIds?, excludedBulletIds?, includedCompanyIds?,
+ *   excludedCompanyIds?, mustCoverTags? } (see `SelectionConfig` in `lib/selection.ts`)
  *
  * Returns:
  * - selected bullets with scores
  * - company/position metadata
+ * - blend: the hybrid profile and its normalized sources, when `blend` was sent
+ * - coverage: one entry per `mustCoverTags` requirement, with the gap reason if uncovered
  *
  * Rate limit: 10 requests per hour per IP
  */
 type SelectRequestBody = {
   roleProfileId?: string;
+  blend?: BlendWeight[];
   turnstileToken?: string;
-  config?: { maxBullets?: number; maxPerCompany?: number; maxPerPosition?: number };
+  config?: { maxBullets?: number; maxPerCompany?: number; maxPerPosition?: number } & Record<
+    string,
+    unknown
+  >;
   email?: string;
   linkedin?: string;
   sessionId?: string;
@@ -38,11 +56,11 @@ export async function POST(request: NextRequest) {
   try {
     // Parse request body
     const body = (await request.json()) as SelectRequestBody;
-    const { roleProfileId, turnstileToken, config, email, linkedin, sessionId } = body;
+    const { roleProfileId, blend, turnstileToken, config, email, linkedin, sessionId } = body;
 
-    if (!roleProfileId || !turnstileToken) {
+    if ((!roleProfileId && !blend?.length) || !turnstileToken) {
       return NextResponse.json(
-        { error: "Missing required fields: roleProfileId, turnstileToken" },
+        { error: "Missing required fields: roleProfileId (or blend), turnstileToken" },
         { status: 400 },
       );
     }
@@ -114,8 +132,27 @@ export async function POST(request: NextRequest) {
       return NextResponse.json({ error: "Resume data not available" }, { status: 500 });
     }
 
-    // Find role profile
-    const roleProfile = resumeData.roleProfiles?.find((p) => p.id === roleProfileId);
+    // Find role profile, or blend several for hybrid postings
+    let roleProfile: RoleProfile | undefined;
+    let blended: BlendedRoleProfile | undefined;
+    if (blend?.length) {
+      const sources: Array<{ profile: RoleProfile; weight: number }> = [];
+      for (const { roleProfileId: id, weight } of blend) {
+        const profile = resumeData.roleProfiles?.find((p) => p.id === id);
+        if (!profile) {
+          return NextResponse.json({ error: `Role profile not found: ${id}` }, { status: 404 });
+        }
+        sources.push({ profile, weight });
+      }
+      try {
+        blended = blendRoleProfiles(sources);
+      } catch (error) {
+        return NextResponse.json({ error: (error as Error).message }, { status: 400 });
+      }
+      roleProfile = blended.profile;
+    } else {
+      roleProfile = resumeData.roleProfiles?.find((p) => p.id === roleProfileId);
+    }
 
     if (!roleProfile) {
       return NextResponse.json(
@@ -124,15 +161,25 @@ export async function POST(request: NextRequest) {
       );
     }
 
-    // Run bullet selection algorithm (TypeScript version until WASM is ready)
-    const selectionConfig = {
+    // Run bullet selection algorithm (TypeScript port of crates/resume-core selector)
+    const selectionConfig: SelectionConfig = {
       maxBullets: config?.maxBullets ?? 28, // Ceiling - may select fewer based on content
       maxPerCompany: config?.maxPerCompany ?? 6,
       maxPerPosition: config?.maxPerPosition ?? 4,
+      ...parseSelectionOptions(config),
     };
 
+    const overridesError = validateSelectionOverrides(selectionConfig, resumeData);
+    if (overridesError) {
+      return NextResponse.json({ error: overridesError }, { status: 400 });
+    }
+
     const startTime = Date.now();
-    const selected = selectBullets(resumeData, roleProfile, selectionConfig);
+    const { bullets: selected, coverage } = selectBullets(
+      resumeData,
+      roleProfile,
+      selectionConfig,
+    );
     const selectionDuration = Date.now() - startTime;
 
     // Track resume_prepared event (snake_case per spec)
@@ -158,6 +205,7 @@ export async function POST(request: NextRequest) {
         download_type: "resume_heuristic",
         role_profile_id: roleProfile.id,
         role_profile_name: roleProfile.name,
+        source_profiles: blended?.sources,
         bullet_ids,
         bullet_count: selected.length,
         bullets_by_company,
@@ -185,8 +233,10 @@ export async function POST(request: NextRequest) {
           name: roleProfile.name,
           description: roleProfile.description,
         },
+        ...(blended && { blend: blended }),
         config: selectionConfig,
         selected,
+        coverage,
         count: selected.length,
         timestamp: Date.now(),
       },
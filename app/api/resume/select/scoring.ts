@@ -9,8 +9,8 @@
  */
 
 import type { Bullet, Company, Position, ResumeData, RoleProfile } from "@/types/resume";
-import { type ScoredBullet, type SelectionConfig } from "@/lib/selection";
-import { applyDiversityConstraints } from "@/lib/selection-diversity";
+import { type ScoredBullet, type Selection, type SelectionConfig } from "@/lib/selection";
+import { applyConstraints } from "@/lib/selection-diversity";
 
 /**
  * Load resume data from the build cache.
@@ -30,18 +30,31 @@ export async function loadResumeData(): Promise<ResumeData | null> {
 /**
  * TypeScript implementation of the heuristic bullet selection algorithm.
  * Scores every bullet (plus position descriptions) against the role profile,
- * sorts by score, then applies diversity constraints.
+ * sorts by score, then applies overrides, tag coverage and diversity
+ * constraints.
  *
  * @param resumeData - Full resume compendium.
  * @param roleProfile - Profile containing tag weights and scoring weights.
- * @param config - Diversity constraints (max total / per-company / per-position).
- * @returns Bullets selected after constraints, sorted by score descending.
+ * @param config - Selection config (caps, mode, overrides, `mustCoverTags`).
+ * @returns Bullets selected after constraints plus the `mustCoverTags` report.
  */
 export function selectBullets(
   resumeData: ResumeData,
   roleProfile: RoleProfile,
   config: SelectionConfig,
-): ScoredBullet[] {
+): Selection {
+  return applyConstraints(scoreBullets(resumeData, roleProfile), config);
+}
+
+/**
+ * Score every bullet (plus position descriptions, as synthetic
+ * `{positionId}-description` bullets) against the role profile.
+ *
+ * @param resumeData - Full resume compendium.
+ * @param roleProfile - Profile containing tag weights and scoring weights.
+ * @returns All scored bullets, sorted by score descending.
+ */
+export function scoreBullets(resumeData: ResumeData, roleProfile: RoleProfile): ScoredBullet[] {
   const allBullets: ScoredBullet[] = [];
 
   // Collect all bullets with scores
@@ -102,9 +115,7 @@ export function selectBullets(
 
   // Sort by score descending
   allBullets.sort((a, b) => b.score - a.score);
-
-  // Apply diversity constraints
-  return applyDiversityConstraints(allBullets, config);
+  return allBullets;
 }
 
 /**
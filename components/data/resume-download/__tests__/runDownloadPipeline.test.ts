@@ -20,6 +20,7 @@ const mockEnsureWasmLoaded = vi.fn();
 const mockGeneratePdfBytes = vi.fn();
 const mockPostLogEvent = vi.fn();
 const mockTriggerPdfDownload = vi.fn();
+const mockSelectSummaryText = vi.fn();
 
 vi.mock("../pipeline", () => ({
   fetchAIBullets: (...args: unknown[]) => mockFetchAIBullets(...args),
@@ -28,6 +29,7 @@ vi.mock("../pipeline", () => ({
   generatePdfBytes: (...args: unknown[]) => mockGeneratePdfBytes(...args),
   postLogEvent: (...args: unknown[]) => mockPostLogEvent(...args),
   triggerPdfDownload: (...args: unknown[]) => mockTriggerPdfDownload(...args),
+  selectSummaryText: (...args: unknown[]) => mockSelectSummaryText(...args),
 }));
 
 // ---- Fixtures ------------------------------------------------------------
@@ -114,6 +116,7 @@ beforeEach(() => {
   mockEnsureWasmLoaded.mockResolvedValue(undefined);
   mockGeneratePdfBytes.mockReturnValue(new Uint8Array([1, 2, 3, 4]));
   mockTriggerPdfDownload.mockReturnValue("jane-doe-developer-relations-lead.pdf");
+  mockSelectSummaryText.mockReturnValue("Summary");
 });
 
 // Helper: advance past the two trailing setTimeouts (500ms + 1500ms).
@@ -180,6 +183,77 @@ describe("runDownloadPipeline", () => {
       );
     });
 
+    it("forwards selection overrides and records the applied ones in the metadata", async () => {
+      const selectionOverrides = { pinnedBulletIds: ["b1"], excludedCompanyIds: [] };
+      mockFetchHeuristicBullets.mockResolvedValueOnce({
+        ...selectResponse,
+        config: selectionOverrides,
+      });
+      const ctx = makeCtx({ isAIMode: false, selectionOverrides });
+
+      const promise = runDownloadPipeline(ctx);
+      await finishPipeline(promise);
+
+      expect(mockFetchHeuristicBullets).toHaveBeenCalledWith(
+        expect.objectContaining({ selectionOverrides }),
+      );
+      const payload = mockGeneratePdfBytes.mock.calls[0]?.[0];
+      expect(payload.metadata).toEqual(
+        expect.objectContaining({
+          selectedBulletIds: ["b1"],
+          roleProfileId: "developer-relations-lead",
+          selectionOverrides: { pinnedBulletIds: ["b1"] },
+        }),
+      );
+    });
+
+    it("records the render options it passes to the renderer in the metadata", async () => {
+      const ctx = makeCtx({ isAIMode: false });
+
+      const promise = runDownloadPipeline(ctx);
+      await finishPipeline(promise);
+
+      const [payload, renderOptions] = mockGeneratePdfBytes.mock.calls[0] ?? [];
+      expect(renderOptions).toEqual({ debug: false });
+      expect(payload.metadata.renderOptions).toEqual(renderOptions);
+    });
+
+    it("renders a blended profile and records its sources in the metadata", async () => {
+      const roleBlend = [
+        { roleProfileId: "developer-relations-lead", weight: 1 },
+        { roleProfileId: "staff-engineer", weight: 1 },
+      ];
+      const sources = [
+        { profileId: "developer-relations-lead", weight: 0.5 },
+        { profileId: "staff-engineer", weight: 0.5 },
+      ];
+      const blendedProfile = {
+        ...heuristicRoleProfile,
+        id: "developer-relations-lead+staff-engineer",
+        name: "Developer Relations Lead / Staff Engineer",
+      };
+      mockFetchHeuristicBullets.mockResolvedValueOnce({
+        ...selectResponse,
+        blend: { profile: blendedProfile, sources },
+      });
+      const ctx = makeCtx({ isAIMode: false, roleBlend });
+
+      const promise = runDownloadPipeline(ctx);
+      await finishPipeline(promise);
+
+      expect(mockFetchHeuristicBullets).toHaveBeenCalledWith(
+        expect.objectContaining({ blend: roleBlend }),
+      );
+      const payload = mockGeneratePdfBytes.mock.calls[0]?.[0];
+      expect(payload.roleProfile).toEqual(blendedProfile);
+      expect(payload.metadata).toEqual(
+        expect.objectContaining({
+          roleProfileId: "developer-relations-lead+staff-engineer",
+          sourceProfiles: sources,
+        }),
+      );
+    });
+
     it("throws when the selected role profile cannot be resolved", async () => {
       const ctx = makeCtx({ isAIMode: false, selectedRoleId: "does-not-exist" });
 
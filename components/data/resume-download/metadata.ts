@@ -0,0 +1,91 @@
+/**
+ * `GenerationMetadata` for the payload handed to the WASM renderer.
+ *
+ * Records what a render was produced from (selected bullets, role profile
+ * and its blend sources, selection overrides, render options) so a PDF can be traced back and
+ * regenerated. Field
+ * names match the Rust `GenerationMetadata` in `crates/shared-types`.
+ *
+ * @module components/data/resume-download/metadata
+ */
+
+import type { ProfileBlendSource } from "@/lib/role-profile-blend";
+import type { SelectionOverrides } from "@/lib/selection";
+import type { SelectApiResponse } from "./types";
+
+/**
+ * The subset of the Rust `RenderOptions` the download path sets. Unset
+ * fields fall back to the renderer defaults.
+ */
+export interface RenderOptions {
+  /** Built-in template name (see `list_templates`); defaults to classic */
+  template?: string;
+  /** Append a build metadata page */
+  debug: boolean;
+}
+
+/** Mirrors the Rust `GenerationMetadata` (camelCase on the wire). */
+export interface GenerationMetadata {
+  generationId: string;
+  /** Unix epoch seconds */
+  timestamp: number;
+  selectedBulletIds: string[];
+  roleProfileId: string;
+  /** Source profiles when the role profile is a blend */
+  sourceProfiles?: ProfileBlendSource[];
+  selectionOverrides?: SelectionOverrides;
+  renderOptions?: RenderOptions;
+}
+
+const OVERRIDE_KEYS = [
+  "pinnedBulletIds",
+  "excludedBulletIds",
+  "includedCompanyIds",
+  "excludedCompanyIds",
+] as const;
+
+/**
+ * Keep only the non-empty override lists, like the Rust serializer.
+ *
+ * @returns `undefined` when no override was applied.
+ */
+function appliedOverrides(config: SelectApiResponse["config"]): SelectionOverrides | undefined {
+  const overrides: SelectionOverrides = {};
+  for (const key of OVERRIDE_KEYS) {
+    const ids = config?.[key];
+    if (ids && ids.length > 0) overrides[key] = ids;
+  }
+  return Object.keys(overrides).length > 0 ? overrides : undefined;
+}
+
+/**
+ * Build the metadata for one generation.
+ *
+ * @param params.selectData - Selection API response; its echoed `config`
+ *   supplies the overrides the server actually applied.
+ * @param params.roleProfileId - Profile the resume is generated for.
+ * @param params.sourceProfiles - Blend sources when that profile is a blend.
+ * @param params.renderOptions - Options the PDF is rendered with; pass the
+ *   same object to `generatePdfBytes`.
+ * @param params.now - Clock in epoch milliseconds (defaults to `Date.now()`).
+ * @returns Metadata ready for `GenerationPayload.metadata`.
+ */
+export function buildGenerationMetadata(params: {
+  selectData: SelectApiResponse;
+  roleProfileId: string;
+  sourceProfiles?: ProfileBlendSource[];
+  renderOptions: RenderOptions;
+  now?: number;
+}): GenerationMetadata {
+  const { selectData, roleProfileId, sourceProfiles, renderOptions, now = Date.now() } = params;
+  const selectionOverrides = appliedOverrides(selectData.config);
+  return {
+    generationId: crypto.randomUUID(),
+    timestamp: Math.floor(now / 1000),
+    selectedBulletIds: selectData.selected.map((s) => s.bullet.id),
+    roleProfileId,
+    ...(sourceProfiles && { sourceProfiles }),
+    ...(selectionOverrides && { selectionOverrides }),
+    renderOptions,
+  };
+}
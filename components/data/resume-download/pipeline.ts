@@ -9,8 +9,12 @@
  * @module components/data/resume-download/pipeline
  */
 
+import type { ResumeData, RoleProfile } from "@/types/resume";
 import type { AIProvider } from "@/lib/ai/providers/types";
+import type { BlendWeight } from "@/lib/role-profile-blend";
+import type { SelectionOverrides } from "@/lib/selection";
 import type { SelectApiResponse } from "./types";
+import type { RenderOptions } from "./metadata";
 
 /**
  * Shape of the error payload both selection endpoints return. All four
@@ -87,7 +91,8 @@ async function readErrorBody(response: Response): Promise<SelectionErrorBody> {
  * "retrying" state before throwing.
  *
  * @param params - Request parameters (job description, provider, Turnstile
- *   token, optional contact info, session id) plus an `onRetryAttempt`
+ *   token, optional contact info, session id, optional selection overrides
+ *   sent as `config`) plus an `onRetryAttempt`
  *   callback invoked when the server reports server-side retries.
  * @returns The decoded `SelectApiResponse`.
  * @throws Error with the server's `userMessage`/`message` on non-2xx.
@@ -99,6 +104,7 @@ export async function fetchAIBullets(params: {
   email?: string;
   linkedin?: string;
   sessionId: string;
+  selectionOverrides?: SelectionOverrides;
   onRetryAttempt: (count: number) => void;
 }): Promise<SelectApiResponse> {
   const response = await fetch("/api/resume/ai-select", {
@@ -111,6 +117,7 @@ export async function fetchAIBullets(params: {
       email: params.email || undefined,
       linkedin: params.linkedin || undefined,
       sessionId: params.sessionId,
+      config: params.selectionOverrides,
     }),
   });
   if (!response.ok) {
@@ -126,8 +133,9 @@ export async function fetchAIBullets(params: {
 /**
  * Call the heuristic selection endpoint.
  *
- * @param params - Role profile id, Turnstile token, optional contact info,
- *   session id.
+ * @param params - Role profile id (or a `blend` of several), Turnstile token,
+ *   optional contact info, session id, optional selection overrides (sent as
+ *   `config`).
  * @returns The decoded `SelectApiResponse`.
  * @throws Error with the server's `message` on non-2xx.
  */
@@ -136,17 +144,21 @@ export async function fetchHeuristicBullets(params: {
   turnstileToken: string;
   email?: string;
   linkedin?: string;
+  blend?: BlendWeight[];
   sessionId: string;
+  selectionOverrides?: SelectionOverrides;
 }): Promise<SelectApiResponse> {
   const response = await fetch("/api/resume/select", {
     method: "POST",
     headers: { "Content-Type": "application/json" },
     body: JSON.stringify({
       roleProfileId: params.roleProfileId,
+      blend: params.blend,
       turnstileToken: params.turnstileToken,
       email: params.email || undefined,
       linkedin: params.linkedin || undefined,
       sessionId: params.sessionId,
+      config: params.selectionOverrides,
     }),
   });
   if (!response.ok) {
@@ -193,13 +205,14 @@ export async function ensureWasmLoaded(): Promise<void> {
       script.type = "module";
       script.setAttribute("data-wasm-loader", "true");
       script.textContent = `
-      import init, { generate_pdf_typst, init_panic_hook, validate_payload_json } from '/wasm/resume_wasm.js';
+      import init, { generate_pdf_typst, init_panic_hook, select_summary, validate_payload_json } from '/wasm/resume_wasm.js';
       await init('/wasm/resume_wasm_bg.wasm');
       init_panic_hook();
       console.warn('✅ WASM loaded and cached');
       window.__wasmReady = true;
       window.__generatePdfTypst = generate_pdf_typst;
       window.__validatePayloadJson = validate_payload_json;
+      window.__selectSummary = select_summary;
     `;
       document.head.appendChild(script);
     }
@@ -223,15 +236,40 @@ export async function ensureWasmLoaded(): Promise<void> {
   await wasmLoadPromise;
 }
 
+/**
+ * Pick the summary text for a role profile via WASM.
+ *
+ * Scores the compendium's tagged summary candidates against the profile (or
+ * honours its `summaryId` override). Falls back to the plain `summary` when
+ * WASM has not exposed the selector or the compendium has no candidates.
+ *
+ * @param resumeData - Full compendium.
+ * @param roleProfile - Profile the resume is generated for.
+ * @returns The summary text, or `undefined` when the compendium has none.
+ */
+export function selectSummaryText(
+  resumeData: ResumeData,
+  roleProfile: RoleProfile,
+): string | null | undefined {
+  if (!window.__selectSummary) return resumeData.summary;
+  const selected = JSON.parse(
+    window.__selectSummary(JSON.stringify(resumeData), JSON.stringify(roleProfile)),
+  ) as { text: string } | null;
+  return selected?.text ?? resumeData.summary;
+}
+
 /**
  * Validate + compile the resume payload to a PDF byte array via WASM.
  *
  * @param payload - `GenerationPayload` produced by the selection pipeline.
- * @param isDevMode - Passes through to the Typst compiler's debug flag.
+ * @param renderOptions - Render options, also recorded in the payload metadata.
  * @returns The compiled PDF bytes as a `Uint8Array`.
  * @throws If validation fails or the Typst compiler is not initialised.
  */
-export function generatePdfBytes(payload: unknown, isDevMode: boolean): Uint8Array {
+export function generatePdfBytes(
+  payload: unknown,
+  renderOptions: RenderOptions,
+): Uint8Array {
   if (!window.__generatePdfTypst) {
     throw new Error("Typst WASM module not initialized");
   }
@@ -247,7 +285,7 @@ export function generatePdfBytes(payload: unknown, isDevMode: boolean): Uint8Arr
     }
   }
   console.warn("🎨 Generating PDF with Typst...");
-  return window.__generatePdfTypst(payloadJson, isDevMode);
+  return window.__generatePdfTypst(payloadJson, JSON.stringify(renderOptions));
 }
 
 /**
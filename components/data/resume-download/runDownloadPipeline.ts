@@ -14,14 +14,18 @@ import type { ResumeData, RoleProfile } from "@/types/resume";
 import type { AIProvider } from "@/lib/ai/providers/types";
 import type { usePostHogResume } from "@/lib/posthog-client";
 import type { AIProgressStage } from "@/components/ui/AIProgressIndicator";
+import type { SelectionOverrides } from "@/lib/selection";
+import type { BlendWeight } from "@/lib/role-profile-blend";
 import {
   ensureWasmLoaded,
   fetchAIBullets,
   fetchHeuristicBullets,
   generatePdfBytes,
   postLogEvent,
+  selectSummaryText,
   triggerPdfDownload,
 } from "./pipeline";
+import { buildGenerationMetadata, type RenderOptions } from "./metadata";
 import type { DownloadErrorStage, DownloadStatus, SelectApiResponse } from "./types";
 
 type Analytics = ReturnType<typeof usePostHogResume>;
@@ -43,11 +47,15 @@ export interface PipelineContext {
   jobDescription: string;
   aiProvider: AIProvider;
   selectedRoleId: string;
+  /** Blend of role profiles for hybrid postings; replaces `selectedRoleId` */
+  roleBlend?: BlendWeight[];
   roleProfiles: readonly RoleProfile[];
   resumeData: ResumeData;
   email: string;
   linkedin: string;
   analytics: Analytics;
+  /** Pins and exclusions for this application, forwarded to the selection API */
+  selectionOverrides?: SelectionOverrides;
   flowStartMs: number;
   errorStageRef: ErrorStageRef;
   setStatus: (value: DownloadStatus) => void;
@@ -72,6 +80,7 @@ async function fetchSelection(ctx: PipelineContext, sessionId: string): Promise<
       email,
       linkedin,
       sessionId,
+      selectionOverrides: ctx.selectionOverrides,
       onRetryAttempt: (count) => {
         ctx.setAiRetryCount(count);
         ctx.setAiStage("retrying");
@@ -83,10 +92,12 @@ async function fetchSelection(ctx: PipelineContext, sessionId: string): Promise<
   }
   return fetchHeuristicBullets({
     roleProfileId: ctx.selectedRoleId,
+    blend: ctx.roleBlend,
     turnstileToken: verifiedToken,
     email,
     linkedin,
     sessionId,
+    selectionOverrides: ctx.selectionOverrides,
   });
 }
 
@@ -107,6 +118,7 @@ function resolveRoleProfile(ctx: PipelineContext, selectData: SelectApiResponse)
       scoringWeights: { tagRelevance: 0.5, priority: 0.5 },
     };
   }
+  if (selectData.blend) return selectData.blend.profile;
   const profile = ctx.roleProfiles.find((r) => r.id === ctx.selectedRoleId);
   if (!profile) throw new Error("Role profile not found");
   return profile;
@@ -155,17 +167,23 @@ export async function runDownloadPipeline(ctx: PipelineContext): Promise<void> {
 
   ctx.errorStageRef.current = "pdf_generation";
   const roleProfile = resolveRoleProfile(ctx, selectData);
+  const renderOptions: RenderOptions = { debug: process.env.NODE_ENV === "development" };
   const payload = {
     personal: ctx.resumeData.personal,
     selectedBullets: selectData.selected,
     roleProfile,
     education: ctx.resumeData.education,
     skills: ctx.resumeData.skills,
-    summary: ctx.resumeData.summary,
-    metadata: null,
+    summary: selectSummaryText(ctx.resumeData, roleProfile),
+    metadata: buildGenerationMetadata({
+      selectData,
+      roleProfileId: roleProfile.id,
+      sourceProfiles: selectData.blend?.sources,
+      renderOptions,
+    }),
   };
   const generationStart = Date.now();
-  const pdfBytes = generatePdfBytes(payload, process.env.NODE_ENV === "development");
+  const pdfBytes = generatePdfBytes(payload, renderOptions);
   const generationDuration = Date.now() - generationStart;
   console.warn("✅ PDF generated successfully with Typst");
 
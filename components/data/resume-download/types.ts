@@ -9,6 +9,8 @@
  */
 
 import type { ResumeData } from "@/types/resume";
+import type { BlendedRoleProfile } from "@/lib/role-profile-blend";
+import type { SelectionOverrides } from "@/lib/selection";
 
 /** Minimum job-description length that unlocks AI-mode selection. */
 export const MIN_JOB_DESCRIPTION_LENGTH = 50;
@@ -42,6 +44,10 @@ export interface SelectApiResponse {
   jobTitle?: string | null;
   salary?: { min?: number; max?: number; currency: string; period: string } | null;
   metadata?: { provider: string; tokensUsed?: number; duration?: number };
+  /** Selection config the server applied, echoed back (overrides included) */
+  config?: SelectionOverrides;
+  /** Hybrid profile the server selected for, when a blend was requested */
+  blend?: BlendedRoleProfile;
 }
 
 // Extend Window interface for WASM functions
@@ -49,7 +55,8 @@ declare global {
   interface Window {
     __wasmReady?: boolean;
     __generatePdf?: (payload: string, devMode: boolean) => Uint8Array;
-    __generatePdfTypst?: (payload: string, devMode: boolean) => Uint8Array;
+    __generatePdfTypst?: (payload: string, optionsJson?: string | null) => Uint8Array;
     __validatePayloadJson?: (json: string) => void;
+    __selectSummary?: (resumeJson: string, roleProfileJson: string) => string;
   }
 }
@@ -0,0 +1,368 @@
+//! Parse error codes and rustc-style diagnostics
+//!
+//! Port of `lib/ai/errors.ts`. Two renderings per error:
+//! - [`ParseError::format_rust_style`]: verbose, with a source snippet; fed back
+//!   to the model in retry prompts
+//! - [`ParseErrorCode::simplified_message`]: short, user-facing
+
+use serde::Serialize;
+use std::fmt;
+
+/// Longest underline drawn under a span (matches the TS cap)
+const MAX_UNDERLINE: usize = 60;
+
+/// Characters of context kept either side of a span on long (minified) lines
+const SNIPPET_CONTEXT: usize = 40;
+
+/// Error codes shared with the TS AI pipeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum ParseErrorCode {
+    #[serde(rename = "E000_PROVIDER_ERROR")]
+    ProviderError,
+    #[serde(rename = "E001_NO_JSON_FOUND")]
+    NoJsonFound,
+    #[serde(rename = "E002_INVALID_JSON")]
+    InvalidJson,
+    #[serde(rename = "E003_MISSING_BULLET_IDS")]
+    MissingBulletIds,
+    #[serde(rename = "E004_WRONG_BULLET_COUNT")]
+    WrongBulletCount,
+    #[serde(rename = "E005_INVALID_BULLET_ID")]
+    InvalidBulletId,
+    #[serde(rename = "E006_DUPLICATE_BULLET_ID")]
+    DuplicateBulletId,
+    #[serde(rename = "E007_DIVERSITY_VIOLATION")]
+    DiversityViolation,
+    #[serde(rename = "E008_MISSING_REASONING")]
+    MissingReasoning,
+    #[serde(rename = "E009_INVALID_SCORE")]
+    InvalidScore,
+    #[serde(rename = "E010_INVALID_SALARY")]
+    InvalidSalary,
+    #[serde(rename = "E011_PROVIDER_DOWN")]
+    ProviderDown,
+    #[serde(rename = "E012_PROVIDER_BUSY")]
+    ProviderBusy,
+}
+
+impl ParseErrorCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParseErrorCode::ProviderError => "E000_PROVIDER_ERROR",
+            ParseErrorCode::NoJsonFound => "E001_NO_JSON_FOUND",
+            ParseErrorCode::InvalidJson => "E002_INVALID_JSON",
+            ParseErrorCode::MissingBulletIds => "E003_MISSING_BULLET_IDS",
+            ParseErrorCode::WrongBulletCount => "E004_WRONG_BULLET_COUNT",
+            ParseErrorCode::InvalidBulletId => "E005_INVALID_BULLET_ID",
+            ParseErrorCode::DuplicateBulletId => "E006_DUPLICATE_BULLET_ID",
+            ParseErrorCode::DiversityViolation => "E007_DIVERSITY_VIOLATION",
+            ParseErrorCode::MissingReasoning => "E008_MISSING_REASONING",
+            ParseErrorCode::InvalidScore => "E009_INVALID_SCORE",
+            ParseErrorCode::InvalidSalary => "E010_INVALID_SALARY",
+            ParseErrorCode::ProviderDown => "E011_PROVIDER_DOWN",
+            ParseErrorCode::ProviderBusy => "E012_PROVIDER_BUSY",
+        }
+    }
+
+    /// Friendly message for the UI (`formatSimplifiedError`)
+    pub fn simplified_message(&self) -> &'static str {
+        match self {
+            ParseErrorCode::ProviderError => {
+                "The AI service encountered an issue. Please try again."
+            }
+            ParseErrorCode::NoJsonFound => {
+                "The AI response was unclear. Retrying with a different approach..."
+            }
+            ParseErrorCode::InvalidJson => {
+                "The AI response was malformed. Retrying with a different approach..."
+            }
+            ParseErrorCode::MissingBulletIds => "The AI did not select any experience. Retrying...",
+            ParseErrorCode::WrongBulletCount => {
+                "The AI selected the wrong number of experiences. Retrying..."
+            }
+            ParseErrorCode::InvalidBulletId => {
+                "The AI referenced unknown experiences. Retrying with corrections..."
+            }
+            ParseErrorCode::DuplicateBulletId => {
+                "The AI selected duplicate experiences. Retrying..."
+            }
+            ParseErrorCode::DiversityViolation => {
+                "The AI selection needs more variety. Retrying with constraints..."
+            }
+            ParseErrorCode::MissingReasoning => "The AI did not explain its selection. Retrying...",
+            ParseErrorCode::InvalidScore => "The AI provided invalid relevance scores. Retrying...",
+            ParseErrorCode::InvalidSalary => {
+                "The AI salary extraction was malformed. Continuing without salary..."
+            }
+            ParseErrorCode::ProviderDown => {
+                "This AI model is currently unavailable. Please try a different model."
+            }
+            ParseErrorCode::ProviderBusy => {
+                "This AI model is busy right now. Please try a different model or try again shortly."
+            }
+        }
+    }
+
+    /// Output format problems worth retrying against the same provider
+    pub fn is_output_format_error(&self) -> bool {
+        matches!(
+            self,
+            ParseErrorCode::NoJsonFound
+                | ParseErrorCode::InvalidJson
+                | ParseErrorCode::MissingBulletIds
+                | ParseErrorCode::WrongBulletCount
+                | ParseErrorCode::InvalidBulletId
+                | ParseErrorCode::DuplicateBulletId
+                | ParseErrorCode::MissingReasoning
+                | ParseErrorCode::InvalidScore
+        )
+    }
+
+    /// Provider down or busy: stop retrying and suggest another model
+    pub fn is_provider_down(&self) -> bool {
+        matches!(
+            self,
+            ParseErrorCode::ProviderDown | ParseErrorCode::ProviderBusy
+        )
+    }
+}
+
+impl fmt::Display for ParseErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Location of a problem in the raw AI response
+///
+/// `start`/`end` are byte offsets into the response; `line`/`column` are
+/// 1-based (column counted in characters) for display.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+    /// Full text of the line containing `start`
+    pub source_line: String,
+}
+
+impl Span {
+    /// Build a span over `source[start..end]`, clamped to valid char boundaries
+    pub fn new(source: &str, start: usize, end: usize) -> Self {
+        let start = floor_char_boundary(source, start);
+        let end = floor_char_boundary(source, end.max(start));
+
+        let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |i| start + i);
+
+        Self {
+            start,
+            end,
+            line: source[..start].matches('\n').count() + 1,
+            column: source[line_start..start].chars().count() + 1,
+            source_line: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// Characters to underline on the first line of the span
+    fn underline_width(&self) -> usize {
+        let line_chars = self.source_line.chars().count();
+        let available = line_chars.saturating_sub(self.column - 1);
+        let span_len = self.end - self.start;
+        // Byte length is an upper bound on char length; clamp to the line
+        span_len.min(available).clamp(1, MAX_UNDERLINE)
+    }
+}
+
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    let mut index = index.min(s.len());
+    while !s.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// A validation failure (or warning) for an AI response
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParseError {
+    pub code: ParseErrorCode,
+    pub message: String,
+    pub help: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span: Option<Span>,
+}
+
+impl ParseError {
+    pub fn new(code: ParseErrorCode, message: impl Into<String>, help: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            help: help.into(),
+            span: None,
+        }
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    /// Render in rustc style, e.g.
+    ///
+    /// ```text
+    /// error[E005_INVALID_BULLET_ID]: 1 invalid bullet ID(s) found
+    ///  --> AI response:3:12
+    ///   |
+    /// 3 |     {"id": "made-up", "score": 0.9},
+    ///   |            ^^^^^^^^^
+    ///
+    ///   These IDs do not exist in the compendium:
+    /// ```
+    pub fn format_rust_style(&self) -> String {
+        let mut lines = vec![format!("error[{}]: {}", self.code, self.message)];
+
+        if let Some(span) = &self.span {
+            let gutter = " ".repeat(span.line.to_string().len());
+            let (snippet, offset) = snippet_window(&span.source_line, span.column - 1);
+
+            lines.push(format!(
+                "{gutter}--> AI response:{}:{}",
+                span.line, span.column
+            ));
+            lines.push(format!("{gutter} |"));
+            lines.push(format!("{} | {}", span.line, snippet));
+            lines.push(format!(
+                "{gutter} | {}{}",
+                " ".repeat(offset),
+                "^".repeat(span.underline_width())
+            ));
+        }
+
+        lines.push(String::new());
+        for line in self.help.lines() {
+            lines.push(if line.is_empty() {
+                String::new()
+            } else {
+                format!("  {line}")
+            });
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Trim long lines around `column` (0-based chars)
+///
+/// Returns the displayed text and the caret offset within it.
+fn snippet_window(line: &str, column: usize) -> (String, usize) {
+    let chars: Vec<char> = line
+        .chars()
+        .map(|c| if c == '\t' { ' ' } else { c })
+        .collect();
+    let from = column.saturating_sub(SNIPPET_CONTEXT);
+    let to = (column + MAX_UNDERLINE + SNIPPET_CONTEXT).min(chars.len());
+
+    let mut snippet = String::new();
+    let mut offset = column - from;
+    if from > 0 {
+        snippet.push_str("...");
+        offset += 3;
+    }
+    snippet.extend(&chars[from..to.max(from)]);
+    if to < chars.len() {
+        snippet.push_str("...");
+    }
+
+    (snippet, offset)
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format_rust_style())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span_line_and_column() {
+        let source = "{\n  \"bullets\": [\n    {\"id\": \"nope\"}\n  ]\n}";
+        let start = source.find("\"nope\"").unwrap();
+        let span = Span::new(source, start, start + 6);
+
+        assert_eq!(span.line, 3);
+        assert_eq!(span.column, 12);
+        assert_eq!(span.source_line, "    {\"id\": \"nope\"}");
+    }
+
+    #[test]
+    fn test_span_clamps_to_char_boundaries() {
+        let source = "é{}";
+        let span = Span::new(source, 1, 99);
+        assert_eq!(span.start, 0);
+        assert_eq!(span.end, source.len());
+    }
+
+    #[test]
+    fn test_format_rust_style_with_span() {
+        let source = "{\n  \"id\": \"nope\"\n}";
+        let start = source.find("\"nope\"").unwrap();
+        let error = ParseError::new(
+            ParseErrorCode::InvalidBulletId,
+            "1 invalid bullet ID(s) found",
+            "These IDs do not exist\n\n  - \"nope\"",
+        )
+        .with_span(Span::new(source, start, start + 6));
+
+        let expected = [
+            "error[E005_INVALID_BULLET_ID]: 1 invalid bullet ID(s) found",
+            " --> AI response:2:9",
+            "  |",
+            "2 |   \"id\": \"nope\"",
+            "  |         ^^^^^^",
+            "",
+            "  These IDs do not exist",
+            "",
+            "    - \"nope\"",
+        ]
+        .join("\n");
+        assert_eq!(error.format_rust_style(), expected);
+    }
+
+    #[test]
+    fn test_format_rust_style_windows_long_lines() {
+        let source = format!("{{\"pad\": \"{}\", \"id\": \"nope\"}}", "x".repeat(200));
+        let start = source.find("\"nope\"").unwrap();
+        let error = ParseError::new(ParseErrorCode::InvalidBulletId, "bad", "help")
+            .with_span(Span::new(&source, start, start + 6));
+
+        let rendered = error.format_rust_style();
+        let snippet = rendered.lines().nth(3).unwrap();
+        let carets = rendered.lines().nth(4).unwrap();
+
+        assert!(snippet.contains("| ...x"));
+        assert!(snippet.len() < 150);
+        let caret_col = carets.find('^').unwrap();
+        assert_eq!(&snippet[caret_col..caret_col + 6], "\"nope\"");
+    }
+
+    #[test]
+    fn test_code_serializes_as_ts_code() {
+        let json = serde_json::to_string(&ParseErrorCode::InvalidScore).unwrap();
+        assert_eq!(json, "\"E009_INVALID_SCORE\"");
+        assert!(ParseErrorCode::InvalidScore.is_output_format_error());
+        assert!(!ParseErrorCode::InvalidSalary.is_output_format_error());
+        assert!(ParseErrorCode::ProviderBusy.is_provider_down());
+    }
+}
@@ -0,0 +1,105 @@
+//! JSON extraction from free-form model output
+//!
+//! Port of `extractJSON`. Tries, in order:
+//! 1. the first fenced code block (```` ```json { ... } ``` ````)
+//! 2. a raw object containing `"bullets"` (current format)
+//! 3. a raw object containing `"bullet_ids"` (legacy format)
+//! 4. any raw object (first `{` to last `}`)
+
+/// Extract the JSON object from an AI response
+pub fn extract_json(raw: &str) -> Option<&str> {
+    extract_json_range(raw).map(|(start, end)| &raw[start..end])
+}
+
+/// Byte range of the extracted JSON within `raw`
+pub(crate) fn extract_json_range(raw: &str) -> Option<(usize, usize)> {
+    code_block(raw)
+        .or_else(|| object_containing(raw, Some("\"bullets\"")))
+        .or_else(|| object_containing(raw, Some("\"bullet_ids\"")))
+        .or_else(|| object_containing(raw, None))
+}
+
+/// `/```(?:json)?\s*(\{[\s\S]*?\})\s*```/`
+fn code_block(raw: &str) -> Option<(usize, usize)> {
+    for (fence, _) in raw.match_indices("```") {
+        let mut start = fence + 3;
+        if raw[start..].starts_with("json") {
+            start += 4;
+        }
+        let rest = &raw[start..];
+        let open = start + (rest.len() - rest.trim_start().len());
+        if !raw[open..].starts_with('{') {
+            continue;
+        }
+
+        // Lazy match: the first `}` followed by optional whitespace and a fence
+        for (close, _) in raw[open..].match_indices('}') {
+            let end = open + close + 1;
+            if raw[end..].trim_start().starts_with("```") {
+                return Some((open, end));
+            }
+        }
+    }
+    None
+}
+
+/// `/\{[\s\S]*NEEDLE[\s\S]*\}/`: leftmost `{` to the last `}`
+///
+/// Later braces only shrink the window, so only the first `{` needs checking.
+fn object_containing(raw: &str, needle: Option<&str>) -> Option<(usize, usize)> {
+    let end = raw.rfind('}')? + 1;
+    let open = raw[..end].find('{')?;
+
+    match needle {
+        Some(needle) if !raw[open + 1..end - 1].contains(needle) => None,
+        _ => Some((open, end)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_from_code_block() {
+        let raw = "Here you go:\n```json\n{\"bullets\": []}\n```\nThanks {not json}";
+        assert_eq!(extract_json(raw), Some("{\"bullets\": []}"));
+
+        let bare_fence = "```\n{\"a\": {\"b\": 1}}\n```";
+        assert_eq!(extract_json(bare_fence), Some("{\"a\": {\"b\": 1}}"));
+    }
+
+    #[test]
+    fn test_extract_raw_object_prefers_bullets() {
+        let raw = "Reasoning first. {\"bullets\": [{\"id\": \"a\"}], \"reasoning\": \"x\"} done";
+        assert_eq!(
+            extract_json(raw),
+            Some("{\"bullets\": [{\"id\": \"a\"}], \"reasoning\": \"x\"}")
+        );
+    }
+
+    #[test]
+    fn test_extract_legacy_and_fallback() {
+        assert_eq!(
+            extract_json("x {\"bullet_ids\": []} y"),
+            Some("{\"bullet_ids\": []}")
+        );
+        assert_eq!(extract_json("x {\"other\": 1} y"), Some("{\"other\": 1}"));
+    }
+
+    #[test]
+    fn test_extract_none() {
+        assert_eq!(extract_json("no json here"), None);
+        assert_eq!(extract_json("} backwards {"), None);
+        assert_eq!(extract_json(""), None);
+    }
+
+    #[test]
+    fn test_extract_handles_multibyte_text() {
+        let raw = "Résumé → {\"bullets\": [], \"reasoning\": \"naïve\"} ✓";
+        assert_eq!(
+            extract_json(raw),
+            Some("{\"bullets\": [], \"reasoning\": \"naïve\"}")
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! Bullet → position → company lookup for validating AI output
+//!
+//! Port of `lib/ai/output-parser-hierarchy.ts`. Also remembers position and
+//! company IDs so the parser can explain when the model returned a parent
+//! instead of a bullet.
+
+use shared_types::ResumeData;
+use std::collections::{HashMap, HashSet};
+
+/// Where a bullet sits in the compendium
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulletLocation {
+    pub company_id: String,
+    pub position_id: String,
+}
+
+/// What kind of compendium item an ID refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemKind {
+    Company,
+    Position,
+    Bullet,
+}
+
+/// Index of every selectable bullet in a compendium
+#[derive(Debug, Clone, Default)]
+pub struct BulletHierarchy {
+    bullets: HashMap<String, BulletLocation>,
+    /// Bullet IDs in compendium order (for stable "valid IDs look like" hints)
+    order: Vec<String>,
+    positions: HashMap<String, Vec<String>>,
+    companies: HashSet<String>,
+}
+
+impl BulletHierarchy {
+    pub fn from_resume(data: &ResumeData) -> Self {
+        let mut hierarchy = Self::default();
+
+        for company in &data.experience {
+            hierarchy.companies.insert(company.id.clone());
+            for position in &company.children {
+                let bullet_ids = position.children.iter().map(|b| b.id.clone()).collect();
+                hierarchy.positions.insert(position.id.clone(), bullet_ids);
+
+                for bullet in &position.children {
+                    hierarchy.order.push(bullet.id.clone());
+                    hierarchy.bullets.insert(
+                        bullet.id.clone(),
+                        BulletLocation {
+                            company_id: company.id.clone(),
+                            position_id: position.id.clone(),
+                        },
+                    );
+                }
+            }
+        }
+
+        hierarchy
+    }
+
+    pub fn get(&self, bullet_id: &str) -> Option<&BulletLocation> {
+        self.bullets.get(bullet_id)
+    }
+
+    pub fn contains(&self, bullet_id: &str) -> bool {
+        self.bullets.contains_key(bullet_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.bullets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bullets.is_empty()
+    }
+
+    /// Bullet IDs in compendium order
+    pub fn bullet_ids(&self) -> impl Iterator<Item = &str> {
+        self.order.iter().map(String::as_str)
+    }
+
+    /// Classify an ID (bullets win if an ID is reused across levels)
+    pub fn kind_of(&self, id: &str) -> Option<ItemKind> {
+        if self.bullets.contains_key(id) {
+            Some(ItemKind::Bullet)
+        } else if self.positions.contains_key(id) {
+            Some(ItemKind::Position)
+        } else if self.companies.contains(id) {
+            Some(ItemKind::Company)
+        } else {
+            None
+        }
+    }
+
+    /// Bullet IDs under a position
+    pub fn position_bullets(&self, position_id: &str) -> &[String] {
+        self.positions
+            .get(position_id)
+            .map_or(&[], |ids| ids.as_slice())
+    }
+}
@@ -0,0 +1,637 @@
+//! AI selection output parsing
+//!
+//! Rust port of `lib/ai/output-parser.ts`. Takes the raw text an LLM returned
+//! for a selection prompt and validates it against the compendium:
+//!
+//! 1. Extract the JSON object (code fence or raw braces)
+//! 2. Parse it; syntax errors point at the offending character
+//! 3. Require a `bullets` array of `{id, score}` with scores in 0.0-1.0
+//! 4. Require at least `max_bullets + AI_BULLET_BUFFER` bullets
+//! 5. Every ID must be a bullet in the compendium (not a position or company)
+//! 6. No duplicates
+//! 7. Non-empty `reasoning`
+//! 8. Optional `salary` (ISO 4217) and `job_title`
+//!
+//! Failures come back as a [`ParseError`] with a [`Span`] into the raw
+//! response, rendered rustc-style for retry prompts. An invalid salary is a
+//! warning, not a failure, matching the TS behaviour.
+
+mod diagnostic;
+mod extract;
+mod hierarchy;
+mod salary;
+
+pub use diagnostic::{ParseError, ParseErrorCode, Span};
+pub use extract::extract_json;
+pub use hierarchy::{BulletHierarchy, BulletLocation, ItemKind};
+pub use salary::validate_salary;
+
+use crate::scorer::ExternalScores;
+use serde::Serialize;
+use serde_json::value::RawValue;
+use serde_json::Value;
+use shared_types::SalaryInfo;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Extra bullets the model must score beyond `max_bullets`, so the server
+/// still has options after applying diversity constraints
+pub const AI_BULLET_BUFFER: usize = 10;
+
+/// How many "valid IDs look like" examples to include in E005 help
+const SAMPLE_VALID_IDS: usize = 5;
+
+/// Parser configuration (the parser-relevant half of the TS `SelectionConfig`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseConfig {
+    /// Target bullets for the final selection
+    pub max_bullets: usize,
+    /// Minimum bullets the model must return (default: `max_bullets + AI_BULLET_BUFFER`)
+    pub min_bullets: Option<usize>,
+}
+
+impl ParseConfig {
+    pub fn new(max_bullets: usize) -> Self {
+        Self {
+            max_bullets,
+            min_bullets: None,
+        }
+    }
+
+    pub fn min_required(&self) -> usize {
+        self.min_bullets
+            .unwrap_or(self.max_bullets + AI_BULLET_BUFFER)
+    }
+}
+
+/// A bullet the model scored, with its place in the compendium
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoredBulletId {
+    pub id: String,
+    pub score: f32,
+    pub company_id: String,
+    pub position_id: String,
+}
+
+/// Validated AI selection response
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedAiResponse {
+    pub bullets: Vec<ScoredBulletId>,
+    pub reasoning: String,
+    pub job_title: Option<String>,
+    pub salary: Option<SalaryInfo>,
+    /// Non-fatal problems (e.g. a malformed salary that was dropped)
+    pub warnings: Vec<ParseError>,
+}
+
+impl ParsedAiResponse {
+    /// Scores as an [`ExternalScores`] scorer, ready for ensembling
+    pub fn scores(&self) -> ExternalScores {
+        ExternalScores::from_pairs(self.bullets.iter().map(|b| (b.id.clone(), b.score)))
+    }
+}
+
+/// Parse and validate raw AI output against the compendium
+pub fn parse_ai_output(
+    raw: &str,
+    hierarchy: &BulletHierarchy,
+    config: &ParseConfig,
+) -> Result<ParsedAiResponse, ParseError> {
+    Parser { raw, hierarchy }.parse(config)
+}
+
+struct Parser<'a> {
+    raw: &'a str,
+    hierarchy: &'a BulletHierarchy,
+}
+
+impl<'a> Parser<'a> {
+    fn parse(&self, config: &ParseConfig) -> Result<ParsedAiResponse, ParseError> {
+        let raw = self.raw;
+
+        // Step 1: Extract JSON from response
+        let (json_start, json_end) = extract::extract_json_range(raw).ok_or_else(|| {
+            ParseError::new(
+                ParseErrorCode::NoJsonFound,
+                "No JSON object found in AI response",
+                format!(
+                    "Expected format: {{\"bullets\": [{{\"id\": \"...\", \"score\": 0.9}}], \"reasoning\": \"...\", \"job_title\": \"...\", \"salary\": {{...}}}}\n\nGot: {}",
+                    truncate(raw, 200)
+                ),
+            )
+        })?;
+        let json = &raw[json_start..json_end];
+
+        // Step 2: Parse JSON, keeping each field's raw text for spans
+        let parsed: BTreeMap<String, &RawValue> =
+            serde_json::from_str(json).map_err(|e| self.json_error(json, json_start, &e))?;
+
+        // Step 3: Validate bullets array exists
+        let elements: Vec<&RawValue> = parsed
+            .get("bullets")
+            .and_then(|value| serde_json::from_str(value.get()).ok())
+            .ok_or_else(|| {
+                let keys: Vec<&str> = parsed.keys().map(String::as_str).collect();
+                let error = ParseError::new(
+                    ParseErrorCode::MissingBulletIds,
+                    "Response missing \"bullets\" array",
+                    format!(
+                        "The AI response must contain a \"bullets\" array with {{id, score}} objects.\n\nGot keys: {}\n\nExpected: bullets, reasoning, job_title, salary",
+                        keys.join(", ")
+                    ),
+                );
+                match parsed.get("bullets") {
+                    Some(value) => error.with_span(self.span_of(value)),
+                    None => error,
+                }
+            })?;
+
+        // Step 4: Each bullet has an id string and a 0-1 score
+        let mut bullets = Vec::with_capacity(elements.len());
+        for (index, element) in elements.iter().enumerate() {
+            bullets.push(self.parse_bullet(index, element)?);
+        }
+
+        // Step 5: Minimum count
+        let min_required = config.min_required();
+        if bullets.len() < min_required {
+            return Err(ParseError::new(
+                ParseErrorCode::WrongBulletCount,
+                format!(
+                    "Expected at least {min_required} bullets, got {}",
+                    bullets.len()
+                ),
+                format!(
+                    "The AI must score at least {min_required} bullets (maxBullets={} + buffer={AI_BULLET_BUFFER}) to give the server selection options.\n\nReceived only {} bullets. Please score more bullets from the compendium.",
+                    config.max_bullets,
+                    bullets.len()
+                ),
+            )
+            .with_span(self.span_of(parsed["bullets"])));
+        }
+
+        // Step 6: Every ID is a bullet in the compendium
+        let invalid: Vec<&RawBullet> = bullets
+            .iter()
+            .filter(|b| !self.hierarchy.contains(&b.id))
+            .collect();
+        if let Some(first) = invalid.first() {
+            return Err(self
+                .invalid_ids_error(&invalid)
+                .with_span(first.id_span.clone()));
+        }
+
+        // Step 7: No duplicates (span points at the first repeat)
+        let mut seen = HashSet::new();
+        let dupes: Vec<&RawBullet> = bullets
+            .iter()
+            .filter(|b| !seen.insert(b.id.as_str()))
+            .collect();
+        if let Some(first) = dupes.first() {
+            let ids: Vec<&str> = dupes.iter().map(|b| b.id.as_str()).collect();
+            return Err(ParseError::new(
+                ParseErrorCode::DuplicateBulletId,
+                format!("{} duplicate bullet ID(s)", dupes.len()),
+                format!(
+                    "Each bullet can only be selected once:\n\nDuplicates: {}\n\nRemove duplicates and select unique bullets.",
+                    ids.join(", ")
+                ),
+            )
+            .with_span(first.id_span.clone()));
+        }
+
+        // Step 8: Diversity is enforced server-side (see `selector`)
+
+        // Step 9: Reasoning
+        let reasoning = parsed.get("reasoning").map(|value| parse_value(value));
+        let reasoning = match reasoning {
+            Some(Value::String(text)) if !text.is_empty() => text,
+            other => {
+                let got = match &other {
+                    Some(Value::String(_)) => "(empty string)",
+                    Some(value) => js_typeof(value),
+                    None => "undefined",
+                };
+                let error = ParseError::new(
+                    ParseErrorCode::MissingReasoning,
+                    "Response missing \"reasoning\" field",
+                    format!(
+                        "The AI response must include a \"reasoning\" string explaining the selection.\n\nGot: {got}"
+                    ),
+                );
+                return Err(match parsed.get("reasoning") {
+                    Some(value) => error.with_span(self.span_of(value)),
+                    None => error,
+                });
+            }
+        };
+
+        // Step 10: Salary (optional; invalid salary is a warning)
+        let mut warnings = Vec::new();
+        let salary = match parsed.get("salary") {
+            None => None,
+            Some(value) => match validate_salary(&parse_value(value)) {
+                Ok(salary) => salary,
+                Err(message) => {
+                    warnings.push(
+                        ParseError::new(
+                            ParseErrorCode::InvalidSalary,
+                            format!("Invalid salary: {message}"),
+                            "Salary is optional; continuing without it.",
+                        )
+                        .with_span(self.span_of(value)),
+                    );
+                    None
+                }
+            },
+        };
+
+        // Step 11: Job title (optional)
+        let job_title = match parsed.get("job_title").map(|value| parse_value(value)) {
+            Some(Value::String(title)) if !title.is_empty() => Some(title),
+            _ => None,
+        };
+
+        let bullets = bullets
+            .into_iter()
+            .map(|b| {
+                let location = self
+                    .hierarchy
+                    .get(&b.id)
+                    .expect("IDs validated against hierarchy");
+                ScoredBulletId {
+                    company_id: location.company_id.clone(),
+                    position_id: location.position_id.clone(),
+                    id: b.id,
+                    score: b.score,
+                }
+            })
+            .collect();
+
+        Ok(ParsedAiResponse {
+            bullets,
+            reasoning,
+            job_title,
+            salary,
+            warnings,
+        })
+    }
+
+    fn parse_bullet(&self, index: usize, element: &RawValue) -> Result<RawBullet, ParseError> {
+        let fields: HashMap<String, &RawValue> =
+            serde_json::from_str(element.get()).unwrap_or_default();
+
+        let id = fields
+            .get("id")
+            .and_then(|value| Some((value, serde_json::from_str::<String>(value.get()).ok()?)));
+        let Some((id_value, id)) = id else {
+            return Err(ParseError::new(
+                ParseErrorCode::InvalidBulletId,
+                format!("Bullet at index {index} missing valid \"id\" string"),
+                format!(
+                    "Each bullet must have an \"id\" string. Got: {}",
+                    element.get()
+                ),
+            )
+            .with_span(self.span_of(element)));
+        };
+
+        let score = fields
+            .get("score")
+            .and_then(|value| serde_json::from_str::<f64>(value.get()).ok())
+            .filter(|score| (0.0..=1.0).contains(score));
+        let Some(score) = score else {
+            let got = fields.get("score").map_or("undefined", |value| value.get());
+            let span = fields.get("score").map_or(element, |value| *value);
+            return Err(ParseError::new(
+                ParseErrorCode::InvalidScore,
+                format!("Bullet \"{id}\" has invalid score"),
+                format!("Score must be a number between 0.0 and 1.0. Got: {got}"),
+            )
+            .with_span(self.span_of(span)));
+        };
+
+        Ok(RawBullet {
+            id,
+            score: score as f32,
+            id_span: self.span_of(id_value),
+        })
+    }
+
+    fn invalid_ids_error(&self, invalid: &[&RawBullet]) -> ParseError {
+        let listed: Vec<String> = invalid
+            .iter()
+            .map(|b| match self.hierarchy.kind_of(&b.id) {
+                Some(ItemKind::Position) => {
+                    let children = self.hierarchy.position_bullets(&b.id);
+                    if children.is_empty() {
+                        format!("  - \"{}\" (a position with no bullets)", b.id)
+                    } else {
+                        format!(
+                            "  - \"{}\" (a position; score its bullets instead: {})",
+                            b.id,
+                            children.join(", ")
+                        )
+                    }
+                }
+                Some(ItemKind::Company) => {
+                    format!("  - \"{}\" (a company, not a bullet)", b.id)
+                }
+                _ => format!("  - \"{}\"", b.id),
+            })
+            .collect();
+
+        let sample: Vec<String> = self
+            .hierarchy
+            .bullet_ids()
+            .take(SAMPLE_VALID_IDS)
+            .map(|id| format!("  - \"{id}\""))
+            .collect();
+
+        ParseError::new(
+            ParseErrorCode::InvalidBulletId,
+            format!("{} invalid bullet ID(s) found", invalid.len()),
+            format!(
+                "These IDs do not exist in the compendium:\n\n{}\n\nValid IDs look like:\n{}...",
+                listed.join("\n"),
+                sample.join("\n")
+            ),
+        )
+    }
+
+    fn json_error(&self, json: &str, json_start: usize, error: &serde_json::Error) -> ParseError {
+        // serde_json reports 1-based line/column within the extracted JSON
+        let line_start: usize = json
+            .split_inclusive('\n')
+            .take(error.line().saturating_sub(1))
+            .map(str::len)
+            .sum();
+        let offset = json_start + line_start + error.column().saturating_sub(1);
+
+        ParseError::new(
+            ParseErrorCode::InvalidJson,
+            format!("JSON parse error: {error}"),
+            format!(
+                "The AI returned malformed JSON.\n\nAttempted to parse:\n{}",
+                truncate(json, 300)
+            ),
+        )
+        .with_span(Span::new(self.raw, offset, offset + 1))
+    }
+
+    /// Span of a value borrowed from the extracted JSON
+    fn span_of(&self, value: &RawValue) -> Span {
+        let text = value.get();
+        let start = text.as_ptr() as usize - self.raw.as_ptr() as usize;
+        Span::new(self.raw, start, start + text.len())
+    }
+}
+
+/// A bullet entry before hierarchy validation
+struct RawBullet {
+    id: String,
+    score: f32,
+    id_span: Span,
+}
+
+fn parse_value(value: &RawValue) -> Value {
+    serde_json::from_str(value.get()).unwrap_or(Value::Null)
+}
+
+/// JavaScript `typeof` for error messages shared with the TS parser
+fn js_typeof(value: &Value) -> &'static str {
+    match value {
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Null | Value::Array(_) | Value::Object(_) => "object",
+    }
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((cut, _)) => format!("{}...", &text[..cut]),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{bullet, company, position, resume};
+    use shared_types::SalaryPeriod;
+
+    fn hierarchy() -> BulletHierarchy {
+        BulletHierarchy::from_resume(&resume(vec![
+            company(
+                "acme",
+                vec![position(
+                    "acme-eng",
+                    vec![
+                        bullet("acme-b1", "One", &[], 5),
+                        bullet("acme-b2", "Two", &[], 5),
+                    ],
+                )],
+            ),
+            company(
+                "initech",
+                vec![position(
+                    "initech-pm",
+                    vec![bullet("initech-b1", "Three", &[], 5)],
+                )],
+            ),
+        ]))
+    }
+
+    fn config() -> ParseConfig {
+        ParseConfig {
+            max_bullets: 2,
+            min_bullets: Some(2),
+        }
+    }
+
+    fn parse(raw: &str) -> Result<ParsedAiResponse, ParseError> {
+        parse_ai_output(raw, &hierarchy(), &config())
+    }
+
+    #[test]
+    fn test_parse_valid_response() {
+        let raw = r#"Sure! Here is my selection:
+```json
+{
+  "bullets": [
+    {"id": "acme-b1", "score": 0.92},
+    {"id": "initech-b1", "score": 0.4}
+  ],
+  "reasoning": "Strong Rust focus",
+  "job_title": "Staff Engineer",
+  "salary": {"min": 150000, "max": 190000, "currency": "gbp", "period": "annual"}
+}
+```"#;
+
+        let parsed = parse(raw).unwrap();
+        assert_eq!(parsed.bullets.len(), 2);
+        assert_eq!(parsed.bullets[0].company_id, "acme");
+        assert_eq!(parsed.bullets[1].position_id, "initech-pm");
+        assert_eq!(parsed.reasoning, "Strong Rust focus");
+        assert_eq!(parsed.job_title.as_deref(), Some("Staff Engineer"));
+
+        let salary = parsed.salary.unwrap();
+        assert_eq!(salary.currency, "GBP");
+        assert_eq!(salary.period, SalaryPeriod::Annual);
+        assert!(parsed.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_no_json() {
+        let error = parse("I cannot help with that.").unwrap_err();
+        assert_eq!(error.code, ParseErrorCode::NoJsonFound);
+        assert!(error.help.contains("Got: I cannot help with that."));
+    }
+
+    #[test]
+    fn test_invalid_json_points_at_error() {
+        let raw = "{\n  \"bullets\": [\n    {\"id\": \"acme-b1\" \"score\": 0.9}\n  ]\n}";
+        let error = parse(raw).unwrap_err();
+
+        assert_eq!(error.code, ParseErrorCode::InvalidJson);
+        let span = error.span.unwrap();
+        assert_eq!(span.line, 3);
+        assert_eq!(&raw[span.start..span.start + 1], "\"");
+    }
+
+    #[test]
+    fn test_missing_bullets_array() {
+        let error = parse(r#"{"bullet_ids": ["acme-b1"], "reasoning": "x"}"#).unwrap_err();
+        assert_eq!(error.code, ParseErrorCode::MissingBulletIds);
+        assert!(error.help.contains("Got keys: bullet_ids, reasoning"));
+    }
+
+    #[test]
+    fn test_invalid_score_spans_score_value() {
+        let raw = r#"{"bullets": [{"id": "acme-b1", "score": 1.5}, {"id": "acme-b2", "score": 0.2}], "reasoning": "x"}"#;
+        let error = parse(raw).unwrap_err();
+
+        assert_eq!(error.code, ParseErrorCode::InvalidScore);
+        assert_eq!(error.message, "Bullet \"acme-b1\" has invalid score");
+        let span = error.span.unwrap();
+        assert_eq!(&raw[span.start..span.end], "1.5");
+    }
+
+    #[test]
+    fn test_missing_id() {
+        let raw =
+            r#"{"bullets": [{"score": 0.5}, {"id": "acme-b2", "score": 0.2}], "reasoning": "x"}"#;
+        let error = parse(raw).unwrap_err();
+
+        assert_eq!(error.code, ParseErrorCode::InvalidBulletId);
+        assert_eq!(
+            error.message,
+            "Bullet at index 0 missing valid \"id\" string"
+        );
+    }
+
+    #[test]
+    fn test_too_few_bullets() {
+        let raw = r#"{"bullets": [{"id": "acme-b1", "score": 0.5}], "reasoning": "x"}"#;
+        let error = parse(raw).unwrap_err();
+
+        assert_eq!(error.code, ParseErrorCode::WrongBulletCount);
+        assert_eq!(error.message, "Expected at least 2 bullets, got 1");
+    }
+
+    #[test]
+    fn test_min_bullets_defaults_to_buffer() {
+        assert_eq!(ParseConfig::new(24).min_required(), 34);
+    }
+
+    #[test]
+    fn test_unknown_ids_are_spanned() {
+        let raw = "{\"bullets\": [\n  {\"id\": \"acme-b1\", \"score\": 0.5},\n  {\"id\": \"made-up\", \"score\": 0.5}\n], \"reasoning\": \"x\"}";
+        let error = parse(raw).unwrap_err();
+
+        assert_eq!(error.code, ParseErrorCode::InvalidBulletId);
+        assert!(error.help.contains("  - \"made-up\""));
+        assert!(error.help.contains("Valid IDs look like:\n  - \"acme-b1\""));
+
+        let span = error.span.as_ref().unwrap();
+        assert_eq!((span.line, span.column), (3, 10));
+        assert!(error
+            .format_rust_style()
+            .contains("3 |   {\"id\": \"made-up\", \"score\": 0.5}\n  |          ^^^^^^^^^"));
+    }
+
+    #[test]
+    fn test_parent_ids_are_explained() {
+        let raw = r#"{"bullets": [{"id": "acme-eng", "score": 0.5}, {"id": "initech", "score": 0.5}], "reasoning": "x"}"#;
+        let error = parse(raw).unwrap_err();
+
+        assert_eq!(error.message, "2 invalid bullet ID(s) found");
+        assert!(error
+            .help
+            .contains("\"acme-eng\" (a position; score its bullets instead: acme-b1, acme-b2)"));
+        assert!(error.help.contains("\"initech\" (a company, not a bullet)"));
+    }
+
+    #[test]
+    fn test_duplicate_ids_span_second_occurrence() {
+        let raw = r#"{"bullets": [{"id": "acme-b1", "score": 0.5}, {"id": "acme-b1", "score": 0.4}], "reasoning": "x"}"#;
+        let error = parse(raw).unwrap_err();
+
+        assert_eq!(error.code, ParseErrorCode::DuplicateBulletId);
+        assert!(error.help.contains("Duplicates: acme-b1"));
+        assert_eq!(error.span.unwrap().start, raw.rfind("\"acme-b1\"").unwrap());
+    }
+
+    #[test]
+    fn test_missing_reasoning() {
+        let base =
+            r#"{"bullets": [{"id": "acme-b1", "score": 0.5}, {"id": "acme-b2", "score": 0.4}]"#;
+
+        let missing = parse(&format!("{base}}}")).unwrap_err();
+        assert_eq!(missing.code, ParseErrorCode::MissingReasoning);
+        assert!(missing.help.ends_with("Got: undefined"));
+
+        let empty = parse(&format!("{base}, \"reasoning\": \"\"}}")).unwrap_err();
+        assert!(empty.help.ends_with("Got: (empty string)"));
+
+        let wrong_type = parse(&format!("{base}, \"reasoning\": 42}}")).unwrap_err();
+        assert!(wrong_type.help.ends_with("Got: number"));
+    }
+
+    #[test]
+    fn test_invalid_salary_is_warning() {
+        let raw = r#"{"bullets": [{"id": "acme-b1", "score": 0.5}, {"id": "acme-b2", "score": 0.4}], "reasoning": "x", "salary": {"currency": "DOGE", "period": "annual"}, "job_title": ""}"#;
+        let parsed = parse(raw).unwrap();
+
+        assert_eq!(parsed.salary, None);
+        assert_eq!(parsed.job_title, None);
+        assert_eq!(parsed.warnings.len(), 1);
+        assert_eq!(parsed.warnings[0].code, ParseErrorCode::InvalidSalary);
+    }
+
+    #[test]
+    fn test_scores_feed_external_scorer() {
+        let raw = r#"{"bullets": [{"id": "acme-b1", "score": 0.5}, {"id": "acme-b2", "score": 0.4}], "reasoning": "x"}"#;
+        let scores = parse(raw).unwrap().scores();
+        assert_eq!(scores.len(), 2);
+    }
+
+    #[test]
+    fn test_never_panics_on_odd_input() {
+        let inputs = [
+            "{",
+            "}{",
+            "{}",
+            "```json\n{\"bullets\": null}\n```",
+            "{\"bullets\": [1, \"two\", null, []], \"reasoning\": \"x\"}",
+            "{\"bullets\": [{\"id\": \"é\", \"score\": 0.5}]}",
+            "😀 {\"bullets\": [{\"id\": \"acme-b1\", \"score\": \"high\"}]} 😀",
+        ];
+        for input in inputs {
+            let error = parse(input).unwrap_err();
+            assert!(!error.format_rust_style().is_empty());
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Salary validation for AI output
+//!
+//! Port of `lib/ai/output-parser-salary.ts`. Error strings match the TS
+//! validator so logs read the same on both sides.
+
+use serde_json::Value;
+use shared_types::{SalaryInfo, SalaryPeriod};
+
+/// Validate a raw `salary` value from parsed AI JSON
+///
+/// `null` means "no salary" and returns `Ok(None)`. The currency is
+/// normalized to uppercase and must be ISO 4217.
+pub fn validate_salary(salary: &Value) -> Result<Option<SalaryInfo>, String> {
+    let object = match salary {
+        Value::Null => return Ok(None),
+        Value::Object(object) => object,
+        _ => return Err("salary must be an object or null".to_string()),
+    };
+
+    let currency = match object.get("currency") {
+        Some(Value::String(currency)) if !currency.is_empty() => currency,
+        _ => return Err("salary.currency must be a non-empty string".to_string()),
+    };
+
+    let period = object
+        .get("period")
+        .and_then(Value::as_str)
+        .and_then(SalaryPeriod::parse)
+        .ok_or_else(|| {
+            let periods: Vec<&str> = SalaryPeriod::ALL.iter().map(|p| p.as_str()).collect();
+            format!("salary.period must be one of: {}", periods.join(", "))
+        })?;
+
+    let min = optional_number(object.get("min"), "salary.min")?;
+    let max = optional_number(object.get("max"), "salary.max")?;
+
+    let info = SalaryInfo {
+        min,
+        max,
+        currency: currency.to_uppercase(),
+        period,
+    };
+    info.validate().map_err(|_| {
+        format!("salary.currency must be ISO 4217 (USD, GBP, EUR, etc.). Got: {currency}")
+    })?;
+
+    Ok(Some(info))
+}
+
+/// JSON `undefined` has no equivalent, so an explicit `null` counts as absent
+fn optional_number(value: Option<&Value>, field: &str) -> Result<Option<f64>, String> {
+    match value {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Number(n)) => Ok(n.as_f64()),
+        Some(_) => Err(format!("{field} must be a number")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_valid_salary_is_normalized() {
+        let salary = validate_salary(&json!({
+            "min": 150000,
+            "max": 190000,
+            "currency": "usd",
+            "period": "annual"
+        }))
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(salary.currency, "USD");
+        assert_eq!(salary.period, SalaryPeriod::Annual);
+        assert_eq!(salary.min, Some(150000.0));
+    }
+
+    #[test]
+    fn test_null_salary_is_absent() {
+        assert_eq!(validate_salary(&Value::Null), Ok(None));
+    }
+
+    #[test]
+    fn test_invalid_salaries_rejected() {
+        let cases = [
+            (json!("100k"), "salary must be an object or null"),
+            (
+                json!({"period": "annual"}),
+                "salary.currency must be a non-empty string",
+            ),
+            (
+                json!({"currency": "DOGE", "period": "annual"}),
+                "salary.currency must be ISO 4217 (USD, GBP, EUR, etc.). Got: DOGE",
+            ),
+            (
+                json!({"currency": "GBP", "period": "yearly"}),
+                "salary.period must be one of: annual, monthly, hourly, daily, weekly",
+            ),
+            (
+                json!({"currency": "GBP", "period": "annual", "min": "90k"}),
+                "salary.min must be a number",
+            ),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(validate_salary(&input), Err(expected.to_string()));
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Search compendium bullets from the command line.
+//!
+//! Usage:
+//!   search_bullets <resume-data.json> <query> [--limit N] [--json]
+//!
+//! The query language is documented in `resume_core::search`, e.g.
+//! `tag:rust company:acme after:2020 priority>=7 "latency"`.
+
+use resume_core::search::{SearchIndex, SearchOptions};
+use shared_types::ResumeData;
+use std::fs;
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: search_bullets <resume-data.json> <query> [--limit N] [--json]";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let mut positional = Vec::new();
+    let mut options = SearchOptions::default();
+    let mut json = false;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            "--limit" => {
+                let value = args.next().ok_or(USAGE)?;
+                options.limit = value
+                    .parse()
+                    .map_err(|_| format!("--limit expects a number, got '{}'", value))?;
+            }
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ => positional.push(arg),
+        }
+    }
+    let [path, query] = <[String; 2]>::try_from(positional).map_err(|_| USAGE)?;
+
+    let data: ResumeData = serde_json::from_str(&fs::read_to_string(&path)?)
+        .map_err(|e| format!("Invalid JSON in {}: {}", path, e))?;
+    let query = query.parse()?;
+    let hits = SearchIndex::build(&data).search(&query, &options);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&hits)?);
+        return Ok(());
+    }
+
+    for hit in &hits {
+        let company = hit.company.name.as_deref().unwrap_or(&hit.company.id);
+        let position = hit.position.name.as_deref().unwrap_or(&hit.position.id);
+        println!(
+            "{:>6.2}  {}  ({} › {}, p{})",
+            hit.score, hit.bullet_id, company, position, hit.priority
+        );
+        println!("        {}", hit.description);
+    }
+    println!("{} result(s)", hits.len());
+    Ok(())
+}
@@ -0,0 +1,505 @@
+//! Derive a [`RoleProfile`] from a pasted job description
+//!
+//! Replaces hand-written `tag_weights` with a local, deterministic analysis:
+//!
+//! 1. Every tag in the compendium (plus caller-supplied aliases) becomes a
+//!    phrase that is matched against the job description.
+//! 2. Each matched tag is weighted TF-IDF style: sublinear term frequency in the
+//!    job description × inverse document frequency across bullets. Tags that
+//!    appear on every bullet say little about fit; rare tags say a lot.
+//! 3. Weights are scaled so the strongest tag is 1.0, matching the 0.0-1.0
+//!    range used by hand-written profiles.
+//!
+//! Job-description keywords that no bullet supports (neither by tag nor by
+//! description text) are reported separately, so gaps are visible before the
+//! resume is sent.
+
+use crate::text;
+use serde::{Deserialize, Serialize};
+use shared_types::{ResumeData, RoleProfile, ScoringWeights, Tag};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Options for [`derive_role_profile`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeriveOptions {
+    /// ID for the generated profile
+    pub profile_id: String,
+
+    /// Display name for the generated profile
+    pub profile_name: String,
+
+    /// Extra phrases that should count as a mention of a tag
+    /// (e.g. `"kubernetes" → ["k8s"]`)
+    #[serde(default)]
+    pub aliases: HashMap<Tag, Vec<String>>,
+
+    /// Keep at most this many tags (strongest first)
+    pub max_tags: usize,
+
+    /// Drop tags whose scaled weight falls below this threshold
+    pub min_weight: f32,
+}
+
+impl Default for DeriveOptions {
+    fn default() -> Self {
+        Self {
+            profile_id: "derived".to_string(),
+            profile_name: "Derived from job description".to_string(),
+            aliases: HashMap::new(),
+            max_tags: 15,
+            min_weight: 0.1,
+        }
+    }
+}
+
+/// Evidence for a single weighted tag
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagMatch {
+    /// Tag from the compendium vocabulary
+    pub tag: Tag,
+    /// Final weight written into the profile (0.0-1.0)
+    pub weight: f32,
+    /// Times the tag (or an alias) was mentioned in the job description
+    pub mentions: usize,
+    /// Bullets that carry the tag or mention it in their description
+    pub supporting_bullets: usize,
+}
+
+/// Job-description keyword with no supporting bullet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeywordGap {
+    /// Keyword as it first appeared in the job description (lowercased)
+    pub keyword: String,
+    /// Times it was mentioned
+    pub mentions: usize,
+}
+
+/// Result of [`derive_role_profile`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivedRoleProfile {
+    /// Ready-to-use profile with normalized scoring weights
+    pub profile: RoleProfile,
+    /// Tags that made it into the profile, strongest first
+    pub matches: Vec<TagMatch>,
+    /// Keywords the compendium has no evidence for, most mentioned first
+    pub unsupported_keywords: Vec<KeywordGap>,
+    /// Share of job-description keywords explained by supported tags (0.0-1.0)
+    pub coverage: f32,
+}
+
+/// Derive a role profile from job-description text
+///
+/// Deterministic: the same job description, compendium and options always
+/// produce the same profile.
+///
+/// Scoring weights lean on tag relevance when the job description is well
+/// covered by the compendium (up to 0.8), and fall back towards priority when
+/// most of it is unmatched (down to 0.5), since weak tag evidence should not
+/// outrank the author's own priorities.
+///
+/// # Example
+/// ```no_run
+/// use resume_core::job_description::{derive_role_profile, DeriveOptions};
+/// use shared_types::ResumeData;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let data: ResumeData = serde_json::from_str("{...}")?;
+/// let derived = derive_role_profile("Senior Rust engineer ...", &data, &DeriveOptions::default());
+/// for gap in &derived.unsupported_keywords {
+///     println!("No bullets mention '{}'", gap.keyword);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn derive_role_profile(
+    job_description: &str,
+    data: &ResumeData,
+    options: &DeriveOptions,
+) -> DerivedRoleProfile {
+    let corpus = BulletCorpus::from_resume(data);
+    let jd_terms = text::terms(job_description);
+    let mut consumed = vec![false; jd_terms.len()];
+
+    // Longer phrases first, so "distributed-systems" claims its terms before
+    // a bare "systems" tag can
+    let mut phrases: Vec<(Tag, Vec<String>)> = Vec::new();
+    for tag in &corpus.vocabulary {
+        phrases.push((tag.clone(), text::phrase_terms(tag)));
+        if let Some(aliases) = options.aliases.get(tag) {
+            for alias in aliases {
+                phrases.push((tag.clone(), text::phrase_terms(alias)));
+            }
+        }
+    }
+    phrases.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(&b.0)));
+
+    let mut mentions: BTreeMap<Tag, usize> = BTreeMap::new();
+    for (tag, phrase) in &phrases {
+        let count = text::count_phrase(&jd_terms, phrase, &mut consumed);
+        if count > 0 {
+            *mentions.entry(tag.clone()).or_default() += count;
+        }
+    }
+
+    // TF-IDF over bullets; unsupported tags are reported as gaps instead
+    let mut raw: Vec<(Tag, f32, usize, usize)> = Vec::new();
+    // Keyed by stemmed term so "streams" and "streaming" collapse into one gap
+    let mut gaps: BTreeMap<String, (String, usize)> = BTreeMap::new();
+    let mut supported_mentions = 0usize;
+    let mut unsupported_mentions = 0usize;
+    for (tag, &count) in &mentions {
+        let support = corpus.support(tag, options.aliases.get(tag));
+        if support == 0 {
+            gaps.entry(tag.clone())
+                .or_insert_with(|| (tag.clone(), 0))
+                .1 += count;
+            unsupported_mentions += count;
+            continue;
+        }
+        supported_mentions += count;
+        let tf = 1.0 + (count as f32).ln();
+        let idf = ((corpus.bullets.len() as f32 + 1.0) / (support as f32 + 1.0)).ln() + 1.0;
+        raw.push((tag.clone(), tf * idf, count, support));
+    }
+
+    // Leftover job-description keywords with no bullet evidence at all
+    let mut leftover_mentions = 0usize;
+    let raw_words = text::words(job_description);
+    for (i, term) in jd_terms.iter().enumerate() {
+        if consumed[i] {
+            continue;
+        }
+        let word = &raw_words[i];
+        if text::is_stopword(word) || !word.chars().any(char::is_alphabetic) {
+            continue;
+        }
+        leftover_mentions += 1;
+        if !corpus.terms.contains(term) {
+            gaps.entry(term.clone())
+                .or_insert_with(|| (word.clone(), 0))
+                .1 += 1;
+        }
+    }
+
+    let max_score = raw
+        .iter()
+        .map(|(_, score, _, _)| *score)
+        .fold(0.0, f32::max);
+    let mut matches: Vec<TagMatch> = raw
+        .into_iter()
+        .map(|(tag, score, mentions, supporting_bullets)| TagMatch {
+            tag,
+            weight: round2(score / max_score),
+            mentions,
+            supporting_bullets,
+        })
+        .filter(|m| m.weight >= options.min_weight)
+        .collect();
+    matches.sort_by(|a, b| {
+        b.weight
+            .total_cmp(&a.weight)
+            .then_with(|| b.mentions.cmp(&a.mentions))
+            .then_with(|| a.tag.cmp(&b.tag))
+    });
+    matches.truncate(options.max_tags);
+
+    let mut unsupported_keywords: Vec<KeywordGap> = gaps
+        .into_values()
+        .map(|(keyword, mentions)| KeywordGap { keyword, mentions })
+        .collect();
+    unsupported_keywords.sort_by(|a, b| {
+        b.mentions
+            .cmp(&a.mentions)
+            .then_with(|| a.keyword.cmp(&b.keyword))
+    });
+
+    let total_mentions = supported_mentions + unsupported_mentions + leftover_mentions;
+    let coverage = if total_mentions == 0 {
+        0.0
+    } else {
+        supported_mentions as f32 / total_mentions as f32
+    };
+
+    let tag_relevance = 0.5 + 0.3 * coverage;
+    let (scoring_weights, _) = ScoringWeights {
+        tag_relevance,
+        priority: 1.0 - tag_relevance,
+    }
+    .normalize();
+
+    let profile = RoleProfile {
+        id: options.profile_id.clone(),
+        name: options.profile_name.clone(),
+        description: Some(format!(
+            "Derived from job description ({} of {} keywords covered)",
+            supported_mentions, total_mentions
+        )),
+        tag_weights: matches.iter().map(|m| (m.tag.clone(), m.weight)).collect(),
+        scoring_weights,
+        summary_id: None,
+    };
+
+    DerivedRoleProfile {
+        profile,
+        matches,
+        unsupported_keywords,
+        coverage,
+    }
+}
+
+/// Round to two decimals so generated weights read like hand-written ones
+fn round2(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0
+}
+
+// ====================
+// INTERNAL TYPES
+// ====================
+
+/// Pre-tokenized view of every bullet in the compendium
+struct BulletCorpus {
+    /// Tags used anywhere in the compendium (company, position or bullet)
+    vocabulary: BTreeSet<Tag>,
+    /// Per bullet: tags and stemmed description terms
+    bullets: Vec<(Vec<Tag>, Vec<String>)>,
+    /// Union of all bullet description terms and tag terms
+    terms: BTreeSet<String>,
+}
+
+impl BulletCorpus {
+    fn from_resume(data: &ResumeData) -> Self {
+        let mut vocabulary = BTreeSet::new();
+        let mut bullets = Vec::new();
+        let mut terms = BTreeSet::new();
+
+        for company in &data.experience {
+            vocabulary.extend(company.tags.iter().cloned());
+            for position in &company.children {
+                vocabulary.extend(position.tags.iter().cloned());
+                for bullet in &position.children {
+                    vocabulary.extend(bullet.tags.iter().cloned());
+
+                    let bullet_terms = text::terms(&bullet.description);
+                    terms.extend(bullet_terms.iter().cloned());
+                    for tag in &bullet.tags {
+                        terms.extend(text::phrase_terms(tag));
+                    }
+                    bullets.push((bullet.tags.clone(), bullet_terms));
+                }
+            }
+        }
+
+        Self {
+            vocabulary,
+            bullets,
+            terms,
+        }
+    }
+
+    /// Number of bullets that carry `tag` or mention it (or an alias) in text
+    fn support(&self, tag: &str, aliases: Option<&Vec<String>>) -> usize {
+        let mut phrases = vec![text::phrase_terms(tag)];
+        if let Some(aliases) = aliases {
+            phrases.extend(aliases.iter().map(|a| text::phrase_terms(a)));
+        }
+
+        self.bullets
+            .iter()
+            .filter(|(tags, terms)| {
+                tags.iter().any(|t| t == tag)
+                    || phrases.iter().any(|p| text::contains_phrase(terms, p))
+            })
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{bullet, company, position, resume};
+
+    fn sample_resume() -> ResumeData {
+        let mut acme = company(
+            "acme",
+            vec![position(
+                "acme-eng",
+                vec![
+                    bullet(
+                        "b1",
+                        "Built a Rust ingestion service",
+                        &["rust", "backend"],
+                        5,
+                    ),
+                    bullet("b2", "Ran the on-call rotation", &["backend"], 5),
+                    bullet(
+                        "b3",
+                        "Scaled distributed systems to 10M users",
+                        &["distributed-systems", "backend"],
+                        5,
+                    ),
+                    bullet("b4", "Mentored four engineers", &["leadership"], 5),
+                ],
+            )],
+        );
+        acme.tags = vec!["startup".to_string()];
+        resume(vec![acme])
+    }
+
+    #[test]
+    fn test_derive_weights_matched_tags() {
+        let data = sample_resume();
+        let jd = "We need a Rust engineer for distributed systems. Rust is our core language.";
+        let derived = derive_role_profile(jd, &data, &DeriveOptions::default());
+
+        let weights = &derived.profile.tag_weights;
+        assert_eq!(weights.get("rust"), Some(&1.0));
+        assert!(weights.contains_key("distributed-systems"));
+        assert!(!weights.contains_key("leadership"));
+        assert!(!weights.contains_key("backend"));
+    }
+
+    #[test]
+    fn test_rare_tags_outweigh_common_tags() {
+        let data = sample_resume();
+        // One mention each: "backend" is on 3 bullets, "leadership" on 1
+        let jd = "Backend leadership";
+        let derived = derive_role_profile(jd, &data, &DeriveOptions::default());
+
+        let weights = &derived.profile.tag_weights;
+        assert!(weights["leadership"] > weights["backend"]);
+    }
+
+    #[test]
+    fn test_overlapping_tags_count_once() {
+        let mut data = sample_resume();
+        data.experience[0].children[0].children.push(bullet(
+            "b5",
+            "Owned the build systems",
+            &["systems"],
+            5,
+        ));
+        let jd = "Distributed systems experience. Distributed systems at scale.";
+        let derived = derive_role_profile(jd, &data, &DeriveOptions::default());
+
+        let mentions = |tag: &str| {
+            derived
+                .matches
+                .iter()
+                .find(|m| m.tag == tag)
+                .map(|m| m.mentions)
+        };
+        assert_eq!(mentions("distributed-systems"), Some(2));
+        assert_eq!(mentions("systems"), None);
+    }
+
+    #[test]
+    fn test_aliases_count_as_mentions() {
+        let data = sample_resume();
+        let mut options = DeriveOptions::default();
+        options.aliases.insert(
+            "leadership".to_string(),
+            vec!["people management".to_string()],
+        );
+
+        let derived = derive_role_profile("Strong people management skills", &data, &options);
+
+        assert_eq!(derived.profile.tag_weights.get("leadership"), Some(&1.0));
+        assert!(derived.unsupported_keywords.is_empty());
+    }
+
+    #[test]
+    fn test_unsupported_keywords_reported() {
+        let data = sample_resume();
+        let jd = "Rust and Kafka. Kafka streams at scale. Terraform a plus. Streaming!";
+        let derived = derive_role_profile(jd, &data, &DeriveOptions::default());
+
+        let gaps: Vec<&str> = derived
+            .unsupported_keywords
+            .iter()
+            .map(|g| g.keyword.as_str())
+            .collect();
+        assert_eq!(gaps[0], "kafka");
+        assert_eq!(derived.unsupported_keywords[0].mentions, 2);
+        assert!(gaps.contains(&"terraform"));
+        // Inflections collapse into the first spelling seen
+        assert!(gaps.contains(&"streams"));
+        assert!(!gaps.contains(&"streaming"));
+        // "scale" is supported by "Scaled distributed systems" via stemming
+        assert!(!gaps.contains(&"scale"));
+        assert!(!gaps.contains(&"rust"));
+    }
+
+    #[test]
+    fn test_tags_without_bullets_are_gaps() {
+        let data = sample_resume();
+        // "startup" is a company tag with no bullet support
+        let derived = derive_role_profile("Startup experience", &data, &DeriveOptions::default());
+
+        assert!(!derived.profile.tag_weights.contains_key("startup"));
+        assert_eq!(derived.unsupported_keywords[0].keyword, "startup");
+    }
+
+    #[test]
+    fn test_unsupported_tags_lower_coverage() {
+        let data = sample_resume();
+        let derived = derive_role_profile(
+            "Rust. Startup, startup, startup.",
+            &data,
+            &DeriveOptions::default(),
+        );
+
+        assert!((derived.coverage - 0.25).abs() < f32::EPSILON);
+        assert!((derived.profile.scoring_weights.tag_relevance - 0.575).abs() < 0.001);
+        assert_eq!(
+            derived.profile.description.as_deref(),
+            Some("Derived from job description (1 of 4 keywords covered)")
+        );
+    }
+
+    #[test]
+    fn test_scoring_weights_are_normalized() {
+        let data = sample_resume();
+        let derived = derive_role_profile("Rust", &data, &DeriveOptions::default());
+
+        assert!(derived.profile.scoring_weights.validate().is_ok());
+        assert!((derived.coverage - 1.0).abs() < f32::EPSILON);
+        assert!((derived.profile.scoring_weights.tag_relevance - 0.8).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_empty_job_description() {
+        let data = sample_resume();
+        let derived = derive_role_profile("", &data, &DeriveOptions::default());
+
+        assert!(derived.profile.tag_weights.is_empty());
+        assert!(derived.unsupported_keywords.is_empty());
+        assert!(derived.profile.scoring_weights.validate().is_ok());
+    }
+
+    #[test]
+    fn test_max_tags_limits_output() {
+        let data = sample_resume();
+        let options = DeriveOptions {
+            max_tags: 1,
+            ..DeriveOptions::default()
+        };
+        let derived = derive_role_profile("rust backend leadership", &data, &options);
+
+        assert_eq!(derived.profile.tag_weights.len(), 1);
+        assert_eq!(derived.matches.len(), 1);
+    }
+
+    #[test]
+    fn test_derivation_is_deterministic() {
+        let data = sample_resume();
+        let jd = "Rust backend leadership distributed systems kafka";
+        let first = derive_role_profile(jd, &data, &DeriveOptions::default());
+        let second = derive_role_profile(jd, &data, &DeriveOptions::default());
+
+        assert_eq!(first, second);
+    }
+}
@@ -0,0 +1,42 @@
+//! # resume-core
+//!
+//! Compendium analysis, scoring and bullet selection for Resumate.
+//!
+//! Works on [`shared_types::ResumeData`] and produces the inputs the rest of the
+//! pipeline consumes (role profiles, selected bullets). Everything in this
+//! crate is pure, deterministic Rust with no I/O, so it can run natively or
+//! inside WASM.
+//!
+//! # Modules
+//! - [`ai_output`]: validate LLM selection output, with rustc-style diagnostics
+//! - [`job_description`]: derive a `RoleProfile` from pasted job-description text
+//! - [`lint`]: rule-based content linter for bullets and summaries
+//! - [`scorer`]: pluggable `Scorer` trait (heuristic, external/AI, ensemble)
+//! - [`scoring`]: heuristic bullet scoring (port of the TS scorer)
+//! - [`search`]: BM25 full-text search and query language over bullets
+//! - [`selector`]: diversity-constrained selection, greedy or MMR
+//! - [`sensitivity`]: what-if sweeps over role profile weights
+//! - [`similarity`]: shingle-based near-duplicate detection
+//! - [`summary`]: pick the tagged summary candidate for a role profile
+//! - [`tag_analytics`]: per-tag usage, coverage and co-occurrence statistics
+//! - [`tag_learning`]: fit tag weights to accepted/rejected bullet feedback
+//! - [`text`]: tokenization and stemming shared by the analyzers
+//! - [`timeline`]: employment gaps, overlapping positions and tenure
+
+pub mod ai_output;
+pub mod job_description;
+pub mod lint;
+pub mod scorer;
+pub mod scoring;
+pub mod search;
+pub mod selector;
+pub mod sensitivity;
+pub mod similarity;
+pub mod summary;
+pub mod tag_analytics;
+pub mod tag_learning;
+pub mod text;
+pub mod timeline;
+
+#[cfg(test)]
+mod test_fixtures;
@@ -0,0 +1,788 @@
+//! Rule-based content linter
+//!
+//! Checks bullet descriptions and summaries for the usual resume-review
+//! comments. Findings carry a JSON-style path into the serialized
+//! `ResumeData` (`experience[0].children[1].children[2].description`) so the
+//! editor can jump straight to the field.
+//!
+//! | Rule                  | Applies to                | Default  |
+//! |-----------------------|---------------------------|----------|
+//! | `missing-metric`      | bullet descriptions       | info     |
+//! | `weak-opening`        | bullet descriptions       | warning  |
+//! | `overlong`            | bullet descriptions       | warning  |
+//! | `repeated-opening`    | bullets within a position | info     |
+//! | `first-person`        | descriptions, summaries   | warning  |
+//! | `trailing-punctuation`| bullets within a position | info     |
+//! | `near-duplicate`      | all bullet pairs          | warning  |
+//!
+//! Summaries are the resume summary plus `summary` on companies, positions
+//! and bullets, and position descriptions.
+
+use crate::similarity::Shingles;
+use crate::text;
+use serde::{Deserialize, Serialize};
+use shared_types::ResumeData;
+use std::collections::BTreeMap;
+
+/// Lint rule identifiers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LintRule {
+    /// No number, percentage or quantity word
+    MissingMetric,
+    /// Opens with a weak verb ("Helped", "Responsible for") or uses passive voice
+    WeakOpening,
+    /// Wraps to more than `max_lines` rendered lines
+    Overlong,
+    /// Same opening verb as an earlier bullet in the position
+    RepeatedOpening,
+    /// Uses I / me / my / we / our
+    FirstPerson,
+    /// Ends with different punctuation than most bullets in the position
+    TrailingPunctuation,
+    /// Too similar to another bullet
+    NearDuplicate,
+}
+
+impl LintRule {
+    pub const ALL: [LintRule; 7] = [
+        LintRule::MissingMetric,
+        LintRule::WeakOpening,
+        LintRule::Overlong,
+        LintRule::RepeatedOpening,
+        LintRule::FirstPerson,
+        LintRule::TrailingPunctuation,
+        LintRule::NearDuplicate,
+    ];
+
+    pub fn default_severity(self) -> Severity {
+        match self {
+            LintRule::MissingMetric | LintRule::RepeatedOpening | LintRule::TrailingPunctuation => {
+                Severity::Info
+            }
+            LintRule::WeakOpening
+            | LintRule::Overlong
+            | LintRule::FirstPerson
+            | LintRule::NearDuplicate => Severity::Warning,
+        }
+    }
+}
+
+/// How loudly a rule reports; `Off` disables it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Off,
+    Info,
+    Warning,
+    Error,
+}
+
+/// Linter configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LintConfig {
+    /// Per-rule overrides; unlisted rules use their default severity
+    pub severities: BTreeMap<LintRule, Severity>,
+    /// Estimated characters per rendered bullet line. Not derived from the
+    /// render options, so `overlong` line counts are approximate; lower it for
+    /// larger fonts, narrower paper or wider margins.
+    pub chars_per_line: usize,
+    /// Bullets wrapping to more lines than this are `overlong`
+    pub max_lines: usize,
+    /// Shingle Jaccard similarity at which two bullets are near-duplicates
+    pub duplicate_threshold: f32,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            severities: BTreeMap::new(),
+            // Roughly 10pt Liberation Serif across the classic template's 7in
+            // text width, less the list indent
+            chars_per_line: 110,
+            max_lines: 2,
+            duplicate_threshold: 0.6,
+        }
+    }
+}
+
+impl LintConfig {
+    pub fn severity(&self, rule: LintRule) -> Severity {
+        self.severities
+            .get(&rule)
+            .copied()
+            .unwrap_or_else(|| rule.default_severity())
+    }
+
+    fn enabled(&self, rule: LintRule) -> bool {
+        self.severity(rule) != Severity::Off
+    }
+}
+
+/// One lint finding
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintFinding {
+    pub rule: LintRule,
+    pub severity: Severity,
+    /// Path of the offending field in the serialized `ResumeData`
+    pub path: String,
+    /// ID of the company, position or bullet; `None` for the resume summary
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<String>,
+    pub message: String,
+    /// The other field involved (earlier duplicate or repeated opening)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub related_path: Option<String>,
+}
+
+/// Verbs that describe presence rather than impact
+const WEAK_OPENINGS: &[&str] = &[
+    "assisted",
+    "attempted",
+    "did",
+    "duties",
+    "handled",
+    "helped",
+    "involved",
+    "made",
+    "participated",
+    "responsible",
+    "supported",
+    "tasked",
+    "tried",
+    "utilized",
+    "worked",
+];
+
+const BE_VERBS: &[&str] = &["am", "are", "been", "being", "is", "was", "were"];
+
+const FIRST_PERSON: &[&str] = &[
+    "me",
+    "mine",
+    "my",
+    "myself",
+    "our",
+    "ours",
+    "ourselves",
+    "us",
+    "we",
+];
+
+/// Words that quantify without digits
+const QUANTITY_WORDS: &[&str] = &[
+    "billion",
+    "billions",
+    "double",
+    "doubled",
+    "doubling",
+    "dozen",
+    "dozens",
+    "eight",
+    "five",
+    "four",
+    "halved",
+    "hundred",
+    "hundreds",
+    "million",
+    "millions",
+    "nine",
+    "percent",
+    "seven",
+    "six",
+    "tenfold",
+    "ten",
+    "thousand",
+    "thousands",
+    "three",
+    "threefold",
+    "triple",
+    "tripled",
+    "twice",
+    "two",
+    "twofold",
+];
+
+/// A bullet description with where it lives
+struct BulletText<'a> {
+    order: usize,
+    path: String,
+    id: &'a str,
+    text: &'a str,
+}
+
+/// Lint a compendium
+///
+/// Findings come back in document order.
+pub fn lint_resume(data: &ResumeData, config: &LintConfig) -> Vec<LintFinding> {
+    let mut findings: Vec<(usize, LintFinding)> = Vec::new();
+    let mut order = 0;
+    let mut next = || {
+        order += 1;
+        order
+    };
+
+    let mut all_bullets: Vec<BulletText> = Vec::new();
+
+    if let Some(summary) = &data.summary {
+        lint_summary(summary, "summary", None, next(), config, &mut findings);
+    }
+
+    for (ci, company) in data.experience.iter().enumerate() {
+        let company_path = format!("experience[{}]", ci);
+        if let Some(summary) = &company.summary {
+            let path = format!("{}.summary", company_path);
+            lint_summary(
+                summary,
+                &path,
+                Some(&company.id),
+                next(),
+                config,
+                &mut findings,
+            );
+        }
+
+        for (pi, position) in company.children.iter().enumerate() {
+            let position_path = format!("{}.children[{}]", company_path, pi);
+            for (field, text) in [
+                ("summary", &position.summary),
+                ("description", &position.description),
+            ] {
+                if let Some(text) = text {
+                    let path = format!("{}.{}", position_path, field);
+                    lint_summary(
+                        text,
+                        &path,
+                        Some(&position.id),
+                        next(),
+                        config,
+                        &mut findings,
+                    );
+                }
+            }
+
+            let bullets: Vec<BulletText> = position
+                .children
+                .iter()
+                .enumerate()
+                .map(|(bi, bullet)| BulletText {
+                    order: next(),
+                    path: format!("{}.children[{}].description", position_path, bi),
+                    id: &bullet.id,
+                    text: &bullet.description,
+                })
+                .collect();
+
+            for bullet in &bullets {
+                lint_bullet(bullet, config, &mut findings);
+            }
+            lint_position(&bullets, config, &mut findings);
+
+            for (bi, bullet) in position.children.iter().enumerate() {
+                if let Some(summary) = &bullet.summary {
+                    let path = format!("{}.children[{}].summary", position_path, bi);
+                    lint_summary(
+                        summary,
+                        &path,
+                        Some(&bullet.id),
+                        next(),
+                        config,
+                        &mut findings,
+                    );
+                }
+            }
+            all_bullets.extend(bullets);
+        }
+    }
+
+    lint_duplicates(&all_bullets, config, &mut findings);
+
+    findings.sort_by_key(|(order, _)| *order);
+    findings.into_iter().map(|(_, finding)| finding).collect()
+}
+
+/// Number of lines `text` wraps to at `chars_per_line` (greedy word wrap)
+pub fn rendered_lines(text: &str, chars_per_line: usize) -> usize {
+    let width = chars_per_line.max(1);
+    let mut lines = 0;
+    let mut current = 0;
+    for word in text.split_whitespace() {
+        let len = word.chars().count();
+        if current == 0 {
+            lines += 1;
+            current = len;
+        } else if current + 1 + len <= width {
+            current += 1 + len;
+        } else {
+            lines += 1;
+            current = len;
+        }
+        // A single word longer than a line spills onto extra lines
+        if current > width {
+            lines += (current - 1) / width;
+            current = (current - 1) % width + 1;
+        }
+    }
+    lines
+}
+
+fn finding(
+    rule: LintRule,
+    config: &LintConfig,
+    path: &str,
+    item_id: Option<&str>,
+    message: String,
+) -> LintFinding {
+    LintFinding {
+        rule,
+        severity: config.severity(rule),
+        path: path.to_string(),
+        item_id: item_id.map(str::to_string),
+        message,
+        related_path: None,
+    }
+}
+
+fn lint_summary(
+    text: &str,
+    path: &str,
+    item_id: Option<&str>,
+    order: usize,
+    config: &LintConfig,
+    findings: &mut Vec<(usize, LintFinding)>,
+) {
+    if let Some(message) = check_first_person(text, config) {
+        findings.push((
+            order,
+            finding(LintRule::FirstPerson, config, path, item_id, message),
+        ));
+    }
+}
+
+fn lint_bullet(bullet: &BulletText, config: &LintConfig, findings: &mut Vec<(usize, LintFinding)>) {
+    let mut push = |rule: LintRule, message: String| {
+        findings.push((
+            bullet.order,
+            finding(rule, config, &bullet.path, Some(bullet.id), message),
+        ));
+    };
+
+    if bullet.text.trim().is_empty() {
+        return;
+    }
+    if config.enabled(LintRule::MissingMetric) && !is_quantified(bullet.text) {
+        push(
+            LintRule::MissingMetric,
+            "No quantified result (number, percentage or scale)".to_string(),
+        );
+    }
+    if config.enabled(LintRule::WeakOpening) {
+        if let Some(message) = check_weak_opening(bullet.text) {
+            push(LintRule::WeakOpening, message);
+        }
+    }
+    if config.enabled(LintRule::Overlong) {
+        let lines = rendered_lines(bullet.text, config.chars_per_line);
+        if lines > config.max_lines {
+            push(
+                LintRule::Overlong,
+                format!(
+                    "Wraps to about {} lines (limit {}, estimated at {} characters per line)",
+                    lines, config.max_lines, config.chars_per_line
+                ),
+            );
+        }
+    }
+    if let Some(message) = check_first_person(bullet.text, config) {
+        push(LintRule::FirstPerson, message);
+    }
+}
+
+/// Rules comparing bullets within one position
+fn lint_position(
+    bullets: &[BulletText],
+    config: &LintConfig,
+    findings: &mut Vec<(usize, LintFinding)>,
+) {
+    if config.enabled(LintRule::RepeatedOpening) {
+        let mut first_seen: BTreeMap<String, (&BulletText, String)> = BTreeMap::new();
+        for bullet in bullets {
+            let Some(opening) = text::words(bullet.text).into_iter().next() else {
+                continue;
+            };
+            match first_seen.get(&text::stem(&opening)) {
+                Some((earlier, earlier_opening)) => {
+                    let mut f = finding(
+                        LintRule::RepeatedOpening,
+                        config,
+                        &bullet.path,
+                        Some(bullet.id),
+                        format!(
+                            "Opens with '{}', like an earlier bullet ('{}')",
+                            opening, earlier_opening
+                        ),
+                    );
+                    f.related_path = Some(earlier.path.clone());
+                    findings.push((bullet.order, f));
+                }
+                None => {
+                    first_seen.insert(text::stem(&opening), (bullet, opening));
+                }
+            }
+        }
+    }
+
+    if config.enabled(LintRule::TrailingPunctuation) {
+        let styles: Vec<(&BulletText, bool)> = bullets
+            .iter()
+            .filter(|b| !b.text.trim().is_empty())
+            .map(|b| (b, ends_with_punctuation(b.text)))
+            .collect();
+        if styles.len() < 2 {
+            return;
+        }
+        let punctuated = styles.iter().filter(|(_, p)| *p).count();
+        let unpunctuated = styles.len() - punctuated;
+        // Majority wins; ties follow the first bullet
+        let expected = match punctuated.cmp(&unpunctuated) {
+            std::cmp::Ordering::Greater => true,
+            std::cmp::Ordering::Less => false,
+            std::cmp::Ordering::Equal => styles[0].1,
+        };
+        for (bullet, style) in styles {
+            if style != expected {
+                let message = if expected {
+                    "Missing trailing punctuation; other bullets in this position end with a period"
+                } else {
+                    "Trailing punctuation; other bullets in this position have none"
+                };
+                findings.push((
+                    bullet.order,
+                    finding(
+                        LintRule::TrailingPunctuation,
+                        config,
+                        &bullet.path,
+                        Some(bullet.id),
+                        message.to_string(),
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+/// Flag the later bullet of each near-duplicate pair
+fn lint_duplicates(
+    bullets: &[BulletText],
+    config: &LintConfig,
+    findings: &mut Vec<(usize, LintFinding)>,
+) {
+    if !config.enabled(LintRule::NearDuplicate) {
+        return;
+    }
+    let shingles: Vec<Shingles> = bullets.iter().map(|b| Shingles::new(b.text)).collect();
+    for (i, bullet) in bullets.iter().enumerate() {
+        let closest = (0..i)
+            .map(|j| (j, shingles[i].jaccard(&shingles[j])))
+            .filter(|(_, similarity)| *similarity >= config.duplicate_threshold)
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((j, similarity)) = closest {
+            let mut f = finding(
+                LintRule::NearDuplicate,
+                config,
+                &bullet.path,
+                Some(bullet.id),
+                format!(
+                    "{:.0}% similar to bullet '{}'",
+                    similarity * 100.0,
+                    bullets[j].id
+                ),
+            );
+            f.related_path = Some(bullets[j].path.clone());
+            findings.push((bullet.order, f));
+        }
+    }
+}
+
+fn is_quantified(text: &str) -> bool {
+    text.chars().any(|c| c.is_ascii_digit())
+        || text::words(text)
+            .iter()
+            .any(|w| QUANTITY_WORDS.contains(&w.as_str()))
+}
+
+fn check_weak_opening(text: &str) -> Option<String> {
+    let words = text::words(text);
+    let opening = words.first()?;
+    if WEAK_OPENINGS.contains(&opening.as_str()) {
+        return Some(format!(
+            "Opens with weak verb '{}'; lead with what you did",
+            first_word(text)
+        ));
+    }
+
+    // "was implemented", "were quickly adopted"
+    for (i, word) in words.iter().enumerate() {
+        if !BE_VERBS.contains(&word.as_str()) {
+            continue;
+        }
+        let participle = words[i + 1..]
+            .iter()
+            .take(2)
+            .find(|w| w.len() > 3 && (w.ends_with("ed") || w.ends_with("en")));
+        if let Some(participle) = participle {
+            return Some(format!("Passive voice ('{} {}')", word, participle));
+        }
+    }
+    None
+}
+
+fn check_first_person(text: &str, config: &LintConfig) -> Option<String> {
+    if !config.enabled(LintRule::FirstPerson) {
+        return None;
+    }
+    // "I" must be capitalised; "i" is more likely a list marker or typo.
+    // Other all-caps tokens are acronyms ("US", "ME").
+    let pronoun = text
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .find_map(|token| {
+            let bare = token.split('\'').next().unwrap_or(token);
+            let acronym = bare.len() > 1 && bare.chars().all(|c| c.is_ascii_uppercase());
+            if bare == "I" || (!acronym && FIRST_PERSON.contains(&bare.to_lowercase().as_str())) {
+                Some(bare.to_string())
+            } else {
+                None
+            }
+        })?;
+    Some(format!(
+        "First-person pronoun '{}'; resumes imply the subject",
+        pronoun
+    ))
+}
+
+fn first_word(text: &str) -> &str {
+    text.split_whitespace().next().unwrap_or("")
+}
+
+fn ends_with_punctuation(text: &str) -> bool {
+    text.trim_end().ends_with(['.', '!', '?'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{bullet, company, position, resume};
+
+    fn rules_for(findings: &[LintFinding], path_suffix: &str) -> Vec<LintRule> {
+        findings
+            .iter()
+            .filter(|f| f.path.ends_with(path_suffix))
+            .map(|f| f.rule)
+            .collect()
+    }
+
+    fn single_position(descriptions: &[&str]) -> ResumeData {
+        let bullets = descriptions
+            .iter()
+            .enumerate()
+            .map(|(i, d)| bullet(&format!("b{}", i), d, &[], 5))
+            .collect();
+        resume(vec![company("acme", vec![position("acme-eng", bullets)])])
+    }
+
+    #[test]
+    fn test_clean_bullet_has_no_findings() {
+        let data =
+            single_position(&["Cut p99 latency by 40% by rewriting the cache layer in Rust."]);
+        assert!(lint_resume(&data, &LintConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_missing_metric_and_quantity_words() {
+        assert!(!is_quantified("Improved the build system"));
+        assert!(is_quantified("Cut build time by 30%"));
+        assert!(is_quantified("Doubled throughput of the ingest service"));
+        assert!(is_quantified("Served millions of requests"));
+    }
+
+    #[test]
+    fn test_weak_opening_and_passive_voice() {
+        assert!(check_weak_opening("Helped migrate services to Kubernetes")
+            .unwrap()
+            .contains("'Helped'"));
+        assert!(check_weak_opening("Responsible for the payments API").is_some());
+        assert_eq!(
+            check_weak_opening("The new pipeline was quickly adopted by 12 teams").unwrap(),
+            "Passive voice ('was adopted')"
+        );
+        assert_eq!(check_weak_opening("Led a team of 5 engineers"), None);
+    }
+
+    #[test]
+    fn test_rendered_lines_wraps_on_words() {
+        assert_eq!(rendered_lines("", 10), 0);
+        assert_eq!(rendered_lines("one two", 10), 1);
+        assert_eq!(rendered_lines("alpha beta gamma", 10), 2);
+        // Overlong single word spills across lines
+        assert_eq!(rendered_lines(&"x".repeat(25), 10), 3);
+    }
+
+    #[test]
+    fn test_overlong_bullet() {
+        let long = "Built 3 things ".repeat(20);
+        let data = single_position(&[&long]);
+        let findings = lint_resume(&data, &LintConfig::default());
+        let overlong = findings
+            .iter()
+            .find(|f| f.rule == LintRule::Overlong)
+            .unwrap();
+        assert_eq!(
+            overlong.message,
+            "Wraps to about 3 lines (limit 2, estimated at 110 characters per line)"
+        );
+        assert_eq!(
+            overlong.path,
+            "experience[0].children[0].children[0].description"
+        );
+        assert_eq!(overlong.item_id.as_deref(), Some("b0"));
+    }
+
+    #[test]
+    fn test_repeated_opening_within_position() {
+        let data = single_position(&[
+            "Led migration of 40 services",
+            "Led the on-call rotation for 6 teams",
+            "Designed 3 APIs",
+        ]);
+        let findings = lint_resume(&data, &LintConfig::default());
+        let repeated: Vec<&LintFinding> = findings
+            .iter()
+            .filter(|f| f.rule == LintRule::RepeatedOpening)
+            .collect();
+        assert_eq!(repeated.len(), 1);
+        assert_eq!(
+            repeated[0].related_path.as_deref(),
+            Some("experience[0].children[0].children[0].description")
+        );
+        assert!(repeated[0].path.ends_with("children[1].description"));
+    }
+
+    #[test]
+    fn test_first_person_in_bullets_and_summaries() {
+        let mut data = single_position(&["I shipped 4 releases"]);
+        data.summary = Some("Engineer who loves my craft".to_string());
+        data.experience[0].children[0].description = Some("We built 2 products".to_string());
+
+        let findings = lint_resume(&data, &LintConfig::default());
+        assert_eq!(rules_for(&findings, "summary"), vec![LintRule::FirstPerson]);
+        assert_eq!(findings[0].path, "summary");
+        assert_eq!(findings[0].item_id, None);
+        assert!(findings
+            .iter()
+            .any(|f| f.path == "experience[0].children[0].description"
+                && f.item_id.as_deref() == Some("acme-eng")));
+        assert!(rules_for(&findings, "children[0].description").contains(&LintRule::FirstPerson));
+
+        assert_eq!(
+            check_first_person("Helped us ship 3 launches", &LintConfig::default()).as_deref(),
+            Some("First-person pronoun 'us'; resumes imply the subject")
+        );
+
+        // "US" and "i" aren't pronouns here
+        assert_eq!(
+            check_first_person("Expanded to US markets", &LintConfig::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_trailing_punctuation_follows_majority() {
+        let data = single_position(&[
+            "Shipped 4 releases.",
+            "Cut costs by 20%.",
+            "Hired 3 engineers",
+        ]);
+        let findings = lint_resume(&data, &LintConfig::default());
+        let punctuation: Vec<&str> = findings
+            .iter()
+            .filter(|f| f.rule == LintRule::TrailingPunctuation)
+            .map(|f| f.path.as_str())
+            .collect();
+        assert_eq!(
+            punctuation,
+            vec!["experience[0].children[0].children[2].description"]
+        );
+    }
+
+    #[test]
+    fn test_near_duplicates_across_positions() {
+        let data = resume(vec![
+            company(
+                "acme",
+                vec![position(
+                    "acme-eng",
+                    vec![bullet(
+                        "a1",
+                        "Migrated the payments platform to Kubernetes, cutting deploy time by 80%",
+                        &[],
+                        5,
+                    )],
+                )],
+            ),
+            company(
+                "rival",
+                vec![position(
+                    "rival-eng",
+                    vec![bullet(
+                        "r1",
+                        "Migrated the payments platform to Kubernetes, cutting deploy time by 75%",
+                        &[],
+                        5,
+                    )],
+                )],
+            ),
+        ]);
+
+        let findings = lint_resume(&data, &LintConfig::default());
+        let duplicate = findings
+            .iter()
+            .find(|f| f.rule == LintRule::NearDuplicate)
+            .unwrap();
+        assert_eq!(duplicate.item_id.as_deref(), Some("r1"));
+        assert_eq!(
+            duplicate.related_path.as_deref(),
+            Some("experience[0].children[0].children[0].description")
+        );
+    }
+
+    #[test]
+    fn test_severity_overrides_and_off() {
+        let data = single_position(&["Helped with the build system"]);
+        let mut config = LintConfig::default();
+        config
+            .severities
+            .insert(LintRule::WeakOpening, Severity::Error);
+        config
+            .severities
+            .insert(LintRule::MissingMetric, Severity::Off);
+
+        let findings = lint_resume(&data, &config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule, LintRule::WeakOpening);
+        assert_eq!(findings[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_config_deserializes_rule_names() {
+        let config: LintConfig = serde_json::from_str(
+            r#"{"severities": {"missing-metric": "off", "near-duplicate": "error"}, "maxLines": 3}"#,
+        )
+        .unwrap();
+        assert_eq!(config.severity(LintRule::MissingMetric), Severity::Off);
+        assert_eq!(config.severity(LintRule::NearDuplicate), Severity::Error);
+        assert_eq!(config.severity(LintRule::Overlong), Severity::Warning);
+        assert_eq!(config.max_lines, 3);
+        assert_eq!(config.chars_per_line, 110);
+    }
+}
@@ -0,0 +1,332 @@
+//! Pluggable bullet scorers
+//!
+//! The heuristic path (`/api/resume/select`) and the AI path
+//! (`/api/resume/ai-select`) produce scores in different ways. The [`Scorer`]
+//! trait puts both behind one interface so they can be combined:
+//!
+//! - [`HeuristicScorer`]: tag relevance × priority × hierarchy multipliers
+//! - [`ExternalScores`]: precomputed `(bullet_id, score)` pairs, e.g. from an LLM
+//! - [`EnsembleScorer`]: weighted blend of any scorers, so AI scores can refine
+//!   the heuristic instead of replacing it
+//!
+//! Scored candidates feed straight into [`crate::selector`].
+
+use crate::scoring;
+use serde::{Deserialize, Serialize};
+use shared_types::{Bullet, Company, Position, ResumeData, RoleProfile, ScoredBullet};
+use std::collections::HashMap;
+
+/// A bullet in its company/position context
+#[derive(Debug, Clone, Copy)]
+pub struct Candidate<'a> {
+    pub bullet: &'a Bullet,
+    pub position: &'a Position,
+    pub company: &'a Company,
+}
+
+/// Assigns relevance scores to bullets
+pub trait Scorer {
+    /// Score a candidate, or `None` if this scorer has no opinion on it
+    fn score(&self, candidate: &Candidate) -> Option<f32>;
+
+    /// Largest score this scorer can return
+    ///
+    /// Used by [`EnsembleScorer`] to put members on a common 0.0-1.0 scale.
+    fn max_score(&self) -> f32 {
+        1.0
+    }
+}
+
+/// Heuristic scorer driven by a [`RoleProfile`]
+///
+/// See [`scoring::score_bullet`] for the formula.
+#[derive(Debug, Clone, Copy)]
+pub struct HeuristicScorer<'a> {
+    role_profile: &'a RoleProfile,
+}
+
+impl<'a> HeuristicScorer<'a> {
+    pub fn new(role_profile: &'a RoleProfile) -> Self {
+        Self { role_profile }
+    }
+}
+
+impl Scorer for HeuristicScorer<'_> {
+    fn score(&self, candidate: &Candidate) -> Option<f32> {
+        Some(scoring::score_bullet(
+            candidate.bullet,
+            candidate.position,
+            candidate.company,
+            self.role_profile,
+        ))
+    }
+
+    /// Base score ≤ 1.0 × company ≤ 1.2 × position ≤ 1.2 × 1.1
+    fn max_score(&self) -> f32 {
+        1.2 * 1.2 * 1.1
+    }
+}
+
+/// Precomputed scores keyed by bullet ID
+///
+/// Bullets without an entry are unscored (`None`), matching the TS
+/// `selectBulletsWithConstraints` behaviour of skipping them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExternalScores {
+    scores: HashMap<String, f32>,
+}
+
+impl ExternalScores {
+    /// Build from `(bullet_id, score)` pairs; later duplicates win
+    pub fn from_pairs<I, S>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (S, f32)>,
+        S: Into<String>,
+    {
+        Self {
+            scores: pairs
+                .into_iter()
+                .map(|(id, score)| (id.into(), score))
+                .collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.scores.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+}
+
+impl Scorer for ExternalScores {
+    fn score(&self, candidate: &Candidate) -> Option<f32> {
+        self.scores.get(&candidate.bullet.id).copied()
+    }
+}
+
+/// Weighted blend of several scorers
+///
+/// Each member's score is divided by its [`Scorer::max_score`], then the
+/// ensemble takes the weighted mean over the members that scored the bullet.
+/// A bullet the AI skipped therefore keeps its heuristic score rather than
+/// dropping to zero. Output is on a 0.0-1.0 scale.
+#[derive(Default)]
+pub struct EnsembleScorer<'a> {
+    members: Vec<(Box<dyn Scorer + 'a>, f32)>,
+}
+
+impl<'a> EnsembleScorer<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a member with a non-negative weight (weights need not sum to 1.0)
+    pub fn with(mut self, scorer: impl Scorer + 'a, weight: f32) -> Self {
+        self.members.push((Box::new(scorer), weight.max(0.0)));
+        self
+    }
+
+    /// Heuristic scores for `role_profile` refined by `external` (e.g. AI) scores
+    pub fn refine(
+        role_profile: &'a RoleProfile,
+        external: ExternalScores,
+        weights: EnsembleWeights,
+    ) -> Self {
+        Self::new()
+            .with(HeuristicScorer::new(role_profile), weights.heuristic)
+            .with(external, weights.external)
+    }
+}
+
+/// Member weights for [`EnsembleScorer::refine`]
+///
+/// Relative, so they need not sum to 1.0. The default lets the external
+/// scores lead while the heuristic still ranks the bullets they skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EnsembleWeights {
+    pub heuristic: f32,
+    pub external: f32,
+}
+
+impl Default for EnsembleWeights {
+    fn default() -> Self {
+        Self {
+            heuristic: 0.3,
+            external: 0.7,
+        }
+    }
+}
+
+impl Scorer for EnsembleScorer<'_> {
+    fn score(&self, candidate: &Candidate) -> Option<f32> {
+        let mut weighted = 0.0;
+        let mut total_weight = 0.0;
+
+        for (scorer, weight) in &self.members {
+            if *weight == 0.0 {
+                continue;
+            }
+            if let Some(score) = scorer.score(candidate) {
+                let max = scorer.max_score();
+                let normalized = if max > 0.0 { score / max } else { score };
+                weighted += normalized * weight;
+                total_weight += weight;
+            }
+        }
+
+        (total_weight > 0.0).then(|| weighted / total_weight)
+    }
+}
+
+/// Score every bullet (and position description) with `scorer`
+///
+/// Unscored candidates are skipped. Sorted by score descending; ties keep
+/// compendium order.
+pub fn score_candidates(data: &ResumeData, scorer: &dyn Scorer) -> Vec<ScoredBullet> {
+    let mut scored = Vec::new();
+
+    for company in &data.experience {
+        for position in &company.children {
+            let description = scoring::position_description_bullet(position);
+            let candidates = description.iter().chain(position.children.iter());
+
+            for bullet in candidates {
+                let candidate = Candidate {
+                    bullet,
+                    position,
+                    company,
+                };
+                if let Some(score) = scorer.score(&candidate) {
+                    scored.push(scoring::scored_bullet(
+                        bullet.clone(),
+                        score,
+                        position,
+                        company,
+                    ));
+                }
+            }
+        }
+    }
+
+    scored.sort_by(|a, b| b.score.total_cmp(&a.score));
+    scored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{bullet, company, position, profile, resume};
+
+    fn sample_resume() -> ResumeData {
+        resume(vec![company(
+            "acme",
+            vec![position(
+                "acme-eng",
+                vec![
+                    bullet("rust", "Rust work", &["rust"], 9),
+                    bullet("talk", "Conference talk", &["speaking"], 6),
+                    bullet("misc", "Other work", &[], 3),
+                ],
+            )],
+        )])
+    }
+
+    fn ids(scored: &[ScoredBullet]) -> Vec<&str> {
+        scored.iter().map(|s| s.bullet.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_heuristic_scorer_matches_score_all() {
+        let data = sample_resume();
+        let role = profile(&[("rust", 1.0)]);
+
+        let via_trait = score_candidates(&data, &HeuristicScorer::new(&role));
+        let direct = scoring::score_all(&data, &role);
+
+        assert_eq!(via_trait, direct);
+    }
+
+    #[test]
+    fn test_external_scores_skip_unscored_bullets() {
+        let data = sample_resume();
+        let scores = ExternalScores::from_pairs([("talk", 0.9), ("misc", 0.2)]);
+
+        let scored = score_candidates(&data, &scores);
+        assert_eq!(ids(&scored), vec!["talk", "misc"]);
+        assert_eq!(scored[0].score, 0.9);
+    }
+
+    #[test]
+    fn test_ensemble_lets_ai_refine_heuristic() {
+        let data = sample_resume();
+        let role = profile(&[("rust", 1.0)]);
+        let ai = ExternalScores::from_pairs([("rust", 0.1), ("talk", 1.0)]);
+
+        let heuristic_only = score_candidates(&data, &HeuristicScorer::new(&role));
+        assert_eq!(ids(&heuristic_only), vec!["rust", "talk", "misc"]);
+
+        let ensemble = EnsembleScorer::new()
+            .with(HeuristicScorer::new(&role), 0.3)
+            .with(ai, 0.7);
+        let blended = score_candidates(&data, &ensemble);
+
+        // AI boosts "talk" above "rust"; "misc" keeps its heuristic score
+        assert_eq!(ids(&blended), vec!["talk", "rust", "misc"]);
+        assert!(blended.iter().all(|s| (0.0..=1.0).contains(&s.score)));
+    }
+
+    #[test]
+    fn test_refine_uses_heuristic_and_external_weights() {
+        let data = sample_resume();
+        let role = profile(&[("rust", 1.0)]);
+        let ai = || ExternalScores::from_pairs([("rust", 0.1), ("talk", 1.0)]);
+
+        let refined = EnsembleScorer::refine(&role, ai(), EnsembleWeights::default());
+        assert_eq!(
+            ids(&score_candidates(&data, &refined)),
+            vec!["talk", "rust", "misc"]
+        );
+
+        let heuristic_led = EnsembleWeights {
+            heuristic: 1.0,
+            external: 0.1,
+        };
+        let refined = EnsembleScorer::refine(&role, ai(), heuristic_led);
+        assert_eq!(
+            ids(&score_candidates(&data, &refined)),
+            vec!["rust", "talk", "misc"]
+        );
+    }
+
+    #[test]
+    fn test_ensemble_normalizes_member_scales() {
+        let data = sample_resume();
+        let role = profile(&[("rust", 1.0)]);
+        let heuristic = HeuristicScorer::new(&role);
+
+        let ensemble = EnsembleScorer::new().with(heuristic, 1.0);
+        let candidate = Candidate {
+            bullet: &data.experience[0].children[0].children[0],
+            position: &data.experience[0].children[0],
+            company: &data.experience[0],
+        };
+
+        let raw = heuristic.score(&candidate).unwrap();
+        let normalized = ensemble.score(&candidate).unwrap();
+        assert!((normalized - raw / heuristic.max_score()).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_ensemble_without_opinions_is_unscored() {
+        let data = sample_resume();
+        let empty = EnsembleScorer::new().with(ExternalScores::default(), 1.0);
+        assert!(score_candidates(&data, &empty).is_empty());
+
+        let zero_weight =
+            EnsembleScorer::new().with(ExternalScores::from_pairs([("rust", 1.0)]), 0.0);
+        assert!(score_candidates(&data, &zero_weight).is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+//! Heuristic bullet scoring
+//!
+//! Rust port of `app/api/resume/select/scoring.ts`. Scores every bullet (plus
+//! each position description, treated as a synthetic bullet) against a
+//! [`RoleProfile`]:
+//!
+//! ```text
+//! base  = tag_relevance × weights.tag_relevance + (priority / 10) × weights.priority
+//! score = base × company_multiplier × position_multiplier
+//! ```
+
+use crate::scorer::{self, HeuristicScorer};
+use shared_types::{Bullet, Company, Position, ResumeData, RoleProfile, ScoredBullet, Tag};
+use std::collections::HashMap;
+
+/// Score a single bullet against a role profile
+///
+/// Base score = tag relevance × weight + priority × weight, multiplied by the
+/// company and position multipliers.
+pub fn score_bullet(
+    bullet: &Bullet,
+    position: &Position,
+    company: &Company,
+    role_profile: &RoleProfile,
+) -> f32 {
+    let weights = &role_profile.scoring_weights;
+
+    let tag_score = calculate_tag_relevance(&bullet.tags, &role_profile.tag_weights);
+    let priority_score = bullet.priority as f32 / 10.0;
+    let base_score = tag_score * weights.tag_relevance + priority_score * weights.priority;
+
+    base_score
+        * company_multiplier(company)
+        * position_multiplier(position, &role_profile.tag_weights)
+}
+
+/// Average weight of the tags that appear in the profile's weight map
+///
+/// Returns 0.0 when no tags match (or the item is untagged).
+pub fn calculate_tag_relevance(tags: &[Tag], tag_weights: &HashMap<Tag, f32>) -> f32 {
+    let matched: Vec<f32> = tags
+        .iter()
+        .filter_map(|tag| tag_weights.get(tag).copied())
+        .collect();
+
+    if matched.is_empty() {
+        return 0.0;
+    }
+
+    matched.iter().sum::<f32>() / matched.len() as f32
+}
+
+/// Map company priority (1-10) to a 0.8-1.2 multiplier
+///
+/// A zero (unset) priority is neutral (1.0), matching the TS scorer.
+pub fn company_multiplier(company: &Company) -> f32 {
+    if company.priority == 0 {
+        return 1.0;
+    }
+    0.8 + (company.priority as f32 / 10.0) * 0.4
+}
+
+/// Position multiplier = priority multiplier × tag multiplier
+///
+/// Priority maps to 0.8-1.2, tag relevance to 0.9-1.1 (1.0 when untagged).
+pub fn position_multiplier(position: &Position, tag_weights: &HashMap<Tag, f32>) -> f32 {
+    let priority_multiplier = 0.8 + (position.priority as f32 / 10.0) * 0.4;
+
+    let tag_multiplier = if position.tags.is_empty() {
+        1.0
+    } else {
+        0.9 + calculate_tag_relevance(&position.tags, tag_weights) * 0.2
+    };
+
+    priority_multiplier * tag_multiplier
+}
+
+/// Priority of a position-description bullet when the position has none
+const DESCRIPTION_FALLBACK_PRIORITY: u8 = 5;
+
+/// Synthesize a bullet from a position description
+///
+/// Position descriptions compete with regular bullets for selection. The
+/// synthetic ID is `{position_id}-description` and an unset (zero) priority
+/// falls back to 5, both matching the TS scorer.
+pub fn position_description_bullet(position: &Position) -> Option<Bullet> {
+    let description = position.description.as_ref()?;
+
+    Some(Bullet {
+        id: format!("{}-description", position.id),
+        name: None,
+        location: None,
+        date_start: None,
+        date_end: None,
+        summary: None,
+        description: description.clone(),
+        tags: position.tags.clone(),
+        priority: if position.priority == 0 {
+            DESCRIPTION_FALLBACK_PRIORITY
+        } else {
+            position.priority
+        },
+        link: None,
+    })
+}
+
+/// Wrap a bullet with its company/position context
+pub fn scored_bullet(
+    bullet: Bullet,
+    score: f32,
+    position: &Position,
+    company: &Company,
+) -> ScoredBullet {
+    ScoredBullet {
+        bullet,
+        score,
+        company_id: company.id.clone(),
+        company_name: company.name.clone(),
+        company_description: company.description.clone(),
+        company_link: company.link.clone(),
+        company_date_start: company.date_start.clone(),
+        company_date_end: company.date_end.clone(),
+        company_location: company.location.clone(),
+        position_id: position.id.clone(),
+        position_name: position.name.clone(),
+        position_description: position.description.clone(),
+        position_date_start: position.date_start.clone(),
+        position_date_end: position.date_end.clone(),
+    }
+}
+
+/// Score every bullet in the compendium, sorted by score descending
+///
+/// Position descriptions are included as synthetic bullets (see
+/// [`position_description_bullet`]). Ties keep compendium order.
+pub fn score_all(data: &ResumeData, role_profile: &RoleProfile) -> Vec<ScoredBullet> {
+    scorer::score_candidates(data, &HeuristicScorer::new(role_profile))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{bullet, company, position, profile};
+
+    #[test]
+    fn test_tag_relevance_averages_matched_tags() {
+        let weights = HashMap::from([("rust".to_string(), 1.0), ("go".to_string(), 0.5)]);
+        let tags = vec!["rust".to_string(), "go".to_string(), "cooking".to_string()];
+
+        assert!((calculate_tag_relevance(&tags, &weights) - 0.75).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_tag_relevance_zero_without_matches() {
+        let weights = HashMap::from([("rust".to_string(), 1.0)]);
+
+        assert_eq!(calculate_tag_relevance(&[], &weights), 0.0);
+        assert_eq!(calculate_tag_relevance(&["go".to_string()], &weights), 0.0);
+    }
+
+    #[test]
+    fn test_multiplier_ranges() {
+        let mut co = company("acme", vec![]);
+        co.priority = 10;
+        assert!((company_multiplier(&co) - 1.2).abs() < 0.0001);
+        co.priority = 1;
+        assert!((company_multiplier(&co) - 0.84).abs() < 0.0001);
+        co.priority = 0;
+        assert!((company_multiplier(&co) - 1.0).abs() < 0.0001);
+
+        let mut pos = position("p", vec![]);
+        pos.priority = 10;
+        pos.tags = vec!["rust".to_string()];
+        let weights = HashMap::from([("rust".to_string(), 1.0)]);
+        assert!((position_multiplier(&pos, &weights) - 1.2 * 1.1).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_score_bullet_prefers_relevant_tags() {
+        let co = company("acme", vec![]);
+        let pos = position("p", vec![]);
+        let role = profile(&[("rust", 1.0)]);
+
+        let relevant = bullet("b1", "Rust work", &["rust"], 5);
+        let irrelevant = bullet("b2", "Other work", &["cooking"], 5);
+
+        assert!(
+            score_bullet(&relevant, &pos, &co, &role) > score_bullet(&irrelevant, &pos, &co, &role)
+        );
+    }
+
+    #[test]
+    fn test_score_all_includes_position_descriptions() {
+        let mut pos = position("acme-eng", vec![bullet("b1", "Rust work", &["rust"], 5)]);
+        pos.description = Some("Owned the platform".to_string());
+        let data = crate::test_fixtures::resume(vec![company("acme", vec![pos])]);
+
+        let scored = score_all(&data, &profile(&[("rust", 1.0)]));
+
+        assert_eq!(scored.len(), 2);
+        assert!(scored
+            .iter()
+            .any(|s| s.bullet.id == "acme-eng-description" && s.position_id == "acme-eng"));
+        assert!(scored.windows(2).all(|w| w[0].score >= w[1].score));
+    }
+
+    #[test]
+    fn test_description_bullet_priority_fallback() {
+        let mut pos = position("p", vec![]);
+        pos.description = Some("Owned the platform".to_string());
+        pos.priority = 0;
+        assert_eq!(position_description_bullet(&pos).unwrap().priority, 5);
+        pos.priority = 9;
+        assert_eq!(position_description_bullet(&pos).unwrap().priority, 9);
+    }
+}
@@ -0,0 +1,732 @@
+//! Full-text search over compendium bullets
+//!
+//! Every bullet (and every position description, under its synthetic
+//! `{position_id}-description` ID) is a document made of three weighted
+//! fields: the bullet's own text, its position and its company. Free-text
+//! terms are ranked with BM25 over those fields; structured filters narrow
+//! the candidates first.
+//!
+//! # Query language
+//!
+//! Whitespace-separated clauses, all of which must hold:
+//!
+//! | Clause              | Matches                                            |
+//! |---------------------|----------------------------------------------------|
+//! | `kafka`             | ranks by BM25; at least one term must match        |
+//! | `"kafka migration"` | the exact (stemmed) phrase in one field            |
+//! | `tag:rust`          | bullets carrying the tag                           |
+//! | `company:acme`      | company ID, or a substring of the company name     |
+//! | `after:2020`        | bullets still running on or after the date         |
+//! | `before:2019-06`    | bullets started on or before the date              |
+//! | `priority>=7`       | also `>`, `<`, `<=`, `=`                           |
+//!
+//! Filter values can be quoted (`company:"Big Corp"`). Terms and phrases are
+//! stemmed with [`crate::text`], so "migrations" finds "migration".
+
+use crate::scoring;
+use crate::text;
+use crate::timeline::{parse_month, Month};
+use serde::{Deserialize, Serialize};
+use shared_types::{Bullet, Company, Position, ResumeData, Tag};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+/// Relative weight of the bullet's own text, its position and its company
+const FIELD_WEIGHTS: [f32; 3] = [1.0, 0.4, 0.2];
+
+/// Comparison operator for `priority` clauses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Comparison {
+    Lt,
+    Le,
+    Eq,
+    Ge,
+    Gt,
+}
+
+impl Comparison {
+    fn holds(self, value: u8, bound: u8) -> bool {
+        match self {
+            Comparison::Lt => value < bound,
+            Comparison::Le => value <= bound,
+            Comparison::Eq => value == bound,
+            Comparison::Ge => value >= bound,
+            Comparison::Gt => value > bound,
+        }
+    }
+}
+
+/// A parsed search query
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Query {
+    /// Stemmed free-text terms
+    pub terms: Vec<String>,
+    /// Quoted phrases, as written
+    pub phrases: Vec<String>,
+    pub tags: Vec<Tag>,
+    pub companies: Vec<String>,
+    /// Lower bound on the bullet's end date, as written
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// Upper bound on the bullet's start date, as written
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    pub priority: Vec<(Comparison, u8)>,
+}
+
+/// A query that could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryError {
+    pub message: String,
+    /// Byte offset of the offending clause
+    pub offset: usize,
+}
+
+impl QueryError {
+    fn new(offset: usize, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            offset,
+        }
+    }
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at offset {})", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+/// Split on whitespace outside double quotes, returning byte offsets
+fn clauses(input: &str) -> Result<Vec<(usize, &str)>, QueryError> {
+    let mut clauses = Vec::new();
+    let mut start = None;
+    let mut quote = None;
+    for (i, c) in input.char_indices() {
+        match c {
+            '"' => {
+                start.get_or_insert(i);
+                quote = if quote.is_some() { None } else { Some(i) };
+            }
+            c if c.is_whitespace() && quote.is_none() => {
+                if let Some(s) = start.take() {
+                    clauses.push((s, &input[s..i]));
+                }
+            }
+            _ => {
+                start.get_or_insert(i);
+            }
+        }
+    }
+    if let Some(q) = quote {
+        return Err(QueryError::new(q, "unterminated quote"));
+    }
+    if let Some(s) = start {
+        clauses.push((s, &input[s..]));
+    }
+    Ok(clauses)
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+impl Query {
+    /// Parse the query language described in the module docs
+    pub fn parse(input: &str) -> Result<Self, QueryError> {
+        let mut query = Query::default();
+
+        for (offset, clause) in clauses(input)? {
+            if clause.starts_with('"') {
+                // Phrases with no terms (`""`, `"--"`) match nothing; drop them
+                let phrase = unquote(clause).trim();
+                let terms = text::terms(phrase);
+                if !terms.is_empty() {
+                    query.terms.extend(terms);
+                    query.phrases.push(phrase.to_string());
+                }
+                continue;
+            }
+
+            if let Some(rest) = clause.strip_prefix("priority") {
+                let (op, bound) = [
+                    (">=", Comparison::Ge),
+                    ("<=", Comparison::Le),
+                    (">", Comparison::Gt),
+                    ("<", Comparison::Lt),
+                    ("=", Comparison::Eq),
+                    (":", Comparison::Eq),
+                ]
+                .into_iter()
+                .find_map(|(symbol, op)| rest.strip_prefix(symbol).map(|bound| (op, bound)))
+                .ok_or_else(|| {
+                    QueryError::new(
+                        offset,
+                        "expected an operator after 'priority' (e.g. priority>=7)",
+                    )
+                })?;
+                let bound: u8 = bound
+                    .parse()
+                    .ok()
+                    .filter(|p| (1..=10).contains(p))
+                    .ok_or_else(|| {
+                        QueryError::new(offset, format!("priority must be 1-10, got '{}'", bound))
+                    })?;
+                query.priority.push((op, bound));
+                continue;
+            }
+
+            let Some((key, value)) = clause.split_once(':') else {
+                query.terms.extend(text::terms(clause));
+                continue;
+            };
+            let value = unquote(value).trim();
+            if value.is_empty() {
+                return Err(QueryError::new(offset, format!("'{}:' needs a value", key)));
+            }
+            let date = |is_end: bool| {
+                parse_month(value, is_end)
+                    .map(|_| value.to_string())
+                    .ok_or_else(|| {
+                        QueryError::new(
+                            offset,
+                            format!("invalid date '{}', expected YYYY or YYYY-MM", value),
+                        )
+                    })
+            };
+            match key {
+                "tag" => query.tags.push(value.to_lowercase()),
+                "company" => query.companies.push(value.to_lowercase()),
+                "after" => query.after = Some(date(false)?),
+                "before" => query.before = Some(date(true)?),
+                _ => {
+                    return Err(QueryError::new(
+                        offset,
+                        format!(
+                        "unknown filter '{}' (expected tag, company, after, before or priority)",
+                        key
+                    ),
+                    ))
+                }
+            }
+        }
+
+        Ok(query)
+    }
+
+    /// True when the query has no clauses at all
+    pub fn is_empty(&self) -> bool {
+        self == &Query::default()
+    }
+}
+
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Query::parse(s)
+    }
+}
+
+/// Search tuning
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SearchOptions {
+    /// Maximum hits returned (0 = unlimited)
+    pub limit: usize,
+    /// BM25 term-frequency saturation
+    pub k1: f32,
+    /// BM25 length normalization
+    pub b: f32,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            limit: 20,
+            k1: 1.2,
+            b: 0.75,
+        }
+    }
+}
+
+/// Company or position a hit belongs to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ancestor {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// A matching bullet with its ancestry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHit {
+    pub bullet_id: String,
+    pub description: String,
+    pub tags: Vec<Tag>,
+    pub priority: u8,
+    /// BM25 score, 0.0 for filter-only queries
+    pub score: f32,
+    /// Location of the text, e.g. `experience[0].children[1].children[2].description`
+    pub path: String,
+    pub company: Ancestor,
+    pub position: Ancestor,
+}
+
+struct Document {
+    hit: SearchHit,
+    company_id: String,
+    company_name: String,
+    /// Stemmed terms per field, for phrase matching
+    fields: [Vec<String>; 3],
+    /// Field-weighted term frequencies
+    term_freq: HashMap<String, f32>,
+    /// Field-weighted term count
+    length: f32,
+    start: Option<Month>,
+    /// `None` when ongoing; `Some(None)` when unparseable
+    end: Option<Option<Month>>,
+}
+
+/// Prebuilt index over a compendium; build once, query many times
+pub struct SearchIndex {
+    docs: Vec<Document>,
+    doc_freq: HashMap<String, usize>,
+    avg_length: f32,
+}
+
+fn field_text(parts: &[Option<&str>]) -> String {
+    parts
+        .iter()
+        .flatten()
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn open_end(date: Option<&str>) -> Option<Option<Month>> {
+    match date {
+        None => None,
+        Some(d) if d.is_empty() || d.eq_ignore_ascii_case("present") => None,
+        Some(d) => Some(parse_month(d, true)),
+    }
+}
+
+impl Document {
+    fn new(bullet: &Bullet, path: String, position: &Position, company: &Company) -> Self {
+        let texts = [
+            field_text(&[
+                bullet.name.as_deref(),
+                Some(&bullet.description),
+                bullet.summary.as_deref(),
+            ]),
+            field_text(&[
+                Some(&position.name),
+                position.summary.as_deref(),
+                position.description.as_deref(),
+            ]),
+            field_text(&[
+                company.name.as_deref(),
+                company.summary.as_deref(),
+                company.description.as_deref(),
+            ]),
+        ];
+
+        let fields = texts.map(|t| text::terms(&t));
+        let mut term_freq = HashMap::new();
+        let mut length = 0.0;
+        for (terms, weight) in fields.iter().zip(FIELD_WEIGHTS) {
+            for term in terms {
+                *term_freq.entry(term.clone()).or_insert(0.0) += weight;
+                length += weight;
+            }
+        }
+
+        // Dated bullets use their own span, otherwise the position's
+        let (start, end) = match &bullet.date_start {
+            Some(start) => (start.as_str(), bullet.date_end.as_deref()),
+            None => (position.date_start.as_str(), position.date_end.as_deref()),
+        };
+
+        Self {
+            hit: SearchHit {
+                bullet_id: bullet.id.clone(),
+                description: bullet.description.clone(),
+                tags: bullet.tags.clone(),
+                priority: bullet.priority,
+                score: 0.0,
+                path,
+                company: Ancestor {
+                    id: company.id.clone(),
+                    name: company.name.clone(),
+                },
+                position: Ancestor {
+                    id: position.id.clone(),
+                    name: Some(position.name.clone()),
+                },
+            },
+            company_id: company.id.to_lowercase(),
+            company_name: company.name.as_deref().unwrap_or_default().to_lowercase(),
+            fields,
+            term_freq,
+            length,
+            start: parse_month(start, false),
+            end: open_end(end),
+        }
+    }
+
+    fn matches_filters(&self, query: &Query, after: Option<Month>, before: Option<Month>) -> bool {
+        let tags: HashSet<String> = self.hit.tags.iter().map(|t| t.to_lowercase()).collect();
+        if !query.tags.iter().all(|t| tags.contains(t)) {
+            return false;
+        }
+        if !query
+            .companies
+            .iter()
+            .all(|c| self.company_id == *c || self.company_name.contains(c.as_str()))
+        {
+            return false;
+        }
+        if !query
+            .priority
+            .iter()
+            .all(|(op, bound)| op.holds(self.hit.priority, *bound))
+        {
+            return false;
+        }
+        if let Some(after) = after {
+            match self.end {
+                None => {}
+                Some(Some(end)) if end >= after => {}
+                _ => return false,
+            }
+        }
+        if let Some(before) = before {
+            match self.start {
+                Some(start) if start <= before => {}
+                _ => return false,
+            }
+        }
+        query.phrases.iter().all(|phrase| {
+            let phrase = text::terms(phrase);
+            self.fields
+                .iter()
+                .any(|field| text::contains_phrase(field, &phrase))
+        })
+    }
+}
+
+impl SearchIndex {
+    /// Index every bullet and position description in the compendium
+    pub fn build(data: &ResumeData) -> Self {
+        let mut docs = Vec::new();
+        for (ci, company) in data.experience.iter().enumerate() {
+            for (pi, position) in company.children.iter().enumerate() {
+                let position_path = format!("experience[{}].children[{}]", ci, pi);
+                if let Some(description) = scoring::position_description_bullet(position) {
+                    let path = format!("{}.description", position_path);
+                    docs.push(Document::new(&description, path, position, company));
+                }
+                for (bi, bullet) in position.children.iter().enumerate() {
+                    let path = format!("{}.children[{}].description", position_path, bi);
+                    docs.push(Document::new(bullet, path, position, company));
+                }
+            }
+        }
+
+        let mut doc_freq = HashMap::new();
+        for doc in &docs {
+            for term in doc.term_freq.keys() {
+                *doc_freq.entry(term.clone()).or_insert(0) += 1;
+            }
+        }
+        let avg_length = if docs.is_empty() {
+            0.0
+        } else {
+            docs.iter().map(|d| d.length).sum::<f32>() / docs.len() as f32
+        };
+
+        Self {
+            docs,
+            doc_freq,
+            avg_length,
+        }
+    }
+
+    /// Number of indexed documents
+    pub fn len(&self) -> usize {
+        self.docs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.docs.is_empty()
+    }
+
+    fn bm25(&self, doc: &Document, terms: &[String], options: &SearchOptions) -> f32 {
+        if terms.is_empty() {
+            return 0.0;
+        }
+        let n = self.docs.len() as f32;
+        let norm = if self.avg_length > 0.0 {
+            1.0 - options.b + options.b * doc.length / self.avg_length
+        } else {
+            1.0
+        };
+        terms
+            .iter()
+            .filter_map(|term| {
+                let tf = *doc.term_freq.get(term)?;
+                let df = self.doc_freq[term] as f32;
+                let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+                Some(idf * tf * (options.k1 + 1.0) / (tf + options.k1 * norm))
+            })
+            .sum()
+    }
+
+    /// Run a parsed query
+    ///
+    /// Hits are ordered by score, then priority, then compendium order. With
+    /// free-text terms, documents matching none of them are dropped.
+    pub fn search(&self, query: &Query, options: &SearchOptions) -> Vec<SearchHit> {
+        let after = query.after.as_deref().and_then(|d| parse_month(d, false));
+        let before = query.before.as_deref().and_then(|d| parse_month(d, true));
+        let mut terms = query.terms.clone();
+        terms.sort();
+        terms.dedup();
+
+        let mut hits: Vec<(usize, SearchHit)> = self
+            .docs
+            .iter()
+            .enumerate()
+            .filter(|(_, doc)| doc.matches_filters(query, after, before))
+            .filter_map(|(i, doc)| {
+                let score = self.bm25(doc, &terms, options);
+                if !terms.is_empty() && score <= 0.0 {
+                    return None;
+                }
+                Some((
+                    i,
+                    SearchHit {
+                        score,
+                        ..doc.hit.clone()
+                    },
+                ))
+            })
+            .collect();
+
+        hits.sort_by(|(ia, a), (ib, b)| {
+            b.score
+                .total_cmp(&a.score)
+                .then(b.priority.cmp(&a.priority))
+                .then(ia.cmp(ib))
+        });
+        if options.limit > 0 {
+            hits.truncate(options.limit);
+        }
+        hits.into_iter().map(|(_, hit)| hit).collect()
+    }
+}
+
+/// Parse `query` and search `data` in one go
+///
+/// # Example
+/// ```
+/// use resume_core::search::{search, SearchOptions};
+/// use shared_types::ResumeData;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let data: ResumeData = serde_json::from_str(r#"{"personal": {"name": "A"}, "experience": []}"#)?;
+/// let hits = search(&data, r#"tag:kafka after:2020 "migration""#, &SearchOptions::default())?;
+/// assert!(hits.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+pub fn search(
+    data: &ResumeData,
+    query: &str,
+    options: &SearchOptions,
+) -> Result<Vec<SearchHit>, QueryError> {
+    let query = Query::parse(query)?;
+    Ok(SearchIndex::build(data).search(&query, options))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{bullet, company, position, resume};
+
+    fn data() -> ResumeData {
+        let mut old_job = position(
+            "platform",
+            vec![
+                bullet(
+                    "kafka",
+                    "Led the Kafka migration, cutting p99 latency by 40%",
+                    &["kafka", "distributed-systems"],
+                    9,
+                ),
+                bullet("oncall", "Ran the on-call rotation", &["operations"], 5),
+            ],
+        );
+        old_job.date_start = "2016-01".to_string();
+        old_job.date_end = Some("2019-06".to_string());
+        let mut acme = company("acme", vec![old_job]);
+        acme.name = Some("Acme Corp".to_string());
+
+        let mut new_job = position(
+            "backend",
+            vec![
+                bullet(
+                    "latency",
+                    "Reduced API latency with a Rust rewrite",
+                    &["rust", "performance"],
+                    8,
+                ),
+                bullet(
+                    "hiring",
+                    "Hired and mentored four engineers",
+                    &["leadership"],
+                    7,
+                ),
+            ],
+        );
+        new_job.date_start = "2021-03".to_string();
+        new_job.description = Some("Backend lead for the payments platform".to_string());
+        new_job.tags = vec!["leadership".to_string()];
+        new_job.priority = 6;
+        let mut globex = company("globex", vec![new_job]);
+        globex.name = Some("Globex".to_string());
+
+        resume(vec![acme, globex])
+    }
+
+    fn ids(hits: &[SearchHit]) -> Vec<&str> {
+        hits.iter().map(|h| h.bullet_id.as_str()).collect()
+    }
+
+    fn run(query: &str) -> Vec<SearchHit> {
+        search(&data(), query, &SearchOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_clauses() {
+        let query = Query::parse(
+            r#"tag:Rust company:"Acme Corp" after:2020 priority>=7 "p99 latency" kafka"#,
+        )
+        .unwrap();
+
+        assert_eq!(query.tags, vec!["rust"]);
+        assert_eq!(query.companies, vec!["acme corp"]);
+        assert_eq!(query.after.as_deref(), Some("2020"));
+        assert_eq!(query.priority, vec![(Comparison::Ge, 7)]);
+        assert_eq!(query.phrases, vec!["p99 latency"]);
+        assert_eq!(query.terms, vec!["p99", "latency", "kafka"]);
+        assert!(Query::parse("  ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors_point_at_clause() {
+        let err = Query::parse(r#"rust "kafka migration"#).unwrap_err();
+        assert_eq!(err.offset, 5);
+        assert!(err.message.contains("unterminated"));
+
+        assert_eq!(Query::parse("rust foo:bar").unwrap_err().offset, 5);
+        assert!(Query::parse("priority>=11").is_err());
+        assert!(Query::parse("priority~3").is_err());
+        assert!(Query::parse("after:last-year").is_err());
+        assert!(Query::parse("tag:").is_err());
+    }
+
+    #[test]
+    fn test_free_text_ranks_by_bm25() {
+        // Both mention latency; only one also mentions Rust
+        let hits = run("rust latency");
+        assert_eq!(ids(&hits), vec!["latency", "kafka"]);
+        assert!(hits[0].score > hits[1].score);
+
+        // Stemming: "migrations" finds "migration"
+        assert_eq!(ids(&run("migrations")), vec!["kafka"]);
+        assert!(run("blockchain").is_empty());
+    }
+
+    #[test]
+    fn test_hits_carry_ancestry() {
+        let hits = run("kafka");
+        let hit = &hits[0];
+        assert_eq!(hit.company.id, "acme");
+        assert_eq!(hit.company.name.as_deref(), Some("Acme Corp"));
+        assert_eq!(hit.position.id, "platform");
+        assert_eq!(
+            hit.path,
+            "experience[0].children[0].children[0].description"
+        );
+    }
+
+    #[test]
+    fn test_filters() {
+        assert_eq!(ids(&run("tag:rust")), vec!["latency"]);
+        assert_eq!(ids(&run("company:acme")), vec!["kafka", "oncall"]);
+        assert_eq!(ids(&run("company:corp priority<9")), vec!["oncall"]);
+        assert_eq!(ids(&run("priority>=8")), vec!["kafka", "latency"]);
+
+        // Ongoing positions count as "after" any date; ended ones only up to their end
+        assert_eq!(
+            ids(&run("after:2020")),
+            vec!["latency", "hiring", "backend-description"]
+        );
+        assert_eq!(ids(&run("after:2019-06 company:acme")).len(), 2);
+        assert_eq!(ids(&run("before:2018")), vec!["kafka", "oncall"]);
+    }
+
+    #[test]
+    fn test_phrase_must_match_contiguously() {
+        assert_eq!(ids(&run(r#""p99 latency""#)), vec!["kafka"]);
+        assert!(run(r#""latency p99""#).is_empty());
+    }
+
+    #[test]
+    fn test_punctuation_only_phrase_is_dropped() {
+        assert_eq!(Query::parse(r#""--""#).unwrap(), Query::default());
+        assert_eq!(
+            Query::parse(r#""!!" tag:rust"#).unwrap().phrases,
+            Vec::<String>::new()
+        );
+
+        assert_eq!(ids(&run(r#""--""#)), ids(&run("")));
+        assert_eq!(ids(&run(r#""!!" kafka"#)), ids(&run("kafka")));
+    }
+
+    #[test]
+    fn test_position_descriptions_and_field_weights() {
+        // Position text is searchable but weighs less than the bullet's own text
+        let hits = run("payments");
+        assert_eq!(ids(&hits)[0], "backend-description");
+        assert_eq!(hits[0].path, "experience[1].children[0].description");
+        assert!(ids(&hits).contains(&"latency"));
+
+        let hits = run("globex lead");
+        assert_eq!(ids(&hits)[0], "backend-description");
+    }
+
+    #[test]
+    fn test_limit() {
+        let options = SearchOptions {
+            limit: 2,
+            ..SearchOptions::default()
+        };
+        let hits = search(&data(), "", &options).unwrap();
+        // Filter-only queries rank by priority
+        assert_eq!(ids(&hits), vec!["kafka", "latency"]);
+    }
+}
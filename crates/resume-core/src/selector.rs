@@ -0,0 +1,1038 @@
+//! Bullet selection with diversity constraints
+//!
+//! The selection API routes run the TypeScript engine (`lib/selection.ts`,
+//! `lib/selection-diversity.ts` and the `lib/selection-*.ts` passes), which
+//! mirrors this module pass for pass; change both together. Two modes:
+//!
+//! - [`SelectionMode::Greedy`] walks bullets in score order and takes each one
+//!   that fits the per-company / per-position caps.
+//! - [`SelectionMode::Mmr`] uses maximal marginal relevance: each pick
+//!   maximises `score - redundancy_penalty × max_similarity(selected)`, so a
+//!   bullet that restates an already-selected achievement loses out to a
+//!   slightly lower-scored but distinct one.
+//!
+//! Both modes finish by dropping companies with fewer than `min_per_company`
+//! bullets.
+//!
+//! [`SelectionOverrides`] apply first: excluded bullets and companies are
+//! removed, then pinned bullets are taken unconditionally. Pins use up
+//! `max_bullets` and the per-company / per-position caps like any other pick,
+//! but are never dropped themselves. The `select_*` entry points first reject
+//! overrides naming unknown IDs or pinning an excluded bullet.
+//!
+//! `must_cover_tags` come next: a weighted greedy set cover picks, step by
+//! step, the bullet with the highest `score × still-uncovered required tags it
+//! carries` until every requirement has evidence or no candidate helps.
+//! [`Selection::coverage`] reports which requirements stayed uncovered and why.
+
+use crate::scorer::{self, HeuristicScorer, Scorer};
+use crate::scoring;
+use crate::similarity::Shingles;
+use serde::{Deserialize, Serialize};
+use shared_types::{ResumeData, RoleProfile, ScoredBullet, SelectionOverrides, Tag};
+use std::collections::{HashMap, HashSet};
+
+/// How bullets are picked from the scored list
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum SelectionMode {
+    /// Highest score first, constrained only by the diversity caps
+    #[default]
+    Greedy,
+
+    /// Maximal marginal relevance over bullet-text shingles
+    Mmr {
+        /// How strongly similarity to already-selected bullets is penalised.
+        /// 0.0 behaves like `Greedy`; 0.5 is a reasonable starting point.
+        redundancy_penalty: f32,
+    },
+}
+
+/// Configuration for bullet selection diversity constraints
+///
+/// `None` (or 0) disables a cap, mirroring the TS `SelectionConfig`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectionConfig {
+    /// Maximum total bullets to select (ceiling - may select fewer)
+    pub max_bullets: usize,
+
+    /// Maximum bullets per company (diversity across employers)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_per_company: Option<usize>,
+
+    /// Minimum bullets per company (drop sparse single-bullet companies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_per_company: Option<usize>,
+
+    /// Maximum bullets per position (prevent one role dominating)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_per_position: Option<usize>,
+
+    /// Selection strategy
+    #[serde(default)]
+    pub mode: SelectionMode,
+
+    /// Pinned/excluded bullets and company include/exclude lists
+    #[serde(default, flatten)]
+    pub overrides: SelectionOverrides,
+
+    /// Tags that need at least one selected bullet as evidence
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub must_cover_tags: Vec<Tag>,
+}
+
+impl Default for SelectionConfig {
+    /// Same defaults as `DEFAULT_SELECTION_CONFIG` in `lib/selection.ts`
+    fn default() -> Self {
+        Self {
+            max_bullets: 24,
+            max_per_company: Some(6),
+            min_per_company: Some(2),
+            max_per_position: Some(4),
+            mode: SelectionMode::Greedy,
+            overrides: SelectionOverrides::default(),
+            must_cover_tags: Vec::new(),
+        }
+    }
+}
+
+/// Why a required tag has no selected bullet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CoverageGap {
+    /// No candidate bullet carries the tag
+    NoBullet,
+    /// Every bullet carrying the tag is excluded by the overrides
+    Excluded,
+    /// Candidates exist but `max_bullets` or a diversity cap left no room
+    NoRoom,
+}
+
+/// Coverage of one `must_cover_tags` requirement
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequirementCoverage {
+    pub tag: Tag,
+    /// Selected bullets carrying the tag, in selection order
+    pub covered_by: Vec<String>,
+    /// Set when `covered_by` is empty
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gap: Option<CoverageGap>,
+}
+
+/// Selected bullets plus a coverage report for `must_cover_tags`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Selection {
+    pub bullets: Vec<ScoredBullet>,
+    /// One entry per required tag, in config order
+    pub coverage: Vec<RequirementCoverage>,
+}
+
+impl Selection {
+    /// Requirements without a selected bullet
+    pub fn uncovered(&self) -> impl Iterator<Item = &RequirementCoverage> {
+        self.coverage.iter().filter(|c| c.covered_by.is_empty())
+    }
+}
+
+/// Score every bullet against the role profile and select under constraints
+///
+/// Fails when the overrides reference unknown bullets or companies, or pin a
+/// bullet they also exclude (see [`SelectionOverrides::validate`]).
+///
+/// # Example
+/// ```no_run
+/// use resume_core::selector::{select_bullets, SelectionConfig, SelectionMode};
+/// use shared_types::ResumeData;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let data: ResumeData = serde_json::from_str("{...}")?;
+/// let profile = &data.role_profiles.as_ref().unwrap()[0];
+/// let config = SelectionConfig {
+///     mode: SelectionMode::Mmr { redundancy_penalty: 0.5 },
+///     ..SelectionConfig::default()
+/// };
+/// let selected = select_bullets(&data, profile, &config)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn select_bullets(
+    data: &ResumeData,
+    role_profile: &RoleProfile,
+    config: &SelectionConfig,
+) -> Result<Vec<ScoredBullet>, String> {
+    select_bullets_with(data, &HeuristicScorer::new(role_profile), config)
+}
+
+/// Select bullets using any [`Scorer`], e.g. an ensemble of heuristic and AI scores
+pub fn select_bullets_with(
+    data: &ResumeData,
+    scorer: &dyn Scorer,
+    config: &SelectionConfig,
+) -> Result<Vec<ScoredBullet>, String> {
+    Ok(select_with_coverage(data, scorer, config)?.bullets)
+}
+
+/// Like [`select_bullets_with`], also reporting `must_cover_tags` coverage
+pub fn select_with_coverage(
+    data: &ResumeData,
+    scorer: &dyn Scorer,
+    config: &SelectionConfig,
+) -> Result<Selection, String> {
+    config.overrides.validate(data)?;
+    Ok(select_validated(data, scorer, config))
+}
+
+/// Selection for overrides already checked against `data`
+pub(crate) fn select_validated(
+    data: &ResumeData,
+    scorer: &dyn Scorer,
+    config: &SelectionConfig,
+) -> Selection {
+    let mut scored = scorer::score_candidates(data, scorer);
+    add_unscored_pins(data, &mut scored, &config.overrides);
+    apply_constraints(scored, config)
+}
+
+/// Pins bypass scoring, so a scorer with no opinion on a pinned bullet
+/// (e.g. AI scores that skipped it) must not lose it. Such pins get score 0.
+fn add_unscored_pins(
+    data: &ResumeData,
+    scored: &mut Vec<ScoredBullet>,
+    overrides: &SelectionOverrides,
+) {
+    for pin in &overrides.pinned_bullet_ids {
+        if scored.iter().any(|s| &s.bullet.id == pin) {
+            continue;
+        }
+        let found = data.experience.iter().find_map(|company| {
+            company.children.iter().find_map(|position| {
+                position
+                    .children
+                    .iter()
+                    .find(|b| &b.id == pin)
+                    .map(|b| scoring::scored_bullet(b.clone(), 0.0, position, company))
+            })
+        });
+        scored.extend(found);
+    }
+}
+
+/// Apply diversity constraints to a list of scored bullets
+///
+/// `sorted_bullets` must be sorted by score descending. Pinned bullets come
+/// first in pin order, then bullets picked for `must_cover_tags`; the rest
+/// follow in score order (greedy) or pick order (MMR).
+pub fn apply_diversity_constraints(
+    sorted_bullets: Vec<ScoredBullet>,
+    config: &SelectionConfig,
+) -> Vec<ScoredBullet> {
+    apply_constraints(sorted_bullets, config).bullets
+}
+
+/// Like [`apply_diversity_constraints`], also reporting tag coverage
+pub fn apply_constraints(sorted_bullets: Vec<ScoredBullet>, config: &SelectionConfig) -> Selection {
+    let requirements = unique_tags(&config.must_cover_tags);
+    let candidate_tags = tags_of(&sorted_bullets);
+
+    let (mut required, mut candidates) = split_pinned(sorted_bullets, &config.overrides);
+    let allowed_tags: HashSet<Tag> = tags_of(&required)
+        .union(&tags_of(&candidates))
+        .cloned()
+        .collect();
+
+    let mut caps = Caps::default();
+    for bullet in &required {
+        caps.record(bullet);
+    }
+    let cover_picks =
+        cover_requirements(&mut candidates, &required, &requirements, config, &mut caps);
+    required.extend(cover_picks);
+    let remaining = config.max_bullets.saturating_sub(required.len());
+
+    let picks = match config.mode {
+        SelectionMode::Greedy => select_greedy(candidates, config, caps, remaining),
+        SelectionMode::Mmr { redundancy_penalty } => select_mmr(
+            candidates,
+            &required,
+            config,
+            caps,
+            remaining,
+            redundancy_penalty,
+        ),
+    };
+
+    let required_companies: Vec<String> = required.iter().map(|b| b.company_id.clone()).collect();
+    let mut selected = required;
+    selected.extend(picks);
+    let bullets = enforce_min_per_company(selected, config.min_per_company, &required_companies);
+
+    let coverage = requirements
+        .into_iter()
+        .map(|tag| {
+            let covered_by: Vec<String> = bullets
+                .iter()
+                .filter(|b| b.bullet.tags.contains(&tag))
+                .map(|b| b.bullet.id.clone())
+                .collect();
+            let gap = if !covered_by.is_empty() {
+                None
+            } else if !candidate_tags.contains(&tag) {
+                Some(CoverageGap::NoBullet)
+            } else if !allowed_tags.contains(&tag) {
+                Some(CoverageGap::Excluded)
+            } else {
+                Some(CoverageGap::NoRoom)
+            };
+            RequirementCoverage {
+                tag,
+                covered_by,
+                gap,
+            }
+        })
+        .collect();
+
+    Selection { bullets, coverage }
+}
+
+fn unique_tags(tags: &[Tag]) -> Vec<Tag> {
+    let mut seen = HashSet::new();
+    tags.iter()
+        .filter(|tag| seen.insert(tag.as_str()))
+        .cloned()
+        .collect()
+}
+
+fn tags_of(bullets: &[ScoredBullet]) -> HashSet<Tag> {
+    bullets
+        .iter()
+        .flat_map(|b| b.bullet.tags.iter().cloned())
+        .collect()
+}
+
+/// Weighted greedy set cover over `requirements`, seeded with what `already`
+/// covers
+///
+/// Each step takes the bullet (within caps and `max_bullets`) with the highest
+/// `score × newly covered requirements`: covering more tags counts in
+/// proportion to the score, so a 0.5 bullet covering two tags (1.0) beats a
+/// 0.9 bullet covering one. Equal values go to the larger gain, then the
+/// earlier, higher-scored bullet.
+fn cover_requirements(
+    candidates: &mut Vec<ScoredBullet>,
+    already: &[ScoredBullet],
+    requirements: &[Tag],
+    config: &SelectionConfig,
+    caps: &mut Caps,
+) -> Vec<ScoredBullet> {
+    let covered = tags_of(already);
+    let mut uncovered: Vec<&Tag> = requirements
+        .iter()
+        .filter(|tag| !covered.contains(*tag))
+        .collect();
+    let mut picks = Vec::new();
+
+    while !uncovered.is_empty() && already.len() + picks.len() < config.max_bullets {
+        let best = candidates
+            .iter()
+            .enumerate()
+            .filter(|(_, bullet)| caps.allows(bullet, config))
+            .map(|(i, bullet)| {
+                let gain = uncovered
+                    .iter()
+                    .filter(|tag| bullet.bullet.tags.contains(tag))
+                    .count();
+                (i, gain as f32 * bullet.score, gain)
+            })
+            .filter(|&(_, _, gain)| gain > 0)
+            .fold(
+                None,
+                |best: Option<(usize, f32, usize)>, candidate| match best {
+                    Some((_, value, gain)) if (value, gain) >= (candidate.1, candidate.2) => best,
+                    _ => Some(candidate),
+                },
+            );
+
+        let Some((index, _, _)) = best else {
+            break;
+        };
+        let pick = candidates.remove(index);
+        uncovered.retain(|tag| !pick.bullet.tags.contains(tag));
+        caps.record(&pick);
+        picks.push(pick);
+    }
+
+    picks
+}
+
+/// Drop excluded bullets, then pull pins out in pin order
+fn split_pinned(
+    sorted_bullets: Vec<ScoredBullet>,
+    overrides: &SelectionOverrides,
+) -> (Vec<ScoredBullet>, Vec<ScoredBullet>) {
+    let mut candidates: Vec<ScoredBullet> = sorted_bullets
+        .into_iter()
+        .filter(|b| overrides.allows(&b.bullet.id, &b.company_id))
+        .collect();
+
+    let mut pinned = Vec::new();
+    for pin in &overrides.pinned_bullet_ids {
+        if let Some(index) = candidates.iter().position(|b| &b.bullet.id == pin) {
+            pinned.push(candidates.remove(index));
+        }
+    }
+
+    (pinned, candidates)
+}
+
+/// Running per-company / per-position counts
+#[derive(Default)]
+struct Caps {
+    company: HashMap<String, usize>,
+    position: HashMap<String, usize>,
+}
+
+impl Caps {
+    fn allows(&self, bullet: &ScoredBullet, config: &SelectionConfig) -> bool {
+        let under = |limit: Option<usize>, count: Option<&usize>| match limit {
+            Some(max) if max > 0 => count.copied().unwrap_or(0) < max,
+            _ => true,
+        };
+
+        under(config.max_per_company, self.company.get(&bullet.company_id))
+            && under(
+                config.max_per_position,
+                self.position.get(&bullet.position_id),
+            )
+    }
+
+    fn record(&mut self, bullet: &ScoredBullet) {
+        *self.company.entry(bullet.company_id.clone()).or_default() += 1;
+        *self.position.entry(bullet.position_id.clone()).or_default() += 1;
+    }
+}
+
+fn select_greedy(
+    sorted_bullets: Vec<ScoredBullet>,
+    config: &SelectionConfig,
+    mut caps: Caps,
+    limit: usize,
+) -> Vec<ScoredBullet> {
+    let mut selected = Vec::new();
+
+    for bullet in sorted_bullets {
+        if selected.len() >= limit {
+            break;
+        }
+        if !caps.allows(&bullet, config) {
+            continue;
+        }
+        caps.record(&bullet);
+        selected.push(bullet);
+    }
+
+    selected
+}
+
+fn select_mmr(
+    sorted_bullets: Vec<ScoredBullet>,
+    pinned: &[ScoredBullet],
+    config: &SelectionConfig,
+    mut caps: Caps,
+    limit: usize,
+    redundancy_penalty: f32,
+) -> Vec<ScoredBullet> {
+    let shingles: Vec<Shingles> = sorted_bullets
+        .iter()
+        .map(|b| Shingles::new(&b.bullet.description))
+        .collect();
+
+    // Highest similarity of each candidate to anything selected so far,
+    // starting with the pins
+    let pinned_shingles: Vec<Shingles> = pinned
+        .iter()
+        .map(|b| Shingles::new(&b.bullet.description))
+        .collect();
+    let mut max_similarity: Vec<f32> = shingles
+        .iter()
+        .map(|candidate| {
+            pinned_shingles
+                .iter()
+                .map(|pin| candidate.jaccard(pin))
+                .fold(0.0, f32::max)
+        })
+        .collect();
+    let mut taken = vec![false; sorted_bullets.len()];
+    let mut picks = Vec::new();
+
+    while picks.len() < limit {
+        let best = sorted_bullets
+            .iter()
+            .enumerate()
+            .filter(|(i, bullet)| !taken[*i] && caps.allows(bullet, config))
+            .map(|(i, bullet)| (i, bullet.score - redundancy_penalty * max_similarity[i]))
+            // Strictly greater keeps the earlier (higher-scored) bullet on ties
+            .fold(None, |best: Option<(usize, f32)>, (i, value)| match best {
+                Some((_, best_value)) if best_value >= value => best,
+                _ => Some((i, value)),
+            });
+
+        let Some((pick, _)) = best else {
+            break;
+        };
+
+        taken[pick] = true;
+        caps.record(&sorted_bullets[pick]);
+        picks.push(pick);
+
+        for (i, similarity) in max_similarity.iter_mut().enumerate() {
+            if !taken[i] {
+                *similarity = similarity.max(shingles[i].jaccard(&shingles[pick]));
+            }
+        }
+    }
+
+    let mut slots: Vec<Option<ScoredBullet>> = sorted_bullets.into_iter().map(Some).collect();
+    picks.into_iter().filter_map(|i| slots[i].take()).collect()
+}
+
+/// Drop companies with fewer than `min_per_company` selected bullets
+///
+/// Companies holding a pin or coverage pick are exempt, since those bullets
+/// must stay.
+fn enforce_min_per_company(
+    selected: Vec<ScoredBullet>,
+    min_per_company: Option<usize>,
+    required_companies: &[String],
+) -> Vec<ScoredBullet> {
+    let Some(min) = min_per_company.filter(|&min| min > 1) else {
+        return selected;
+    };
+
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for bullet in &selected {
+        *counts.entry(bullet.company_id.as_str()).or_default() += 1;
+    }
+    let sparse: Vec<String> = counts
+        .into_iter()
+        .filter(|(id, count)| *count < min && !required_companies.iter().any(|p| p == id))
+        .map(|(id, _)| id.to_string())
+        .collect();
+
+    selected
+        .into_iter()
+        .filter(|bullet| !sparse.contains(&bullet.company_id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{bullet, company, position, profile, resume};
+
+    fn unconstrained(max_bullets: usize, mode: SelectionMode) -> SelectionConfig {
+        SelectionConfig {
+            max_bullets,
+            max_per_company: None,
+            min_per_company: None,
+            max_per_position: None,
+            mode,
+            overrides: SelectionOverrides::default(),
+            must_cover_tags: Vec::new(),
+        }
+    }
+
+    fn ids(selected: &[ScoredBullet]) -> Vec<&str> {
+        selected.iter().map(|s| s.bullet.id.as_str()).collect()
+    }
+
+    /// Three near-identical Kubernetes bullets outscore one distinct bullet
+    fn duplicate_heavy_resume() -> ResumeData {
+        resume(vec![company(
+            "acme",
+            vec![position(
+                "acme-eng",
+                vec![
+                    bullet(
+                        "k8s-1",
+                        "Migrated payments platform to Kubernetes",
+                        &["rust"],
+                        10,
+                    ),
+                    bullet(
+                        "k8s-2",
+                        "Migrated the payments platform to Kubernetes clusters",
+                        &["rust"],
+                        10,
+                    ),
+                    bullet(
+                        "k8s-3",
+                        "Payments platform migrated to Kubernetes",
+                        &["rust"],
+                        9,
+                    ),
+                    bullet("mentor", "Mentored four junior engineers", &["rust"], 8),
+                ],
+            )],
+        )])
+    }
+
+    #[test]
+    fn test_greedy_respects_max_bullets() {
+        let data = duplicate_heavy_resume();
+        let selected = select_bullets(
+            &data,
+            &profile(&[("rust", 1.0)]),
+            &unconstrained(2, SelectionMode::Greedy),
+        )
+        .unwrap();
+
+        assert_eq!(ids(&selected), vec!["k8s-1", "k8s-2"]);
+    }
+
+    #[test]
+    fn test_greedy_respects_per_position_cap() {
+        let data = resume(vec![company(
+            "acme",
+            vec![
+                position(
+                    "p1",
+                    vec![bullet("a", "A", &[], 10), bullet("b", "B", &[], 9)],
+                ),
+                position("p2", vec![bullet("c", "C", &[], 5)]),
+            ],
+        )]);
+        let config = SelectionConfig {
+            max_per_position: Some(1),
+            ..unconstrained(10, SelectionMode::Greedy)
+        };
+
+        let selected = select_bullets(&data, &profile(&[]), &config).unwrap();
+        assert_eq!(ids(&selected), vec!["a", "c"]);
+    }
+
+    #[test]
+    fn test_min_per_company_drops_sparse_companies() {
+        let data = resume(vec![
+            company(
+                "big",
+                vec![position(
+                    "p1",
+                    vec![bullet("a", "A", &[], 10), bullet("b", "B", &[], 9)],
+                )],
+            ),
+            company(
+                "small",
+                vec![position("p2", vec![bullet("c", "C", &[], 5)])],
+            ),
+        ]);
+        let config = SelectionConfig {
+            min_per_company: Some(2),
+            ..unconstrained(10, SelectionMode::Greedy)
+        };
+
+        let selected = select_bullets(&data, &profile(&[]), &config).unwrap();
+        assert_eq!(ids(&selected), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_mmr_skips_near_duplicates() {
+        let data = duplicate_heavy_resume();
+        let selected = select_bullets(
+            &data,
+            &profile(&[("rust", 1.0)]),
+            &unconstrained(
+                2,
+                SelectionMode::Mmr {
+                    redundancy_penalty: 1.0,
+                },
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(ids(&selected), vec!["k8s-1", "mentor"]);
+    }
+
+    #[test]
+    fn test_mmr_zero_penalty_matches_greedy() {
+        let data = duplicate_heavy_resume();
+        let role = profile(&[("rust", 1.0)]);
+
+        let greedy =
+            select_bullets(&data, &role, &unconstrained(3, SelectionMode::Greedy)).unwrap();
+        let mmr = select_bullets(
+            &data,
+            &role,
+            &unconstrained(
+                3,
+                SelectionMode::Mmr {
+                    redundancy_penalty: 0.0,
+                },
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(ids(&greedy), ids(&mmr));
+    }
+
+    #[test]
+    fn test_mmr_respects_caps() {
+        let data = duplicate_heavy_resume();
+        let config = SelectionConfig {
+            max_per_position: Some(1),
+            ..unconstrained(
+                10,
+                SelectionMode::Mmr {
+                    redundancy_penalty: 1.0,
+                },
+            )
+        };
+
+        let selected = select_bullets(&data, &profile(&[("rust", 1.0)]), &config).unwrap();
+        assert_eq!(selected.len(), 1);
+    }
+
+    #[test]
+    fn test_selection_config_serde_roundtrip() {
+        let config = SelectionConfig {
+            mode: SelectionMode::Mmr {
+                redundancy_penalty: 0.5,
+            },
+            ..SelectionConfig::default()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains(r#""mode":{"type":"mmr","redundancyPenalty":0.5}"#));
+
+        let parsed: SelectionConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, config);
+
+        // Mode defaults to greedy when omitted
+        let parsed: SelectionConfig = serde_json::from_str(r#"{"maxBullets": 5}"#).unwrap();
+        assert_eq!(parsed.mode, SelectionMode::Greedy);
+    }
+
+    fn overrides(pinned: &[&str], excluded: &[&str]) -> SelectionOverrides {
+        SelectionOverrides {
+            pinned_bullet_ids: pinned.iter().map(|id| id.to_string()).collect(),
+            excluded_bullet_ids: excluded.iter().map(|id| id.to_string()).collect(),
+            ..SelectionOverrides::default()
+        }
+    }
+
+    fn two_company_resume() -> ResumeData {
+        resume(vec![
+            company(
+                "acme",
+                vec![position(
+                    "acme-eng",
+                    vec![
+                        bullet("a1", "A1", &["rust"], 10),
+                        bullet("a2", "A2", &["rust"], 9),
+                        bullet("a3", "A3", &[], 1),
+                    ],
+                )],
+            ),
+            company(
+                "rival",
+                vec![position(
+                    "rival-eng",
+                    vec![
+                        bullet("r1", "R1", &["rust"], 10),
+                        bullet("r2", "R2", &["rust"], 9),
+                    ],
+                )],
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_pins_bypass_scoring_and_come_first() {
+        let data = two_company_resume();
+        let config = SelectionConfig {
+            overrides: overrides(&["a3"], &[]),
+            ..unconstrained(2, SelectionMode::Greedy)
+        };
+
+        let selected = select_bullets(&data, &profile(&[("rust", 1.0)]), &config).unwrap();
+        assert_eq!(ids(&selected), vec!["a3", "a1"]);
+    }
+
+    #[test]
+    fn test_pins_count_against_caps() {
+        let data = two_company_resume();
+        let config = SelectionConfig {
+            max_per_company: Some(1),
+            overrides: overrides(&["a3"], &[]),
+            ..unconstrained(10, SelectionMode::Greedy)
+        };
+
+        let selected = select_bullets(&data, &profile(&[("rust", 1.0)]), &config).unwrap();
+        assert_eq!(ids(&selected), vec!["a3", "r1"]);
+    }
+
+    #[test]
+    fn test_pins_survive_min_per_company() {
+        let data = two_company_resume();
+        let config = SelectionConfig {
+            max_per_company: Some(1),
+            min_per_company: Some(2),
+            overrides: overrides(&["a3"], &[]),
+            ..unconstrained(10, SelectionMode::Greedy)
+        };
+
+        let selected = select_bullets(&data, &profile(&[("rust", 1.0)]), &config).unwrap();
+        assert_eq!(ids(&selected), vec!["a3"]);
+    }
+
+    #[test]
+    fn test_excluded_bullets_and_companies() {
+        let data = two_company_resume();
+        let role = profile(&[("rust", 1.0)]);
+
+        let config = SelectionConfig {
+            overrides: overrides(&[], &["a1"]),
+            ..unconstrained(2, SelectionMode::Greedy)
+        };
+        assert_eq!(
+            ids(&select_bullets(&data, &role, &config).unwrap()),
+            vec!["r1", "a2"]
+        );
+
+        let config = SelectionConfig {
+            overrides: SelectionOverrides {
+                excluded_company_ids: vec!["rival".to_string()],
+                ..SelectionOverrides::default()
+            },
+            ..unconstrained(10, SelectionMode::Greedy)
+        };
+        assert_eq!(
+            ids(&select_bullets(&data, &role, &config).unwrap()),
+            vec!["a1", "a2", "a3"]
+        );
+
+        let config = SelectionConfig {
+            overrides: SelectionOverrides {
+                included_company_ids: vec!["rival".to_string()],
+                ..SelectionOverrides::default()
+            },
+            ..unconstrained(10, SelectionMode::Greedy)
+        };
+        assert_eq!(
+            ids(&select_bullets(&data, &role, &config).unwrap()),
+            vec!["r1", "r2"]
+        );
+    }
+
+    #[test]
+    fn test_exclusion_beats_pin() {
+        let data = two_company_resume();
+        let config = SelectionConfig {
+            overrides: overrides(&["a3"], &["a3"]),
+            ..unconstrained(10, SelectionMode::Greedy)
+        };
+
+        // The selection entry points reject the conflict outright...
+        let err = select_bullets(&data, &profile(&[]), &config).unwrap_err();
+        assert!(err.contains("'a3' is also excluded"));
+
+        // ...while pre-scored lists still let the exclusion win
+        let scored = scorer::score_candidates(&data, &HeuristicScorer::new(&profile(&[])));
+        let selected = apply_diversity_constraints(scored, &config);
+        assert!(!ids(&selected).contains(&"a3"));
+    }
+
+    #[test]
+    fn test_unknown_override_ids_are_rejected() {
+        let data = two_company_resume();
+        let config = SelectionConfig {
+            overrides: overrides(&["missing"], &[]),
+            ..unconstrained(10, SelectionMode::Greedy)
+        };
+
+        let err = select_bullets(&data, &profile(&[]), &config).unwrap_err();
+        assert!(err.contains("unknown bullet 'missing'"));
+    }
+
+    #[test]
+    fn test_unscored_pins_are_kept() {
+        let data = two_company_resume();
+        let scores = crate::scorer::ExternalScores::from_pairs([("r1", 0.9), ("r2", 0.8)]);
+        let config = SelectionConfig {
+            overrides: overrides(&["a2"], &[]),
+            ..unconstrained(2, SelectionMode::Greedy)
+        };
+
+        let selected = select_bullets_with(&data, &scores, &config).unwrap();
+        assert_eq!(ids(&selected), vec!["a2", "r1"]);
+        assert_eq!(selected[0].score, 0.0);
+    }
+
+    #[test]
+    fn test_mmr_penalises_similarity_to_pins() {
+        let data = duplicate_heavy_resume();
+        let config = SelectionConfig {
+            overrides: overrides(&["k8s-1"], &[]),
+            ..unconstrained(
+                2,
+                SelectionMode::Mmr {
+                    redundancy_penalty: 1.0,
+                },
+            )
+        };
+
+        let selected = select_bullets(&data, &profile(&[("rust", 1.0)]), &config).unwrap();
+        assert_eq!(ids(&selected), vec!["k8s-1", "mentor"]);
+    }
+
+    #[test]
+    fn test_overrides_serialize_flat() {
+        let config = SelectionConfig {
+            overrides: overrides(&["a1"], &["r1"]),
+            ..SelectionConfig::default()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains(r#""pinnedBulletIds":["a1"]"#));
+        assert!(json.contains(r#""excludedBulletIds":["r1"]"#));
+        assert!(!json.contains("includedCompanyIds"));
+
+        let parsed: SelectionConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, config);
+    }
+
+    fn must_cover(tags: &[&str], max_bullets: usize) -> SelectionConfig {
+        SelectionConfig {
+            must_cover_tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..unconstrained(max_bullets, SelectionMode::Greedy)
+        }
+    }
+
+    fn coverage_resume() -> ResumeData {
+        resume(vec![company(
+            "acme",
+            vec![position(
+                "acme-eng",
+                vec![
+                    bullet("rust-1", "Rust services", &["rust"], 10),
+                    bullet("rust-2", "Rust tooling", &["rust"], 10),
+                    bullet("lead", "Led a team", &["leadership"], 3),
+                    bullet(
+                        "lead-ds",
+                        "Led a distributed systems rewrite",
+                        &["leadership", "distributed-systems"],
+                        2,
+                    ),
+                ],
+            )],
+        )])
+    }
+
+    #[test]
+    fn test_must_cover_picks_evidence_over_score() {
+        let data = coverage_resume();
+        let role = profile(&[("rust", 1.0)]);
+        let config = must_cover(&["rust", "leadership", "distributed-systems"], 2);
+
+        let selection = select_with_coverage(&data, &HeuristicScorer::new(&role), &config).unwrap();
+
+        // The strong rust bullet goes first; one low-scored bullet then covers
+        // both remaining requirements, beating `lead` on the larger gain
+        assert_eq!(ids(&selection.bullets), vec!["rust-1", "lead-ds"]);
+        assert_eq!(selection.uncovered().count(), 0);
+        assert_eq!(selection.coverage[1].covered_by, vec!["lead-ds"]);
+    }
+
+    #[test]
+    fn test_must_cover_weighs_gain_by_score() {
+        let data = coverage_resume();
+        let role = profile(&[("rust", 1.0)]);
+        let config = must_cover(&["rust", "leadership", "distributed-systems"], 1);
+
+        // Unweighted max-gain would take `lead-ds` (two tags); weighted by
+        // score, the strong single-tag rust bullet wins the only slot
+        let selection = select_with_coverage(&data, &HeuristicScorer::new(&role), &config).unwrap();
+        assert_eq!(ids(&selection.bullets), vec!["rust-1"]);
+        assert_eq!(selection.uncovered().count(), 2);
+    }
+
+    #[test]
+    fn test_must_cover_prefers_higher_score_on_equal_gain() {
+        let data = coverage_resume();
+        let selection = apply_constraints(
+            scorer::score_candidates(&data, &HeuristicScorer::new(&profile(&[]))),
+            &must_cover(&["leadership"], 1),
+        );
+
+        assert_eq!(ids(&selection.bullets), vec!["lead"]);
+    }
+
+    #[test]
+    fn test_must_cover_reports_gaps() {
+        let data = coverage_resume();
+        let role = profile(&[("rust", 1.0)]);
+        let mut config = must_cover(&["kubernetes", "leadership", "rust"], 1);
+        config.overrides.excluded_bullet_ids = vec!["lead".to_string(), "lead-ds".to_string()];
+
+        let selection = select_with_coverage(&data, &HeuristicScorer::new(&role), &config).unwrap();
+        let gaps: Vec<(&str, Option<CoverageGap>)> = selection
+            .coverage
+            .iter()
+            .map(|c| (c.tag.as_str(), c.gap))
+            .collect();
+
+        assert_eq!(
+            gaps,
+            vec![
+                ("kubernetes", Some(CoverageGap::NoBullet)),
+                ("leadership", Some(CoverageGap::Excluded)),
+                ("rust", None),
+            ]
+        );
+
+        let config = must_cover(&["rust", "leadership"], 1);
+        let selection = select_with_coverage(&data, &HeuristicScorer::new(&role), &config).unwrap();
+        assert_eq!(selection.coverage[1].gap, Some(CoverageGap::NoRoom));
+    }
+
+    #[test]
+    fn test_pins_count_toward_coverage() {
+        let data = coverage_resume();
+        let mut config = must_cover(&["leadership"], 2);
+        config.overrides.pinned_bullet_ids = vec!["lead".to_string()];
+
+        let selected = select_bullets(&data, &profile(&[("rust", 1.0)]), &config).unwrap();
+        assert_eq!(ids(&selected), vec!["lead", "rust-1"]);
+    }
+
+    #[test]
+    fn test_coverage_picks_survive_min_per_company() {
+        let data = resume(vec![
+            company(
+                "acme",
+                vec![position(
+                    "acme-eng",
+                    vec![bullet("a1", "A1", &[], 10), bullet("a2", "A2", &[], 9)],
+                )],
+            ),
+            company(
+                "initech",
+                vec![position(
+                    "initech-eng",
+                    vec![bullet("i1", "I1", &["leadership"], 1)],
+                )],
+            ),
+        ]);
+        let config = SelectionConfig {
+            min_per_company: Some(2),
+            ..must_cover(&["leadership"], 10)
+        };
+
+        let selected = select_bullets(&data, &profile(&[]), &config).unwrap();
+        assert!(ids(&selected).contains(&"i1"));
+    }
+}
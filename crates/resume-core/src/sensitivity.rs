@@ -0,0 +1,547 @@
+//! What-if sensitivity analysis for role profile weights
+//!
+//! Sweeps each knob of a [`RoleProfile`] over a range, re-runs selection at
+//! every sample and compares the result with the baseline selection:
+//!
+//! - each tag weight, scaled by `min_factor..=max_factor` and clamped to the
+//!   valid 0.0-1.0 range
+//! - the scoring split (`tag_relevance` share, with `priority = 1 - share`)
+//!
+//! The report ranks knobs by how much they move the selection, lists the
+//! bullets that flip in or out (the selection boundary), and gives each
+//! baseline pick a retention rate across all samples.
+
+use crate::scorer::HeuristicScorer;
+use crate::scoring;
+use crate::selector::{self, SelectionConfig};
+use serde::{Deserialize, Serialize};
+use shared_types::{ResumeData, RoleProfile, ScoringWeights, Tag};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+/// A single adjustable weight in a role profile
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum Knob {
+    /// `ScoringWeights::tag_relevance`, with `priority` as the remainder
+    ScoringSplit,
+    /// One entry of `tag_weights`
+    TagWeight { tag: Tag },
+}
+
+impl Knob {
+    fn value_in(&self, profile: &RoleProfile) -> f32 {
+        match self {
+            Knob::ScoringSplit => {
+                let weights = &profile.scoring_weights;
+                let sum = weights.tag_relevance + weights.priority;
+                if sum > 0.0 {
+                    weights.tag_relevance / sum
+                } else {
+                    0.5
+                }
+            }
+            Knob::TagWeight { tag } => profile.tag_weights.get(tag).copied().unwrap_or(0.0),
+        }
+    }
+
+    fn apply(&self, profile: &RoleProfile, value: f32) -> RoleProfile {
+        let mut adjusted = profile.clone();
+        match self {
+            Knob::ScoringSplit => {
+                adjusted.scoring_weights = ScoringWeights {
+                    tag_relevance: value,
+                    priority: 1.0 - value,
+                };
+            }
+            Knob::TagWeight { tag } => {
+                adjusted.tag_weights.insert(tag.clone(), value);
+            }
+        }
+        adjusted
+    }
+}
+
+impl fmt::Display for Knob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Knob::ScoringSplit => f.write_str("scoring split"),
+            Knob::TagWeight { tag } => write!(f, "tag weight '{tag}'"),
+        }
+    }
+}
+
+/// Sweep configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SensitivityOptions {
+    /// Selection constraints used for every run
+    #[serde(default)]
+    pub selection: SelectionConfig,
+    /// Lowest multiplier applied to each tag weight
+    pub min_factor: f32,
+    /// Highest multiplier applied to each tag weight (results are capped at 1.0)
+    pub max_factor: f32,
+    /// Samples per knob, evenly spaced (at least 2)
+    pub steps: usize,
+}
+
+impl Default for SensitivityOptions {
+    /// Tag weights from 0× to 2× in 0.25 steps (1.0× is a sample)
+    fn default() -> Self {
+        Self {
+            selection: SelectionConfig::default(),
+            min_factor: 0.0,
+            max_factor: 2.0,
+            steps: 9,
+        }
+    }
+}
+
+/// Selection at one sampled knob value, relative to the baseline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KnobSample {
+    pub value: f32,
+    /// Bullets selected here but not in the baseline
+    pub added: Vec<String>,
+    /// Baseline bullets no longer selected
+    pub removed: Vec<String>,
+}
+
+impl KnobSample {
+    pub fn is_unchanged(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// How selection responds to one knob
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KnobSensitivity {
+    pub knob: Knob,
+    pub baseline_value: f32,
+    /// In ascending value order
+    pub samples: Vec<KnobSample>,
+    /// Approximate values where the selected set changes (midpoints
+    /// between adjacent samples that select different bullets)
+    pub breakpoints: Vec<f32>,
+    /// Samples whose selection differs from the baseline
+    pub changed_samples: usize,
+    /// Largest number of baseline bullets swapped out by any sample
+    pub max_churn: usize,
+}
+
+/// How safe a baseline pick is
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PickRobustness {
+    pub bullet_id: String,
+    pub score: f32,
+    /// Score minus the best score among unselected bullets the overrides
+    /// allow (negative when a cap, not the score, is what keeps a
+    /// higher-scored bullet out)
+    pub margin: f32,
+    /// Share of all samples that still select this bullet (0.0-1.0)
+    pub retention: f32,
+    /// Knobs that can drop it
+    pub dropped_by: Vec<Knob>,
+}
+
+/// A bullet whose membership changes somewhere in the sweep
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoundaryBullet {
+    pub bullet_id: String,
+    pub score: f32,
+    /// Whether the baseline selects it
+    pub selected: bool,
+    /// Samples where its membership differs from the baseline
+    pub flips: usize,
+}
+
+/// Result of [`analyze_sensitivity`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SensitivityReport {
+    /// Baseline selection, in selection order
+    pub baseline: Vec<String>,
+    /// Most influential knob first
+    pub knobs: Vec<KnobSensitivity>,
+    /// One entry per baseline pick, least robust first
+    pub picks: Vec<PickRobustness>,
+    /// Most frequently flipping bullet first
+    pub boundary: Vec<BoundaryBullet>,
+}
+
+/// Sweep every knob of `profile` and report how the selection responds
+///
+/// Fails when the selection overrides don't match `data`.
+pub fn analyze_sensitivity(
+    data: &ResumeData,
+    profile: &RoleProfile,
+    options: &SensitivityOptions,
+) -> Result<SensitivityReport, String> {
+    options.selection.overrides.validate(data)?;
+    let select = |profile: &RoleProfile| -> Vec<String> {
+        selector::select_validated(data, &HeuristicScorer::new(profile), &options.selection)
+            .bullets
+            .into_iter()
+            .map(|s| s.bullet.id)
+            .collect()
+    };
+
+    // Only bullets the overrides allow can ever compete for a slot
+    let scores: HashMap<String, f32> = scoring::score_all(data, profile)
+        .into_iter()
+        .filter(|s| {
+            options
+                .selection
+                .overrides
+                .allows(&s.bullet.id, &s.company_id)
+        })
+        .map(|s| (s.bullet.id, s.score))
+        .collect();
+    let baseline = select(profile);
+    let baseline_set: HashSet<&str> = baseline.iter().map(String::as_str).collect();
+
+    let mut knobs: Vec<Knob> = vec![Knob::ScoringSplit];
+    let mut tags: Vec<&Tag> = profile.tag_weights.keys().collect();
+    tags.sort();
+    knobs.extend(
+        tags.into_iter()
+            .map(|tag| Knob::TagWeight { tag: tag.clone() }),
+    );
+
+    let mut flips: BTreeMap<String, usize> = BTreeMap::new();
+    let mut dropped_by: HashMap<String, Vec<Knob>> = HashMap::new();
+    let mut total_samples = 0;
+    let mut sensitivities = Vec::with_capacity(knobs.len());
+
+    for knob in knobs {
+        let baseline_value = knob.value_in(profile);
+        let mut samples = Vec::new();
+        let mut breakpoints = Vec::new();
+        let mut previous: Option<(f32, HashSet<String>)> = None;
+
+        for value in sample_values(&knob, baseline_value, options) {
+            let selected = select(&knob.apply(profile, value));
+            let selected_set: HashSet<String> = selected.iter().cloned().collect();
+
+            let added: Vec<String> = selected
+                .iter()
+                .filter(|id| !baseline_set.contains(id.as_str()))
+                .cloned()
+                .collect();
+            let removed: Vec<String> = baseline
+                .iter()
+                .filter(|id| !selected_set.contains(*id))
+                .cloned()
+                .collect();
+
+            for id in added.iter().chain(&removed) {
+                *flips.entry(id.clone()).or_default() += 1;
+            }
+            for id in &removed {
+                let knobs = dropped_by.entry(id.clone()).or_default();
+                if !knobs.contains(&knob) {
+                    knobs.push(knob.clone());
+                }
+            }
+
+            if let Some((prev_value, prev_set)) = &previous {
+                if *prev_set != selected_set {
+                    breakpoints.push((prev_value + value) / 2.0);
+                }
+            }
+            previous = Some((value, selected_set));
+            total_samples += 1;
+            samples.push(KnobSample {
+                value,
+                added,
+                removed,
+            });
+        }
+
+        sensitivities.push(KnobSensitivity {
+            changed_samples: samples.iter().filter(|s| !s.is_unchanged()).count(),
+            max_churn: samples.iter().map(|s| s.removed.len()).max().unwrap_or(0),
+            knob,
+            baseline_value,
+            samples,
+            breakpoints,
+        });
+    }
+
+    sensitivities.sort_by(|a, b| {
+        b.changed_samples
+            .cmp(&a.changed_samples)
+            .then_with(|| b.max_churn.cmp(&a.max_churn))
+            .then_with(|| a.knob.cmp(&b.knob))
+    });
+
+    let score_of = |id: &str| scores.get(id).copied().unwrap_or(0.0);
+    let best_unselected = scores
+        .iter()
+        .filter(|(id, _)| !baseline_set.contains(id.as_str()))
+        .map(|(_, score)| *score)
+        .fold(None, |best: Option<f32>, s| {
+            Some(best.map_or(s, |b| b.max(s)))
+        });
+
+    let mut picks: Vec<PickRobustness> = baseline
+        .iter()
+        .map(|id| {
+            let score = score_of(id);
+            let dropped = dropped_by.remove(id).unwrap_or_default();
+            let drops = flips.get(id).copied().unwrap_or(0);
+            PickRobustness {
+                bullet_id: id.clone(),
+                score,
+                margin: best_unselected.map_or(score, |best| score - best),
+                retention: if total_samples == 0 {
+                    1.0
+                } else {
+                    1.0 - drops as f32 / total_samples as f32
+                },
+                dropped_by: dropped,
+            }
+        })
+        .collect();
+    picks.sort_by(|a, b| {
+        a.retention
+            .total_cmp(&b.retention)
+            .then_with(|| a.margin.total_cmp(&b.margin))
+    });
+
+    let mut boundary: Vec<BoundaryBullet> = flips
+        .into_iter()
+        .map(|(id, flips)| BoundaryBullet {
+            score: score_of(&id),
+            selected: baseline_set.contains(id.as_str()),
+            bullet_id: id,
+            flips,
+        })
+        .collect();
+    boundary.sort_by(|a, b| {
+        b.flips
+            .cmp(&a.flips)
+            .then_with(|| b.score.total_cmp(&a.score))
+            .then_with(|| a.bullet_id.cmp(&b.bullet_id))
+    });
+
+    Ok(SensitivityReport {
+        baseline,
+        knobs: sensitivities,
+        picks,
+        boundary,
+    })
+}
+
+/// Evenly spaced values for a knob (tag weights scale around their baseline;
+/// the scoring split covers 0.0-1.0)
+///
+/// Tag weights are clamped to 0.0-1.0, so samples past the cap collapse into
+/// one and a weight already at 1.0 is only swept downwards.
+fn sample_values(knob: &Knob, baseline_value: f32, options: &SensitivityOptions) -> Vec<f32> {
+    let (low, high) = match knob {
+        Knob::ScoringSplit => (0.0, 1.0),
+        Knob::TagWeight { .. } => (
+            baseline_value * options.min_factor,
+            baseline_value * options.max_factor,
+        ),
+    };
+    let steps = options.steps.max(2);
+    let mut values: Vec<f32> = (0..steps)
+        .map(|i| (low + (high - low) * i as f32 / (steps - 1) as f32).clamp(0.0, 1.0))
+        .collect();
+    values.dedup();
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{bullet, company, position, profile, resume};
+
+    fn sample() -> ResumeData {
+        resume(vec![company(
+            "acme",
+            vec![position(
+                "acme-eng",
+                vec![
+                    bullet("rust-1", "Rust services", &["rust"], 7),
+                    bullet("rust-2", "Rust tooling", &["rust"], 6),
+                    bullet("talk", "Conference talk", &["speaking"], 8),
+                    bullet("misc", "Office admin", &[], 10),
+                ],
+            )],
+        )])
+    }
+
+    fn options(max_bullets: usize) -> SensitivityOptions {
+        SensitivityOptions {
+            selection: SelectionConfig {
+                max_bullets,
+                max_per_company: None,
+                min_per_company: None,
+                max_per_position: None,
+                ..SelectionConfig::default()
+            },
+            ..SensitivityOptions::default()
+        }
+    }
+
+    #[test]
+    fn test_sample_values_cover_range() {
+        let tag = Knob::TagWeight {
+            tag: "rust".to_string(),
+        };
+        let values = sample_values(&tag, 0.4, &SensitivityOptions::default());
+        assert_eq!(values.len(), 9);
+        assert_eq!(values[0], 0.0);
+        assert!((values[4] - 0.4).abs() < 1e-6);
+        assert!((values[8] - 0.8).abs() < 1e-6);
+
+        // Scaled weights past 1.0 are capped and collapse into one sample
+        let capped = sample_values(&tag, 0.8, &SensitivityOptions::default());
+        assert_eq!(capped.len(), 6);
+        assert!((capped[4] - 0.8).abs() < 1e-6);
+        assert_eq!(capped[5], 1.0);
+        assert_eq!(
+            sample_values(&tag, 1.0, &SensitivityOptions::default()).last(),
+            Some(&1.0)
+        );
+
+        let split = sample_values(&Knob::ScoringSplit, 0.6, &SensitivityOptions::default());
+        assert_eq!((split[0], split[8]), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_irrelevant_knob_never_changes_selection() {
+        let data = sample();
+        let role = profile(&[("rust", 1.0), ("kubernetes", 1.0)]);
+        let report = analyze_sensitivity(&data, &role, &options(2)).unwrap();
+
+        let kubernetes = report
+            .knobs
+            .iter()
+            .find(|k| {
+                k.knob
+                    == Knob::TagWeight {
+                        tag: "kubernetes".to_string(),
+                    }
+            })
+            .unwrap();
+        assert_eq!(kubernetes.changed_samples, 0);
+        assert!(kubernetes.breakpoints.is_empty());
+        assert!(kubernetes.samples.iter().all(KnobSample::is_unchanged));
+    }
+
+    #[test]
+    fn test_influential_knob_ranks_first_with_breakpoints() {
+        let data = sample();
+        let role = profile(&[("rust", 1.0), ("kubernetes", 1.0)]);
+        let report = analyze_sensitivity(&data, &role, &options(2)).unwrap();
+
+        assert_eq!(report.baseline, vec!["rust-1", "rust-2"]);
+        let top = &report.knobs[0];
+        assert_ne!(
+            top.knob,
+            Knob::TagWeight {
+                tag: "kubernetes".to_string()
+            }
+        );
+        assert!(top.changed_samples > 0);
+        assert!(!top.breakpoints.is_empty());
+
+        // Dropping the rust weight to zero swaps the rust bullets out
+        let rust = report
+            .knobs
+            .iter()
+            .find(|k| {
+                k.knob
+                    == Knob::TagWeight {
+                        tag: "rust".to_string(),
+                    }
+            })
+            .unwrap();
+        assert_eq!(rust.samples[0].value, 0.0);
+        assert!(!rust.samples[0].removed.is_empty());
+        assert!(rust.samples.last().unwrap().is_unchanged());
+    }
+
+    #[test]
+    fn test_picks_and_boundary() {
+        let data = sample();
+        let role = profile(&[("rust", 1.0)]);
+        let report = analyze_sensitivity(&data, &role, &options(2)).unwrap();
+
+        assert_eq!(report.picks.len(), 2);
+        assert!(report
+            .picks
+            .iter()
+            .all(|p| (0.0..=1.0).contains(&p.retention)));
+
+        // The weakest pick is the least robust and sits on the boundary
+        let weakest = &report.picks[0];
+        assert_eq!(weakest.bullet_id, "rust-2");
+        assert!(weakest.retention < 1.0);
+        assert!(!weakest.dropped_by.is_empty());
+
+        let boundary_ids: Vec<&str> = report
+            .boundary
+            .iter()
+            .map(|b| b.bullet_id.as_str())
+            .collect();
+        assert!(boundary_ids.contains(&"rust-2"));
+        assert!(report.boundary.iter().any(|b| !b.selected));
+    }
+
+    #[test]
+    fn test_margin_relative_to_best_unselected() {
+        let data = sample();
+        let role = profile(&[("rust", 1.0)]);
+        let report = analyze_sensitivity(&data, &role, &options(2)).unwrap();
+
+        let scores: HashMap<String, f32> = scoring::score_all(&data, &role)
+            .into_iter()
+            .map(|s| (s.bullet.id, s.score))
+            .collect();
+        let best_unselected = scores["misc"].max(scores["talk"]);
+
+        let rust_1 = report
+            .picks
+            .iter()
+            .find(|p| p.bullet_id == "rust-1")
+            .unwrap();
+        assert!((rust_1.margin - (scores["rust-1"] - best_unselected)).abs() < 1e-6);
+
+        // An excluded bullet can never be picked, so it is not a competitor
+        assert!(scores["misc"] > scores["talk"]);
+        let mut excluded = options(2);
+        excluded.selection.overrides.excluded_bullet_ids = vec!["misc".to_string()];
+        let report = analyze_sensitivity(&data, &role, &excluded).unwrap();
+        let rust_1 = report
+            .picks
+            .iter()
+            .find(|p| p.bullet_id == "rust-1")
+            .unwrap();
+        assert!((rust_1.margin - (scores["rust-1"] - scores["talk"])).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_report_serializes_knob_tags() {
+        let data = sample();
+        let role = profile(&[("rust", 1.0)]);
+        let json = serde_json::to_string(&analyze_sensitivity(&data, &role, &options(2)).unwrap())
+            .unwrap();
+
+        assert!(json.contains(r#""knob":{"type":"tagWeight","tag":"rust"}"#));
+        assert!(json.contains(r#""type":"scoringSplit""#));
+    }
+}
@@ -0,0 +1,73 @@
+//! Text similarity for near-duplicate detection
+//!
+//! Bullets are compared as sets of word shingles (stemmed unigrams plus
+//! bigrams, stopwords removed) using Jaccard similarity. Cheap, deterministic,
+//! and good enough to spot "Migrated services to Kubernetes" vs "Migrated
+//! services to Kubernetes, leading the effort".
+
+use crate::text;
+use std::collections::BTreeSet;
+
+/// Shingle set for a piece of text
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Shingles(BTreeSet<String>);
+
+impl Shingles {
+    /// Build unigram + bigram shingles from text
+    pub fn new(content: &str) -> Self {
+        let keywords = text::keywords(content);
+        let mut shingles: BTreeSet<String> = keywords.iter().cloned().collect();
+        for pair in keywords.windows(2) {
+            shingles.insert(format!("{} {}", pair[0], pair[1]));
+        }
+        Self(shingles)
+    }
+
+    /// Whether the text produced no shingles (empty or all stopwords)
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Jaccard similarity in 0.0-1.0 (0.0 if either side is empty)
+    pub fn jaccard(&self, other: &Shingles) -> f32 {
+        if self.0.is_empty() || other.0.is_empty() {
+            return 0.0;
+        }
+        let intersection = self.0.intersection(&other.0).count();
+        let union = self.0.len() + other.0.len() - intersection;
+        intersection as f32 / union as f32
+    }
+}
+
+/// Similarity of two texts, see [`Shingles::jaccard`]
+pub fn similarity(a: &str, b: &str) -> f32 {
+    Shingles::new(a).jaccard(&Shingles::new(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_text_is_fully_similar() {
+        let text = "Migrated payments platform to Kubernetes";
+        assert!((similarity(text, text) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_near_duplicates_score_higher_than_unrelated() {
+        let a = "Migrated the payments platform to Kubernetes, cutting deploy time by 80%";
+        let b = "Migrated payments platform to Kubernetes, leading the effort";
+        let c = "Organised a community meetup for 200 developers";
+
+        assert!(similarity(a, b) > 0.3);
+        assert!(similarity(a, c) < 0.05);
+    }
+
+    #[test]
+    fn test_empty_text_has_no_similarity() {
+        assert_eq!(similarity("", "anything"), 0.0);
+        assert_eq!(similarity("the and of", "the and of"), 0.0);
+        assert!(Shingles::new("").is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+//! Summary selection
+//!
+//! Picks the professional summary for a generation from the compendium's
+//! tagged candidates (`ResumeData.summaries`):
+//!
+//! 1. the role profile's `summaryId`, when it names a known candidate
+//! 2. otherwise the best-scoring candidate, scored like a bullet:
+//!    `tag_relevance × weights.tag_relevance + (priority / 10) × weights.priority`
+//! 3. with no candidates, the plain `ResumeData.summary`
+//!
+//! Score ties go to the candidate listed first.
+
+use crate::scoring::calculate_tag_relevance;
+use serde::{Deserialize, Serialize};
+use shared_types::{ResumeData, RoleProfile, SummaryCandidate};
+
+/// How the summary was chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SummarySource {
+    /// Named by the role profile's `summaryId`
+    Override,
+    /// Highest tag-weight score
+    Scored,
+    /// No candidates; `ResumeData.summary` used as-is
+    Fallback,
+}
+
+/// The summary to put at the top of the resume
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectedSummary {
+    /// Candidate ID, `None` for the fallback summary
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub text: String,
+    pub source: SummarySource,
+    /// Candidate score against the role profile, `None` for the fallback
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f32>,
+}
+
+/// Score a summary candidate against a role profile
+pub fn score_summary(candidate: &SummaryCandidate, role_profile: &RoleProfile) -> f32 {
+    let weights = &role_profile.scoring_weights;
+    let tag_score = calculate_tag_relevance(&candidate.tags, &role_profile.tag_weights);
+    let priority_score = candidate.priority as f32 / 10.0;
+    tag_score * weights.tag_relevance + priority_score * weights.priority
+}
+
+/// Choose the summary for a role profile
+///
+/// Returns `None` only when the compendium has neither candidates nor a
+/// plain summary.
+pub fn select_summary(data: &ResumeData, role_profile: &RoleProfile) -> Option<SelectedSummary> {
+    let candidates = data.summaries.as_deref().unwrap_or_default();
+
+    let chosen = |candidate: &SummaryCandidate, source| SelectedSummary {
+        id: Some(candidate.id.clone()),
+        text: candidate.text.clone(),
+        source,
+        score: Some(score_summary(candidate, role_profile)),
+    };
+
+    if let Some(pinned) = role_profile
+        .summary_id
+        .as_ref()
+        .and_then(|id| candidates.iter().find(|c| &c.id == id))
+    {
+        return Some(chosen(pinned, SummarySource::Override));
+    }
+
+    let best = candidates.iter().reduce(|best, candidate| {
+        if score_summary(candidate, role_profile) > score_summary(best, role_profile) {
+            candidate
+        } else {
+            best
+        }
+    });
+    if let Some(best) = best {
+        return Some(chosen(best, SummarySource::Scored));
+    }
+
+    data.summary
+        .as_ref()
+        .filter(|text| !text.is_empty())
+        .map(|text| SelectedSummary {
+            id: None,
+            text: text.clone(),
+            source: SummarySource::Fallback,
+            score: None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{profile, resume};
+
+    fn candidate(id: &str, tags: &[&str], priority: u8) -> SummaryCandidate {
+        SummaryCandidate {
+            id: id.to_string(),
+            text: format!("Summary {}", id),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            priority,
+        }
+    }
+
+    fn data_with_candidates() -> ResumeData {
+        let mut data = resume(vec![]);
+        data.summary = Some("Generic summary".to_string());
+        data.summaries = Some(vec![
+            candidate("platform", &["rust", "kubernetes"], 6),
+            candidate("leadership", &["leadership", "hiring"], 8),
+            candidate("generalist", &[], 9),
+        ]);
+        data
+    }
+
+    #[test]
+    fn test_best_tag_match_wins() {
+        let data = data_with_candidates();
+
+        let platform = select_summary(&data, &profile(&[("rust", 1.0), ("kubernetes", 0.8)]));
+        assert_eq!(platform.unwrap().id.as_deref(), Some("platform"));
+
+        let manager = select_summary(&data, &profile(&[("leadership", 1.0), ("hiring", 0.9)]));
+        let manager = manager.unwrap();
+        assert_eq!(manager.id.as_deref(), Some("leadership"));
+        assert_eq!(manager.source, SummarySource::Scored);
+        assert_eq!(manager.text, "Summary leadership");
+    }
+
+    #[test]
+    fn test_priority_breaks_untagged_ties() {
+        let data = data_with_candidates();
+        let unrelated = select_summary(&data, &profile(&[("design", 1.0)])).unwrap();
+        assert_eq!(unrelated.id.as_deref(), Some("generalist"));
+    }
+
+    #[test]
+    fn test_profile_override_beats_scoring() {
+        let data = data_with_candidates();
+        let mut role = profile(&[("rust", 1.0)]);
+        role.summary_id = Some("leadership".to_string());
+
+        let selected = select_summary(&data, &role).unwrap();
+        assert_eq!(selected.id.as_deref(), Some("leadership"));
+        assert_eq!(selected.source, SummarySource::Override);
+
+        // Unknown override falls back to scoring
+        role.summary_id = Some("missing".to_string());
+        let selected = select_summary(&data, &role).unwrap();
+        assert_eq!(selected.id.as_deref(), Some("platform"));
+        assert_eq!(selected.source, SummarySource::Scored);
+    }
+
+    #[test]
+    fn test_falls_back_to_plain_summary() {
+        let mut data = data_with_candidates();
+        data.summaries = None;
+
+        let selected = select_summary(&data, &profile(&[("rust", 1.0)])).unwrap();
+        assert_eq!(selected.source, SummarySource::Fallback);
+        assert_eq!(selected.text, "Generic summary");
+        assert_eq!(selected.id, None);
+
+        data.summary = None;
+        assert_eq!(select_summary(&data, &profile(&[("rust", 1.0)])), None);
+    }
+}
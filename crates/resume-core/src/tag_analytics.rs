@@ -0,0 +1,439 @@
+//! Tag coverage and usage analytics
+//!
+//! Rust counterpart of `lib/tags.ts` (`getSortedTagsWithMetrics`) with extra
+//! coverage data. Unlike the TS, company tags count alongside position and
+//! bullet tags. For each tag in the compendium:
+//!
+//! - occurrence and bullet counts
+//! - priority-weighted frequency (`count × avgPriority`, the TS sort weight)
+//! - the date span it covers
+//! - companies it appears under
+//! - which other tags it co-occurs with on the same bullet
+//!
+//! Plus two hygiene lists: role-profile tags no company, position or bullet
+//! uses, and orphan tags. A tag is an orphan when it occurs exactly once
+//! (`count == 1`) across companies, positions and bullets; a bullet repeating
+//! a tag still counts once.
+
+use serde::{Deserialize, Serialize};
+use shared_types::{Bullet, Company, Position, ResumeData, Tag};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Priority assumed for items with priority 0 (mid-range, as in the TS)
+const DEFAULT_PRIORITY: u32 = 5;
+
+/// Earliest start and latest end of the items carrying a tag
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DateSpan {
+    pub start: String,
+    /// `None` when any tagged item is ongoing
+    pub end: Option<String>,
+}
+
+impl DateSpan {
+    fn merge(&mut self, other: DateSpan) {
+        if other.start < self.start {
+            self.start = other.start;
+        }
+        self.end = match (self.end.take(), other.end) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            _ => None,
+        };
+    }
+}
+
+/// How often another tag appears on the same bullet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagCooccurrence {
+    pub tag: Tag,
+    pub count: usize,
+}
+
+/// Usage statistics for one tag
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagStats {
+    pub tag: Tag,
+    /// Occurrences on companies, positions and bullets (the TS `count`
+    /// skips companies)
+    pub count: usize,
+    /// Bullets carrying the tag
+    pub bullet_count: usize,
+    pub total_priority: u32,
+    pub avg_priority: f32,
+    /// Priority-weighted frequency: `count × avg_priority`
+    pub weight: f32,
+    pub date_span: DateSpan,
+    /// Company IDs, in compendium order
+    pub companies: Vec<String>,
+    /// Sorted by count descending, then tag
+    pub co_occurrence: Vec<TagCooccurrence>,
+}
+
+/// A role-profile tag that nothing in the compendium carries
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnusedProfileTag {
+    pub profile_id: String,
+    pub tag: Tag,
+    pub weight: f32,
+}
+
+/// Tag analytics for a whole compendium
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagAnalytics {
+    /// Sorted like `getSortedTagsWithMetrics`: weight, count, then tag
+    pub tags: Vec<TagStats>,
+    /// Grouped by profile (in `role_profiles` order), heaviest tag first
+    pub unused_profile_tags: Vec<UnusedProfileTag>,
+    /// Tags with `count == 1`, alphabetical
+    pub orphan_tags: Vec<Tag>,
+}
+
+impl TagAnalytics {
+    pub fn get(&self, tag: &str) -> Option<&TagStats> {
+        self.tags.iter().find(|stats| stats.tag == tag)
+    }
+}
+
+#[derive(Default)]
+struct Accumulator {
+    count: usize,
+    bullet_count: usize,
+    total_priority: u32,
+    date_span: Option<DateSpan>,
+    companies: Vec<String>,
+    co_occurrence: BTreeMap<Tag, usize>,
+}
+
+impl Accumulator {
+    fn record(&mut self, priority: u8, span: DateSpan, company_id: &str) {
+        self.count += 1;
+        self.total_priority += match priority {
+            0 => DEFAULT_PRIORITY,
+            p => u32::from(p),
+        };
+        match &mut self.date_span {
+            Some(existing) => existing.merge(span),
+            None => self.date_span = Some(span),
+        }
+        if !self.companies.iter().any(|c| c == company_id) {
+            self.companies.push(company_id.to_string());
+        }
+    }
+
+    fn finish(self, tag: Tag) -> TagStats {
+        let avg_priority = self.total_priority as f32 / self.count as f32;
+
+        let mut co_occurrence: Vec<TagCooccurrence> = self
+            .co_occurrence
+            .into_iter()
+            .map(|(tag, count)| TagCooccurrence { tag, count })
+            .collect();
+        co_occurrence.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.tag.cmp(&b.tag)));
+
+        TagStats {
+            tag,
+            count: self.count,
+            bullet_count: self.bullet_count,
+            total_priority: self.total_priority,
+            avg_priority,
+            weight: self.count as f32 * avg_priority,
+            date_span: self.date_span.expect("accumulator has at least one record"),
+            companies: self.companies,
+            co_occurrence,
+        }
+    }
+}
+
+/// Compute tag analytics for a compendium
+pub fn analyze_tags(data: &ResumeData) -> TagAnalytics {
+    let mut accumulators: BTreeMap<Tag, Accumulator> = BTreeMap::new();
+
+    for company in &data.experience {
+        for tag in unique(&company.tags) {
+            accumulators.entry(tag.clone()).or_default().record(
+                company.priority,
+                company_span(company),
+                &company.id,
+            );
+        }
+
+        for position in &company.children {
+            for tag in unique(&position.tags) {
+                accumulators.entry(tag.clone()).or_default().record(
+                    position.priority,
+                    position_span(position),
+                    &company.id,
+                );
+            }
+
+            for bullet in &position.children {
+                let tags = unique(&bullet.tags);
+                for &tag in &tags {
+                    let acc = accumulators.entry(tag.clone()).or_default();
+                    acc.record(bullet.priority, bullet_span(bullet, position), &company.id);
+                    acc.bullet_count += 1;
+                    for &other in &tags {
+                        if other != tag {
+                            *acc.co_occurrence.entry(other.clone()).or_default() += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let orphan_tags = accumulators
+        .iter()
+        .filter(|(_, acc)| acc.count == 1)
+        .map(|(tag, _)| tag.clone())
+        .collect();
+
+    let mut unused_profile_tags = Vec::new();
+    for profile in data.role_profiles.iter().flatten() {
+        let mut unused: Vec<UnusedProfileTag> = profile
+            .tag_weights
+            .iter()
+            .filter(|(tag, _)| !accumulators.contains_key(*tag))
+            .map(|(tag, weight)| UnusedProfileTag {
+                profile_id: profile.id.clone(),
+                tag: tag.clone(),
+                weight: *weight,
+            })
+            .collect();
+        unused.sort_by(|a, b| {
+            b.weight
+                .total_cmp(&a.weight)
+                .then_with(|| a.tag.cmp(&b.tag))
+        });
+        unused_profile_tags.extend(unused);
+    }
+
+    let mut tags: Vec<TagStats> = accumulators
+        .into_iter()
+        .map(|(tag, acc)| acc.finish(tag))
+        .collect();
+    tags.sort_by(|a, b| {
+        b.weight
+            .total_cmp(&a.weight)
+            .then_with(|| b.count.cmp(&a.count))
+            .then_with(|| a.tag.cmp(&b.tag))
+    });
+
+    TagAnalytics {
+        tags,
+        unused_profile_tags,
+        orphan_tags,
+    }
+}
+
+/// Deduplicate while keeping first-seen order
+fn unique(tags: &[Tag]) -> Vec<&Tag> {
+    let mut seen = BTreeSet::new();
+    tags.iter().filter(|tag| seen.insert(*tag)).collect()
+}
+
+fn company_span(company: &Company) -> DateSpan {
+    DateSpan {
+        start: company.date_start.clone(),
+        end: company.date_end.clone(),
+    }
+}
+
+fn position_span(position: &Position) -> DateSpan {
+    DateSpan {
+        start: position.date_start.clone(),
+        end: position.date_end.clone(),
+    }
+}
+
+/// Dated bullets (talks, projects) use their own dates; an open-ended dated
+/// bullet is a single point in time. Undated bullets inherit the position's.
+fn bullet_span(bullet: &Bullet, position: &Position) -> DateSpan {
+    match &bullet.date_start {
+        Some(start) => DateSpan {
+            start: start.clone(),
+            end: Some(bullet.date_end.clone().unwrap_or_else(|| start.clone())),
+        },
+        None => position_span(position),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{bullet, company, position, profile, resume};
+
+    fn sample() -> ResumeData {
+        let mut early = position(
+            "old-eng",
+            vec![
+                bullet("b1", "Rust services", &["rust", "backend"], 8),
+                bullet("b2", "Rust CLI", &["rust"], 6),
+            ],
+        );
+        early.date_start = "2018-03".to_string();
+        early.date_end = Some("2020-01".to_string());
+
+        let mut talk = bullet("b3", "Conference talk", &["speaking", "rust"], 0);
+        talk.date_start = Some("2023-05".to_string());
+
+        let mut lead = position("new-lead", vec![talk]);
+        lead.tags = vec!["leadership".to_string()];
+        lead.priority = 9;
+
+        let mut data = resume(vec![
+            company("old", vec![early]),
+            company("new", vec![lead]),
+        ]);
+        let mut role = profile(&[("rust", 1.0), ("kubernetes", 0.8), ("go", 0.3)]);
+        role.id = "platform".to_string();
+        data.role_profiles = Some(vec![role]);
+        data
+    }
+
+    #[test]
+    fn test_counts_and_weight_match_ts() {
+        let analytics = analyze_tags(&sample());
+        let rust = analytics.get("rust").unwrap();
+
+        assert_eq!(rust.count, 3);
+        assert_eq!(rust.bullet_count, 3);
+        // Priority 0 defaults to 5
+        assert_eq!(rust.total_priority, 8 + 6 + 5);
+        assert!((rust.weight - 19.0).abs() < 0.001);
+        assert_eq!(analytics.tags[0].tag, "rust");
+    }
+
+    #[test]
+    fn test_position_tags_count_but_not_as_bullets() {
+        let analytics = analyze_tags(&sample());
+        let leadership = analytics.get("leadership").unwrap();
+
+        assert_eq!(leadership.count, 1);
+        assert_eq!(leadership.bullet_count, 0);
+        assert_eq!(leadership.total_priority, 9);
+    }
+
+    #[test]
+    fn test_date_span_and_companies() {
+        let analytics = analyze_tags(&sample());
+        let rust = analytics.get("rust").unwrap();
+
+        assert_eq!(rust.companies, vec!["old", "new"]);
+        assert_eq!(
+            rust.date_span,
+            DateSpan {
+                start: "2018-03".to_string(),
+                end: Some("2023-05".to_string()),
+            }
+        );
+
+        // Ongoing position leaves the span open
+        let leadership = analytics.get("leadership").unwrap();
+        assert_eq!(leadership.date_span.end, None);
+    }
+
+    #[test]
+    fn test_co_occurrence() {
+        let analytics = analyze_tags(&sample());
+        let rust = analytics.get("rust").unwrap();
+
+        assert_eq!(
+            rust.co_occurrence,
+            vec![
+                TagCooccurrence {
+                    tag: "backend".to_string(),
+                    count: 1,
+                },
+                TagCooccurrence {
+                    tag: "speaking".to_string(),
+                    count: 1,
+                },
+            ]
+        );
+        assert!(analytics
+            .get("leadership")
+            .unwrap()
+            .co_occurrence
+            .is_empty());
+    }
+
+    #[test]
+    fn test_unused_profile_tags_and_orphans() {
+        let analytics = analyze_tags(&sample());
+
+        let unused: Vec<(&str, &str)> = analytics
+            .unused_profile_tags
+            .iter()
+            .map(|u| (u.profile_id.as_str(), u.tag.as_str()))
+            .collect();
+        assert_eq!(unused, vec![("platform", "kubernetes"), ("platform", "go")]);
+
+        assert_eq!(
+            analytics.orphan_tags,
+            vec!["backend", "leadership", "speaking"]
+        );
+    }
+
+    #[test]
+    fn test_company_tags_count() {
+        let mut data = sample();
+        data.experience[0].tags = vec!["startup".to_string(), "rust".to_string()];
+        data.experience[0].priority = 7;
+
+        let analytics = analyze_tags(&data);
+        let startup = analytics.get("startup").unwrap();
+        assert_eq!(startup.count, 1);
+        assert_eq!(startup.bullet_count, 0);
+        assert_eq!(startup.total_priority, 7);
+        assert_eq!(startup.companies, vec!["old"]);
+
+        let rust = analytics.get("rust").unwrap();
+        assert_eq!(rust.count, 4);
+        assert_eq!(rust.bullet_count, 3);
+
+        // A company-only tag is used once, so it is an orphan
+        assert!(analytics.orphan_tags.contains(&"startup".to_string()));
+    }
+
+    #[test]
+    fn test_orphans_count_every_level() {
+        // "backend" appears once on a bullet; tagging its company too means
+        // it is used twice and no longer an orphan
+        let mut data = sample();
+        data.experience[0].tags = vec!["backend".to_string()];
+
+        let analytics = analyze_tags(&data);
+        assert_eq!(analytics.get("backend").unwrap().count, 2);
+        assert_eq!(analytics.orphan_tags, vec!["leadership", "speaking"]);
+    }
+
+    #[test]
+    fn test_duplicate_tags_on_one_bullet_count_once() {
+        let data = resume(vec![company(
+            "acme",
+            vec![position(
+                "acme-eng",
+                vec![bullet("b1", "Rust", &["rust", "rust"], 5)],
+            )],
+        )]);
+
+        let analytics = analyze_tags(&data);
+        let rust = analytics.get("rust").unwrap();
+        assert_eq!(rust.count, 1);
+        assert!(rust.co_occurrence.is_empty());
+    }
+
+    #[test]
+    fn test_serializes_camel_case() {
+        let json = serde_json::to_string(&analyze_tags(&sample())).unwrap();
+        assert!(json.contains("\"bulletCount\""));
+        assert!(json.contains("\"unusedProfileTags\""));
+        assert!(json.contains("\"coOccurrence\""));
+    }
+}
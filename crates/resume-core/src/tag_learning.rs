@@ -0,0 +1,396 @@
+//! Learn tag weights from generation feedback
+//!
+//! After a generation the user keeps some bullets and swaps others out. This
+//! module turns those labels into an updated [`RoleProfile`]:
+//!
+//! 1. Each labeled bullet becomes an example with one binary feature per tag
+//!    (position descriptions use their synthetic `{position_id}-description` ID).
+//! 2. An L2-regularized logistic regression is fitted with full-batch gradient
+//!    descent: `P(accepted) = σ(bias + Σ θ_tag)`. The bias is not regularized,
+//!    so it absorbs the overall accept rate and `θ` only captures what tells
+//!    kept bullets apart from dropped ones.
+//! 3. Each coefficient shifts the current weight in logit space:
+//!    `new = σ(logit(old) + θ_tag)`. The regularizer therefore pulls towards
+//!    the existing profile, and a handful of labels nudges rather than
+//!    rewrites it. Tags not yet in the profile start from `new_tag_prior`.
+//!
+//! Everything is deterministic: fixed iteration count, zero initialization and
+//! tags processed in sorted order, so the same labels always produce the same
+//! profile.
+
+use crate::scoring;
+use serde::{Deserialize, Serialize};
+use shared_types::{Bullet, ResumeData, RoleProfile, Tag};
+use std::collections::{BTreeSet, HashMap};
+
+/// Keeps `logit` finite for weights of exactly 0.0 or 1.0
+const WEIGHT_EPSILON: f32 = 0.001;
+
+/// Bullet IDs labeled during review of a generated resume
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BulletFeedback {
+    /// Bullets kept on the resume
+    pub accepted: Vec<String>,
+    /// Bullets swapped out
+    pub rejected: Vec<String>,
+}
+
+/// Learner hyperparameters
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LearnOptions {
+    /// L2 penalty on tag coefficients; higher keeps the profile closer to the
+    /// current weights
+    pub l2: f32,
+    /// Gradient descent step size
+    pub learning_rate: f32,
+    /// Gradient descent steps
+    pub iterations: usize,
+    /// Starting weight for tags that appear in feedback but not in the profile
+    pub new_tag_prior: f32,
+    /// Weight changes smaller than this are discarded
+    pub min_change: f32,
+}
+
+impl Default for LearnOptions {
+    fn default() -> Self {
+        Self {
+            l2: 0.1,
+            learning_rate: 0.5,
+            iterations: 500,
+            new_tag_prior: 0.1,
+            min_change: 0.01,
+        }
+    }
+}
+
+/// One entry of the diff between the old and learned profile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagWeightChange {
+    pub tag: Tag,
+    /// Weight in the input profile, `None` for newly added tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<f32>,
+    pub after: f32,
+    /// Fitted logistic-regression coefficient behind the change
+    pub coefficient: f32,
+}
+
+impl TagWeightChange {
+    pub fn delta(&self) -> f32 {
+        self.after - self.before.unwrap_or(0.0)
+    }
+}
+
+/// Result of [`learn_tag_weights`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LearnedProfile {
+    /// Input profile with updated `tag_weights`; everything else is unchanged
+    pub profile: RoleProfile,
+    /// Largest change first
+    pub changes: Vec<TagWeightChange>,
+    /// Accepted examples used for fitting
+    pub accepted: usize,
+    /// Rejected examples used for fitting
+    pub rejected: usize,
+    /// Mean log loss on the training examples after fitting
+    pub log_loss: f32,
+    /// Labeled IDs that match no bullet in the compendium
+    pub unknown_ids: Vec<String>,
+    /// IDs labeled both accepted and rejected (ignored)
+    pub conflicting_ids: Vec<String>,
+}
+
+impl LearnedProfile {
+    pub fn is_unchanged(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+fn logit(p: f32) -> f32 {
+    let p = p.clamp(WEIGHT_EPSILON, 1.0 - WEIGHT_EPSILON);
+    (p / (1.0 - p)).ln()
+}
+
+/// Every bullet candidate in the compendium, keyed by ID
+fn bullets_by_id(data: &ResumeData) -> HashMap<String, Bullet> {
+    let mut bullets = HashMap::new();
+    for company in &data.experience {
+        for position in &company.children {
+            if let Some(description) = scoring::position_description_bullet(position) {
+                bullets.insert(description.id.clone(), description);
+            }
+            for bullet in &position.children {
+                bullets.insert(bullet.id.clone(), bullet.clone());
+            }
+        }
+    }
+    bullets
+}
+
+/// Fit tag weights to accepted/rejected bullets and return the updated profile
+pub fn learn_tag_weights(
+    data: &ResumeData,
+    profile: &RoleProfile,
+    feedback: &BulletFeedback,
+    options: &LearnOptions,
+) -> LearnedProfile {
+    let bullets = bullets_by_id(data);
+    let accepted_ids: BTreeSet<&str> = feedback.accepted.iter().map(String::as_str).collect();
+    let rejected_ids: BTreeSet<&str> = feedback.rejected.iter().map(String::as_str).collect();
+
+    let conflicting_ids: Vec<String> = accepted_ids
+        .intersection(&rejected_ids)
+        .map(|id| id.to_string())
+        .collect();
+    let unknown_ids: Vec<String> = accepted_ids
+        .union(&rejected_ids)
+        .filter(|id| !bullets.contains_key(**id))
+        .map(|id| id.to_string())
+        .collect();
+
+    let labeled = |ids: &BTreeSet<&str>, other: &BTreeSet<&str>, label: f32| {
+        ids.iter()
+            .filter(|id| !other.contains(**id))
+            .filter_map(|id| bullets.get(*id))
+            .map(|bullet| (bullet.tags.clone(), label))
+            .collect::<Vec<_>>()
+    };
+    let mut examples = labeled(&accepted_ids, &rejected_ids, 1.0);
+    let accepted = examples.len();
+    examples.extend(labeled(&rejected_ids, &accepted_ids, 0.0));
+    let rejected = examples.len() - accepted;
+
+    // Feature index per tag, in sorted order
+    let tags: Vec<Tag> = examples
+        .iter()
+        .flat_map(|(tags, _)| tags.iter().cloned())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let index: HashMap<&str, usize> = tags
+        .iter()
+        .enumerate()
+        .map(|(i, tag)| (tag.as_str(), i))
+        .collect();
+    let rows: Vec<(Vec<usize>, f32)> = examples
+        .iter()
+        .map(|(bullet_tags, label)| {
+            let features: BTreeSet<usize> = bullet_tags.iter().map(|t| index[t.as_str()]).collect();
+            (features.into_iter().collect(), *label)
+        })
+        .collect();
+
+    let (theta, log_loss) = fit(&rows, tags.len(), options);
+
+    let mut learned = profile.clone();
+    let mut changes = Vec::new();
+    for (tag, coefficient) in tags.iter().zip(theta) {
+        let before = profile.tag_weights.get(tag).copied();
+        let start = before.unwrap_or(options.new_tag_prior);
+        let after = sigmoid(logit(start) + coefficient);
+        if (after - start).abs() < options.min_change {
+            continue;
+        }
+        learned.tag_weights.insert(tag.clone(), after);
+        changes.push(TagWeightChange {
+            tag: tag.clone(),
+            before,
+            after,
+            coefficient,
+        });
+    }
+    changes.sort_by(|a, b| {
+        b.delta()
+            .abs()
+            .total_cmp(&a.delta().abs())
+            .then_with(|| a.tag.cmp(&b.tag))
+    });
+
+    LearnedProfile {
+        profile: learned,
+        changes,
+        accepted,
+        rejected,
+        log_loss,
+        unknown_ids,
+        conflicting_ids,
+    }
+}
+
+/// Full-batch gradient descent on mean log loss + `l2 / 2 · |θ|²`
+///
+/// Returns the tag coefficients and the final mean log loss (0.0 without
+/// examples).
+fn fit(rows: &[(Vec<usize>, f32)], features: usize, options: &LearnOptions) -> (Vec<f32>, f32) {
+    let mut theta = vec![0.0f32; features];
+    if rows.is_empty() {
+        return (theta, 0.0);
+    }
+
+    let n = rows.len() as f32;
+    let predict = |bias: f32, theta: &[f32], active: &[usize]| {
+        sigmoid(bias + active.iter().map(|&i| theta[i]).sum::<f32>())
+    };
+
+    let mut bias = 0.0f32;
+    for _ in 0..options.iterations {
+        let mut grad_bias = 0.0f32;
+        let mut grad: Vec<f32> = theta.iter().map(|w| options.l2 * w).collect();
+        for (active, label) in rows {
+            let error = (predict(bias, &theta, active) - label) / n;
+            grad_bias += error;
+            for &i in active {
+                grad[i] += error;
+            }
+        }
+        bias -= options.learning_rate * grad_bias;
+        for (w, g) in theta.iter_mut().zip(&grad) {
+            *w -= options.learning_rate * g;
+        }
+    }
+
+    let log_loss = rows
+        .iter()
+        .map(|(active, label)| {
+            let p = predict(bias, &theta, active).clamp(f32::EPSILON, 1.0 - f32::EPSILON);
+            -(label * p.ln() + (1.0 - label) * (1.0 - p).ln())
+        })
+        .sum::<f32>()
+        / n;
+
+    (theta, log_loss)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{bullet, company, position, profile, resume};
+
+    fn data() -> ResumeData {
+        resume(vec![company(
+            "acme",
+            vec![position(
+                "eng",
+                vec![
+                    bullet("b1", "Built Rust services", &["rust", "backend"], 7),
+                    bullet("b2", "Rewrote the Rust parser", &["rust"], 6),
+                    bullet("b3", "Ran the frontend migration", &["frontend"], 7),
+                    bullet(
+                        "b4",
+                        "Styled the marketing site",
+                        &["frontend", "design"],
+                        5,
+                    ),
+                    bullet("b5", "Set up backend monitoring", &["backend"], 6),
+                ],
+            )],
+        )])
+    }
+
+    fn feedback(accepted: &[&str], rejected: &[&str]) -> BulletFeedback {
+        BulletFeedback {
+            accepted: accepted.iter().map(|id| id.to_string()).collect(),
+            rejected: rejected.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_accepted_tags_gain_and_rejected_tags_lose_weight() {
+        let role = profile(&[("rust", 0.5), ("frontend", 0.5), ("backend", 0.5)]);
+        let learned = learn_tag_weights(
+            &data(),
+            &role,
+            &feedback(&["b1", "b2"], &["b3", "b4"]),
+            &LearnOptions::default(),
+        );
+
+        let weights = &learned.profile.tag_weights;
+        assert!(weights["rust"] > 0.5);
+        assert!(weights["frontend"] < 0.5);
+        assert_eq!(learned.accepted, 2);
+        assert_eq!(learned.rejected, 2);
+        assert!(learned.log_loss < std::f32::consts::LN_2);
+
+        // "design" is new and only seen on a rejected bullet
+        let design = learned.changes.iter().find(|c| c.tag == "design").unwrap();
+        assert_eq!(design.before, None);
+        assert!(design.after < LearnOptions::default().new_tag_prior);
+
+        // Diff is ordered by magnitude
+        let deltas: Vec<f32> = learned.changes.iter().map(|c| c.delta().abs()).collect();
+        assert!(deltas.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn test_untouched_fields_are_preserved() {
+        let mut role = profile(&[("rust", 0.5), ("leadership", 0.9)]);
+        role.summary_id = Some("platform".to_string());
+        let learned = learn_tag_weights(
+            &data(),
+            &role,
+            &feedback(&["b2"], &["b3"]),
+            &LearnOptions::default(),
+        );
+
+        assert_eq!(learned.profile.id, role.id);
+        assert_eq!(learned.profile.scoring_weights, role.scoring_weights);
+        assert_eq!(learned.profile.summary_id, role.summary_id);
+        assert_eq!(learned.profile.tag_weights["leadership"], 0.9);
+        assert!(learned.changes.iter().all(|c| c.tag != "leadership"));
+    }
+
+    #[test]
+    fn test_stronger_regularization_moves_weights_less() {
+        let role = profile(&[("rust", 0.5), ("frontend", 0.5)]);
+        let labels = feedback(&["b1", "b2"], &["b3", "b4"]);
+        let loose = learn_tag_weights(&data(), &role, &labels, &LearnOptions::default());
+        let strict = learn_tag_weights(
+            &data(),
+            &role,
+            &labels,
+            &LearnOptions {
+                l2: 2.0,
+                ..LearnOptions::default()
+            },
+        );
+
+        let rust = |l: &LearnedProfile| l.profile.tag_weights["rust"];
+        assert!(rust(&loose) > rust(&strict));
+        assert!(rust(&strict) > 0.5);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let role = profile(&[("rust", 0.5)]);
+        let labels = feedback(&["b1", "b5"], &["b3", "b4", "b2"]);
+        let first = learn_tag_weights(&data(), &role, &labels, &LearnOptions::default());
+        let second = learn_tag_weights(&data(), &role, &labels, &LearnOptions::default());
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_unknown_and_conflicting_ids_are_reported() {
+        let role = profile(&[("rust", 0.5)]);
+        let learned = learn_tag_weights(
+            &data(),
+            &role,
+            &feedback(&["b1", "ghost"], &["b1"]),
+            &LearnOptions::default(),
+        );
+
+        assert_eq!(learned.unknown_ids, vec!["ghost".to_string()]);
+        assert_eq!(learned.conflicting_ids, vec!["b1".to_string()]);
+        assert_eq!(learned.accepted + learned.rejected, 0);
+        assert!(learned.is_unchanged());
+        assert_eq!(learned.profile, role);
+    }
+}
@@ -0,0 +1,97 @@
+//! Builders for unit-test compendiums
+//!
+//! Keeps the struct-literal noise of `shared_types` out of individual tests.
+
+use shared_types::{
+    Bullet, Company, PersonalInfo, Position, ResumeData, RoleProfile, ScoringWeights,
+};
+use std::collections::HashMap;
+
+pub fn bullet(id: &str, description: &str, tags: &[&str], priority: u8) -> Bullet {
+    Bullet {
+        id: id.to_string(),
+        name: None,
+        location: None,
+        date_start: None,
+        date_end: None,
+        summary: None,
+        description: description.to_string(),
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        priority,
+        link: None,
+    }
+}
+
+pub fn position(id: &str, bullets: Vec<Bullet>) -> Position {
+    Position {
+        id: id.to_string(),
+        name: "Engineer".to_string(),
+        location: None,
+        date_start: "2020-01".to_string(),
+        date_end: None,
+        concurrent: None,
+        summary: None,
+        description: None,
+        tags: vec![],
+        priority: 8,
+        link: None,
+        children: bullets,
+    }
+}
+
+pub fn company(id: &str, positions: Vec<Position>) -> Company {
+    Company {
+        id: id.to_string(),
+        name: Some(id.to_string()),
+        location: None,
+        date_start: "2020-01".to_string(),
+        date_end: None,
+        summary: None,
+        description: None,
+        tags: vec![],
+        priority: 8,
+        link: None,
+        children: positions,
+    }
+}
+
+pub fn resume(companies: Vec<Company>) -> ResumeData {
+    ResumeData {
+        personal: PersonalInfo {
+            name: "Test Person".to_string(),
+            nickname: None,
+            tagline: None,
+            email: None,
+            phone: None,
+            location: None,
+            linkedin: None,
+            github: None,
+            website: None,
+            twitter: None,
+        },
+        summary: None,
+        summaries: None,
+        experience: companies,
+        education: None,
+        skills: None,
+        role_profiles: None,
+        meta_footer: None,
+    }
+}
+
+pub fn profile(tag_weights: &[(&str, f32)]) -> RoleProfile {
+    RoleProfile {
+        id: "test-role".to_string(),
+        name: "Test Role".to_string(),
+        description: None,
+        tag_weights: tag_weights
+            .iter()
+            .map(|(tag, weight)| (tag.to_string(), *weight))
+            .collect::<HashMap<_, _>>(),
+        scoring_weights: ScoringWeights {
+            tag_relevance: 0.6,
+            priority: 0.4,
+        },
+        summary_id: None,
+    }
+}
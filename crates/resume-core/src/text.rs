@@ -0,0 +1,327 @@
+//! Text normalization helpers shared by the compendium analyzers
+//!
+//! Everything here is deliberately simple and deterministic: lowercase ASCII
+//! folding, a small stopword list, and a light suffix stemmer. The goal is
+//! stable matching between free text (job descriptions, bullet descriptions)
+//! and kebab-case tags, not linguistic accuracy.
+
+/// Words that carry no signal for matching
+///
+/// Includes common English function words plus job-posting boilerplate
+/// ("experience", "team", "role") that would otherwise dominate keyword counts.
+const STOPWORDS: &[&str] = &[
+    "a",
+    "about",
+    "across",
+    "all",
+    "also",
+    "an",
+    "and",
+    "any",
+    "are",
+    "as",
+    "at",
+    "be",
+    "been",
+    "being",
+    "both",
+    "but",
+    "by",
+    "can",
+    "could",
+    "do",
+    "does",
+    "each",
+    "either",
+    "etc",
+    "for",
+    "from",
+    "had",
+    "has",
+    "have",
+    "having",
+    "how",
+    "i",
+    "if",
+    "in",
+    "into",
+    "is",
+    "it",
+    "its",
+    "just",
+    "may",
+    "more",
+    "most",
+    "much",
+    "must",
+    "my",
+    "no",
+    "not",
+    "of",
+    "on",
+    "one",
+    "or",
+    "other",
+    "our",
+    "out",
+    "over",
+    "per",
+    "plus",
+    "should",
+    "so",
+    "such",
+    "than",
+    "that",
+    "the",
+    "their",
+    "them",
+    "then",
+    "there",
+    "these",
+    "they",
+    "this",
+    "those",
+    "through",
+    "to",
+    "up",
+    "us",
+    "very",
+    "via",
+    "was",
+    "we",
+    "were",
+    "what",
+    "when",
+    "where",
+    "which",
+    "while",
+    "who",
+    "whom",
+    "why",
+    "will",
+    "with",
+    "within",
+    "would",
+    "you",
+    "your",
+    "yours",
+    // Job-posting boilerplate
+    "ability",
+    "able",
+    "candidate",
+    "company",
+    "day",
+    "environment",
+    "excellent",
+    "experience",
+    "ideal",
+    "ideally",
+    "including",
+    "join",
+    "looking",
+    "new",
+    "opportunity",
+    "preferred",
+    "year",
+    "required",
+    "requirement",
+    "responsibility",
+    "role",
+    "skill",
+    "strong",
+    "team",
+    "work",
+    "working",
+];
+
+/// Lowercase and split text into raw word tokens
+///
+/// Splits on anything that is not alphanumeric, keeping `+` and `#` so that
+/// terms like `c++` and `c#` survive. Tokens are not stemmed or filtered.
+///
+/// # Example
+/// ```
+/// use resume_core::text::words;
+///
+/// assert_eq!(words("Built C++ services, fast!"), vec!["built", "c++", "services", "fast"]);
+/// ```
+pub fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '+' || c == '#'))
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+/// Reduce a lowercase word to a crude stem
+///
+/// Strips plural and a few verb suffixes so "systems"/"system" and
+/// "scaling"/"scaled"/"scale" collapse together. Short words are left alone.
+pub fn stem(word: &str) -> String {
+    let len = word.chars().count();
+    if len <= 3 || !word.is_ascii() {
+        return word.to_string();
+    }
+
+    if let Some(base) = word.strip_suffix("ies") {
+        return format!("{}y", base);
+    }
+    if len > 5 {
+        if let Some(base) = word.strip_suffix("ing") {
+            return base.to_string();
+        }
+    }
+    if len > 4 {
+        if let Some(base) = word.strip_suffix("ed") {
+            return base.to_string();
+        }
+    }
+    if word.ends_with('s')
+        && !word.ends_with("ss")
+        && !word.ends_with("us")
+        && !word.ends_with("is")
+    {
+        return word[..word.len() - 1].to_string();
+    }
+    if len > 4 {
+        if let Some(base) = word.strip_suffix('e') {
+            return base.to_string();
+        }
+    }
+
+    word.to_string()
+}
+
+/// Check whether a (lowercase) word is a stopword
+pub fn is_stopword(word: &str) -> bool {
+    STOPWORDS.contains(&word) || STOPWORDS.contains(&stem(word).as_str())
+}
+
+/// Tokenize text into stemmed terms, keeping stopwords
+///
+/// Keeping stopwords preserves positions for phrase matching; use
+/// [`keywords`] when only meaningful terms are wanted.
+pub fn terms(text: &str) -> Vec<String> {
+    words(text).iter().map(|w| stem(w)).collect()
+}
+
+/// Tokenize text into stemmed terms with stopwords and numbers removed
+pub fn keywords(text: &str) -> Vec<String> {
+    words(text)
+        .into_iter()
+        .filter(|w| !is_stopword(w) && w.chars().any(char::is_alphabetic))
+        .map(|w| stem(&w))
+        .collect()
+}
+
+/// Convert a tag or alias into the stemmed term sequence it should match
+///
+/// Tags are kebab-case (`distributed-systems`), aliases are free text
+/// (`k8s`, `Large Language Models`); both become `["distribut", "system"]`-style
+/// sequences comparable with [`terms`] output.
+pub fn phrase_terms(phrase: &str) -> Vec<String> {
+    terms(&phrase.replace(['-', '_'], " "))
+}
+
+/// Count non-overlapping occurrences of `phrase` inside `haystack`
+///
+/// Both arguments are term sequences as produced by [`terms`] /
+/// [`phrase_terms`]. Windows touching a position already marked in
+/// `consumed` are skipped, so a shorter phrase can't re-count terms a longer
+/// one explained. Returns the match count and marks matched positions in
+/// `consumed`.
+pub fn count_phrase(haystack: &[String], phrase: &[String], consumed: &mut [bool]) -> usize {
+    if phrase.is_empty() || phrase.len() > haystack.len() {
+        return 0;
+    }
+
+    let mut count = 0;
+    let mut i = 0;
+    while i + phrase.len() <= haystack.len() {
+        let window = i..i + phrase.len();
+        if haystack[window.clone()] == *phrase && !consumed[window].contains(&true) {
+            for flag in &mut consumed[i..i + phrase.len()] {
+                *flag = true;
+            }
+            count += 1;
+            i += phrase.len();
+        } else {
+            i += 1;
+        }
+    }
+    count
+}
+
+/// Check whether `phrase` appears anywhere in `haystack`
+pub fn contains_phrase(haystack: &[String], phrase: &[String]) -> bool {
+    !phrase.is_empty()
+        && phrase.len() <= haystack.len()
+        && haystack.windows(phrase.len()).any(|w| w == phrase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_words_keeps_plus_and_hash() {
+        assert_eq!(words("C#, C++ and Rust"), vec!["c#", "c++", "and", "rust"]);
+    }
+
+    #[test]
+    fn test_stem_collapses_plurals_and_verb_forms() {
+        assert_eq!(stem("systems"), stem("system"));
+        assert_eq!(stem("scaling"), stem("scale"));
+        assert_eq!(stem("scaled"), stem("scale"));
+        assert_eq!(stem("technologies"), "technology");
+        assert_eq!(stem("kubernetes"), "kubernete");
+        assert_eq!(stem("process"), "process");
+        assert_eq!(stem("aws"), "aws");
+    }
+
+    #[test]
+    fn test_keywords_drops_stopwords_and_numbers() {
+        assert_eq!(
+            keywords("5+ years of experience with the Rust toolchain in 2024"),
+            vec!["rust", "toolchain"]
+        );
+    }
+
+    #[test]
+    fn test_phrase_terms_from_kebab_tag() {
+        assert_eq!(
+            phrase_terms("distributed-systems"),
+            terms("distributed systems")
+        );
+    }
+
+    #[test]
+    fn test_count_phrase_marks_consumed() {
+        let haystack = terms("distributed systems and more distributed systems");
+        let phrase = phrase_terms("distributed-systems");
+        let mut consumed = vec![false; haystack.len()];
+
+        assert_eq!(count_phrase(&haystack, &phrase, &mut consumed), 2);
+        assert_eq!(consumed, vec![true, true, false, false, true, true]);
+    }
+
+    #[test]
+    fn test_count_phrase_skips_consumed_terms() {
+        let haystack = terms("distributed systems and embedded systems");
+        let mut consumed = vec![false; haystack.len()];
+
+        let longer = phrase_terms("distributed-systems");
+        assert_eq!(count_phrase(&haystack, &longer, &mut consumed), 1);
+        // Only the "systems" outside "distributed systems" is left to count
+        let shorter = phrase_terms("systems");
+        assert_eq!(count_phrase(&haystack, &shorter, &mut consumed), 1);
+        assert_eq!(consumed, vec![true, true, false, false, true]);
+    }
+
+    #[test]
+    fn test_contains_phrase() {
+        let haystack = terms("Scaled the payments platform");
+        assert!(contains_phrase(&haystack, &phrase_terms("payments")));
+        assert!(!contains_phrase(&haystack, &phrase_terms("payments-infra")));
+        assert!(!contains_phrase(&haystack, &[]));
+    }
+}
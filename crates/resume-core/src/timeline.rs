@@ -0,0 +1,595 @@
+//! Employment timeline checks
+//!
+//! The questions recruiters ask about dates, answered before sending:
+//!
+//! - gaps between companies longer than a threshold
+//! - positions that overlap without being marked `concurrent`
+//! - total tenure per company
+//!
+//! Dates are compared at month granularity with both ends inclusive, so a role
+//! ending `2021-03` followed by one starting `2021-04` leaves no gap. A single
+//! shared month (`2021-03` → `2021-03`) is a handover, not an overlap.
+//! Year-only dates span the whole year (`"2019"` starts in January, ends in
+//! December).
+//!
+//! Concurrent positions (board seats, advisory work) don't cover gaps in the
+//! main employment history: gaps are measured between the spans of each
+//! company's non-concurrent positions, so an advisory role that outlasts the
+//! main job doesn't hide the gap after it. Tenure still counts every position.
+
+use serde::{Deserialize, Serialize};
+use shared_types::{Company, Position, ResumeData};
+
+/// Month index: `year * 12 + (month - 1)`
+pub(crate) type Month = i32;
+
+/// Options for [`analyze_timeline`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TimelineOptions {
+    /// Gaps of more than this many months are reported
+    pub gap_threshold_months: u32,
+    /// Date ongoing roles run to (`YYYY-MM`). Without it, ongoing tenure and
+    /// open-ended overlaps have no month count.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as_of: Option<String>,
+}
+
+impl Default for TimelineOptions {
+    fn default() -> Self {
+        Self {
+            gap_threshold_months: 3,
+            as_of: None,
+        }
+    }
+}
+
+/// Time between two companies with no (non-concurrent) employment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmploymentGap {
+    /// Company whose tenure ends the gap's left side
+    pub after_company_id: String,
+    /// Company whose tenure starts after the gap
+    pub before_company_id: String,
+    /// First month without employment (`YYYY-MM`)
+    pub start: String,
+    /// Last month without employment (`YYYY-MM`)
+    pub end: String,
+    pub months: u32,
+}
+
+/// A position in an overlap pair
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRef {
+    pub company_id: String,
+    pub position_id: String,
+}
+
+/// Two non-concurrent positions held at the same time
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionOverlap {
+    /// The position that started first
+    pub first: PositionRef,
+    pub second: PositionRef,
+    /// First overlapping month (`YYYY-MM`)
+    pub start: String,
+    /// Last overlapping month, `None` when both roles are ongoing
+    pub end: Option<String>,
+    /// `None` when open-ended and no `as_of` was given
+    pub months: Option<u32>,
+}
+
+/// Time spent at one company
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyTenure {
+    pub company_id: String,
+    /// Earliest start (`YYYY-MM`)
+    pub start: String,
+    /// Latest end, `None` while ongoing
+    pub end: Option<String>,
+    /// Months covered by its positions, counting overlapping positions once.
+    /// `None` when ongoing and no `as_of` was given.
+    pub months: Option<u32>,
+    pub ongoing: bool,
+}
+
+/// A date that couldn't be parsed; the item is left out of the checks
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidDate {
+    /// Company or position ID
+    pub item_id: String,
+    pub value: String,
+}
+
+/// Timeline checks for a whole compendium
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineReport {
+    /// Chronological
+    pub gaps: Vec<EmploymentGap>,
+    /// Chronological by overlap start
+    pub overlaps: Vec<PositionOverlap>,
+    /// In compendium order
+    pub tenure: Vec<CompanyTenure>,
+    pub invalid_dates: Vec<InvalidDate>,
+}
+
+/// Inclusive month range; `end: None` is ongoing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Span {
+    start: Month,
+    end: Option<Month>,
+}
+
+impl Span {
+    fn end_or(&self, as_of: Option<Month>) -> Option<Month> {
+        self.end.or(as_of)
+    }
+}
+
+/// Run the timeline checks
+pub fn analyze_timeline(data: &ResumeData, options: &TimelineOptions) -> TimelineReport {
+    let mut invalid_dates = Vec::new();
+    let as_of = options.as_of.as_deref().and_then(|d| parse_month(d, false));
+
+    let mut tenure = Vec::new();
+    // (company_id, span of its non-concurrent positions)
+    let mut primary: Vec<(&str, Span)> = Vec::new();
+    // (company_id, position_id, span) for non-concurrent positions
+    let mut positions: Vec<(&str, &str, Span)> = Vec::new();
+
+    for company in &data.experience {
+        let mut spans = Vec::new();
+        let mut primary_spans = Vec::new();
+        for position in &company.children {
+            if let Some(span) = position_span(position, &mut invalid_dates) {
+                spans.push(span);
+                if position.concurrent != Some(true) {
+                    primary_spans.push(span);
+                    positions.push((&company.id, &position.id, span));
+                }
+            }
+        }
+
+        // No usable position dates: fall back to the company's own
+        if spans.is_empty() {
+            spans.extend(company_span(company, &mut invalid_dates));
+            if company.children.is_empty()
+                || company.children.iter().any(|p| p.concurrent != Some(true))
+            {
+                primary_spans.clone_from(&spans);
+            }
+        }
+        let Some(span) = union_bounds(&spans) else {
+            continue;
+        };
+        if let Some(primary_span) = union_bounds(&primary_spans) {
+            primary.push((&company.id, primary_span));
+        }
+        tenure.push(CompanyTenure {
+            company_id: company.id.clone(),
+            start: format_month(span.start),
+            end: span.end.map(format_month),
+            months: covered_months(&spans, as_of),
+            ongoing: span.end.is_none(),
+        });
+    }
+
+    TimelineReport {
+        gaps: find_gaps(primary, options.gap_threshold_months),
+        overlaps: find_overlaps(positions, as_of),
+        tenure,
+        invalid_dates,
+    }
+}
+
+fn find_gaps(mut companies: Vec<(&str, Span)>, threshold: u32) -> Vec<EmploymentGap> {
+    companies.sort_by_key(|(_, span)| span.start);
+
+    let mut gaps = Vec::new();
+    // Latest end seen so far and the company holding it
+    let mut covered: Option<(&str, Option<Month>)> = None;
+    for (company_id, span) in companies {
+        if let Some((prev_id, Some(prev_end))) = covered {
+            let months = span.start - prev_end - 1;
+            if months > threshold as i32 {
+                gaps.push(EmploymentGap {
+                    after_company_id: prev_id.to_string(),
+                    before_company_id: company_id.to_string(),
+                    start: format_month(prev_end + 1),
+                    end: format_month(span.start - 1),
+                    months: months as u32,
+                });
+            }
+        }
+        covered = match covered {
+            // An ongoing role covers everything after it
+            Some((_, None)) => covered,
+            Some((_, Some(prev_end))) if span.end.is_some_and(|end| end <= prev_end) => covered,
+            _ => Some((company_id, span.end)),
+        };
+    }
+    gaps
+}
+
+fn find_overlaps(positions: Vec<(&str, &str, Span)>, as_of: Option<Month>) -> Vec<PositionOverlap> {
+    let mut sorted = positions;
+    sorted.sort_by_key(|(_, _, span)| span.start);
+
+    let mut overlaps = Vec::new();
+    for (i, &(a_company, a_position, a)) in sorted.iter().enumerate() {
+        for &(b_company, b_position, b) in &sorted[i + 1..] {
+            let start = b.start;
+            let end = match (a.end, b.end) {
+                (Some(x), Some(y)) => Some(x.min(y)),
+                (Some(x), None) | (None, Some(x)) => Some(x),
+                (None, None) => None,
+            };
+            // A single shared month is a handover
+            if end.is_some_and(|end| end <= start) {
+                continue;
+            }
+            overlaps.push(PositionOverlap {
+                first: PositionRef {
+                    company_id: a_company.to_string(),
+                    position_id: a_position.to_string(),
+                },
+                second: PositionRef {
+                    company_id: b_company.to_string(),
+                    position_id: b_position.to_string(),
+                },
+                start: format_month(start),
+                end: end.map(format_month),
+                months: end.or(as_of).map(|end| (end - start + 1).max(0) as u32),
+            });
+        }
+    }
+    overlaps.sort_by(|x, y| x.start.cmp(&y.start));
+    overlaps
+}
+
+/// Overall start and end of a set of spans
+fn union_bounds(spans: &[Span]) -> Option<Span> {
+    let start = spans.iter().map(|s| s.start).min()?;
+    let end = spans
+        .iter()
+        .map(|s| s.end)
+        .try_fold(Month::MIN, |acc, end| end.map(|e| acc.max(e)));
+    Some(Span { start, end })
+}
+
+/// Months covered by the union of spans, each month counted once
+fn covered_months(spans: &[Span], as_of: Option<Month>) -> Option<u32> {
+    let mut ranges: Vec<(Month, Month)> = spans
+        .iter()
+        .map(|span| span.end_or(as_of).map(|end| (span.start, end)))
+        .collect::<Option<_>>()?;
+    ranges.sort();
+
+    let mut total = 0;
+    let mut current: Option<(Month, Month)> = None;
+    for (start, end) in ranges {
+        current = match current {
+            Some((cs, ce)) if start <= ce + 1 => Some((cs, ce.max(end))),
+            Some((cs, ce)) => {
+                total += (ce - cs + 1).max(0);
+                Some((start, end))
+            }
+            None => Some((start, end)),
+        };
+    }
+    if let Some((cs, ce)) = current {
+        total += (ce - cs + 1).max(0);
+    }
+    Some(total as u32)
+}
+
+fn position_span(position: &Position, invalid: &mut Vec<InvalidDate>) -> Option<Span> {
+    item_span(
+        &position.id,
+        &position.date_start,
+        position.date_end.as_deref(),
+        invalid,
+    )
+}
+
+fn company_span(company: &Company, invalid: &mut Vec<InvalidDate>) -> Option<Span> {
+    item_span(
+        &company.id,
+        &company.date_start,
+        company.date_end.as_deref(),
+        invalid,
+    )
+}
+
+fn item_span(
+    id: &str,
+    start: &str,
+    end: Option<&str>,
+    invalid: &mut Vec<InvalidDate>,
+) -> Option<Span> {
+    let mut record = |value: &str| {
+        invalid.push(InvalidDate {
+            item_id: id.to_string(),
+            value: value.to_string(),
+        })
+    };
+
+    let Some(start_month) = parse_month(start, false) else {
+        record(start);
+        return None;
+    };
+    let end_month = match end {
+        None => None,
+        Some(e) if e.is_empty() || e.eq_ignore_ascii_case("present") => None,
+        Some(e) => match parse_month(e, true) {
+            Some(month) if month >= start_month => Some(month),
+            _ => {
+                record(e);
+                return None;
+            }
+        },
+    };
+    Some(Span {
+        start: start_month,
+        end: end_month,
+    })
+}
+
+/// Parse `YYYY`, `YYYY-MM` or `YYYY-MM-DD`; a bare year is January, or
+/// December when `is_end`
+pub(crate) fn parse_month(date: &str, is_end: bool) -> Option<Month> {
+    let mut parts = date.trim().split('-');
+    let year_part = parts.next()?;
+    if year_part.len() != 4 {
+        return None;
+    }
+    let year: i32 = year_part.parse().ok()?;
+    let month: i32 = match parts.next() {
+        Some(m) => m.parse().ok().filter(|m| (1..=12).contains(m))?,
+        None if is_end => 12,
+        None => 1,
+    };
+    if let Some(day) = parts.next() {
+        day.parse::<u8>().ok().filter(|d| (1..=31).contains(d))?;
+    }
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(year * 12 + month - 1)
+}
+
+fn format_month(month: Month) -> String {
+    format!(
+        "{:04}-{:02}",
+        month.div_euclid(12),
+        month.rem_euclid(12) + 1
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{company, position, resume};
+
+    fn job(id: &str, start: &str, end: Option<&str>) -> Position {
+        let mut p = position(id, vec![]);
+        p.date_start = start.to_string();
+        p.date_end = end.map(str::to_string);
+        p
+    }
+
+    fn employer(id: &str, positions: Vec<Position>) -> Company {
+        let mut c = company(id, positions);
+        c.date_start = c.children[0].date_start.clone();
+        c.date_end = c.children.last().unwrap().date_end.clone();
+        c
+    }
+
+    #[test]
+    fn test_parse_month_formats() {
+        assert_eq!(parse_month("2020-03", false), Some(2020 * 12 + 2));
+        assert_eq!(parse_month("2020-03-15", false), Some(2020 * 12 + 2));
+        assert_eq!(parse_month("2019", false), Some(2019 * 12));
+        assert_eq!(parse_month("2019", true), Some(2019 * 12 + 11));
+        assert_eq!(parse_month("2020-13", false), None);
+        assert_eq!(parse_month("March 2020", false), None);
+        assert_eq!(format_month(2020 * 12 + 2), "2020-03");
+    }
+
+    #[test]
+    fn test_gap_above_threshold_is_reported() {
+        let data = resume(vec![
+            employer("old", vec![job("old-eng", "2018-01", Some("2019-06"))]),
+            employer("mid", vec![job("mid-eng", "2019-08", Some("2020-12"))]),
+            employer("new", vec![job("new-eng", "2021-09", None)]),
+        ]);
+
+        let report = analyze_timeline(&data, &TimelineOptions::default());
+        assert_eq!(
+            report.gaps,
+            vec![EmploymentGap {
+                after_company_id: "mid".to_string(),
+                before_company_id: "new".to_string(),
+                start: "2021-01".to_string(),
+                end: "2021-08".to_string(),
+                months: 8,
+            }]
+        );
+
+        // Lower threshold picks up the one-month gap too
+        let strict = TimelineOptions {
+            gap_threshold_months: 0,
+            ..TimelineOptions::default()
+        };
+        assert_eq!(analyze_timeline(&data, &strict).gaps.len(), 2);
+    }
+
+    #[test]
+    fn test_concurrent_company_does_not_cover_gap() {
+        let mut advisory = job("advisor", "2019-01", None);
+        advisory.concurrent = Some(true);
+        let data = resume(vec![
+            employer("old", vec![job("old-eng", "2017-01", Some("2019-06"))]),
+            employer("startup", vec![advisory]),
+            employer("new", vec![job("new-eng", "2020-06", None)]),
+        ]);
+
+        let report = analyze_timeline(&data, &TimelineOptions::default());
+        assert_eq!(report.gaps.len(), 1);
+        assert_eq!(report.gaps[0].months, 11);
+        assert!(report.overlaps.is_empty(), "concurrent roles never overlap");
+    }
+
+    #[test]
+    fn test_concurrent_position_outlasting_primary_does_not_cover_gap() {
+        let mut advisory = job("acme-advisor", "2018-06", Some("2020-03"));
+        advisory.concurrent = Some(true);
+        let data = resume(vec![
+            employer(
+                "acme",
+                vec![job("acme-eng", "2017-01", Some("2019-06")), advisory],
+            ),
+            employer("new", vec![job("new-eng", "2020-06", None)]),
+        ]);
+
+        let report = analyze_timeline(&data, &TimelineOptions::default());
+        assert_eq!(
+            report.gaps,
+            vec![EmploymentGap {
+                after_company_id: "acme".to_string(),
+                before_company_id: "new".to_string(),
+                start: "2019-07".to_string(),
+                end: "2020-05".to_string(),
+                months: 11,
+            }]
+        );
+
+        // Tenure still includes the advisory months
+        assert_eq!(report.tenure[0].end.as_deref(), Some("2020-03"));
+    }
+
+    #[test]
+    fn test_overlaps_skip_handover_and_concurrent() {
+        let mut board = job("board", "2020-01", None);
+        board.concurrent = Some(true);
+        let data = resume(vec![
+            employer(
+                "acme",
+                vec![
+                    job("acme-eng", "2018-01", Some("2020-03")),
+                    // Handover month shared with acme-eng
+                    job("acme-lead", "2020-03", Some("2021-12")),
+                ],
+            ),
+            employer(
+                "moonlight",
+                vec![job("moon-eng", "2021-10", Some("2022-06"))],
+            ),
+            employer("charity", vec![board]),
+        ]);
+
+        let report = analyze_timeline(&data, &TimelineOptions::default());
+        assert_eq!(
+            report.overlaps,
+            vec![PositionOverlap {
+                first: PositionRef {
+                    company_id: "acme".to_string(),
+                    position_id: "acme-lead".to_string(),
+                },
+                second: PositionRef {
+                    company_id: "moonlight".to_string(),
+                    position_id: "moon-eng".to_string(),
+                },
+                start: "2021-10".to_string(),
+                end: Some("2021-12".to_string()),
+                months: Some(3),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_tenure_counts_overlapping_positions_once() {
+        let data = resume(vec![employer(
+            "acme",
+            vec![
+                job("acme-eng", "2018-01", Some("2019-12")),
+                job("acme-lead", "2019-06", Some("2020-12")),
+            ],
+        )]);
+
+        let report = analyze_timeline(&data, &TimelineOptions::default());
+        assert_eq!(
+            report.tenure,
+            vec![CompanyTenure {
+                company_id: "acme".to_string(),
+                start: "2018-01".to_string(),
+                end: Some("2020-12".to_string()),
+                months: Some(36),
+                ongoing: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_boomerang_tenure_excludes_time_away() {
+        let data = resume(vec![employer(
+            "acme",
+            vec![
+                job("first-stint", "2015-01", Some("2015-12")),
+                job("second-stint", "2018-01", Some("2018-06")),
+            ],
+        )]);
+
+        let report = analyze_timeline(&data, &TimelineOptions::default());
+        assert_eq!(report.tenure[0].months, Some(18));
+    }
+
+    #[test]
+    fn test_ongoing_tenure_needs_as_of() {
+        let data = resume(vec![employer(
+            "acme",
+            vec![job("acme-eng", "2023-01", None)],
+        )]);
+
+        let report = analyze_timeline(&data, &TimelineOptions::default());
+        assert!(report.tenure[0].ongoing);
+        assert_eq!(report.tenure[0].months, None);
+
+        let options = TimelineOptions {
+            as_of: Some("2024-06".to_string()),
+            ..TimelineOptions::default()
+        };
+        assert_eq!(analyze_timeline(&data, &options).tenure[0].months, Some(18));
+    }
+
+    #[test]
+    fn test_invalid_dates_are_reported_and_skipped() {
+        let data = resume(vec![
+            employer("acme", vec![job("acme-eng", "sometime", None)]),
+            employer("backwards", vec![job("bw-eng", "2020-05", Some("2019-01"))]),
+        ]);
+
+        let report = analyze_timeline(&data, &TimelineOptions::default());
+        let ids: Vec<&str> = report
+            .invalid_dates
+            .iter()
+            .map(|d| d.item_id.as_str())
+            .collect();
+        assert!(ids.contains(&"acme-eng"));
+        assert!(ids.contains(&"bw-eng"));
+        assert!(report.overlaps.is_empty());
+    }
+
+    #[test]
+    fn test_options_deserialize_with_defaults() {
+        let options: TimelineOptions = serde_json::from_str(r#"{"asOf": "2025-01"}"#).unwrap();
+        assert_eq!(options.gap_threshold_months, 3);
+        assert_eq!(options.as_of.as_deref(), Some("2025-01"));
+    }
+}
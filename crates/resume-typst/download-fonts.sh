@@ -1,36 +1,49 @@
 #!/usr/bin/env bash
 #
-# Download minimal Liberation Serif fonts for resume generation
-# From Fedora Project (original creators)
+# Download the embedded font sets for resume generation
+# - Serif: Liberation Serif, from the Liberation project releases
+# - Sans: DejaVu Sans, from the DejaVu project releases
 
 set -e
 
-FONTS_DIR="$(dirname "$0")/fonts"
+FONTS_DIR="$(dirname "$0")/../../typst/fonts"
 
-echo "📥 Downloading Liberation Serif fonts from Fedora mirrors..."
+echo "📥 Downloading Liberation Serif fonts..."
 
-# Download from Fedora CDN (original maintainers)
-# These are the actual TTF files, not HTML wrappers
 curl -sL "https://releases.pagure.org/liberation-fonts/liberation-fonts-ttf-2.1.4.tar.gz" \
   -o /tmp/liberation-fonts.tar.gz
 
-# Extract just the Serif fonts we need
+# Extract just the Serif faces we need
 tar -xzf /tmp/liberation-fonts.tar.gz \
   -C /tmp \
   --strip-components=1 \
   liberation-fonts-ttf-2.1.4/LiberationSerif-Regular.ttf \
-  liberation-fonts-ttf-2.1.4/LiberationSerif-Bold.ttf
+  liberation-fonts-ttf-2.1.4/LiberationSerif-Bold.ttf \
+  liberation-fonts-ttf-2.1.4/LiberationSerif-Italic.ttf \
+  liberation-fonts-ttf-2.1.4/LiberationSerif-BoldItalic.ttf
 
-# Move to fonts directory
-mv /tmp/LiberationSerif-Regular.ttf "$FONTS_DIR/"
-mv /tmp/LiberationSerif-Bold.ttf "$FONTS_DIR/"
-
-# Cleanup
+mv /tmp/LiberationSerif-*.ttf "$FONTS_DIR/"
 rm /tmp/liberation-fonts.tar.gz
 
+echo "📥 Downloading DejaVu Sans fonts..."
+
+curl -sL "https://github.com/dejavu-fonts/dejavu-fonts/releases/download/version_2_37/dejavu-fonts-ttf-2.37.tar.bz2" \
+  -o /tmp/dejavu-fonts.tar.bz2
+
+tar -xjf /tmp/dejavu-fonts.tar.bz2 \
+  -C /tmp \
+  --strip-components=2 \
+  dejavu-fonts-ttf-2.37/ttf/DejaVuSans.ttf \
+  dejavu-fonts-ttf-2.37/ttf/DejaVuSans-Bold.ttf \
+  dejavu-fonts-ttf-2.37/ttf/DejaVuSans-Oblique.ttf \
+  dejavu-fonts-ttf-2.37/ttf/DejaVuSans-BoldOblique.ttf
+
+mv /tmp/DejaVuSans*.ttf "$FONTS_DIR/"
+rm /tmp/dejavu-fonts.tar.bz2
+
 echo "✅ Fonts downloaded:"
-file "$FONTS_DIR"/LiberationSerif-*.ttf
-ls -lh "$FONTS_DIR"/LiberationSerif-*.ttf
+file "$FONTS_DIR"/*.ttf
+ls -lh "$FONTS_DIR"/*.ttf
 
 echo ""
 echo "Total size:"
@@ -0,0 +1,141 @@
+//! Reader hints for exported PDFs
+//!
+//! Typst already writes the catalog `/Lang`, a bookmark outline built from
+//! the template's real headings, and content streams in reading order. It
+//! cannot write a structure tree, so exported files are **not** tagged PDFs
+//! and don't meet PDF/UA: assistive technology gets no heading or paragraph
+//! roles. With `RenderOptions::reader_hints` the exported file gets an
+//! incremental update that:
+//!
+//! - gives every link annotation a `/Contents` description (see
+//!   [`link_description`]), which screen readers announce instead of a bare URL
+//! - sets `/DisplayDocTitle` so viewers show the document title, not the file name
+//! - writes the full language tag (`en-GB`) into `/Lang`, where Typst only
+//!   writes the language
+//!
+//! The update (see [`crate::pdf_edit`]) leaves the original bytes, and with
+//! them PDF/A conformance, untouched.
+
+use crate::pdf_edit::{insert_entry, text_string, text_value, PdfEdit};
+
+/// Human-readable description of a link target
+///
+/// ```
+/// use resume_typst::accessibility::link_description;
+///
+/// assert_eq!(link_description("mailto:jane@example.com"), "Email jane@example.com");
+/// assert_eq!(
+///     link_description("https://github.com/janedoe"),
+///     "GitHub profile: github.com/janedoe"
+/// );
+/// ```
+pub fn link_description(uri: &str) -> String {
+    if let Some(address) = uri.strip_prefix("mailto:") {
+        return format!("Email {}", address);
+    }
+    if let Some(number) = uri.strip_prefix("tel:") {
+        return format!("Phone {}", number);
+    }
+
+    let bare = uri.split_once("://").map_or(uri, |(_, rest)| rest);
+    let bare = bare
+        .strip_prefix("www.")
+        .unwrap_or(bare)
+        .trim_end_matches('/');
+    let host = bare.split('/').next().unwrap_or(bare);
+    match host {
+        "github.com" => format!("GitHub profile: {}", bare),
+        "linkedin.com" => format!("LinkedIn profile: {}", bare),
+        "twitter.com" | "x.com" => format!("X profile: {}", bare),
+        _ => format!("Website: {}", bare),
+    }
+}
+
+/// Add link descriptions, the document title preference and the full
+/// language tag to a PDF written by `typst_pdf`
+pub(crate) fn add_reader_hints(edit: &mut PdfEdit, language: &str) -> Result<(), String> {
+    let root = edit.root();
+    let catalog = edit
+        .dict(root)
+        .map(|catalog| catalog_with_extras(catalog, language))
+        .ok_or_else(|| format!("PDF catalog {} 0 obj not found", root))?;
+    edit.set_dict(root, catalog);
+
+    let links: Vec<(usize, String)> = edit
+        .ids()
+        .filter_map(|id| Some((id, link_with_contents(edit.dict(id)?)?)))
+        .collect();
+    for (id, link) in links {
+        edit.set_dict(id, link);
+    }
+    Ok(())
+}
+
+/// A link annotation with a `/Contents` description, or `None` for other
+/// objects and links that already have one
+fn link_with_contents(dict: &str) -> Option<String> {
+    if !dict.contains("/Subtype /Link") || dict.contains("/Contents") {
+        return None;
+    }
+    let uri = text_value(dict, "/URI")?;
+    let contents = format!("\n  /Contents {}", text_string(&link_description(&uri)));
+    let at = dict.find("/Subtype /Link")? + "/Subtype /Link".len();
+    Some(format!("{}{}{}", &dict[..at], contents, &dict[at..]))
+}
+
+/// The catalog with `/DisplayDocTitle` and `/Lang` set
+fn catalog_with_extras(dict: &str, language: &str) -> String {
+    let mut dict = dict.to_string();
+
+    // `RenderOptions::validate` restricts the tag to ASCII letters and `-`
+    let lang = format!("/Lang ({})", language);
+    match dict.find("/Lang (") {
+        Some(start) => {
+            let end = start + dict[start..].find(')').map_or(0, |i| i + 1);
+            dict.replace_range(start..end, &lang);
+        }
+        None => insert_entry(&mut dict, &lang),
+    }
+
+    if !dict.contains("/DisplayDocTitle") {
+        match dict.find("/ViewerPreferences <<") {
+            Some(start) => {
+                let at = start + "/ViewerPreferences <<".len();
+                dict.insert_str(at, "\n    /DisplayDocTitle true");
+            }
+            None => insert_entry(&mut dict, "/ViewerPreferences << /DisplayDocTitle true >>"),
+        }
+    }
+    dict
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_descriptions() {
+        assert_eq!(link_description("tel:+1 555 0100"), "Phone +1 555 0100");
+        assert_eq!(
+            link_description("https://www.linkedin.com/in/janedoe/"),
+            "LinkedIn profile: linkedin.com/in/janedoe"
+        );
+        assert_eq!(
+            link_description("https://janedoe.com"),
+            "Website: janedoe.com"
+        );
+    }
+
+    #[test]
+    fn test_catalog_and_link_extras() {
+        let catalog = "<<\n  /Type /Catalog\n  /Lang (en)\n>>";
+        let catalog = catalog_with_extras(catalog, "en-GB");
+        assert!(catalog.contains("/Lang (en-GB)"));
+        assert!(catalog.contains("/ViewerPreferences << /DisplayDocTitle true >>"));
+
+        let link = "<<\n  /Type /Annot\n  /Subtype /Link\n  /A << /URI (mailto:a@b.c) >>\n>>";
+        let link = link_with_contents(link).unwrap();
+        assert!(link.contains(&format!("/Contents {}", text_string("Email a@b.c"))));
+        assert!(link_with_contents(&link).is_none());
+    }
+}
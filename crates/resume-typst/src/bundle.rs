@@ -0,0 +1,756 @@
+//! User-supplied template bundles
+//!
+//! A bundle is a main `.typ` file plus any modules, images and fonts it
+//! needs, supplied as an in-memory map or a `.zip`, `.tar` or `.tar.gz`
+//! archive. Nothing is read from disk: imports, `image(..)` and `read(..)`
+//! resolve against the bundle, and fonts in it join the built-in set.
+//!
+//! An optional `template.json` manifest names the main file and the data
+//! version the template was written against:
+//!
+//! ```json
+//! { "name": "Acme Corp", "main": "acme.typ", "dataVersion": 1 }
+//! ```
+//!
+//! Without one, `main.typ`, then `resume.typ`, then the only `.typ` file at
+//! the top level is used. [`TemplateBundle::validate`] checks the bundle
+//! against the published template data schema
+//! ([`crate::template::DATA_SCHEMA`]) before rendering.
+
+use crate::template::{DATA_FILE, DATA_SCHEMA, DATA_VERSION};
+use crate::TypstError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{Cursor, Read};
+use typst::syntax::ast;
+use typst::syntax::{SyntaxKind, SyntaxNode, VirtualPath};
+use typst::text::Font;
+
+/// Largest accepted bundle, summed over all files
+pub const MAX_BUNDLE_BYTES: usize = 20 * 1024 * 1024;
+
+/// Most files accepted in one bundle
+pub const MAX_BUNDLE_FILES: usize = 256;
+
+/// Optional manifest file at the bundle root
+pub const MANIFEST_FILE: &str = "template.json";
+
+/// Contents of `template.json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleManifest {
+    /// Display name
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Path of the main `.typ` file within the bundle
+    pub main: String,
+    /// Template data version the template expects (see [`DATA_VERSION`])
+    #[serde(default = "default_data_version")]
+    pub data_version: u32,
+}
+
+fn default_data_version() -> u32 {
+    DATA_VERSION
+}
+
+/// A template with its modules, images and fonts
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemplateBundle {
+    main: String,
+    files: BTreeMap<String, Vec<u8>>,
+}
+
+impl TemplateBundle {
+    /// Single-file bundle from template source
+    pub fn new(main: &str, source: impl Into<String>) -> Result<Self, TypstError> {
+        let main = normalize_path(main)?;
+        let mut files = BTreeMap::new();
+        files.insert(main.clone(), source.into().into_bytes());
+        Ok(Self { main, files })
+    }
+
+    /// Add a module, image or font at `path` (relative to the bundle root)
+    pub fn with_file(mut self, path: &str, data: impl Into<Vec<u8>>) -> Result<Self, TypstError> {
+        let path = normalize_path(path)?;
+        if path == self.main {
+            return Err(bundle_error(format!(
+                "'{}' is the main file; pass its source to TemplateBundle::new",
+                path
+            )));
+        }
+        self.files.insert(path, data.into());
+        check_limits(&self.files)?;
+        Ok(self)
+    }
+
+    /// Bundle from an in-memory map of path to contents
+    ///
+    /// The main file comes from `template.json` when present, otherwise
+    /// `main.typ`, `resume.typ` or the only top-level `.typ` file.
+    pub fn from_files<P, D>(files: impl IntoIterator<Item = (P, D)>) -> Result<Self, TypstError>
+    where
+        P: AsRef<str>,
+        D: Into<Vec<u8>>,
+    {
+        let mut map = BTreeMap::new();
+        for (path, data) in files {
+            let path = normalize_path(path.as_ref())?;
+            if map.insert(path.clone(), data.into()).is_some() {
+                return Err(bundle_error(format!("'{}' appears more than once", path)));
+            }
+        }
+        check_limits(&map)?;
+        let main = find_main(&map)?;
+        Ok(Self { main, files: map })
+    }
+
+    /// Bundle from a `.zip` archive
+    pub fn from_zip(bytes: &[u8]) -> Result<Self, TypstError> {
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes))
+            .map_err(|e| bundle_error(format!("Invalid zip archive: {}", e)))?;
+
+        let mut files = Vec::new();
+        let mut total = 0;
+        for index in 0..archive.len() {
+            let mut entry = archive
+                .by_index(index)
+                .map_err(|e| bundle_error(format!("Invalid zip entry: {}", e)))?;
+            if entry.is_dir() {
+                continue;
+            }
+            let name = entry.name().to_string();
+            let data = read_limited(&mut entry, &name, &mut total)?;
+            files.push((name, data));
+        }
+
+        Self::from_files(strip_common_root(files))
+    }
+
+    /// Bundle from a `.tar` archive
+    pub fn from_tar(bytes: &[u8]) -> Result<Self, TypstError> {
+        let mut archive = tar::Archive::new(Cursor::new(bytes));
+        let entries = archive
+            .entries()
+            .map_err(|e| bundle_error(format!("Invalid tar archive: {}", e)))?;
+
+        let mut files = Vec::new();
+        let mut total = 0;
+        for entry in entries {
+            let mut entry = entry.map_err(|e| bundle_error(format!("Invalid tar entry: {}", e)))?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            let name = entry
+                .path()
+                .map_err(|e| bundle_error(format!("Invalid tar entry path: {}", e)))?
+                .to_string_lossy()
+                .into_owned();
+            let data = read_limited(&mut entry, &name, &mut total)?;
+            files.push((name, data));
+        }
+
+        Self::from_files(strip_common_root(files))
+    }
+
+    /// Bundle from a `.zip`, `.tar` or `.tar.gz` archive, detected from its
+    /// leading bytes
+    pub fn from_archive(bytes: &[u8]) -> Result<Self, TypstError> {
+        if bytes.starts_with(b"PK\x03\x04") || bytes.starts_with(b"PK\x05\x06") {
+            Self::from_zip(bytes)
+        } else if bytes.starts_with(&[0x1f, 0x8b]) {
+            let mut tar = Vec::new();
+            flate2::read::GzDecoder::new(bytes)
+                .take(MAX_BUNDLE_BYTES as u64 * 2)
+                .read_to_end(&mut tar)
+                .map_err(|e| bundle_error(format!("Invalid gzip data: {}", e)))?;
+            Self::from_tar(&tar)
+        } else if bytes.len() > 262 && &bytes[257..262] == b"ustar" {
+            Self::from_tar(bytes)
+        } else {
+            Err(bundle_error(
+                "Unrecognised archive format (expected .zip, .tar or .tar.gz)".to_string(),
+            ))
+        }
+    }
+
+    /// Path of the main `.typ` file
+    pub fn main(&self) -> &str {
+        &self.main
+    }
+
+    /// Every file in the bundle, including the main file, by path
+    pub fn files(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        self.files
+            .iter()
+            .map(|(path, data)| (path.as_str(), data.as_slice()))
+    }
+
+    /// Parsed `template.json`, if the bundle has one
+    pub fn manifest(&self) -> Result<Option<BundleManifest>, TypstError> {
+        parse_manifest(&self.files)
+    }
+
+    /// Where the template data is served: next to the main file, so
+    /// `json("data.json")` works wherever the main file lives
+    pub fn data_path(&self) -> String {
+        match self.main.rsplit_once('/') {
+            Some((dir, _)) => format!("{}/{}", dir, DATA_FILE),
+            None => DATA_FILE.to_string(),
+        }
+    }
+
+    /// Font files in the bundle
+    pub(crate) fn fonts(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        self.files().filter(|(path, _)| is_font(path))
+    }
+
+    /// Typst sources in the bundle
+    pub(crate) fn sources(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        self.files().filter(|(path, _)| path.ends_with(".typ"))
+    }
+
+    /// Check the bundle before rendering
+    ///
+    /// Reports every problem at once:
+    /// - the manifest's `dataVersion` matches [`DATA_VERSION`]
+    /// - every `.typ` file is UTF-8 and parses
+    /// - `import`/`include` paths resolve to files in the bundle (packages
+    ///   are not available)
+    /// - literal paths passed to `image`, `read`, `json`, `csv`, `yaml`,
+    ///   `toml`, `xml` and `cbor` exist in the bundle
+    /// - fields read from the template data (`data.personal.name` where
+    ///   `data = json("data.json")`) exist in the data schema
+    /// - font files contain at least one usable face
+    /// - no file shadows the served `data.json`
+    pub fn validate(&self) -> Result<(), TypstError> {
+        let mut problems = Vec::new();
+
+        match self.manifest() {
+            Ok(Some(manifest)) if manifest.data_version != DATA_VERSION => problems.push(format!(
+                "{}: dataVersion {} is not supported (current: {})",
+                MANIFEST_FILE, manifest.data_version, DATA_VERSION
+            )),
+            Ok(_) => {}
+            Err(e) => problems.push(e.to_string()),
+        }
+
+        let data_path = self.data_path();
+        if self.files.contains_key(&data_path) {
+            problems.push(format!(
+                "{}: reserved for the template data and served by the renderer",
+                data_path
+            ));
+        }
+
+        let schema: JsonValue =
+            serde_json::from_str(DATA_SCHEMA).expect("template data schema is valid JSON");
+
+        for (path, data) in self.sources() {
+            let Ok(text) = std::str::from_utf8(data) else {
+                problems.push(format!("{}: not valid UTF-8", path));
+                continue;
+            };
+            let root = typst::syntax::parse(text);
+            let errors = root.errors();
+            if let Some(error) = errors.first() {
+                problems.push(format!("{}: syntax error: {}", path, error.message));
+                continue;
+            }
+            self.check_references(path, &root, &schema, &mut problems);
+        }
+
+        for (path, data) in self.fonts() {
+            if Font::iter(typst::foundations::Bytes::from(data.to_vec()))
+                .next()
+                .is_none()
+            {
+                problems.push(format!("{}: not a usable font", path));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(TypstError::TemplateError(format!(
+                "Invalid template bundle: {}",
+                problems.join("; ")
+            )))
+        }
+    }
+
+    /// Check imports, file reads and data field accesses in one source
+    fn check_references(
+        &self,
+        path: &str,
+        root: &SyntaxNode,
+        schema: &JsonValue,
+        problems: &mut Vec<String>,
+    ) {
+        let data_path = self.data_path();
+        let resolve = |target: &str| -> Option<String> {
+            let joined = VirtualPath::new(path).join(target);
+            let rootless = joined
+                .as_rootless_path()
+                .to_string_lossy()
+                .replace('\\', "/");
+            (!joined.as_rooted_path().starts_with("/..")).then_some(rootless)
+        };
+
+        // Names bound to the template data, e.g. `#let data = json("data.json")`
+        let mut data_names = BTreeSet::new();
+        walk(root, &mut |node, _| {
+            let Some(binding) = node.cast::<ast::LetBinding>() else {
+                return;
+            };
+            let Some(ast::Expr::FuncCall(call)) = binding.init() else {
+                return;
+            };
+            if callee_name(call) == Some("json")
+                && first_str_arg(call).and_then(|p| resolve(&p)).as_deref()
+                    == Some(data_path.as_str())
+            {
+                data_names.extend(binding.kind().bindings().iter().map(|i| i.get().clone()));
+            }
+        });
+
+        let mut reported = BTreeSet::new();
+        walk(root, &mut |node, parent| {
+            if let Some(import) = node.cast::<ast::ModuleImport>() {
+                self.check_module_path(path, import.source(), &resolve, problems);
+            } else if let Some(include) = node.cast::<ast::ModuleInclude>() {
+                self.check_module_path(path, include.source(), &resolve, problems);
+            } else if let Some(call) = node.cast::<ast::FuncCall>() {
+                let reads_file = matches!(
+                    callee_name(call),
+                    Some("image" | "read" | "json" | "csv" | "yaml" | "toml" | "xml" | "cbor")
+                );
+                if let Some(target) = first_str_arg(call).filter(|_| reads_file) {
+                    match resolve(&target) {
+                        Some(file) if file == data_path || self.files.contains_key(&file) => {}
+                        _ => problems
+                            .push(format!("{}: file '{}' is not in the bundle", path, target)),
+                    }
+                }
+            } else if let Some(access) = node.cast::<ast::FieldAccess>() {
+                // Only whole chains: skip accesses that are the target of a longer one
+                if parent == Some(SyntaxKind::FieldAccess) {
+                    return;
+                }
+                let Some((name, fields)) = field_chain(access) else {
+                    return;
+                };
+                if !data_names.contains(&name) {
+                    return;
+                }
+                if let Some(unknown) = unknown_field(schema, &fields) {
+                    let access = format!("{}.{}", name, fields.join("."));
+                    if reported.insert(access.clone()) {
+                        problems.push(format!(
+                            "{}: '{}' reads '{}', which is not in the template data schema",
+                            path, access, unknown
+                        ));
+                    }
+                }
+            }
+        });
+    }
+
+    fn check_module_path(
+        &self,
+        path: &str,
+        source: ast::Expr,
+        resolve: &dyn Fn(&str) -> Option<String>,
+        problems: &mut Vec<String>,
+    ) {
+        let ast::Expr::Str(target) = source else {
+            return;
+        };
+        let target = target.get();
+        if target.starts_with('@') {
+            problems.push(format!(
+                "{}: package '{}' is not available; copy it into the bundle",
+                path, target
+            ));
+            return;
+        }
+        match resolve(&target) {
+            Some(file) if self.files.contains_key(&file) => {}
+            _ => problems.push(format!(
+                "{}: module '{}' is not in the bundle",
+                path, target
+            )),
+        }
+    }
+}
+
+/// Visit every node below `node`, depth first, with its parent's kind
+fn walk<'a>(node: &'a SyntaxNode, visit: &mut impl FnMut(&'a SyntaxNode, Option<SyntaxKind>)) {
+    fn go<'a>(
+        node: &'a SyntaxNode,
+        parent: Option<SyntaxKind>,
+        visit: &mut impl FnMut(&'a SyntaxNode, Option<SyntaxKind>),
+    ) {
+        visit(node, parent);
+        for child in node.children() {
+            go(child, Some(node.kind()), visit);
+        }
+    }
+    go(node, None, visit);
+}
+
+fn callee_name(call: ast::FuncCall<'_>) -> Option<&str> {
+    match call.callee() {
+        ast::Expr::Ident(ident) => Some(ident.as_str()),
+        _ => None,
+    }
+}
+
+fn first_str_arg(call: ast::FuncCall) -> Option<String> {
+    match call.args().items().next() {
+        Some(ast::Arg::Pos(ast::Expr::Str(s))) => Some(s.get().to_string()),
+        _ => None,
+    }
+}
+
+/// `data.layout.margin` → `("data", ["layout", "margin"])`
+fn field_chain(access: ast::FieldAccess) -> Option<(ecow::EcoString, Vec<String>)> {
+    let mut fields = vec![access.field().as_str().to_string()];
+    let mut target = access.target();
+    loop {
+        match target {
+            ast::Expr::FieldAccess(inner) => {
+                fields.push(inner.field().as_str().to_string());
+                target = inner.target();
+            }
+            ast::Expr::Ident(ident) => {
+                fields.reverse();
+                return Some((ident.get().clone(), fields));
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// First field in the chain the schema doesn't define
+///
+/// Stops checking (and accepts) once the chain leaves plain objects, e.g.
+/// at an array, where later accesses are methods like `len`.
+fn unknown_field<'a>(schema: &JsonValue, fields: &'a [String]) -> Option<&'a str> {
+    let mut current = schema;
+    for field in fields {
+        let properties = current.get("properties").and_then(JsonValue::as_object)?;
+        match properties.get(field) {
+            Some(next) => current = next,
+            None => return Some(field),
+        }
+    }
+    None
+}
+
+fn bundle_error(message: String) -> TypstError {
+    TypstError::TemplateError(message)
+}
+
+fn is_font(path: &str) -> bool {
+    let lower = path.to_ascii_lowercase();
+    [".ttf", ".otf", ".ttc", ".otc"]
+        .iter()
+        .any(|ext| lower.ends_with(ext))
+}
+
+/// Bundle-relative path with `/` separators, rejecting escapes
+fn normalize_path(path: &str) -> Result<String, TypstError> {
+    let path = path.replace('\\', "/");
+    let mut parts = Vec::new();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                return Err(bundle_error(format!(
+                    "'{}' points outside the bundle",
+                    path
+                )))
+            }
+            part => parts.push(part),
+        }
+    }
+    if parts.is_empty() {
+        return Err(bundle_error(format!("'{}' is not a file path", path)));
+    }
+    Ok(parts.join("/"))
+}
+
+fn check_limits(files: &BTreeMap<String, Vec<u8>>) -> Result<(), TypstError> {
+    if files.len() > MAX_BUNDLE_FILES {
+        return Err(bundle_error(format!(
+            "Too many files ({}), maximum is {}",
+            files.len(),
+            MAX_BUNDLE_FILES
+        )));
+    }
+    let total: usize = files.values().map(Vec::len).sum();
+    if total > MAX_BUNDLE_BYTES {
+        return Err(bundle_error(format!(
+            "Bundle is {} bytes, maximum is {}",
+            total, MAX_BUNDLE_BYTES
+        )));
+    }
+    Ok(())
+}
+
+/// Read an archive entry without letting the whole bundle exceed the limit
+fn read_limited(
+    reader: &mut impl Read,
+    name: &str,
+    total: &mut usize,
+) -> Result<Vec<u8>, TypstError> {
+    let remaining = MAX_BUNDLE_BYTES.saturating_sub(*total);
+    let mut data = Vec::new();
+    reader
+        .take(remaining as u64 + 1)
+        .read_to_end(&mut data)
+        .map_err(|e| bundle_error(format!("Failed to read '{}': {}", name, e)))?;
+    *total += data.len();
+    if *total > MAX_BUNDLE_BYTES {
+        return Err(bundle_error(format!(
+            "Bundle exceeds {} bytes",
+            MAX_BUNDLE_BYTES
+        )));
+    }
+    Ok(data)
+}
+
+/// Drop a single directory every entry sits in (`acme/main.typ` → `main.typ`),
+/// as produced by zipping a folder
+fn strip_common_root(files: Vec<(String, Vec<u8>)>) -> Vec<(String, Vec<u8>)> {
+    let normalized: Vec<String> = files
+        .iter()
+        .map(|(path, _)| path.replace('\\', "/").trim_start_matches("./").to_string())
+        .collect();
+    let root = normalized
+        .first()
+        .and_then(|path| path.split_once('/'))
+        .map(|(dir, _)| format!("{}/", dir));
+
+    match root {
+        Some(root) if normalized.iter().all(|path| path.starts_with(&root)) => normalized
+            .into_iter()
+            .zip(files)
+            .map(|(path, (_, data))| (path[root.len()..].to_string(), data))
+            .collect(),
+        _ => files,
+    }
+}
+
+fn parse_manifest(files: &BTreeMap<String, Vec<u8>>) -> Result<Option<BundleManifest>, TypstError> {
+    files
+        .get(MANIFEST_FILE)
+        .map(|data| {
+            serde_json::from_slice(data)
+                .map_err(|e| bundle_error(format!("{}: {}", MANIFEST_FILE, e)))
+        })
+        .transpose()
+}
+
+fn find_main(files: &BTreeMap<String, Vec<u8>>) -> Result<String, TypstError> {
+    if let Some(manifest) = parse_manifest(files)? {
+        let main = normalize_path(&manifest.main)?;
+        if !files.contains_key(&main) {
+            return Err(bundle_error(format!(
+                "{}: main file '{}' is not in the bundle",
+                MANIFEST_FILE, main
+            )));
+        }
+        if !main.ends_with(".typ") {
+            return Err(bundle_error(format!(
+                "{}: main file '{}' is not a .typ file",
+                MANIFEST_FILE, main
+            )));
+        }
+        return Ok(main);
+    }
+
+    for candidate in ["main.typ", "resume.typ"] {
+        if files.contains_key(candidate) {
+            return Ok(candidate.to_string());
+        }
+    }
+
+    let top_level: Vec<&String> = files
+        .keys()
+        .filter(|path| path.ends_with(".typ") && !path.contains('/'))
+        .collect();
+    match top_level.as_slice() {
+        [only] => Ok((*only).clone()),
+        [] => Err(bundle_error("No .typ file at the bundle root".to_string())),
+        _ => Err(bundle_error(format!(
+            "Several .typ files at the bundle root; name the main one in {}",
+            MANIFEST_FILE
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const MAIN: &str = r#"#import "lib/style.typ": accent
+#let data = json("data.json")
+#text(fill: accent, data.personal.name)
+#image("logo.svg")"#;
+    const STYLE: &str = "#let accent = rgb(\"#c00\")";
+    const LOGO: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10"/>"#;
+
+    fn files() -> Vec<(&'static str, Vec<u8>)> {
+        vec![
+            ("main.typ", MAIN.as_bytes().to_vec()),
+            ("lib/style.typ", STYLE.as_bytes().to_vec()),
+            ("logo.svg", LOGO.as_bytes().to_vec()),
+        ]
+    }
+
+    fn zip_bytes(files: &[(&str, Vec<u8>)]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (path, data) in files {
+            writer
+                .start_file(*path, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(data).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn tar_bytes(files: &[(&str, Vec<u8>)]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for (path, data) in files {
+            let mut header = tar::Header::new_ustar();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, path, data.as_slice())
+                .unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    #[test]
+    fn test_from_files_finds_main_and_validates() {
+        let bundle = TemplateBundle::from_files(files()).unwrap();
+        assert_eq!(bundle.main(), "main.typ");
+        assert_eq!(bundle.data_path(), "data.json");
+        bundle.validate().unwrap();
+    }
+
+    #[test]
+    fn test_manifest_names_main_in_subdirectory() {
+        let bundle = TemplateBundle::from_files([
+            ("template.json", br#"{"main": "src/acme.typ"}"#.to_vec()),
+            (
+                "src/acme.typ",
+                br#"#let d = json("data.json")
+#d.summary"#
+                    .to_vec(),
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(bundle.main(), "src/acme.typ");
+        assert_eq!(bundle.data_path(), "src/data.json");
+        bundle.validate().unwrap();
+    }
+
+    #[test]
+    fn test_archives_strip_common_root() {
+        let prefixed: Vec<(String, Vec<u8>)> = files()
+            .into_iter()
+            .map(|(path, data)| (format!("acme/{}", path), data))
+            .collect();
+        let prefixed: Vec<(&str, Vec<u8>)> = prefixed
+            .iter()
+            .map(|(path, data)| (path.as_str(), data.clone()))
+            .collect();
+        let expected = TemplateBundle::from_files(files()).unwrap();
+
+        let zip = zip_bytes(&prefixed);
+        assert_eq!(TemplateBundle::from_archive(&zip).unwrap(), expected);
+
+        let tar = tar_bytes(&prefixed);
+        assert_eq!(TemplateBundle::from_archive(&tar).unwrap(), expected);
+
+        let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        gz.write_all(&tar).unwrap();
+        let tar_gz = gz.finish().unwrap();
+        assert_eq!(TemplateBundle::from_archive(&tar_gz).unwrap(), expected);
+
+        assert!(TemplateBundle::from_archive(b"not an archive").is_err());
+    }
+
+    #[test]
+    fn test_validate_reports_every_problem() {
+        let main = r#"#import "missing.typ": x
+#import "@preview/cetz:0.3.0"
+#let data = json("data.json")
+#data.personal.nickname
+#data.layout.colour
+#data.companies.len()
+#image("logo.png")"#;
+        let bundle = TemplateBundle::from_files([
+            ("main.typ", main.as_bytes().to_vec()),
+            ("broken.typ", b"#let x = (".to_vec()),
+            ("fonts/brand.ttf", b"not a font".to_vec()),
+            (
+                "template.json",
+                br#"{"main": "main.typ", "dataVersion": 99}"#.to_vec(),
+            ),
+        ])
+        .unwrap();
+
+        let message = bundle.validate().unwrap_err().to_string();
+        for expected in [
+            "dataVersion 99 is not supported",
+            "broken.typ: syntax error",
+            "module 'missing.typ' is not in the bundle",
+            "package '@preview/cetz:0.3.0' is not available",
+            "'data.personal.nickname' reads 'nickname'",
+            "'data.layout.colour' reads 'colour'",
+            "file 'logo.png' is not in the bundle",
+            "fonts/brand.ttf: not a usable font",
+        ] {
+            assert!(
+                message.contains(expected),
+                "missing {:?} in {}",
+                expected,
+                message
+            );
+        }
+        assert!(!message.contains("companies"), "{}", message);
+    }
+
+    #[test]
+    fn test_paths_cannot_escape_bundle() {
+        assert!(TemplateBundle::from_files([("../main.typ", b"x".to_vec())]).is_err());
+        assert!(TemplateBundle::new("main.typ", "")
+            .unwrap()
+            .with_file("a/../../etc/passwd", b"x".to_vec())
+            .is_err());
+
+        let bundle = TemplateBundle::new("main.typ", "#import \"../outside.typ\": x").unwrap();
+        assert!(bundle.validate().is_err());
+    }
+
+    #[test]
+    fn test_data_file_is_reserved() {
+        let bundle = TemplateBundle::new("main.typ", "")
+            .unwrap()
+            .with_file("data.json", b"{}".to_vec())
+            .unwrap();
+        assert!(bundle
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("reserved for the template data"));
+    }
+}
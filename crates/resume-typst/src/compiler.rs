@@ -3,10 +3,12 @@
 //! Implements the Typst `World` trait to provide the compiler with access to
 //! source files, fonts, and other resources needed for PDF generation.
 
-use crate::fonts;
+use crate::bundle::TemplateBundle;
+use crate::diagnostics::{Diagnostic, Diagnostics};
+use crate::fonts::{self, FontCollection};
 use crate::TypstError;
 use chrono::Datelike; // For year(), month(), day() methods
-use ecow::eco_format;
+use std::collections::HashMap;
 use typst::diag::{FileError, FileResult};
 use typst::foundations::{Bytes, Datetime};
 use typst::syntax::{FileId, Source, VirtualPath};
@@ -18,19 +20,26 @@ use typst_utils::LazyHash;
 ///
 /// The World trait is Typst's interface for accessing files, fonts, and other
 /// resources during compilation. Our implementation provides:
-/// - A single source file (the rendered template)
-/// - Built-in fonts from Typst assets
-/// - Current date/time
+/// - The template source, plus any modules from a [`TemplateBundle`]
+/// - In-memory data files registered with [`ResumeWorld::with_file`]
+/// - Embedded or user-supplied fonts, plus any fonts from the bundle
+/// - The current date, or a pinned one set with [`ResumeWorld::with_clock`]
 ///
 pub struct ResumeWorld {
     /// The main resume template source
     main: Source,
+    /// Other Typst modules the template imports (bundles only)
+    sources: HashMap<FileId, Source>,
+    /// Virtual files readable from the template (e.g. `data.json`)
+    files: HashMap<FileId, Bytes>,
     /// The Typst standard library
     library: LazyHash<Library>,
     /// Font book for font selection
     book: LazyHash<FontBook>,
     /// Available fonts
     fonts: Vec<Font>,
+    /// Unix timestamp `datetime.today()` answers with; `None` uses the wall clock
+    clock: Option<u64>,
 }
 
 impl ResumeWorld {
@@ -44,22 +53,111 @@ impl ResumeWorld {
     /// * `Err(TypstError)` - Font loading or setup failed
     ///
     pub fn new(template_content: String) -> Result<Self, TypstError> {
-        // Load fonts
         let (book, fonts) = fonts::load_fonts().map_err(TypstError::FontError)?;
+        Ok(Self::with_book(template_content, book, fonts))
+    }
+
+    /// Create a World that uses the given fonts instead of the default set
+    ///
+    pub fn with_fonts(
+        template_content: String,
+        fonts: &FontCollection,
+    ) -> Result<Self, TypstError> {
+        let (book, fonts) = fonts.load();
+        Ok(Self::with_book(template_content, book, fonts))
+    }
 
+    fn with_book(template_content: String, book: FontBook, fonts: Vec<Font>) -> Self {
         // Create source from template content
         // FileId requires a VirtualPath for identification
         let path = VirtualPath::new("resume.typ");
         let source = Source::new(FileId::new(None, path), template_content);
 
-        Ok(Self {
+        Self {
             main: source,
+            sources: HashMap::new(),
+            files: HashMap::new(),
             library: LazyHash::new(Library::default()),
             book: LazyHash::new(book),
             fonts,
+            clock: None,
+        }
+    }
+
+    /// Create a World from a template bundle
+    ///
+    /// Every bundle file is readable at its bundle path, `.typ` files are
+    /// importable, and bundled fonts are added to `fonts`.
+    ///
+    /// # Returns
+    /// * `Ok(ResumeWorld)` - Ready to compile
+    /// * `Err(TypstError)` - A source isn't UTF-8, or font loading failed
+    ///
+    pub fn from_bundle(
+        bundle: &TemplateBundle,
+        fonts: &FontCollection,
+    ) -> Result<Self, TypstError> {
+        let (mut book, mut fonts) = fonts.load();
+
+        let mut main = None;
+        let mut sources = HashMap::new();
+        for (path, data) in bundle.sources() {
+            let text = std::str::from_utf8(data)
+                .map_err(|_| TypstError::TemplateError(format!("{}: not valid UTF-8", path)))?;
+            let source = Source::new(FileId::new(None, VirtualPath::new(path)), text.into());
+            if path == bundle.main() {
+                main = Some(source);
+            } else {
+                sources.insert(source.id(), source);
+            }
+        }
+        let main = main.ok_or_else(|| {
+            TypstError::TemplateError(format!("Main file '{}' is missing", bundle.main()))
+        })?;
+
+        for (_, data) in bundle.fonts() {
+            for font in Font::iter(Bytes::from(data.to_vec())) {
+                book.push(font.info().clone());
+                fonts.push(font);
+            }
+        }
+
+        let files = bundle
+            .files()
+            .map(|(path, data)| {
+                (
+                    FileId::new(None, VirtualPath::new(path)),
+                    Bytes::from(data.to_vec()),
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            main,
+            sources,
+            files,
+            library: LazyHash::new(Library::default()),
+            book: LazyHash::new(book),
+            fonts,
+            clock: None,
         })
     }
 
+    /// Serve `data` at `path` (relative to the template), e.g. `data.json`
+    /// for `json("data.json")`
+    pub fn with_file(mut self, path: &str, data: impl Into<Bytes>) -> Self {
+        let id = FileId::new(None, VirtualPath::new(path));
+        self.files.insert(id, data.into());
+        self
+    }
+
+    /// Answer `datetime.today()` with the date at `timestamp` (Unix seconds)
+    /// instead of the wall clock, for reproducible output
+    pub fn with_clock(mut self, timestamp: Option<u64>) -> Self {
+        self.clock = timestamp;
+        self
+    }
+
     /// Compile the template to a Typst document
     ///
     /// # Returns
@@ -67,16 +165,49 @@ impl ResumeWorld {
     /// * `Err(TypstError)` - Compilation failed
     ///
     pub fn compile(&self) -> Result<typst::model::Document, TypstError> {
+        self.compile_with_diagnostics()
+            .map(|(document, _)| document)
+    }
+
+    /// Compile, also returning warnings: Typst's own plus
+    /// [`fonts::font_diagnostics`] for text the fonts couldn't set as asked
+    ///
+    /// # Returns
+    /// * `Ok((Document, warnings))` - Compiled document and any warnings
+    /// * `Err(TypstError::CompilationError)` - The errors, followed by any
+    ///   warnings raised before compilation failed
+    ///
+    pub fn compile_with_diagnostics(
+        &self,
+    ) -> Result<(typst::model::Document, Vec<Diagnostic>), TypstError> {
         let result = typst::compile(self);
 
+        let mut warnings: Vec<Diagnostic> = result
+            .warnings
+            .iter()
+            .map(|warning| Diagnostic::from_typst(self, warning))
+            .collect();
+
         // Handle Warned<Result<...>> return type
-        result.output.map_err(|errors| {
-            let error_msgs: Vec<String> = errors
-                .iter()
-                .map(|e| eco_format!("{:?}", e).to_string())
-                .collect();
-            TypstError::CompilationError(error_msgs.join("; "))
-        })
+        let document = match result.output {
+            Ok(document) => document,
+            Err(errors) => {
+                let mut diagnostics: Vec<Diagnostic> = errors
+                    .iter()
+                    .map(|error| Diagnostic::from_typst(self, error))
+                    .collect();
+                diagnostics.append(&mut warnings);
+                return Err(TypstError::CompilationError(Diagnostics(diagnostics)));
+            }
+        };
+
+        let sources: Vec<Source> = std::iter::once(&self.main)
+            .chain(self.sources.values())
+            .cloned()
+            .collect();
+        warnings.extend(fonts::font_diagnostics(&document, self, &sources));
+
+        Ok((document, warnings))
     }
 }
 
@@ -97,26 +228,32 @@ impl World for ResumeWorld {
         if id == self.main.id() {
             Ok(self.main.clone())
         } else {
-            Err(FileError::NotFound(
-                id.vpath().as_rootless_path().to_path_buf(),
-            ))
+            self.sources
+                .get(&id)
+                .cloned()
+                .ok_or_else(|| FileError::NotFound(id.vpath().as_rootless_path().to_path_buf()))
         }
     }
 
     fn file(&self, id: FileId) -> FileResult<Bytes> {
-        // We don't support external file loading in resume generation
-        Err(FileError::NotFound(
-            id.vpath().as_rootless_path().to_path_buf(),
-        ))
+        // Only registered in-memory files; nothing is read from disk
+        self.files
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| FileError::NotFound(id.vpath().as_rootless_path().to_path_buf()))
     }
 
     fn font(&self, index: usize) -> Option<Font> {
         self.fonts.get(index).cloned()
     }
 
-    fn today(&self, _offset: Option<i64>) -> Option<Datetime> {
+    fn today(&self, offset: Option<i64>) -> Option<Datetime> {
         // Use UTC date for PDF metadata (Local::now() unavailable in WASM)
-        let now = chrono::Utc::now();
+        let now = match self.clock {
+            Some(timestamp) => chrono::DateTime::from_timestamp(i64::try_from(timestamp).ok()?, 0)?,
+            None => chrono::Utc::now(),
+        };
+        let now = now + chrono::Duration::hours(offset.unwrap_or(0));
         Datetime::from_ymd(now.year(), now.month() as u8, now.day() as u8)
     }
 }
@@ -220,6 +357,43 @@ mod tests {
         assert!(unknown_result.is_err());
     }
 
+    #[test]
+    fn test_registered_file_is_readable_from_template() {
+        let template = r#"#let data = json("data.json")
+#data.name"#
+            .to_string();
+
+        let world = ResumeWorld::new(template.clone())
+            .unwrap()
+            .with_file("data.json", br#"{"name": "Ada"}"#.as_slice());
+        assert!(world.compile().is_ok());
+
+        // Without the file the template can't load its data
+        let world = ResumeWorld::new(template).unwrap();
+        assert!(world.compile().is_err());
+    }
+
+    #[test]
+    fn test_bundle_modules_and_files_resolve() {
+        let bundle = TemplateBundle::new(
+            "src/main.typ",
+            r#"#import "parts/header.typ": header
+#header(read("../assets/name.txt"))"#,
+        )
+        .unwrap()
+        .with_file(
+            "src/parts/header.typ",
+            b"#let header(name) = heading(name)".to_vec(),
+        )
+        .unwrap()
+        .with_file("assets/name.txt", b"Ada".to_vec())
+        .unwrap();
+
+        let fonts = FontCollection::builtin(Default::default()).unwrap();
+        let world = ResumeWorld::from_bundle(&bundle, &fonts).unwrap();
+        assert!(world.compile().is_ok());
+    }
+
     #[test]
     fn test_today_returns_valid_date() {
         let template = "#set document(title: \"Test\")".to_string();
@@ -232,4 +406,14 @@ mod tests {
         // Year should be reasonable (2024+)
         assert!(date.year() >= Some(2024));
     }
+
+    #[test]
+    fn test_pinned_clock() {
+        let world = ResumeWorld::new(String::new())
+            .unwrap()
+            .with_clock(Some(1_700_000_000)); // 2023-11-14 22:13:20 UTC
+
+        assert_eq!(world.today(None), Datetime::from_ymd(2023, 11, 14));
+        assert_eq!(world.today(Some(2)), Datetime::from_ymd(2023, 11, 15));
+    }
 }
@@ -0,0 +1,351 @@
+//! Structured compile diagnostics
+//!
+//! Typst errors and warnings, plus the font checks in [`crate::fonts`], are
+//! reported as [`Diagnostic`]s: severity, message, hints, where in the
+//! template they arose and, when the problem is in the resume content
+//! itself, which payload fields to fix (e.g.
+//! `selected_bullets[4].bullet.description`).
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use shared_types::GenerationPayload;
+use std::fmt;
+use typst::syntax::Span;
+use typst::World;
+
+/// How serious a diagnostic is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Compilation failed
+    Error,
+    /// The PDF was produced but may not look as intended
+    Warning,
+}
+
+/// Location in a template source file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceSpan {
+    /// Path within the template (e.g. `resume.typ`)
+    pub file: String,
+    /// Byte range in the file
+    pub start: usize,
+    pub end: usize,
+    /// 1-based line and column of `start`
+    pub line: usize,
+    pub column: usize,
+    /// The source text covered by the span
+    pub snippet: String,
+}
+
+impl SourceSpan {
+    /// Resolve a Typst span against the world's sources
+    ///
+    /// Returns `None` for detached spans (such as text converted from a data
+    /// string) and spans into non-source files.
+    pub fn resolve(world: &dyn World, span: Span) -> Option<Self> {
+        let source = world.source(span.id()?).ok()?;
+        let range = source.range(span)?;
+        Some(Self {
+            file: source
+                .id()
+                .vpath()
+                .as_rootless_path()
+                .to_string_lossy()
+                .replace('\\', "/"),
+            line: source.byte_to_line(range.start)? + 1,
+            column: source.byte_to_column(range.start)? + 1,
+            snippet: source.text()[range.clone()].to_string(),
+            start: range.start,
+            end: range.end,
+        })
+    }
+}
+
+impl fmt::Display for SourceSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// A compile error or warning
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    /// Suggestions for fixing the problem
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hints: Vec<String>,
+    /// Where in the template the problem arose
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<SourceSpan>,
+    /// Payload text the problem is about, if it comes from resume content
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Payload fields containing `text`, filled in by [`locate_in_payload`]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+}
+
+impl Diagnostic {
+    /// A diagnostic with no hints, span or payload text
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            hints: Vec::new(),
+            span: None,
+            text: None,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Convert a Typst diagnostic, resolving its span against `world`
+    pub fn from_typst(world: &dyn World, diagnostic: &typst::diag::SourceDiagnostic) -> Self {
+        let severity = match diagnostic.severity {
+            typst::diag::Severity::Error => Severity::Error,
+            typst::diag::Severity::Warning => Severity::Warning,
+        };
+        Self {
+            hints: diagnostic.hints.iter().map(|h| h.to_string()).collect(),
+            span: SourceSpan::resolve(world, diagnostic.span),
+            ..Self::new(severity, diagnostic.message.as_str())
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{}: {}", severity, self.message)?;
+        if let Some(span) = &self.span {
+            write!(f, " at {}", span)?;
+        }
+        if !self.fields.is_empty() {
+            write!(f, " (in {})", self.fields.join(", "))?;
+        }
+        for hint in &self.hints {
+            write!(f, "; hint: {}", hint)?;
+        }
+        Ok(())
+    }
+}
+
+/// Diagnostics from a failed compile, shown one per `; `
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Diagnostics(pub Vec<Diagnostic>);
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, diagnostic) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", diagnostic)?;
+        }
+        Ok(())
+    }
+}
+
+/// Point diagnostics about resume content at the payload fields it came from
+///
+/// Every string field in the payload containing a diagnostic's `text` is
+/// listed, using Rust field names (`selected_bullets[4].bullet.description`).
+pub fn locate_in_payload(diagnostics: &mut [Diagnostic], payload: &GenerationPayload) {
+    if diagnostics.iter().all(|d| d.text.is_none()) {
+        return;
+    }
+    let Ok(value) = serde_json::to_value(payload) else {
+        return;
+    };
+
+    let mut strings = Vec::new();
+    collect_strings(&value, String::new(), &mut strings);
+
+    for diagnostic in diagnostics {
+        if let Some(text) = &diagnostic.text {
+            diagnostic.fields = strings
+                .iter()
+                .filter(|(_, value)| value.contains(text.as_str()))
+                .map(|(path, _)| path.clone())
+                .collect();
+        }
+    }
+}
+
+/// Every string in `value` with its path
+fn collect_strings<'a>(value: &'a JsonValue, path: String, out: &mut Vec<(String, &'a str)>) {
+    match value {
+        JsonValue::String(s) => out.push((path, s)),
+        JsonValue::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                collect_strings(item, format!("{}[{}]", path, i), out);
+            }
+        }
+        JsonValue::Object(map) => {
+            for (key, item) in map {
+                let segment = field_segment(key);
+                let path = if path.is_empty() || segment.starts_with('[') {
+                    format!("{}{}", path, segment)
+                } else {
+                    format!("{}.{}", path, segment)
+                };
+                collect_strings(item, path, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `selectedBullets` → `selected_bullets`; map keys that aren't
+/// identifiers (`machine-learning`) are quoted
+fn field_segment(key: &str) -> String {
+    if !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') || key.is_empty() {
+        return format!("[{:?}]", key);
+    }
+    let mut snake = String::with_capacity(key.len() + 4);
+    for c in key.chars() {
+        if c.is_ascii_uppercase() {
+            snake.push('_');
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared_types::{Bullet, PersonalInfo, RoleProfile, ScoredBullet, ScoringWeights};
+    use std::collections::HashMap;
+
+    fn bullet(id: &str, description: &str) -> ScoredBullet {
+        ScoredBullet {
+            bullet: Bullet {
+                id: id.to_string(),
+                name: None,
+                location: None,
+                date_start: None,
+                date_end: None,
+                summary: None,
+                description: description.to_string(),
+                tags: vec![],
+                priority: 5,
+                link: None,
+            },
+            score: 1.0,
+            company_id: "co".to_string(),
+            company_name: Some("Co".to_string()),
+            company_description: None,
+            company_link: None,
+            company_date_start: "2020-01".to_string(),
+            company_date_end: None,
+            company_location: None,
+            position_id: "pos".to_string(),
+            position_name: "Engineer".to_string(),
+            position_description: None,
+            position_date_start: "2020-01".to_string(),
+            position_date_end: None,
+        }
+    }
+
+    fn payload() -> GenerationPayload {
+        GenerationPayload {
+            personal: PersonalInfo {
+                name: "Test Person".to_string(),
+                nickname: None,
+                tagline: None,
+                email: None,
+                phone: None,
+                location: None,
+                linkedin: None,
+                github: None,
+                website: None,
+                twitter: None,
+            },
+            selected_bullets: vec![
+                bullet("b0", "Shipped things"),
+                bullet("b1", "Built a \u{2603} factory"),
+            ],
+            role_profile: RoleProfile {
+                id: "role".to_string(),
+                name: "Engineer".to_string(),
+                description: None,
+                tag_weights: HashMap::new(),
+                scoring_weights: ScoringWeights {
+                    tag_relevance: 0.6,
+                    priority: 0.4,
+                },
+                summary_id: None,
+            },
+            education: None,
+            skills: Some(HashMap::from([(
+                "machine-learning".to_string(),
+                vec!["\u{2603}Net".to_string()],
+            )])),
+            summary: None,
+            meta_footer: None,
+            total_bullets_available: None,
+            total_companies_available: None,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn test_locate_in_payload_names_rust_fields() {
+        let mut diagnostics = vec![
+            Diagnostic {
+                text: Some("\u{2603}".to_string()),
+                ..Diagnostic::new(Severity::Warning, "missing glyph")
+            },
+            Diagnostic::new(Severity::Error, "unrelated"),
+        ];
+        locate_in_payload(&mut diagnostics, &payload());
+
+        assert_eq!(
+            diagnostics[0].fields,
+            [
+                "selected_bullets[1].bullet.description",
+                "skills[\"machine-learning\"][0]",
+            ]
+        );
+        assert!(diagnostics[1].fields.is_empty());
+    }
+
+    #[test]
+    fn test_display_and_serialization() {
+        let diagnostic = Diagnostic {
+            hints: vec!["try another font".to_string()],
+            span: Some(SourceSpan {
+                file: "resume.typ".to_string(),
+                start: 10,
+                end: 14,
+                line: 3,
+                column: 5,
+                snippet: "text".to_string(),
+            }),
+            fields: vec!["summary".to_string()],
+            ..Diagnostic::new(Severity::Warning, "something odd")
+        };
+        assert_eq!(
+            diagnostic.to_string(),
+            "warning: something odd at resume.typ:3:5 (in summary); hint: try another font"
+        );
+
+        let json = serde_json::to_value(&diagnostic).unwrap();
+        assert_eq!(json["severity"], "warning");
+        assert_eq!(json["span"]["line"], 3);
+        assert!(json.get("text").is_none());
+    }
+}
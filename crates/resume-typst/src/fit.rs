@@ -0,0 +1,489 @@
+//! Fit-to-page rendering
+//!
+//! Compiles the resume, counts the pages the layout actually produced, and
+//! shrinks it until it fits a page limit:
+//!
+//! 1. step through tighter [`Spacing`] levels
+//! 2. at the tightest level, drop the lowest-scored bullets (binary search
+//!    over how many to drop, since fewer bullets never means more pages)
+//!
+//! Pinned bullets (`metadata.selectionOverrides.pinnedBulletIds`) are never
+//! dropped. Appendix pages (meta footer, dev metadata) come after the
+//! end-of-resume marker and don't count towards the limit.
+
+use crate::template::Spacing;
+use crate::{compile_resume, export_pdf, TypstError};
+use serde::{Deserialize, Serialize};
+use shared_types::{GenerationPayload, RenderOptions};
+use std::collections::HashSet;
+use typst::foundations::Label;
+use typst::model::Document;
+
+/// Label the template places after the last resume section (`<resume-end>`)
+pub(crate) const RESUME_END_LABEL: &str = "resume-end";
+
+/// A bullet removed to make the resume fit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DroppedBullet {
+    pub bullet_id: String,
+    pub company_id: String,
+    pub position_id: String,
+    pub score: f32,
+}
+
+/// What the fit loop did to the resume
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FitReport {
+    pub max_pages: usize,
+    /// Resume pages in the returned PDF (excluding appendix pages)
+    pub pages: usize,
+    /// All pages in the returned PDF
+    pub total_pages: usize,
+    /// False when even dropping every unpinned bullet wouldn't fit; the PDF is
+    /// then the tightest layout with nothing dropped
+    pub fits: bool,
+    pub spacing: Spacing,
+    /// Lowest score first
+    pub dropped: Vec<DroppedBullet>,
+    /// Number of Typst compilations performed
+    pub compilations: usize,
+}
+
+/// PDF plus the report describing how it was fitted
+#[derive(Debug, Clone)]
+pub struct FitResult {
+    pub pdf: Vec<u8>,
+    pub report: FitReport,
+}
+
+/// Generate a PDF resume that fits within `max_pages`
+///
+/// # Arguments
+/// * `payload` - Generation payload containing resume data
+/// * `options` - Template, page and layout choices (see [`crate::render_resume`])
+/// * `max_pages` - Page limit for the resume proper (at least 1)
+///
+/// # Returns
+/// * `Ok(FitResult)` - PDF and fit report (check `report.fits`)
+/// * `Err(TypstError)` - Error during generation
+///
+/// # Example
+/// ```no_run
+/// use resume_typst::render_resume_fit;
+/// use shared_types::{GenerationPayload, RenderOptions};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let payload: GenerationPayload = serde_json::from_str("{...}")?;
+/// let result = render_resume_fit(&payload, &RenderOptions::default(), 1)?;
+/// for dropped in &result.report.dropped {
+///     eprintln!("dropped {} ({:.2})", dropped.bullet_id, dropped.score);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn render_resume_fit(
+    payload: &GenerationPayload,
+    options: &RenderOptions,
+    max_pages: usize,
+) -> Result<FitResult, TypstError> {
+    if max_pages == 0 {
+        return Err(TypstError::TemplateError(
+            "max_pages must be at least 1".to_string(),
+        ));
+    }
+
+    let mut compilations = 0;
+    let mut compile = |payload: &GenerationPayload, spacing: Spacing| {
+        compilations += 1;
+        compile_resume(payload, options, spacing)
+    };
+
+    // 1. Tighten spacing
+    let mut tightest = None;
+    for spacing in Spacing::ALL {
+        let document = compile(payload, spacing)?;
+        if content_pages(&document) <= max_pages {
+            return finish(
+                document,
+                payload,
+                options,
+                spacing,
+                max_pages,
+                Vec::new(),
+                compilations,
+            );
+        }
+        tightest = Some(document);
+    }
+    let spacing = Spacing::Tight;
+    let tightest = tightest.expect("Spacing::ALL is not empty");
+
+    // 2. Drop bullets: find the smallest prefix of the drop order that fits
+    let order = drop_order(payload);
+    let mut best: Option<(usize, Document)> = None;
+    let (mut lo, mut hi) = (1, order.len() + 1);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let document = compile(&without(payload, &order[..mid]), spacing)?;
+        if content_pages(&document) <= max_pages {
+            best = Some((mid, document));
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    match best {
+        Some((count, document)) => {
+            let dropped = order[..count]
+                .iter()
+                .map(|&index| {
+                    let scored = &payload.selected_bullets[index];
+                    DroppedBullet {
+                        bullet_id: scored.bullet.id.clone(),
+                        company_id: scored.company_id.clone(),
+                        position_id: scored.position_id.clone(),
+                        score: scored.score,
+                    }
+                })
+                .collect();
+            finish(
+                document,
+                &without(payload, &order[..count]),
+                options,
+                spacing,
+                max_pages,
+                dropped,
+                compilations,
+            )
+        }
+        None => finish(
+            tightest,
+            payload,
+            options,
+            spacing,
+            max_pages,
+            Vec::new(),
+            compilations,
+        ),
+    }
+}
+
+fn finish(
+    document: Document,
+    payload: &GenerationPayload,
+    options: &RenderOptions,
+    spacing: Spacing,
+    max_pages: usize,
+    dropped: Vec<DroppedBullet>,
+    compilations: usize,
+) -> Result<FitResult, TypstError> {
+    let pages = content_pages(&document);
+    let report = FitReport {
+        max_pages,
+        pages,
+        total_pages: document.pages.len(),
+        fits: pages <= max_pages,
+        spacing,
+        dropped,
+        compilations,
+    };
+    Ok(FitResult {
+        pdf: export_pdf(&document, payload, options)?,
+        report,
+    })
+}
+
+/// Pages up to and including the one holding the end-of-resume marker
+///
+/// Falls back to the full page count if the marker is missing.
+pub(crate) fn content_pages(document: &Document) -> usize {
+    document
+        .introspector
+        .query_label(Label::new(RESUME_END_LABEL))
+        .ok()
+        .and_then(|content| content.location())
+        .map(|location| document.introspector.page(location).get())
+        .unwrap_or(document.pages.len())
+}
+
+/// Indices into `selected_bullets` in the order they should be dropped
+///
+/// Lowest score first; ties drop the later bullet first. Pinned bullets are
+/// left out entirely.
+fn drop_order(payload: &GenerationPayload) -> Vec<usize> {
+    let pinned: HashSet<&str> = payload
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.selection_overrides.as_ref())
+        .map(|overrides| {
+            overrides
+                .pinned_bullet_ids
+                .iter()
+                .map(String::as_str)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let bullets = &payload.selected_bullets;
+    let mut order: Vec<usize> = (0..bullets.len())
+        .rev()
+        .filter(|&index| !pinned.contains(bullets[index].bullet.id.as_str()))
+        .collect();
+    order.sort_by(|&a, &b| bullets[a].score.total_cmp(&bullets[b].score));
+    order
+}
+
+/// Copy of `payload` without the bullets at `drop` indices
+///
+/// The dropped IDs are also removed from `metadata.selected_bullet_ids`, so
+/// the PDF metadata only lists bullets the PDF contains.
+fn without(payload: &GenerationPayload, drop: &[usize]) -> GenerationPayload {
+    let drop: HashSet<usize> = drop.iter().copied().collect();
+    let mut trimmed = payload.clone();
+    trimmed.selected_bullets = payload
+        .selected_bullets
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !drop.contains(index))
+        .map(|(_, scored)| scored.clone())
+        .collect();
+    if let Some(metadata) = &mut trimmed.metadata {
+        let dropped: HashSet<&str> = drop
+            .iter()
+            .map(|&index| payload.selected_bullets[index].bullet.id.as_str())
+            .collect();
+        metadata
+            .selected_bullet_ids
+            .retain(|id| !dropped.contains(id.as_str()));
+    }
+    trimmed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared_types::{
+        Bullet, GenerationMetadata, PersonalInfo, RoleProfile, ScoredBullet, ScoringWeights,
+        SelectionOverrides,
+    };
+    use std::collections::HashMap;
+
+    fn scored(id: &str, score: f32, description: &str) -> ScoredBullet {
+        ScoredBullet {
+            bullet: Bullet {
+                id: id.to_string(),
+                name: None,
+                location: None,
+                date_start: None,
+                date_end: None,
+                summary: None,
+                description: description.to_string(),
+                tags: vec![],
+                priority: 5,
+                link: None,
+            },
+            score,
+            company_id: "acme".to_string(),
+            company_name: Some("Acme".to_string()),
+            company_description: None,
+            company_link: None,
+            company_date_start: "2020-01".to_string(),
+            company_date_end: None,
+            company_location: None,
+            position_id: "acme-eng".to_string(),
+            position_name: "Engineer".to_string(),
+            position_description: None,
+            position_date_start: "2020-01".to_string(),
+            position_date_end: None,
+        }
+    }
+
+    fn payload(bullets: Vec<ScoredBullet>) -> GenerationPayload {
+        GenerationPayload {
+            personal: PersonalInfo {
+                name: "Test Person".to_string(),
+                nickname: None,
+                tagline: None,
+                email: None,
+                phone: None,
+                location: None,
+                linkedin: None,
+                github: None,
+                website: None,
+                twitter: None,
+            },
+            selected_bullets: bullets,
+            role_profile: RoleProfile {
+                id: "test-role".to_string(),
+                name: "Test Role".to_string(),
+                description: None,
+                tag_weights: HashMap::new(),
+                scoring_weights: ScoringWeights {
+                    tag_relevance: 0.6,
+                    priority: 0.4,
+                },
+                summary_id: None,
+            },
+            education: None,
+            skills: None,
+            summary: None,
+            meta_footer: None,
+            total_bullets_available: None,
+            total_companies_available: None,
+            metadata: None,
+        }
+    }
+
+    /// Enough long bullets to overflow one page at any spacing
+    fn long_payload(count: usize) -> GenerationPayload {
+        let text = "Designed, built and operated a distributed data pipeline that \
+                    processed billions of events per day across several regions, \
+                    cutting infrastructure cost while improving reliability. ";
+        payload(
+            (0..count)
+                .map(|i| {
+                    scored(
+                        &format!("b{}", i),
+                        1.0 - i as f32 / count as f32,
+                        &text.repeat(2),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn pin(payload: &mut GenerationPayload, ids: &[&str]) {
+        payload.metadata = Some(GenerationMetadata {
+            generation_id: "gen-1".to_string(),
+            timestamp: 1_700_000_000,
+            selected_bullet_ids: vec![],
+            role_profile_id: "test-role".to_string(),
+            source_profiles: None,
+            selection_overrides: Some(SelectionOverrides {
+                pinned_bullet_ids: ids.iter().map(|id| id.to_string()).collect(),
+                ..SelectionOverrides::default()
+            }),
+            render_options: None,
+        });
+    }
+
+    #[test]
+    fn test_drop_order_lowest_score_first_skipping_pins() {
+        let mut data = payload(vec![
+            scored("high", 0.9, "x"),
+            scored("low", 0.1, "x"),
+            scored("tie-a", 0.5, "x"),
+            scored("tie-b", 0.5, "x"),
+            scored("pinned", 0.0, "x"),
+        ]);
+        pin(&mut data, &["pinned"]);
+
+        let ids: Vec<&str> = drop_order(&data)
+            .into_iter()
+            .map(|index| data.selected_bullets[index].bullet.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["low", "tie-b", "tie-a", "high"]);
+    }
+
+    #[test]
+    fn test_short_resume_fits_without_changes() {
+        let result = render_resume_fit(
+            &payload(vec![scored("b1", 0.9, "Short")]),
+            &RenderOptions::default(),
+            1,
+        )
+        .expect("render should succeed");
+
+        assert!(result.report.fits);
+        assert_eq!(result.report.pages, 1);
+        assert_eq!(result.report.spacing, Spacing::Normal);
+        assert!(result.report.dropped.is_empty());
+        assert_eq!(result.report.compilations, 1);
+        assert_eq!(&result.pdf[0..4], b"%PDF");
+    }
+
+    #[test]
+    fn test_overflowing_resume_drops_lowest_scores() {
+        let data = long_payload(30);
+        let result =
+            render_resume_fit(&data, &RenderOptions::default(), 1).expect("render should succeed");
+
+        assert!(result.report.fits);
+        assert_eq!(result.report.pages, 1);
+        assert_eq!(result.report.spacing, Spacing::Tight);
+        assert!(!result.report.dropped.is_empty());
+
+        // The dropped bullets are exactly the lowest-scored tail
+        let dropped: Vec<&str> = result
+            .report
+            .dropped
+            .iter()
+            .map(|d| d.bullet_id.as_str())
+            .collect();
+        let expected: Vec<String> = (30 - dropped.len()..30)
+            .rev()
+            .map(|i| format!("b{}", i))
+            .collect();
+        assert_eq!(dropped, expected);
+    }
+
+    #[test]
+    fn test_fitted_pdf_metadata_omits_dropped_bullets() {
+        let mut data = long_payload(30);
+        pin(&mut data, &[]);
+        let ids: Vec<String> = (0..30).map(|i| format!("b{}", i)).collect();
+        if let Some(metadata) = &mut data.metadata {
+            metadata.selected_bullet_ids = ids.clone();
+        }
+
+        let result =
+            render_resume_fit(&data, &RenderOptions::default(), 1).expect("render should succeed");
+        assert!(!result.report.dropped.is_empty());
+
+        let recorded = crate::read_pdf_metadata(&result.pdf).unwrap().bullet_ids;
+        let kept: Vec<String> = ids
+            .into_iter()
+            .filter(|id| !result.report.dropped.iter().any(|d| &d.bullet_id == id))
+            .collect();
+        assert_eq!(recorded, kept);
+    }
+
+    #[test]
+    fn test_pinned_bullets_survive_and_unfittable_reports_failure() {
+        let mut data = long_payload(30);
+        let ids: Vec<String> = (0..30).map(|i| format!("b{}", i)).collect();
+        let refs: Vec<&str> = ids.iter().map(String::as_str).collect();
+        pin(&mut data, &refs);
+
+        let result =
+            render_resume_fit(&data, &RenderOptions::default(), 1).expect("render should succeed");
+        assert!(!result.report.fits);
+        assert!(result.report.pages > 1);
+        assert!(result.report.dropped.is_empty());
+        assert_eq!(result.report.spacing, Spacing::Tight);
+    }
+
+    #[test]
+    fn test_appendix_pages_do_not_count() {
+        let mut data = payload(vec![scored("b1", 0.9, "Short")]);
+        data.meta_footer = Some("Generated by Resumate".to_string());
+
+        let options = RenderOptions {
+            debug: true,
+            ..RenderOptions::default()
+        };
+        let result = render_resume_fit(&data, &options, 1).expect("render should succeed");
+        assert!(result.report.fits);
+        assert_eq!(result.report.pages, 1);
+        assert!(result.report.total_pages > 1);
+    }
+
+    #[test]
+    fn test_zero_max_pages_is_an_error() {
+        let result = render_resume_fit(&payload(vec![]), &RenderOptions::default(), 0);
+        assert!(matches!(result, Err(TypstError::TemplateError(_))));
+    }
+}
@@ -1,98 +1,401 @@
 //! Font management for Typst compilation
 //!
-//! Uses embedded Liberation Serif fonts for minimal WASM size.
-//! Only includes Regular and Bold weights needed for resumes.
+//! Fonts are embedded rather than taken from Typst's assets (5MB+), and
+//! grouped into font sets: one family per set, with a face for each style
+//! the templates use. Templates read the family name from
+//! `layout.font` in the template data, so switching sets needs no template
+//! changes. Callers can also supply their own font files.
 
+use crate::diagnostics::{Diagnostic, Severity, SourceSpan};
+use shared_types::FontSet;
+use std::collections::{BTreeMap, BTreeSet};
 use typst::foundations::Bytes;
-use typst::text::{Font, FontBook};
+use typst::layout::{Frame, FrameItem};
+use typst::model::Document;
+use typst::syntax::{ast, Source, Span, SyntaxKind, SyntaxNode};
+use typst::text::{Font, FontBook, FontStyle, FontWeight};
+use typst::World;
 
-// Embed minimal font files at compile time
-// Liberation Serif: Open source, ~350KB per weight
-// Total: ~700KB vs 5MB+ for full Typst assets
-const FONT_REGULAR: &[u8] = include_bytes!("../../../typst/fonts/LiberationSerif-Regular.ttf");
-const FONT_BOLD: &[u8] = include_bytes!("../../../typst/fonts/LiberationSerif-Bold.ttf");
+// Embed font files at compile time
+// Liberation Serif: metric-compatible with Times New Roman, ~380KB per face.
+// The Italic and BoldItalic faces are fetched by download-fonts.sh but not
+// vendored yet, so italic serif text is set upright (see `font_diagnostics`).
+const SERIF_REGULAR: &[u8] = include_bytes!("../../../typst/fonts/LiberationSerif-Regular.ttf");
+const SERIF_BOLD: &[u8] = include_bytes!("../../../typst/fonts/LiberationSerif-Bold.ttf");
 
-/// Load fonts for Typst compilation
-///
-/// Only includes the minimal font set needed for professional resumes:
-/// - Liberation Serif Regular (body text)
-/// - Liberation Serif Bold (headings)
-///
-/// This approach reduces WASM size by ~5-8MB compared to full Typst assets.
+// DejaVu Sans: wide Unicode coverage, ~700KB per face
+const SANS_REGULAR: &[u8] = include_bytes!("../../../typst/fonts/DejaVuSans.ttf");
+const SANS_BOLD: &[u8] = include_bytes!("../../../typst/fonts/DejaVuSans-Bold.ttf");
+const SANS_OBLIQUE: &[u8] = include_bytes!("../../../typst/fonts/DejaVuSans-Oblique.ttf");
+const SANS_BOLD_OBLIQUE: &[u8] = include_bytes!("../../../typst/fonts/DejaVuSans-BoldOblique.ttf");
+
+/// Family name of each built-in font set, as used by `#set text(font: ..)`
+pub fn family(set: FontSet) -> &'static str {
+    match set {
+        FontSet::Serif => "Liberation Serif",
+        FontSet::Sans => "DejaVu Sans",
+    }
+}
+
+/// Embedded font files for a built-in font set
+pub fn embedded_faces(set: FontSet) -> &'static [&'static [u8]] {
+    match set {
+        FontSet::Serif => &[SERIF_REGULAR, SERIF_BOLD],
+        FontSet::Sans => &[SANS_REGULAR, SANS_BOLD, SANS_OBLIQUE, SANS_BOLD_OBLIQUE],
+    }
+}
+
+/// Fonts available to a compilation
+#[derive(Debug, Clone)]
+pub struct FontCollection {
+    family: String,
+    fonts: Vec<Font>,
+}
+
+impl FontCollection {
+    /// One of the embedded font sets
+    pub fn builtin(set: FontSet) -> Result<Self, String> {
+        let fonts: Vec<Font> = embedded_faces(set)
+            .iter()
+            .flat_map(|data| Font::iter(Bytes::from_static(data)))
+            .collect();
+
+        if fonts.is_empty() {
+            return Err("Failed to load embedded fonts".to_string());
+        }
+
+        Ok(Self {
+            family: family(set).to_string(),
+            fonts,
+        })
+    }
+
+    /// User-supplied font files (`.ttf`, `.otf` or collections)
+    ///
+    /// The family of the first face becomes the document family. The
+    /// embedded serif set stays available as a fallback for glyphs the
+    /// supplied fonts lack.
+    pub fn custom<D: Into<Vec<u8>>>(files: impl IntoIterator<Item = D>) -> Result<Self, String> {
+        let mut fonts = Vec::new();
+        for (i, data) in files.into_iter().enumerate() {
+            let faces: Vec<Font> = Font::iter(Bytes::from(data.into())).collect();
+            if faces.is_empty() {
+                return Err(format!("Font file {} contains no usable font", i + 1));
+            }
+            fonts.extend(faces);
+        }
+
+        let family = fonts
+            .first()
+            .map(|font| font.info().family.clone())
+            .ok_or_else(|| "No font files supplied".to_string())?;
+
+        fonts.extend(Self::builtin(FontSet::default())?.fonts);
+        Ok(Self { family, fonts })
+    }
+
+    /// Family templates should set text in
+    pub fn family(&self) -> &str {
+        &self.family
+    }
+
+    /// Font book and font list for a Typst World
+    pub fn load(&self) -> (FontBook, Vec<Font>) {
+        (FontBook::from_fonts(&self.fonts), self.fonts.clone())
+    }
+}
+
+/// Load the default (serif) fonts for Typst compilation
 ///
 /// # Returns
 /// * `Ok((FontBook, Vec<Font>))` - Font book and font list for Typst World
 /// * `Err(String)` - Error loading fonts
 ///
 pub fn load_fonts() -> Result<(FontBook, Vec<Font>), String> {
-    let mut fonts = Vec::new();
+    FontCollection::builtin(FontSet::default()).map(|collection| collection.load())
+}
 
-    // Load Regular weight
-    for font in Font::iter(Bytes::from(FONT_REGULAR)) {
-        fonts.push(font);
+/// Warnings about text the fonts can't set as asked
+///
+/// - characters no available font has a glyph for (drawn as boxes), with
+///   the character as the diagnostic's `text` so it can be traced to the
+///   payload
+/// - italic or bold text, requested anywhere in `sources`, in a family
+///   that has no such face (set upright or regular instead)
+pub fn font_diagnostics(
+    document: &Document,
+    world: &dyn World,
+    sources: &[Source],
+) -> Vec<Diagnostic> {
+    let mut families = BTreeSet::new();
+    let mut missing = BTreeMap::new();
+    for page in &document.pages {
+        collect_text(&page.frame, &mut families, &mut missing);
     }
 
-    // Load Bold weight
-    for font in Font::iter(Bytes::from(FONT_BOLD)) {
-        fonts.push(font);
+    let mut diagnostics: Vec<Diagnostic> = missing
+        .into_iter()
+        .map(|(c, span)| Diagnostic {
+            hints: vec![
+                "it is drawn as a box; pick a font set that covers it or remove the character"
+                    .to_string(),
+            ],
+            span: SourceSpan::resolve(world, span),
+            text: Some(c.to_string()),
+            ..Diagnostic::new(
+                Severity::Warning,
+                format!(
+                    "No available font has a glyph for '{}' (U+{:04X})",
+                    c, c as u32
+                ),
+            )
+        })
+        .collect();
+
+    let book = world.book();
+    let requested = requested_styles(sources);
+    for family in &families {
+        let faces: Vec<_> = book
+            .select_family(&family.to_lowercase())
+            .filter_map(|index| book.info(index))
+            .collect();
+        let has = |italic: bool, bold: bool| {
+            faces.iter().any(|info| {
+                (info.variant.style != FontStyle::Normal) == italic
+                    && (info.variant.weight >= FontWeight::SEMIBOLD) == bold
+            })
+        };
+
+        for (style, italic, bold) in [
+            ("italic", true, false),
+            ("bold", false, true),
+            ("bold italic", true, true),
+        ] {
+            let Some(span) = requested.get(&(italic, bold)) else {
+                continue;
+            };
+            if !has(italic, bold) {
+                diagnostics.push(Diagnostic {
+                    hints: vec![format!(
+                        "{} text is set in the closest available style; add the face or pick another font set",
+                        style
+                    )],
+                    span: SourceSpan::resolve(world, *span),
+                    ..Diagnostic::new(
+                        Severity::Warning,
+                        format!("{} has no {} face", family, style),
+                    )
+                });
+            }
+        }
     }
 
-    if fonts.is_empty() {
-        return Err("Failed to load embedded fonts".to_string());
+    diagnostics
+}
+
+/// Families used, and characters drawn with the `.notdef` glyph with the
+/// span of the first
+fn collect_text(
+    frame: &Frame,
+    families: &mut BTreeSet<String>,
+    missing: &mut BTreeMap<char, Span>,
+) {
+    for (_, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => collect_text(&group.frame, families, missing),
+            FrameItem::Text(text) => {
+                families.insert(text.font.info().family.clone());
+                for glyph in text.glyphs.iter().filter(|glyph| glyph.id == 0) {
+                    for c in text.text[glyph.range()].chars() {
+                        if !c.is_whitespace() {
+                            missing.entry(c).or_insert(glyph.span.0);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
     }
+}
 
-    let book = FontBook::from_fonts(&fonts);
+/// `(italic, bold)` combinations the sources ask for, with the span of the
+/// first request
+///
+/// Looks for `_emph_` and `*strong*` markup, `emph`/`strong` calls and
+/// `style:`/`weight:` arguments, counting nesting so `*_x_*` asks for both.
+fn requested_styles(sources: &[Source]) -> BTreeMap<(bool, bool), Span> {
+    fn visit(node: &SyntaxNode, italic: bool, bold: bool, out: &mut BTreeMap<(bool, bool), Span>) {
+        let (mut italic, mut bold) = (italic, bold);
+        match node.kind() {
+            SyntaxKind::Emph => italic = true,
+            SyntaxKind::Strong | SyntaxKind::Heading => bold = true,
+            SyntaxKind::FuncCall => {
+                if let Some(call) = node.cast::<ast::FuncCall>() {
+                    if let ast::Expr::Ident(name) = call.callee() {
+                        match name.as_str() {
+                            "emph" => italic = true,
+                            "strong" => bold = true,
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            SyntaxKind::Named => {
+                if let Some(named) = node.cast::<ast::Named>() {
+                    match (named.name().as_str(), named.expr()) {
+                        ("style", ast::Expr::Str(s)) if s.get() != "normal" => italic = true,
+                        ("weight", ast::Expr::Str(s))
+                            if matches!(
+                                s.get().as_str(),
+                                "semibold" | "bold" | "extrabold" | "black"
+                            ) =>
+                        {
+                            bold = true
+                        }
+                        ("weight", ast::Expr::Int(w)) if w.get() >= 600 => bold = true,
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+        if italic || bold {
+            out.entry((italic, bold)).or_insert(node.span());
+        }
+        for child in node.children() {
+            visit(child, italic, bold, out);
+        }
+    }
 
-    Ok((book, fonts))
+    let mut out = BTreeMap::new();
+    for source in sources {
+        visit(source.root(), false, false, &mut out);
+    }
+    out
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::compiler::ResumeWorld;
 
     #[test]
     fn test_load_fonts_success() {
         let result = load_fonts();
         assert!(result.is_ok());
 
-        let (_book, fonts) = result.unwrap();
+        let (book, fonts) = result.unwrap();
         assert!(!fonts.is_empty(), "Should have at least one font");
-        assert!(!fonts.is_empty(), "Font book should contain fonts");
-
-        // Verify we have fonts in the book
-        // (exact count may vary by Typst version)
-        println!("Loaded {} fonts", fonts.len());
+        assert!(book.contains_family("liberation serif"));
     }
 
     #[test]
     fn test_font_count_stays_minimal() {
-        // REGRESSION TEST: Ensure we don't accidentally bloat WASM by loading too many fonts
-        // We need Liberation Serif with Regular, Bold, and Italic = 3 TTF files max
-        // Each TTF may contain multiple font variants (e.g., different Unicode ranges)
-        let result = load_fonts();
-        assert!(result.is_ok(), "Font loading should succeed");
+        // REGRESSION TEST: Ensure we don't accidentally bloat WASM by embedding too many fonts
+        // Each set is one family with one face per (weight, italic) pair the templates use
+        const BOLD: u16 = 700;
+        let expected: [(FontSet, &[(u16, bool)]); 2] = [
+            // Italic and BoldItalic are not vendored yet (see typst/fonts/README.md)
+            (FontSet::Serif, &[(400, false), (BOLD, false)]),
+            (
+                FontSet::Sans,
+                &[(400, false), (BOLD, false), (400, true), (BOLD, true)],
+            ),
+        ];
+        // Sum over all embedded files (currently ~3.5MB raw, ~1.9MB gzipped)
+        const MAX_EMBEDDED_BYTES: usize = 4 * 1024 * 1024;
 
-        let (_book, fonts) = result.unwrap();
+        let mut total = 0;
+        for (set, variants) in expected {
+            let faces: Vec<(u16, bool)> = FontCollection::builtin(set)
+                .unwrap()
+                .fonts
+                .iter()
+                .map(|font| {
+                    let variant = font.info().variant;
+                    (
+                        variant.weight.to_number(),
+                        variant.style != FontStyle::Normal,
+                    )
+                })
+                .collect();
+            assert_eq!(
+                faces, variants,
+                "{:?} should embed exactly these (weight, italic) faces, one per file",
+                set
+            );
+            total += embedded_faces(set)
+                .iter()
+                .map(|face| face.len())
+                .sum::<usize>();
+        }
 
-        // Should have at least 1 font loaded
+        // CRITICAL: Prevent bloat - if this fails, someone added too many fonts!
         assert!(
-            !fonts.is_empty(),
-            "Should have at least 1 font loaded, got {}",
-            fonts.len()
+            total <= MAX_EMBEDDED_BYTES,
+            "Embedded fonts are {} bytes, budget is {}. Check fonts.rs and typst/fonts/ \
+             to prevent WASM bloat.",
+            total,
+            MAX_EMBEDDED_BYTES
         );
+    }
 
-        // CRITICAL: Prevent bloat - if this fails, someone added too many fonts!
-        // Max expected: 3 TTF files (Regular, Bold, Italic) × ~2-3 variants each = ~10 max
+    #[test]
+    fn test_builtin_sets_name_their_family() {
+        for set in [FontSet::Serif, FontSet::Sans] {
+            let (book, _) = FontCollection::builtin(set).unwrap().load();
+            assert!(
+                book.contains_family(&family(set).to_lowercase()),
+                "{:?}",
+                set
+            );
+        }
+    }
+
+    #[test]
+    fn test_custom_fonts() {
+        let collection = FontCollection::custom([SANS_REGULAR.to_vec()]).unwrap();
+        assert_eq!(collection.family(), "DejaVu Sans");
+        // Serif stays available for fallback
+        assert!(collection.load().0.contains_family("liberation serif"));
+
+        assert!(FontCollection::custom([b"not a font".to_vec()]).is_err());
+        assert!(FontCollection::custom(Vec::<Vec<u8>>::new()).is_err());
+    }
+
+    fn warnings_for(source: &str, set: FontSet) -> Vec<String> {
+        let fonts = FontCollection::builtin(set).unwrap();
+        let world = ResumeWorld::with_fonts(source.to_string(), &fonts).unwrap();
+        let (_, diagnostics) = world.compile_with_diagnostics().unwrap();
+        diagnostics.into_iter().map(|d| d.to_string()).collect()
+    }
+
+    #[test]
+    fn test_missing_style_is_reported() {
+        let source = "#set text(font: \"Liberation Serif\")\nPlain _italic_ *bold*";
+        let warnings = warnings_for(source, FontSet::Serif);
         assert!(
-            fonts.len() <= 10,
-            "Font count too high! Got {} fonts. Only Liberation Serif (Regular, Bold, Italic) should be loaded. \
-             Check fonts.rs and embedded fonts to prevent WASM bloat (currently 6.3MB gzipped).",
-            fonts.len()
+            warnings
+                .iter()
+                .any(|w| w.contains("Liberation Serif has no italic face")),
+            "{:?}",
+            warnings
         );
+        assert!(!warnings.iter().any(|w| w.contains("no bold face")));
+        assert!(!warnings.iter().any(|w| w.contains("bold italic")));
 
-        println!(
-            "✓ Font count is minimal: {} fonts loaded (max 10 allowed for Regular/Bold/Italic)",
-            fonts.len()
-        );
+        // The sans set has every style
+        let source = "#set text(font: \"DejaVu Sans\")\nPlain _italic_ *bold* *_both_*";
+        assert!(warnings_for(source, FontSet::Sans).is_empty());
+    }
+
+    #[test]
+    fn test_missing_glyph_is_reported() {
+        let source = "#set text(font: \"Liberation Serif\")\nCafé 中";
+        let warnings = warnings_for(source, FontSet::Serif);
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert!(warnings[0].contains("'中' (U+4E2D)"), "{:?}", warnings);
+        // Points at the markup that printed it
+        assert!(warnings[0].contains("at resume.typ:2:"), "{:?}", warnings);
     }
 }
@@ -9,11 +9,11 @@
 //!
 //! ```no_run
 //! use resume_typst::render_resume;
-//! use shared_types::GenerationPayload;
+//! use shared_types::{GenerationPayload, RenderOptions};
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let payload: GenerationPayload = serde_json::from_str("{...}")?;
-//! let pdf_bytes = render_resume(&payload, false)?;
+//! let pdf_bytes = render_resume(&payload, &RenderOptions::default())?;
 //! # Ok(())
 //! # }
 //! ```
@@ -22,12 +22,30 @@
 #![allow(dead_code)]
 
 // Modules
+pub mod accessibility;
+pub mod bundle;
 pub mod compiler;
+pub mod diagnostics;
+pub mod fit;
 pub mod fonts;
+pub mod metadata;
+mod pdf_edit;
+pub mod preview;
 pub mod template;
-
-use shared_types::GenerationPayload;
+pub mod templates;
+
+pub use bundle::{BundleManifest, TemplateBundle};
+pub use diagnostics::{Diagnostic, Diagnostics, Severity, SourceSpan};
+pub use fit::{render_resume_fit, DroppedBullet, FitReport, FitResult};
+pub use fonts::FontCollection;
+pub use metadata::{read_pdf_metadata, PdfMetadata};
+pub use preview::{render_preview, PagePreview, PreviewFormat};
+pub use template::Spacing;
+pub use templates::{list_templates, BuiltinTemplate, TemplateInfo};
+
+use shared_types::{GenerationPayload, PdfStandard, RenderOptions};
 use thiserror::Error;
+use typst::foundations::Smart;
 
 /// Errors that can occur during Typst PDF generation
 #[derive(Error, Debug)]
@@ -36,7 +54,7 @@ pub enum TypstError {
     TemplateError(String),
 
     #[error("Typst compilation failed: {0}")]
-    CompilationError(String),
+    CompilationError(Diagnostics),
 
     #[error("PDF export failed: {0}")]
     ExportError(String),
@@ -49,7 +67,8 @@ pub enum TypstError {
 ///
 /// # Arguments
 /// * `payload` - Generation payload containing resume data
-/// * `dev_mode` - If true, includes build metadata in PDF
+/// * `options` - Template, page and layout choices; `options.debug` adds a
+///   build metadata page
 ///
 /// # Returns
 /// * `Ok(Vec<u8>)` - PDF binary data
@@ -58,490 +77,328 @@ pub enum TypstError {
 /// # Example
 /// ```no_run
 /// use resume_typst::render_resume;
-/// use shared_types::GenerationPayload;
+/// use shared_types::{GenerationPayload, PaperSize, RenderOptions};
 ///
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let payload: GenerationPayload = serde_json::from_str("{...}")?;
-/// let is_dev = cfg!(debug_assertions);
-/// let pdf_bytes = render_resume(&payload, is_dev)?;
+/// let options = RenderOptions {
+///     template: "modern".to_string(),
+///     paper: PaperSize::A4,
+///     debug: cfg!(debug_assertions),
+///     ..RenderOptions::default()
+/// };
+/// let pdf_bytes = render_resume(&payload, &options)?;
 /// # Ok(())
 /// # }
 /// ```
-pub fn render_resume(payload: &GenerationPayload, dev_mode: bool) -> Result<Vec<u8>, TypstError> {
-    // 1. Prepare data for template
-    let template_data = template::prepare_template_data(payload);
-
-    // 2. Load template and inject data
-    let template_source = include_str!("../../../typst/templates/resume.typ");
-    let rendered_template = render_template(template_source, &template_data, dev_mode)?;
-
-    // 3. Create Typst World
-    let world = compiler::ResumeWorld::new(rendered_template)?;
-
-    // 4. Compile to document
-    let document = world.compile()?;
+pub fn render_resume(
+    payload: &GenerationPayload,
+    options: &RenderOptions,
+) -> Result<Vec<u8>, TypstError> {
+    let document = compile_resume(payload, options, Spacing::Normal)?;
+    export_pdf(&document, payload, options)
+}
 
-    // 5. Export to PDF
-    let pdf_options = typst_pdf::PdfOptions::default();
-    let pdf_bytes = typst_pdf::pdf(&document, &pdf_options)
-        .map_err(|e| TypstError::ExportError(format!("{:?}", e)))?;
+/// A rendered PDF plus anything that didn't come out as asked
+#[derive(Debug, Clone)]
+pub struct RenderOutput {
+    /// PDF binary data
+    pub pdf: Vec<u8>,
+    /// Typst warnings, plus missing glyphs and font styles (see
+    /// [`fonts::font_diagnostics`]), pointed at the payload fields involved
+    pub warnings: Vec<Diagnostic>,
+}
 
-    Ok(pdf_bytes)
+/// Generate a PDF resume and report rendering warnings
+///
+/// Same as [`render_resume`], but also returns warnings such as characters
+/// no embedded font covers or italic text in a family without an italic face.
+pub fn render_resume_with_warnings(
+    payload: &GenerationPayload,
+    options: &RenderOptions,
+) -> Result<RenderOutput, TypstError> {
+    let fonts = FontCollection::builtin(options.font_set).map_err(TypstError::FontError)?;
+    render_resume_with_fonts(payload, options, &fonts)
 }
 
-/// Render Typst template with data injection
+/// Generate a PDF resume set in the given fonts
 ///
-/// Generates professional ATS-optimized resume layout using Typst markup language.
-/// The template is built dynamically from the payload data.
+/// Use [`FontCollection::custom`] for user-supplied font files; the built-in
+/// templates switch to their family. `options.font_set` is ignored.
 ///
-fn render_template(
-    _template: &str,
-    data: &serde_json::Value,
-    dev_mode: bool,
-) -> Result<String, TypstError> {
-    let personal = &data["personal"];
-    let empty_companies = vec![];
-    let companies = data["companies"].as_array().unwrap_or(&empty_companies);
-
-    let mut output = String::new();
-
-    // ====================
-    // DOCUMENT SETTINGS
-    // ====================
-
-    output.push_str("#set document(\n");
-    output.push_str(&format!(
-        "  title: \"Resume - {}\",\n",
-        escape_typst_string(personal["name"].as_str().unwrap_or("Resume"))
-    ));
-    output.push_str(&format!(
-        "  author: (\"{}\",),\n",
-        escape_typst_string(personal["name"].as_str().unwrap_or(""))
-    ));
-    output.push_str(")\n\n");
-
-    output.push_str("#set page(\n");
-    output.push_str("  paper: \"us-letter\",\n");
-    output.push_str("  margin: (top: 0.75in, bottom: 0.75in, x: 0.75in),\n");
-    output.push_str("  numbering: none,\n");
-    output.push_str(")\n\n");
-
-    output.push_str("#set text(\n");
-    output.push_str("  font: \"Liberation Serif\",\n");
-    output.push_str("  size: 10pt,\n");
-    output.push_str("  hyphenate: false,\n");
-    output.push_str(")\n\n");
-
-    output.push_str("#set par(\n");
-    output.push_str("  leading: 0.55em,\n");
-    output.push_str("  justify: false,\n");
-    output.push_str("  first-line-indent: 0pt,\n");
-    output.push_str(")\n\n");
-
-    // Heading styles (section headers) - LARGER for better hierarchy
-    output.push_str("#show heading.where(level: 1): set text(size: 12pt, weight: \"bold\")\n");
-    output.push_str("#show heading.where(level: 1): set block(above: 1.2em, below: 0.6em)\n");
-    output.push_str("#show heading.where(level: 2): set text(size: 10.5pt, weight: \"bold\")\n");
-    output.push_str(
-        "#show heading.where(level: 2): set block(above: 0.8em, below: 0.4em, breakable: false)\n",
-    );
-    output.push_str("#show heading.where(level: 3): set text(size: 10pt, weight: \"regular\", style: \"italic\")\n");
-    output.push_str("#show heading.where(level: 3): set block(above: 0.5em, below: 0.3em)\n\n");
-
-    // List styling (bullets)
-    output.push_str("#set list(marker: [•], indent: 1em, body-indent: 0.5em, spacing: 0.4em)\n\n");
-
-    // ====================
-    // HEADER
-    // ====================
-
-    output.push_str("#align(center)[\n");
-
-    // Name and role on same line
-    let name = personal["name"].as_str().unwrap_or("");
-    let role_name = data["role_profile"]["name"].as_str().unwrap_or("");
-
-    if !role_name.is_empty() {
-        output.push_str(&format!(
-            "  #text(size: 20pt, weight: \"bold\")[{}] #h(0.5em) #text(size: 14pt, style: \"italic\")[– {}]\n",
-            escape_typst_string(name),
-            escape_typst_string(role_name)
-        ));
-    } else {
-        output.push_str(&format!(
-            "  #text(size: 20pt, weight: \"bold\")[{}]\n",
-            escape_typst_string(name)
-        ));
-    }
-
-    output.push_str("  #v(0.4em)\n");
-
-    // Contact line with better spacing
-    output.push_str("  #text(size: 10pt)[\n");
-    let mut contact_parts = Vec::new();
-
-    if let Some(email) = personal["email"].as_str() {
-        if !email.is_empty() {
-            let mailto_url = format!("mailto:{}", email);
-            // Raw text (backticks) doesn't need escaping in Typst
-            contact_parts.push(format!(
-                "#link(\"{}\")[`{}`]",
-                escape_typst_string(&mailto_url),
-                email
-            ));
-        }
-    }
-    if let Some(phone) = personal["phone"].as_str() {
-        if !phone.is_empty() {
-            contact_parts.push(escape_typst_string(phone));
-        }
-    }
-    if let Some(location) = personal["location"].as_str() {
-        if !location.is_empty() {
-            contact_parts.push(escape_typst_string(location));
-        }
-    }
-    if let Some(website) = personal["website"].as_str() {
-        if !website.is_empty() {
-            let url = if website.starts_with("http://") || website.starts_with("https://") {
-                website.to_string()
-            } else {
-                format!("https://{}", website)
-            };
-            contact_parts.push(format!(
-                "#link(\"{}\")[{}]",
-                escape_typst_string(&url),
-                escape_typst_string(website)
-            ));
-        }
-    }
-    if let Some(linkedin) = personal["linkedin"].as_str() {
-        if !linkedin.is_empty() {
-            let url = format!("https://linkedin.com/in/{}", linkedin);
-            contact_parts.push(format!(
-                "#link(\"{}\")[linkedin.com/in/{}]",
-                escape_typst_string(&url),
-                escape_typst_string(linkedin)
-            ));
-        }
-    }
-    if let Some(github) = personal["github"].as_str() {
-        if !github.is_empty() {
-            let url = format!("https://github.com/{}", github);
-            contact_parts.push(format!(
-                "#link(\"{}\")[github.com/{}]",
-                escape_typst_string(&url),
-                escape_typst_string(github)
-            ));
-        }
-    }
-
-    if !contact_parts.is_empty() {
-        output.push_str("    ");
-        output.push_str(&contact_parts.join(" #h(0.6em)•#h(0.6em) ")); // Better spacing between items
-        output.push('\n');
-    }
-
-    output.push_str("  ]\n");
-    output.push_str("]\n\n");
-
-    output.push_str("#v(0.5em)\n\n");
-
-    // ====================
-    // SUMMARY
-    // ====================
-
-    if let Some(summary) = data["summary"].as_str() {
-        if !summary.is_empty() {
-            output.push_str("= PROFESSIONAL SUMMARY\n\n");
-            output.push_str(&escape_typst_string(summary));
-            output.push_str("\n\n");
-        }
-    }
-
-    // ====================
-    // EXPERIENCE
-    // ====================
-
-    if !companies.is_empty() {
-        output.push_str("= EXPERIENCE\n\n");
-
-        for company in companies {
-            let company_name = company["name"].as_str().unwrap_or("");
-            let company_description = company["description"].as_str();
-            let company_link = company["link"].as_str();
-            let company_date_range = company["date_range"].as_str().unwrap_or("");
-            let position_count = company["position_count"].as_u64().unwrap_or(0);
-
-            if !company_name.is_empty() {
-                // Company name - clickable if link available
-                if let Some(link) = company_link {
-                    if !link.is_empty() {
-                        let url = if link.starts_with("http://") || link.starts_with("https://") {
-                            link.to_string()
-                        } else {
-                            format!("https://{}", link)
-                        };
-                        output.push_str(&format!(
-                            "== #link(\"{}\")[{}]",
-                            escape_typst_string(&url),
-                            escape_typst_string(company_name)
-                        ));
-                    } else {
-                        output.push_str(&format!("== {}", escape_typst_string(company_name)));
-                    }
-                } else {
-                    output.push_str(&format!("== {}", escape_typst_string(company_name)));
-                }
-
-                // Show date range if available
-                if !company_date_range.is_empty() {
-                    output.push_str(&format!(
-                        " #h(1fr) _{}_",
-                        escape_typst_string(company_date_range)
-                    ));
-                }
-                output.push_str("\n\n");
-
-                // Company description (context/industry) - Show if available
-                if let Some(desc) = company_description {
-                    if !desc.is_empty() {
-                        output.push_str(&format!("_{}_\n\n", escape_typst_string(desc)));
-                    }
-                }
-            }
+/// # Example
+/// ```no_run
+/// use resume_typst::{render_resume_with_fonts, FontCollection};
+/// use shared_types::{GenerationPayload, RenderOptions};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let payload: GenerationPayload = serde_json::from_str("{...}")?;
+/// let fonts = FontCollection::custom([
+///     std::fs::read("Inter-Regular.ttf")?,
+///     std::fs::read("Inter-Bold.ttf")?,
+/// ])?;
+/// let output = render_resume_with_fonts(&payload, &RenderOptions::default(), &fonts)?;
+/// for warning in &output.warnings {
+///     eprintln!("warning: {}", warning);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn render_resume_with_fonts(
+    payload: &GenerationPayload,
+    options: &RenderOptions,
+    fonts: &FontCollection,
+) -> Result<RenderOutput, TypstError> {
+    let template = BuiltinTemplate::from_name(&options.template)?;
+    let data_json = template_data_json(payload, options, Spacing::Normal, fonts)?;
+    let world = compiler::ResumeWorld::with_fonts(template.source().to_string(), fonts)?
+        .with_file(template::DATA_FILE, data_json)
+        .with_clock(options.clock(payload.metadata.as_ref()));
+    let (document, warnings) = compile_world(&world, payload)?;
+    Ok(RenderOutput {
+        pdf: export_pdf(&document, payload, options)?,
+        warnings,
+    })
+}
 
-            if let Some(positions) = company["positions"].as_array() {
-                for position in positions {
-                    let title = position["title"].as_str().unwrap_or("");
-                    let date_range = position["date_range"].as_str().unwrap_or("");
-
-                    if !title.is_empty() {
-                        output.push_str(&format!("=== {}", escape_typst_string(title)));
-
-                        // Only show position dates if company has multiple positions (avoid redundancy)
-                        if position_count > 1 && !date_range.is_empty() {
-                            output.push_str(&format!(
-                                " #h(1fr) _{}_",
-                                escape_typst_string(date_range)
-                            ));
-                        }
-                        output.push_str("\n\n");
-                    }
+/// Render page previews of a resume without exporting a PDF
+///
+/// Compiles exactly as [`render_resume`] does, then draws each page with
+/// [`render_preview`], so the previews match the downloaded PDF. When the PDF
+/// is needed too, use [`render_resume_with_preview`] to compile only once.
+///
+/// # Example
+/// ```no_run
+/// use resume_typst::{render_resume_preview, PreviewFormat};
+/// use shared_types::{GenerationPayload, RenderOptions};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let payload: GenerationPayload = serde_json::from_str("{...}")?;
+/// let pages = render_resume_preview(&payload, &RenderOptions::default(), PreviewFormat::Svg)?;
+/// println!("{} pages", pages.len());
+/// # Ok(())
+/// # }
+/// ```
+pub fn render_resume_preview(
+    payload: &GenerationPayload,
+    options: &RenderOptions,
+    format: PreviewFormat,
+) -> Result<Vec<PagePreview>, TypstError> {
+    let document = compile_resume(payload, options, Spacing::Normal)?;
+    render_preview(&document, format)
+}
 
-                    // Position description removed - company description is enough context
-
-                    if let Some(bullets) = position["bullets"].as_array() {
-                        for bullet in bullets {
-                            if let Some(bullet_obj) = bullet.as_object() {
-                                let description = bullet_obj
-                                    .get("description")
-                                    .and_then(|v| v.as_str())
-                                    .unwrap_or("");
-
-                                if !description.is_empty() {
-                                    output.push_str(&format!(
-                                        "- {}\n",
-                                        escape_typst_string(description)
-                                    ));
-                                }
-                            }
-                        }
-                        output.push('\n');
-                    }
-                }
-            }
-        }
-    }
+/// A rendered PDF and previews of its pages
+#[derive(Debug, Clone)]
+pub struct RenderWithPreview {
+    /// PDF binary data
+    pub pdf: Vec<u8>,
+    /// One preview per PDF page, in order
+    pub pages: Vec<PagePreview>,
+}
 
-    // ====================
-    // EDUCATION
-    // ====================
+/// Generate a PDF resume and page previews from a single compile
+///
+/// Equivalent to calling [`render_resume`] and [`render_resume_preview`],
+/// but the document is compiled once and both outputs are drawn from it.
+///
+/// # Example
+/// ```no_run
+/// use resume_typst::{render_resume_with_preview, PreviewFormat};
+/// use shared_types::{GenerationPayload, RenderOptions};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let payload: GenerationPayload = serde_json::from_str("{...}")?;
+/// let format = PreviewFormat::default();
+/// let output = render_resume_with_preview(&payload, &RenderOptions::default(), format)?;
+/// println!("{} bytes, {} pages", output.pdf.len(), output.pages.len());
+/// # Ok(())
+/// # }
+/// ```
+pub fn render_resume_with_preview(
+    payload: &GenerationPayload,
+    options: &RenderOptions,
+    format: PreviewFormat,
+) -> Result<RenderWithPreview, TypstError> {
+    let document = compile_resume(payload, options, Spacing::Normal)?;
+    Ok(RenderWithPreview {
+        pdf: export_pdf(&document, payload, options)?,
+        pages: render_preview(&document, format)?,
+    })
+}
 
-    if let Some(education) = data["education"].as_array() {
-        if !education.is_empty() {
-            output.push_str("= EDUCATION\n\n");
+/// Generate a PDF resume from a custom Typst template
+///
+/// The template reads its data with `json("data.json")`; see
+/// [`template::prepare_template_data`] for the shape. Layout changes only
+/// need a new template, not a rebuild. `options.template` is ignored.
+///
+/// # Example
+/// ```no_run
+/// use resume_typst::render_resume_with_template;
+/// use shared_types::{GenerationPayload, RenderOptions};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let payload: GenerationPayload = serde_json::from_str("{...}")?;
+/// let template = std::fs::read_to_string("typst/templates/resume.typ")?;
+/// let pdf_bytes = render_resume_with_template(&payload, &template, &RenderOptions::default())?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn render_resume_with_template(
+    payload: &GenerationPayload,
+    template_source: &str,
+    options: &RenderOptions,
+) -> Result<Vec<u8>, TypstError> {
+    let document = compile_template(template_source, payload, options, Spacing::Normal)?;
+    export_pdf(&document, payload, options)
+}
 
-            for edu in education {
-                let degree = edu["degree"].as_str().unwrap_or("");
-                let institution = edu["institution"].as_str().unwrap_or("");
-                let year = edu["year"].as_str().unwrap_or("");
+/// Generate a PDF resume from a user-supplied template bundle
+///
+/// The bundle's main file reads its data with `json("data.json")`, resolved
+/// next to the main file; modules, images and fonts come from the bundle.
+/// The bundle is checked with [`TemplateBundle::validate`] first, so a
+/// broken import or a field missing from the data schema is reported before
+/// compiling. `options.template` is ignored.
+///
+/// # Example
+/// ```no_run
+/// use resume_typst::{render_resume_with_bundle, TemplateBundle};
+/// use shared_types::{GenerationPayload, RenderOptions};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let payload: GenerationPayload = serde_json::from_str("{...}")?;
+/// let bundle = TemplateBundle::from_archive(&std::fs::read("acme-template.zip")?)?;
+/// let pdf_bytes = render_resume_with_bundle(&payload, &bundle, &RenderOptions::default())?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn render_resume_with_bundle(
+    payload: &GenerationPayload,
+    bundle: &TemplateBundle,
+    options: &RenderOptions,
+) -> Result<Vec<u8>, TypstError> {
+    bundle.validate()?;
+    let fonts = FontCollection::builtin(options.font_set).map_err(TypstError::FontError)?;
+    let data_json = template_data_json(payload, options, Spacing::Normal, &fonts)?;
+    let world = compiler::ResumeWorld::from_bundle(bundle, &fonts)?
+        .with_file(&bundle.data_path(), data_json)
+        .with_clock(options.clock(payload.metadata.as_ref()));
+    let (document, _) = compile_world(&world, payload)?;
+    export_pdf(&document, payload, options)
+}
 
-                if !degree.is_empty() || !institution.is_empty() {
-                    output.push_str(&format!("== {}", escape_typst_string(degree)));
+/// Render and compile a payload with its built-in template at the given spacing level
+pub(crate) fn compile_resume(
+    payload: &GenerationPayload,
+    options: &RenderOptions,
+    spacing: Spacing,
+) -> Result<typst::model::Document, TypstError> {
+    let template = BuiltinTemplate::from_name(&options.template)?;
+    compile_template(template.source(), payload, options, spacing)
+}
 
-                    if !year.is_empty() {
-                        output.push_str(&format!(" #h(1fr) _{}_", escape_typst_string(year)));
-                    }
-                    output.push_str("\n\n");
+/// Compile a template against a payload served as `data.json`
+fn compile_template(
+    template_source: &str,
+    payload: &GenerationPayload,
+    options: &RenderOptions,
+    spacing: Spacing,
+) -> Result<typst::model::Document, TypstError> {
+    // 1. Prepare data for template
+    let fonts = FontCollection::builtin(options.font_set).map_err(TypstError::FontError)?;
+    let data_json = template_data_json(payload, options, spacing, &fonts)?;
 
-                    if !institution.is_empty() {
-                        output.push_str(&format!("_{}_\n\n", escape_typst_string(institution)));
-                    }
-                }
-            }
-        }
-    }
+    // 2. Create Typst World with the template as main file and data alongside
+    let world = compiler::ResumeWorld::with_fonts(template_source.to_string(), &fonts)?
+        .with_file(template::DATA_FILE, data_json)
+        .with_clock(options.clock(payload.metadata.as_ref()));
 
-    // ====================
-    // SKILLS
-    // ====================
-
-    if let Some(skills) = data["skills"].as_object() {
-        if !skills.is_empty() {
-            output.push_str("= SKILLS\n\n");
-
-            for (category, skill_list) in skills {
-                if let Some(skills_array) = skill_list.as_array() {
-                    if !skills_array.is_empty() {
-                        // Capitalize category
-                        let category_display = category
-                            .split('-')
-                            .map(|word| {
-                                let mut chars = word.chars();
-                                match chars.next() {
-                                    None => String::new(),
-                                    Some(first) => {
-                                        first.to_uppercase().collect::<String>() + chars.as_str()
-                                    }
-                                }
-                            })
-                            .collect::<Vec<_>>()
-                            .join(" ");
-
-                        output.push_str(&format!("*{}:* ", escape_typst_string(&category_display)));
-
-                        let skill_strings: Vec<String> = skills_array
-                            .iter()
-                            .filter_map(|s| s.as_str())
-                            .map(escape_typst_string)
-                            .collect();
-
-                        output.push_str(&skill_strings.join(", "));
-                        output.push_str("\n\n");
-                    }
-                }
-            }
-        }
-    }
+    // 3. Compile to document
+    compile_world(&world, payload).map(|(document, _)| document)
+}
 
-    // ====================
-    // META FOOTER (dedicated page with formatting)
-    // ====================
-
-    if let Some(meta_footer) = data["metaFooter"].as_str() {
-        if !meta_footer.is_empty() {
-            // Get totals from database (not selected output)
-            let total_bullets = data["totalBulletsAvailable"]
-                .as_u64()
-                .map(|n| n as usize)
-                .unwrap_or(0);
-            let total_companies = data["totalCompaniesAvailable"]
-                .as_u64()
-                .map(|n| n as usize)
-                .unwrap_or(0);
-
-            // Replace template variables in meta footer text
-            let footer_text = meta_footer
-                .replace("{bullet_count}", &total_bullets.to_string())
-                .replace("{company_count}", &total_companies.to_string());
-
-            // Format for ATS/AI readability: Break into logical sections with line breaks
-            let formatted_footer = format_footer_for_ats(&footer_text);
-
-            // Add footer on page 3 with heading
-            output.push_str("\n\n#pagebreak(weak: true)\n\n");
-            output.push_str("= ABOUT THIS RESUME\n\n");
-            output.push_str(&escape_typst_string(&formatted_footer));
-            output.push('\n');
+/// Compile, pointing diagnostics about resume content at the payload fields
+/// they came from
+fn compile_world(
+    world: &compiler::ResumeWorld,
+    payload: &GenerationPayload,
+) -> Result<(typst::model::Document, Vec<Diagnostic>), TypstError> {
+    match world.compile_with_diagnostics() {
+        Ok((document, mut warnings)) => {
+            diagnostics::locate_in_payload(&mut warnings, payload);
+            Ok((document, warnings))
         }
-    }
-
-    // ====================
-    // DEV MODE METADATA
-    // ====================
-
-    if dev_mode {
-        output.push_str("\n#pagebreak()\n\n");
-        output.push_str("= BUILD METADATA (DEV MODE)\n\n");
-        output.push_str(&format!(
-            "*Build Time:* {}\n\n",
-            chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
-        ));
-        output.push_str(&format!(
-            "*Typst Version:* {}\n\n",
-            env!("CARGO_PKG_VERSION")
-        ));
-
-        if let Some(role_profile) = data["role_profile"].as_object() {
-            if let Some(role_name) = role_profile["name"].as_str() {
-                output.push_str(&format!(
-                    "*Role Profile:* {}\n\n",
-                    escape_typst_string(role_name)
-                ));
-            }
-            if let Some(role_desc) = role_profile["description"].as_str() {
-                output.push_str(&format!(
-                    "*Description:* {}\n\n",
-                    escape_typst_string(role_desc)
-                ));
-            }
+        Err(TypstError::CompilationError(Diagnostics(mut errors))) => {
+            diagnostics::locate_in_payload(&mut errors, payload);
+            Err(TypstError::CompilationError(Diagnostics(errors)))
         }
-
-        output.push_str(&format!("*Companies:* {}\n\n", companies.len()));
-
-        let total_bullets: usize = companies
-            .iter()
-            .filter_map(|c| c["positions"].as_array())
-            .flat_map(|positions| positions.iter())
-            .filter_map(|p| p["bullets"].as_array())
-            .map(|bullets| bullets.len())
-            .sum();
-
-        output.push_str(&format!("*Total Bullets:* {}\n\n", total_bullets));
+        Err(e) => Err(e),
     }
-
-    Ok(output)
 }
 
-/// Format meta footer text for optimal ATS/AI readability
-///
-/// Breaks long paragraph text into logical sections with line breaks between
-/// key concepts to help automated systems parse the information clearly.
-///
-fn format_footer_for_ats(text: &str) -> String {
-    // Break after key sentence endings to create logical paragraphs
-    text.replace(
-        ". A hierarchical scoring engine",
-        ".\n\nA hierarchical scoring engine",
-    )
-    .replace(". The system is built", ".\n\nThe system is built")
-    .replace(". Deployed on", ".\n\nDeployed on")
-    .replace(". Explore the full", ".\n\nExplore the full")
+/// Validate options and serialize the template data
+fn template_data_json(
+    payload: &GenerationPayload,
+    options: &RenderOptions,
+    spacing: Spacing,
+    fonts: &FontCollection,
+) -> Result<Vec<u8>, TypstError> {
+    options.validate().map_err(TypstError::TemplateError)?;
+    let mut template_data = template::prepare_template_data(payload, options, spacing);
+    // Custom fonts replace the family named by `options.font_set`
+    template_data["layout"]["font"] = fonts.family().into();
+    serde_json::to_vec(&template_data)
+        .map_err(|e| TypstError::TemplateError(format!("Failed to serialize data: {}", e)))
 }
 
-/// Escape special characters for Typst strings
+/// Export a compiled document to PDF bytes
 ///
-/// Typst has special meaning for certain characters:
-/// - `#` starts a Typst expression
-/// - `$` starts math mode
-/// - `@` creates a reference
-/// - `[` and `]` delimit content blocks
-/// - `\` is the escape character
-///
-fn escape_typst_string(s: &str) -> String {
-    s.replace('\\', "\\\\")
-        .replace('#', "\\#")
-        .replace('$', "\\$")
-        .replace('@', "\\@")
-        .replace('[', "\\[")
-        .replace(']', "\\]")
+/// Adds the payload's document metadata (see [`metadata`]). The creation
+/// date and `/ID` come from the render clock and document ID (see
+/// [`RenderOptions::clock`]), so pinned renders are byte-for-byte identical.
+/// `options.pdf_standard` selects PDF/A-2b conformance; `options.reader_hints`
+/// adds the reader-facing hints from [`accessibility`].
+pub(crate) fn export_pdf(
+    document: &typst::model::Document,
+    payload: &GenerationPayload,
+    options: &RenderOptions,
+) -> Result<Vec<u8>, TypstError> {
+    let standard = match options.pdf_standard {
+        PdfStandard::Pdf17 => typst_pdf::PdfStandard::V_1_7,
+        PdfStandard::PdfA2b => typst_pdf::PdfStandard::A_2b,
+    };
+    let generation = payload.metadata.as_ref();
+    let pdf_options = typst_pdf::PdfOptions {
+        ident: options
+            .document_id(generation)
+            .map_or(Smart::Auto, Smart::Custom),
+        timestamp: options.clock(generation).and_then(metadata::creation_date),
+        standards: typst_pdf::PdfStandards::new(&[standard])
+            .map_err(|e| TypstError::ExportError(e.to_string()))?,
+        ..typst_pdf::PdfOptions::default()
+    };
+    let pdf = typst_pdf::pdf(document, &pdf_options).map_err(|errors| {
+        let messages: Vec<_> = errors.iter().map(|e| e.message.to_string()).collect();
+        TypstError::ExportError(messages.join("; "))
+    })?;
+
+    let mut edit = pdf_edit::PdfEdit::parse(&pdf).map_err(TypstError::ExportError)?;
+    metadata::write_metadata(&mut edit, payload).map_err(TypstError::ExportError)?;
+    if options.reader_hints {
+        accessibility::add_reader_hints(&mut edit, &options.language)
+            .map_err(TypstError::ExportError)?;
+    }
+    Ok(edit.finish())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use shared_types::{PersonalInfo, RoleProfile, ScoringWeights};
+    use shared_types::{
+        GenerationMetadata, PaperSize, PersonalInfo, ResumeSection, RoleProfile, ScoringWeights,
+    };
     use std::collections::HashMap;
 
     fn create_minimal_payload() -> GenerationPayload {
@@ -568,6 +425,7 @@ mod tests {
                     tag_relevance: 0.6,
                     priority: 0.4,
                 },
+                summary_id: None,
             },
             education: None,
             skills: None,
@@ -582,7 +440,7 @@ mod tests {
     #[test]
     fn test_render_resume_minimal() {
         let payload = create_minimal_payload();
-        let result = render_resume(&payload, false);
+        let result = render_resume(&payload, &RenderOptions::default());
 
         if let Err(e) = &result {
             eprintln!("Error: {:?}", e);
@@ -599,7 +457,11 @@ mod tests {
     #[test]
     fn test_render_resume_with_dev_mode() {
         let payload = create_minimal_payload();
-        let result = render_resume(&payload, true);
+        let options = RenderOptions {
+            debug: true,
+            ..RenderOptions::default()
+        };
+        let result = render_resume(&payload, &options);
 
         if let Err(e) = &result {
             eprintln!("Error: {:?}", e);
@@ -610,4 +472,377 @@ mod tests {
         assert!(!pdf_bytes.is_empty());
         assert_eq!(&pdf_bytes[0..4], b"%PDF");
     }
+
+    #[test]
+    fn test_markup_characters_need_no_escaping() {
+        let mut payload = create_minimal_payload();
+        payload.personal.name = "Ada #Lovelace $ [x] @ref \\ end".to_string();
+        payload.summary = Some("Cut costs by 40% #perf $$ [a] @b _c_ *d*".to_string());
+
+        let result = render_resume(&payload, &RenderOptions::default());
+        assert!(
+            result.is_ok(),
+            "Markup characters broke compilation: {:?}",
+            result.err()
+        );
+    }
+
+    #[test]
+    fn test_render_resume_with_custom_template() {
+        let payload = create_minimal_payload();
+        let template = r#"#let data = json("data.json")
+= #data.personal.name
+#data.summary"#;
+
+        let pdf_bytes =
+            render_resume_with_template(&payload, template, &RenderOptions::default()).unwrap();
+        assert_eq!(&pdf_bytes[0..4], b"%PDF");
+
+        let broken =
+            render_resume_with_template(&payload, "#data.personal.name", &RenderOptions::default());
+        let Err(TypstError::CompilationError(Diagnostics(errors))) = broken else {
+            panic!("expected a compilation error, got {:?}", broken.map(|_| ()));
+        };
+        assert_eq!(errors[0].severity, Severity::Error);
+        assert_eq!(errors[0].message, "unknown variable: data");
+        let span = errors[0].span.as_ref().unwrap();
+        assert_eq!((span.line, span.column), (1, 2));
+        assert_eq!(span.snippet, "data");
+    }
+
+    #[test]
+    fn test_every_builtin_template_renders() {
+        let mut payload = create_minimal_payload();
+        payload.meta_footer = Some("Built with {{name}}".to_string());
+
+        for template in BuiltinTemplate::ALL {
+            let options = RenderOptions {
+                template: template.name().to_string(),
+                debug: true,
+                ..RenderOptions::default()
+            };
+            let document = compile_resume(&payload, &options, Spacing::Normal).unwrap();
+            assert!(
+                fit::content_pages(&document) < document.pages.len(),
+                "{} should mark the end of the resume proper",
+                template.name()
+            );
+
+            let options = RenderOptions {
+                debug: false,
+                ..options
+            };
+            let pdf_bytes = render_resume(&payload, &options).unwrap();
+            assert_eq!(&pdf_bytes[0..4], b"%PDF", "{}", template.name());
+        }
+    }
+
+    #[test]
+    fn test_unknown_template_name_is_rejected() {
+        let payload = create_minimal_payload();
+        let options = RenderOptions {
+            template: "fancy".to_string(),
+            ..RenderOptions::default()
+        };
+
+        let result = render_resume(&payload, &options);
+        assert!(matches!(result, Err(TypstError::TemplateError(_))));
+    }
+
+    #[test]
+    fn test_invalid_render_options_are_rejected() {
+        let payload = create_minimal_payload();
+        let options = RenderOptions {
+            font_size: Some(2.0),
+            ..RenderOptions::default()
+        };
+
+        let result = render_resume(&payload, &options);
+        assert!(matches!(result, Err(TypstError::TemplateError(_))));
+    }
+
+    #[test]
+    fn test_paper_size_applies_to_every_template() {
+        let payload = create_minimal_payload();
+
+        for template in BuiltinTemplate::ALL {
+            for (paper, height_pt) in [
+                (PaperSize::Letter, 792.0),
+                (PaperSize::A4, 841.89),
+                (PaperSize::Legal, 1008.0),
+            ] {
+                let options = RenderOptions {
+                    template: template.name().to_string(),
+                    paper,
+                    ..RenderOptions::default()
+                };
+                let document = compile_resume(&payload, &options, Spacing::Normal).unwrap();
+                let height = document.pages[0].frame.height().to_pt();
+                assert!(
+                    (height - height_pt).abs() < 0.5,
+                    "{} on {:?}: {}pt",
+                    template.name(),
+                    paper,
+                    height
+                );
+            }
+        }
+    }
+
+    fn frame_text(frame: &typst::layout::Frame, out: &mut String) {
+        for (_, item) in frame.items() {
+            match item {
+                typst::layout::FrameItem::Text(text) => {
+                    out.push_str(&text.text);
+                    out.push(' ');
+                }
+                typst::layout::FrameItem::Group(group) => frame_text(&group.frame, out),
+                _ => {}
+            }
+        }
+    }
+
+    fn document_text(document: &typst::model::Document) -> String {
+        let mut out = String::new();
+        for page in &document.pages {
+            frame_text(&page.frame, &mut out);
+        }
+        out
+    }
+
+    #[test]
+    fn test_sections_are_ordered_and_hidden() {
+        let mut payload = create_minimal_payload();
+        payload.skills = Some(HashMap::from([(
+            "languages".to_string(),
+            vec!["Rust".to_string()],
+        )]));
+
+        for template in BuiltinTemplate::ALL {
+            let options = RenderOptions {
+                template: template.name().to_string(),
+                sections: Some(vec![ResumeSection::Skills]),
+                ..RenderOptions::default()
+            };
+            let document = compile_resume(&payload, &options, Spacing::Normal).unwrap();
+            let text = document_text(&document);
+
+            assert!(text.contains("Rust"), "{}: {}", template.name(), text);
+            assert!(
+                !text.contains("Test summary"),
+                "{}: {}",
+                template.name(),
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_builtin_templates_validate_as_bundles() {
+        // The built-ins only read fields the published schema documents
+        for template in BuiltinTemplate::ALL {
+            let bundle = TemplateBundle::new("resume.typ", template.source()).unwrap();
+            bundle
+                .validate()
+                .unwrap_or_else(|e| panic!("{}: {}", template.name(), e));
+        }
+    }
+
+    #[test]
+    fn test_render_resume_with_bundle() {
+        let payload = create_minimal_payload();
+        let bundle = TemplateBundle::from_files([
+            (
+                "template.json",
+                br#"{"name": "Acme", "main": "acme/main.typ", "dataVersion": 1}"#.to_vec(),
+            ),
+            (
+                "acme/main.typ",
+                br#"#import "parts/header.typ": header
+#let data = json("data.json")
+#header(data.personal.name)
+#data.summary"#
+                    .to_vec(),
+            ),
+            (
+                "acme/parts/header.typ",
+                b"#let header(name) = align(center, text(size: 18pt, name))".to_vec(),
+            ),
+        ])
+        .unwrap();
+
+        let pdf_bytes =
+            render_resume_with_bundle(&payload, &bundle, &RenderOptions::default()).unwrap();
+        assert_eq!(&pdf_bytes[0..4], b"%PDF");
+
+        let invalid = TemplateBundle::new(
+            "main.typ",
+            "#let data = json(\"data.json\")\n#data.personal.fullname",
+        )
+        .unwrap();
+        let err = render_resume_with_bundle(&payload, &invalid, &RenderOptions::default());
+        assert!(
+            matches!(&err, Err(TypstError::TemplateError(msg)) if msg.contains("fullname")),
+            "{:?}",
+            err
+        );
+    }
+
+    fn document_families(document: &typst::model::Document) -> Vec<String> {
+        fn walk(frame: &typst::layout::Frame, out: &mut Vec<String>) {
+            for (_, item) in frame.items() {
+                match item {
+                    typst::layout::FrameItem::Group(group) => walk(&group.frame, out),
+                    typst::layout::FrameItem::Text(text) => {
+                        out.push(text.font.info().family.clone())
+                    }
+                    _ => {}
+                }
+            }
+        }
+        let mut out = Vec::new();
+        for page in &document.pages {
+            walk(&page.frame, &mut out);
+        }
+        out.sort();
+        out.dedup();
+        out
+    }
+
+    #[test]
+    fn test_font_set_switches_family() {
+        let payload = create_minimal_payload();
+        for (set, family) in [
+            (shared_types::FontSet::Serif, "Liberation Serif"),
+            (shared_types::FontSet::Sans, "DejaVu Sans"),
+        ] {
+            for template in BuiltinTemplate::ALL {
+                let options = RenderOptions {
+                    template: template.name().to_string(),
+                    font_set: set,
+                    ..RenderOptions::default()
+                };
+                let document = compile_resume(&payload, &options, Spacing::Normal).unwrap();
+                assert_eq!(
+                    document_families(&document),
+                    [family],
+                    "{}",
+                    template.name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_resume_with_warnings() {
+        let mut payload = create_minimal_payload();
+        payload.summary = Some("Ships \u{2603} snowmen".to_string());
+
+        let serif = render_resume_with_warnings(&payload, &RenderOptions::default()).unwrap();
+        assert_eq!(&serif.pdf[0..4], b"%PDF");
+        let snowman = serif
+            .warnings
+            .iter()
+            .find(|w| w.message.contains("U+2603"))
+            .unwrap_or_else(|| panic!("{:?}", serif.warnings));
+        assert_eq!(snowman.severity, Severity::Warning);
+        assert_eq!(snowman.fields, ["summary"]);
+        assert_eq!(snowman.span.as_ref().unwrap().file, "resume.typ");
+
+        // Custom fonts take over the family; the sans faces cover the snowman
+        let fonts = FontCollection::custom(
+            fonts::embedded_faces(shared_types::FontSet::Sans)
+                .iter()
+                .map(|face| face.to_vec()),
+        )
+        .unwrap();
+        let custom = render_resume_with_fonts(&payload, &RenderOptions::default(), &fonts).unwrap();
+        assert!(
+            !custom.warnings.iter().any(|w| w.message.contains("U+2603")),
+            "{:?}",
+            custom.warnings
+        );
+    }
+
+    #[test]
+    fn test_pdf_metadata_round_trip() {
+        let mut payload = create_minimal_payload();
+        payload.role_profile.description = Some("Builds things".to_string());
+        payload.role_profile.tag_weights =
+            HashMap::from([("rust".to_string(), 1.0), ("wasm".to_string(), 0.5)]);
+        payload.metadata = Some(GenerationMetadata {
+            generation_id: "gen-42".to_string(),
+            timestamp: 1_700_000_000,
+            selected_bullet_ids: vec!["b1".to_string(), "b2".to_string()],
+            role_profile_id: "test-role".to_string(),
+            source_profiles: None,
+            selection_overrides: None,
+            render_options: None,
+        });
+
+        for pdf_standard in [PdfStandard::Pdf17, PdfStandard::PdfA2b] {
+            let options = RenderOptions {
+                pdf_standard,
+                ..RenderOptions::default()
+            };
+            let pdf_bytes = render_resume(&payload, &options).unwrap();
+            let metadata = read_pdf_metadata(&pdf_bytes).unwrap();
+
+            assert_eq!(metadata.title.as_deref(), Some("Resume - Test Person"));
+            assert_eq!(metadata.author.as_deref(), Some("Test Person"));
+            assert_eq!(
+                metadata.subject.as_deref(),
+                Some("Test Role: Builds things")
+            );
+            assert_eq!(metadata.keywords, ["rust", "wasm"]);
+            assert_eq!(metadata.created, Some(1_700_000_000));
+            assert_eq!(metadata.generation_id.as_deref(), Some("gen-42"));
+            assert_eq!(metadata.bullet_ids, ["b1", "b2"]);
+
+            // The subject is mirrored into XMP
+            let pdf = String::from_utf8_lossy(&pdf_bytes);
+            assert!(pdf.contains("<dc:description><rdf:Alt><rdf:li xml:lang=\"x-default\">Test Role: Builds things</rdf:li>"));
+        }
+
+        // Without generation metadata there is no date or generation entry
+        let pdf_bytes =
+            render_resume(&create_minimal_payload(), &RenderOptions::default()).unwrap();
+        let metadata = read_pdf_metadata(&pdf_bytes).unwrap();
+        assert_eq!(metadata.subject.as_deref(), Some("Test Role"));
+        assert!(metadata.keywords.is_empty());
+        assert_eq!(metadata.created, None);
+        assert_eq!(metadata.generation_id, None);
+    }
+
+    #[test]
+    fn test_render_resume_preview_matches_pdf_pages() {
+        let payload = create_minimal_payload();
+        let options = RenderOptions {
+            paper: PaperSize::A4,
+            debug: true,
+            ..RenderOptions::default()
+        };
+        let pages = render_resume_preview(&payload, &options, PreviewFormat::Svg).unwrap();
+        let document = compile_resume(&payload, &options, Spacing::Normal).unwrap();
+
+        assert_eq!(pages.len(), document.pages.len());
+        assert!(pages.len() > 1, "debug page should be previewed too");
+        assert!((pages[0].width - 595.28).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_render_resume_with_preview_matches_separate_renders() {
+        let payload = create_minimal_payload();
+        let options = RenderOptions {
+            debug: true,
+            ..RenderOptions::default()
+        };
+        let output = render_resume_with_preview(&payload, &options, PreviewFormat::Svg).unwrap();
+        let pdf = render_resume(&payload, &options).unwrap();
+        let pages = render_resume_preview(&payload, &options, PreviewFormat::Svg).unwrap();
+
+        assert!(output.pdf.starts_with(b"%PDF"));
+        assert_eq!(output.pdf.len(), pdf.len());
+        assert_eq!(output.pages.len(), pages.len());
+    }
 }
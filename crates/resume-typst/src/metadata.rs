@@ -0,0 +1,270 @@
+//! PDF document metadata
+//!
+//! Every exported resume carries metadata that traces it back to the
+//! generation that produced it:
+//!
+//! - title and author, from the template's `#set document`
+//! - keywords, the role profile's highest-weighted tags (the built-in
+//!   templates set them from `role_profile.tags`)
+//! - subject, the role profile's name and description
+//! - creation date, the render clock (by default the generation timestamp)
+//!   rather than the wall clock
+//! - `/GenerationId` and `/SelectedBulletIds`, custom information
+//!   dictionary entries
+//!
+//! Typst has no setting for the subject or custom entries, so they are
+//! added by an incremental update (see [`crate::pdf_edit`]); the subject is
+//! mirrored into the XMP `dc:description` to keep PDF/A files valid.
+//! [`read_pdf_metadata`] reads all of it back from a downloaded file.
+
+use crate::pdf_edit::{dict_value, insert_entry, text_string, text_value, PdfEdit};
+use crate::TypstError;
+use chrono::{DateTime, Datelike, NaiveDateTime, Timelike};
+use serde::{Deserialize, Serialize};
+use shared_types::{GenerationPayload, RoleProfile};
+use typst::foundations::Datetime;
+
+/// Number of role profile tags used as keywords
+pub const KEYWORD_TAGS: usize = 8;
+
+/// Custom information dictionary key for the generation ID
+const GENERATION_ID_KEY: &str = "/GenerationId";
+
+/// Custom information dictionary key for the selected bullet IDs
+const BULLET_IDS_KEY: &str = "/SelectedBulletIds";
+
+/// Metadata stored in an exported PDF
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfMetadata {
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub subject: Option<String>,
+    pub keywords: Vec<String>,
+    /// Creation date as a Unix timestamp
+    pub created: Option<i64>,
+    pub generation_id: Option<String>,
+    pub bullet_ids: Vec<String>,
+}
+
+/// The role profile's highest-weighted tags, ties broken alphabetically
+pub fn keywords(role_profile: &RoleProfile) -> Vec<String> {
+    let mut tags: Vec<(&String, f32)> = role_profile
+        .tag_weights
+        .iter()
+        .filter(|(_, &weight)| weight > 0.0)
+        .map(|(tag, &weight)| (tag, weight))
+        .collect();
+    tags.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    tags.into_iter()
+        .take(KEYWORD_TAGS)
+        .map(|(tag, _)| tag.clone())
+        .collect()
+}
+
+/// Document subject: the role profile's name, then its description
+pub fn subject(role_profile: &RoleProfile) -> String {
+    match &role_profile.description {
+        Some(description) if !description.trim().is_empty() => {
+            format!("{}: {}", role_profile.name, description.trim())
+        }
+        _ => role_profile.name.clone(),
+    }
+}
+
+/// Creation date for the PDF from the render clock
+///
+/// Without a pinned clock the PDF has no date rather than one that changes
+/// with every render.
+pub(crate) fn creation_date(timestamp: u64) -> Option<Datetime> {
+    let timestamp = i64::try_from(timestamp).ok()?;
+    let date = DateTime::from_timestamp(timestamp, 0)?;
+    Datetime::from_ymd_hms(
+        date.year(),
+        date.month() as u8,
+        date.day() as u8,
+        date.hour() as u8,
+        date.minute() as u8,
+        date.second() as u8,
+    )
+}
+
+/// Add the subject and generation entries to a PDF written by `typst_pdf`
+pub(crate) fn write_metadata(
+    edit: &mut PdfEdit,
+    payload: &GenerationPayload,
+) -> Result<(), String> {
+    let info_id = edit
+        .info()
+        .ok_or("PDF has no document information dictionary")?;
+    let mut info = edit
+        .dict(info_id)
+        .ok_or_else(|| format!("PDF information dictionary {} 0 obj not found", info_id))?
+        .to_string();
+
+    let subject = subject(&payload.role_profile);
+    insert_entry(&mut info, &format!("/Subject {}", text_string(&subject)));
+    if let Some(metadata) = &payload.metadata {
+        insert_entry(
+            &mut info,
+            &format!(
+                "{} {}",
+                GENERATION_ID_KEY,
+                text_string(&metadata.generation_id)
+            ),
+        );
+        insert_entry(
+            &mut info,
+            &format!(
+                "{} {}",
+                BULLET_IDS_KEY,
+                text_string(&metadata.selected_bullet_ids.join(", "))
+            ),
+        );
+    }
+    edit.set_dict(info_id, info);
+
+    // PDF/A requires the XMP packet to agree with the information dictionary
+    let root = edit.dict(edit.root()).ok_or("PDF catalog not found")?;
+    if let Some(xmp_id) = dict_value(root, "/Metadata").and_then(object_number) {
+        let (dict, xmp) = edit.stream(xmp_id).ok_or("PDF XMP metadata not found")?;
+        let xmp = String::from_utf8_lossy(xmp);
+        if let Some(at) = xmp.find("</dc:title>").map(|i| i + "</dc:title>".len()) {
+            let description = format!(
+                "<dc:description><rdf:Alt><rdf:li xml:lang=\"x-default\">{}</rdf:li></rdf:Alt></dc:description>",
+                xml_escape(&subject)
+            );
+            let xmp = format!("{}{}{}", &xmp[..at], description, &xmp[at..]);
+            let dict = dict.to_string();
+            edit.set_stream(xmp_id, &dict, xmp.as_bytes());
+        }
+    }
+    Ok(())
+}
+
+/// Read the metadata of a PDF produced by this crate
+///
+/// Entries the PDF doesn't have are `None` or empty, so any PDF with a
+/// readable information dictionary is accepted.
+///
+/// # Example
+/// ```no_run
+/// use resume_typst::metadata::read_pdf_metadata;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let metadata = read_pdf_metadata(&std::fs::read("resume.pdf")?)?;
+/// println!("generation {:?}", metadata.generation_id);
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_pdf_metadata(pdf: &[u8]) -> Result<PdfMetadata, TypstError> {
+    let edit = PdfEdit::parse(pdf).map_err(TypstError::ExportError)?;
+    let Some(info) = edit.info().and_then(|id| edit.dict(id)) else {
+        return Ok(PdfMetadata::default());
+    };
+
+    let list = |key| {
+        text_value(info, key)
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    Ok(PdfMetadata {
+        title: text_value(info, "/Title"),
+        author: text_value(info, "/Author"),
+        subject: text_value(info, "/Subject"),
+        keywords: list("/Keywords"),
+        created: text_value(info, "/CreationDate").and_then(|date| parse_pdf_date(&date)),
+        generation_id: text_value(info, GENERATION_ID_KEY),
+        bullet_ids: list(BULLET_IDS_KEY),
+    })
+}
+
+/// `D:20231114221320Z` (offsets and trailing fields optional) → Unix timestamp
+fn parse_pdf_date(date: &str) -> Option<i64> {
+    let digits: String = date
+        .strip_prefix("D:")
+        .unwrap_or(date)
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    if digits.len() < 4 {
+        return None;
+    }
+    // Pad missing month/day with 01 and missing time with 00
+    let padded = format!("{}{}", digits, &"0101000000"[(digits.len() - 4).min(10)..]);
+    NaiveDateTime::parse_from_str(&padded[..14], "%Y%m%d%H%M%S")
+        .ok()
+        .map(|date| date.and_utc().timestamp())
+}
+
+/// `12 0 R` → 12
+fn object_number(reference: &str) -> Option<usize> {
+    reference.split(' ').next()?.parse().ok()
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared_types::ScoringWeights;
+    use std::collections::HashMap;
+
+    fn role_profile() -> RoleProfile {
+        RoleProfile {
+            id: "staff".to_string(),
+            name: "Staff Engineer".to_string(),
+            description: Some("Technical leadership".to_string()),
+            tag_weights: HashMap::from([
+                ("rust".to_string(), 1.0),
+                ("leadership".to_string(), 0.9),
+                ("distributed-systems".to_string(), 0.9),
+                ("php".to_string(), 0.0),
+            ]),
+            scoring_weights: ScoringWeights {
+                tag_relevance: 0.6,
+                priority: 0.4,
+            },
+            summary_id: None,
+        }
+    }
+
+    #[test]
+    fn test_keywords_and_subject() {
+        let profile = role_profile();
+        assert_eq!(
+            keywords(&profile),
+            ["rust", "distributed-systems", "leadership"]
+        );
+        assert_eq!(subject(&profile), "Staff Engineer: Technical leadership");
+
+        let unnamed = RoleProfile {
+            description: None,
+            ..profile
+        };
+        assert_eq!(subject(&unnamed), "Staff Engineer");
+    }
+
+    #[test]
+    fn test_parse_pdf_date() {
+        assert_eq!(parse_pdf_date("D:20231114221320Z"), Some(1_700_000_000));
+        assert_eq!(
+            parse_pdf_date("D:20231114221320+00'00"),
+            Some(1_700_000_000)
+        );
+        assert_eq!(parse_pdf_date("D:2023"), Some(1_672_531_200));
+        assert_eq!(parse_pdf_date("yesterday"), None);
+    }
+}
@@ -0,0 +1,392 @@
+//! Incremental updates to PDFs written by `typst_pdf`
+//!
+//! `typst_pdf` writes uncompressed object dictionaries and classic xref
+//! tables, so small post-export changes (metadata Typst has no setting for,
+//! accessibility extras) can be made by appending new versions of the
+//! changed objects plus a new xref section. The original bytes stay intact.
+
+use std::collections::BTreeMap;
+
+/// A PDF being amended with an incremental update
+pub(crate) struct PdfEdit<'a> {
+    pdf: &'a [u8],
+    trailer: Trailer,
+    /// Latest byte offset of every in-use object, following `/Prev`
+    offsets: BTreeMap<usize, usize>,
+    /// New object contents (between `obj` and `endobj`)
+    updated: BTreeMap<usize, Vec<u8>>,
+}
+
+/// Entries of the newest trailer that an update must carry over
+struct Trailer {
+    size: usize,
+    root: usize,
+    info: Option<usize>,
+    /// Raw `/ID` value, copied verbatim
+    id: Option<String>,
+    startxref: usize,
+}
+
+impl<'a> PdfEdit<'a> {
+    /// Read the xref sections and trailer of `pdf`
+    ///
+    /// Fails on files without classic xref tables, which `typst_pdf` always
+    /// writes.
+    pub(crate) fn parse(pdf: &'a [u8]) -> Result<Self, String> {
+        let startxref = rfind(pdf, b"startxref")
+            .and_then(|at| std::str::from_utf8(&pdf[at + "startxref".len()..]).ok())
+            .and_then(|rest| rest.split_whitespace().next())
+            .and_then(|n| n.parse().ok())
+            .ok_or("PDF has no startxref")?;
+
+        let mut offsets = BTreeMap::new();
+        let mut newest = None;
+        let mut sections = Vec::new();
+        let mut next = Some(startxref);
+        while let Some(at) = next {
+            if sections.contains(&at) {
+                return Err("PDF xref sections form a loop".to_string());
+            }
+            sections.push(at);
+            let (section, trailer) = xref_section(pdf, at)?;
+            for (id, offset) in section {
+                offsets.entry(id).or_insert(offset);
+            }
+            next = dict_value(&trailer, "/Prev").and_then(|n| n.parse().ok());
+            newest.get_or_insert(trailer);
+        }
+        let trailer = newest.unwrap_or_default();
+
+        let reference = |key| {
+            dict_value(&trailer, key)
+                .and_then(|r| r.split(' ').next())
+                .and_then(|n| n.parse().ok())
+        };
+        let trailer = Trailer {
+            size: dict_value(&trailer, "/Size")
+                .and_then(|n| n.parse().ok())
+                .ok_or("PDF trailer has no /Size")?,
+            root: reference("/Root").ok_or("PDF trailer has no /Root")?,
+            info: reference("/Info"),
+            id: dict_value(&trailer, "/ID").map(str::to_string),
+            startxref,
+        };
+
+        Ok(Self {
+            pdf,
+            trailer,
+            offsets,
+            updated: BTreeMap::new(),
+        })
+    }
+
+    /// Object number of the document catalog
+    pub(crate) fn root(&self) -> usize {
+        self.trailer.root
+    }
+
+    /// Object number of the document information dictionary
+    pub(crate) fn info(&self) -> Option<usize> {
+        self.trailer.info
+    }
+
+    /// Numbers of all in-use objects
+    pub(crate) fn ids(&self) -> impl Iterator<Item = usize> + '_ {
+        self.offsets.keys().copied()
+    }
+
+    /// Current dictionary of a non-stream object, including changes made
+    /// with [`PdfEdit::set_dict`]
+    pub(crate) fn dict(&self, id: usize) -> Option<&str> {
+        let body = std::str::from_utf8(self.contents(id)?).ok()?.trim();
+        (body.starts_with("<<") && !body.contains("\nstream")).then_some(body)
+    }
+
+    /// Current dictionary text and data of a stream object
+    pub(crate) fn stream(&self, id: usize) -> Option<(&str, &[u8])> {
+        let contents = self.contents(id)?;
+        let start = find(contents, b"\nstream\n")?;
+        let dict = std::str::from_utf8(&contents[..start]).ok()?.trim();
+        let length: usize = dict_value(dict, "/Length")?.parse().ok()?;
+        let data = contents.get(start + "\nstream\n".len()..)?.get(..length)?;
+        Some((dict, data))
+    }
+
+    /// Replace an object with a new dictionary
+    pub(crate) fn set_dict(&mut self, id: usize, dict: String) {
+        self.updated.insert(id, dict.into_bytes());
+    }
+
+    /// Replace a stream's data; `dict` entries other than `/Length` are kept
+    pub(crate) fn set_stream(&mut self, id: usize, dict: &str, data: &[u8]) {
+        let mut lines: Vec<&str> = dict
+            .lines()
+            .filter(|line| !line.trim().starts_with("/Length "))
+            .collect();
+        let length = format!("  /Length {}", data.len());
+        lines.insert(1, &length);
+
+        let mut contents = lines.join("\n").into_bytes();
+        contents.extend_from_slice(b"\nstream\n");
+        contents.extend_from_slice(data);
+        contents.extend_from_slice(b"\nendstream");
+        self.updated.insert(id, contents);
+    }
+
+    /// The PDF with all changes appended, or unchanged if there are none
+    pub(crate) fn finish(self) -> Vec<u8> {
+        let mut out = self.pdf.to_vec();
+        if self.updated.is_empty() {
+            return out;
+        }
+        if !out.ends_with(b"\n") {
+            out.push(b'\n');
+        }
+
+        let mut offsets = Vec::with_capacity(self.updated.len());
+        for (id, contents) in &self.updated {
+            offsets.push((id, out.len()));
+            out.extend_from_slice(format!("{} 0 obj\n", id).as_bytes());
+            out.extend_from_slice(contents);
+            out.extend_from_slice(b"\nendobj\n\n");
+        }
+
+        let trailer = &self.trailer;
+        let xref = out.len();
+        let mut section = String::from("xref\n");
+        for (id, offset) in offsets {
+            section.push_str(&format!("{} 1\n{:010} 00000 n \n", id, offset));
+        }
+        section.push_str(&format!(
+            "trailer\n<<\n  /Size {}\n  /Root {} 0 R\n",
+            trailer.size, trailer.root
+        ));
+        if let Some(info) = trailer.info {
+            section.push_str(&format!("  /Info {} 0 R\n", info));
+        }
+        if let Some(id) = &trailer.id {
+            section.push_str(&format!("  /ID {}\n", id));
+        }
+        section.push_str(&format!(
+            "  /Prev {}\n>>\nstartxref\n{}\n%%EOF",
+            trailer.startxref, xref
+        ));
+        out.extend_from_slice(section.as_bytes());
+        out
+    }
+
+    /// Bytes between `obj` and `endobj`
+    fn contents(&self, id: usize) -> Option<&[u8]> {
+        if let Some(contents) = self.updated.get(&id) {
+            return Some(contents);
+        }
+        let rest = self.pdf.get(*self.offsets.get(&id)?..)?;
+        let header = format!("{} 0 obj", id);
+        if !rest.starts_with(header.as_bytes()) {
+            return None;
+        }
+        let end = find(rest, b"endobj")?;
+        Some(&rest[header.len()..end])
+    }
+}
+
+/// Object offsets and trailer text of the xref section at `at`
+fn xref_section(pdf: &[u8], at: usize) -> Result<(Vec<(usize, usize)>, String), String> {
+    let table = pdf
+        .get(at..)
+        .filter(|rest| rest.starts_with(b"xref"))
+        .ok_or("PDF has no classic xref table")?;
+    let end = find(table, b"trailer").ok_or("PDF xref table is not terminated")?;
+    let text = std::str::from_utf8(&table[..end]).map_err(|_| "PDF xref table is not text")?;
+
+    let mut offsets = Vec::new();
+    let mut next_id = 0;
+    for line in text.lines().skip(1) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        match fields[..] {
+            [first, _count] => {
+                next_id = first.parse().map_err(|_| "invalid xref subsection")?;
+            }
+            [offset, _generation, kind] => {
+                if kind == "n" {
+                    let offset = offset.parse().map_err(|_| "invalid xref entry")?;
+                    offsets.push((next_id, offset));
+                }
+                next_id += 1;
+            }
+            _ => {}
+        }
+    }
+
+    let trailer = &table[end..];
+    let trailer_end = find(trailer, b"startxref").unwrap_or(trailer.len());
+    let trailer = String::from_utf8_lossy(&trailer[..trailer_end]).into_owned();
+    Ok((offsets, trailer))
+}
+
+/// Raw value of a one-line dictionary entry, e.g. `12 0 R` for `/Info`
+pub(crate) fn dict_value<'d>(dict: &'d str, key: &str) -> Option<&'d str> {
+    dict.lines()
+        .filter_map(|line| line.trim().strip_prefix(key))
+        .find(|rest| rest.starts_with(' '))
+        .map(str::trim)
+}
+
+/// Add an entry before the closing `>>` of a dictionary
+pub(crate) fn insert_entry(dict: &mut String, entry: &str) {
+    if let Some(end) = dict.rfind(">>") {
+        dict.insert_str(end, &format!("  {}\n", entry));
+    }
+}
+
+/// Decode the string value of `key`, written either as a literal `(...)`
+/// or a hex `<...>` string, as a text string
+pub(crate) fn text_value(dict: &str, key: &str) -> Option<String> {
+    let start = dict.find(&format!("{} ", key))? + key.len() + 1;
+    let value = dict[start..].trim_start();
+    let bytes = if let Some(hex) = value.strip_prefix('<') {
+        let hex: String = hex[..hex.find('>')?]
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(hex.get(i..i + 2).unwrap_or("0"), 16).ok())
+            .collect::<Option<Vec<u8>>>()?
+    } else {
+        literal_bytes(value.strip_prefix('(')?)?
+    };
+
+    match bytes.strip_prefix(&[0xFE, 0xFF]) {
+        Some(utf16) => {
+            let units: Vec<u16> = utf16
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            Some(String::from_utf16_lossy(&units))
+        }
+        None => Some(bytes.iter().map(|&b| char::from(b)).collect()),
+    }
+}
+
+/// Unescape a literal string, starting after its opening parenthesis
+fn literal_bytes(value: &str) -> Option<Vec<u8>> {
+    let mut bytes = value.bytes();
+    let mut out = Vec::new();
+    let mut depth = 0;
+    while let Some(b) = bytes.next() {
+        match b {
+            b'\\' => match bytes.next()? {
+                b'n' => out.push(b'\n'),
+                b'r' => out.push(b'\r'),
+                b't' => out.push(b'\t'),
+                d @ b'0'..=b'7' => {
+                    let mut value = u32::from(d - b'0');
+                    let mut lookahead = bytes.clone();
+                    for _ in 0..2 {
+                        match lookahead.next() {
+                            Some(d @ b'0'..=b'7') => {
+                                value = value * 8 + u32::from(d - b'0');
+                                bytes.next();
+                            }
+                            _ => break,
+                        }
+                    }
+                    out.push(value as u8);
+                }
+                other => out.push(other),
+            },
+            b'(' => {
+                depth += 1;
+                out.push(b);
+            }
+            b')' if depth == 0 => return Some(out),
+            b')' => {
+                depth -= 1;
+                out.push(b);
+            }
+            _ => out.push(b),
+        }
+    }
+    None
+}
+
+/// Encode text as a UTF-16BE hex string, valid for any characters
+pub(crate) fn text_string(text: &str) -> String {
+    let mut hex = String::from("<FEFF");
+    for unit in text.encode_utf16() {
+        hex.push_str(&format!("{:04X}", unit));
+    }
+    hex.push('>');
+    hex
+}
+
+pub(crate) fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).rposition(|w| w == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A two-object PDF with a catalog and a link annotation
+    fn sample_pdf() -> String {
+        let objects = concat!(
+            "%PDF-1.7\n\n",
+            "1 0 obj\n<<\n  /Type /Catalog\n  /Lang (en)\n>>\nendobj\n\n",
+            "2 0 obj\n<<\n  /Type /Annot\n  /Subtype /Link\n  /A << /URI (mailto:a@b.c) >>\n>>\nendobj\n\n",
+            "3 0 obj\n<<\n  /Length 5\n  /Type /Metadata\n>>\nstream\nhello\nendstream\nendobj\n\n",
+        );
+        format!(
+            "{}xref\n0 4\n0000000000 65535 f \n{:010} 00000 n \n{:010} 00000 n \n{:010} 00000 n \ntrailer\n<<\n  /Size 4\n  /Root 1 0 R\n  /ID [(a) (b)]\n>>\nstartxref\n{}\n%%EOF",
+            objects,
+            objects.find("1 0 obj").unwrap(),
+            objects.find("2 0 obj").unwrap(),
+            objects.find("3 0 obj").unwrap(),
+            objects.len()
+        )
+    }
+
+    #[test]
+    fn test_strings() {
+        assert_eq!(
+            text_value("<< /URI (https://a.com/\\(x\\)\\101) >>", "/URI").as_deref(),
+            Some("https://a.com/(x)A")
+        );
+        assert_eq!(text_string("Hi é"), "<FEFF00480069002000E9>");
+        assert_eq!(
+            text_value("/Subject <FEFF00480069002000E9>", "/Subject").as_deref(),
+            Some("Hi é")
+        );
+    }
+
+    #[test]
+    fn test_update_keeps_original_bytes_and_chains_xref() {
+        let pdf = sample_pdf();
+        let mut edit = PdfEdit::parse(pdf.as_bytes()).unwrap();
+        assert_eq!(edit.root(), 1);
+        assert_eq!(edit.ids().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(edit.stream(3).unwrap().1, b"hello");
+
+        let mut catalog = edit.dict(1).unwrap().to_string();
+        insert_entry(&mut catalog, "/PageMode /UseOutlines");
+        edit.set_dict(1, catalog);
+        let (dict, _) = edit.stream(3).unwrap();
+        let dict = dict.to_string();
+        edit.set_stream(3, &dict, b"hello, world");
+        let updated = edit.finish();
+        assert!(updated.starts_with(pdf.as_bytes()));
+
+        // A second edit sees the first one's objects through /Prev
+        let edit = PdfEdit::parse(&updated).unwrap();
+        assert!(edit.dict(1).unwrap().contains("/PageMode /UseOutlines"));
+        assert!(edit.dict(2).unwrap().contains("/Subtype /Link"));
+        assert_eq!(edit.stream(3).unwrap().1, b"hello, world");
+        assert_eq!(edit.trailer.id.as_deref(), Some("[(a) (b)]"));
+        assert_eq!(edit.trailer.size, 4);
+    }
+}
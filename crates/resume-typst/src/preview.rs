@@ -0,0 +1,165 @@
+//! Page previews
+//!
+//! PNG or SVG images of each page of a compiled [`Document`], for template
+//! thumbnails and showing a resume before it is downloaded. They are drawn
+//! from the same document the PDF is exported from, so they match it page
+//! for page.
+
+use crate::TypstError;
+use serde::{Deserialize, Serialize};
+use typst::model::Document;
+
+/// Resolution used when none is given
+pub const DEFAULT_PREVIEW_DPI: f32 = 96.0;
+
+/// Accepted preview resolution range in dots per inch
+pub const PREVIEW_DPI_RANGE: (f32, f32) = (12.0, 600.0);
+
+/// Image format for page previews
+///
+/// Serialized as `{ "format": "png", "dpi": 144 }` or `{ "format": "svg" }`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "format", rename_all = "lowercase")]
+pub enum PreviewFormat {
+    /// Raster image at `dpi` dots per inch
+    Png {
+        #[serde(default = "default_dpi")]
+        dpi: f32,
+    },
+    /// Vector image; text is drawn as paths, so it needs no fonts to display
+    Svg,
+}
+
+fn default_dpi() -> f32 {
+    DEFAULT_PREVIEW_DPI
+}
+
+impl Default for PreviewFormat {
+    fn default() -> Self {
+        Self::Png {
+            dpi: DEFAULT_PREVIEW_DPI,
+        }
+    }
+}
+
+impl PreviewFormat {
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Png { .. } => "image/png",
+            Self::Svg => "image/svg+xml",
+        }
+    }
+}
+
+/// One rendered page
+#[derive(Debug, Clone, PartialEq)]
+pub struct PagePreview {
+    /// Page size in points
+    pub width: f64,
+    pub height: f64,
+    /// PNG bytes or SVG markup
+    pub data: Vec<u8>,
+}
+
+/// Render every page of a compiled document
+///
+/// # Example
+/// ```no_run
+/// use resume_typst::compiler::ResumeWorld;
+/// use resume_typst::preview::{render_preview, PreviewFormat};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let world = ResumeWorld::new("= Jane Doe".to_string())?;
+/// let document = world.compile()?;
+/// let pages = render_preview(&document, PreviewFormat::Png { dpi: 144.0 })?;
+/// std::fs::write("page-1.png", &pages[0].data)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn render_preview(
+    document: &Document,
+    format: PreviewFormat,
+) -> Result<Vec<PagePreview>, TypstError> {
+    if let PreviewFormat::Png { dpi } = format {
+        let (min, max) = PREVIEW_DPI_RANGE;
+        if !dpi.is_finite() || !(min..=max).contains(&dpi) {
+            return Err(TypstError::ExportError(format!(
+                "Preview DPI must be between {} and {}, got {}",
+                min, max, dpi
+            )));
+        }
+    }
+
+    document
+        .pages
+        .iter()
+        .map(|page| {
+            let size = page.frame.size();
+            let data = match format {
+                PreviewFormat::Png { dpi } => typst_render::render(page, dpi / 72.0)
+                    .encode_png()
+                    .map_err(|e| {
+                    TypstError::ExportError(format!("PNG encoding failed: {}", e))
+                })?,
+                PreviewFormat::Svg => typst_svg::svg(page).into_bytes(),
+            };
+            Ok(PagePreview {
+                width: size.x.to_pt(),
+                height: size.y.to_pt(),
+                data,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::ResumeWorld;
+
+    fn document() -> Document {
+        let source = "#set page(paper: \"us-letter\")\n= Jane Doe\n#pagebreak()\nPage two";
+        ResumeWorld::new(source.to_string())
+            .unwrap()
+            .compile()
+            .unwrap()
+    }
+
+    /// Width and height from a PNG's IHDR chunk
+    fn png_size(png: &[u8]) -> (u32, u32) {
+        let be = |at: usize| u32::from_be_bytes(png[at..at + 4].try_into().unwrap());
+        (be(16), be(20))
+    }
+
+    #[test]
+    fn test_png_preview_per_page_at_dpi() {
+        let pages = render_preview(&document(), PreviewFormat::Png { dpi: 144.0 }).unwrap();
+        assert_eq!(pages.len(), 2);
+        for page in &pages {
+            assert!(page.data.starts_with(b"\x89PNG"));
+            assert_eq!((page.width.round(), page.height.round()), (612.0, 792.0));
+            // US Letter is 8.5 x 11 in
+            assert_eq!(png_size(&page.data), (1224, 1584));
+        }
+    }
+
+    #[test]
+    fn test_svg_preview() {
+        let pages = render_preview(&document(), PreviewFormat::Svg).unwrap();
+        assert_eq!(pages.len(), 2);
+        let svg = String::from_utf8(pages[0].data.clone()).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("viewBox=\"0 0 612 79"), "{}", &svg[..200]);
+    }
+
+    #[test]
+    fn test_preview_format_json_and_dpi_range() {
+        let png: PreviewFormat = serde_json::from_str(r#"{"format": "png"}"#).unwrap();
+        assert_eq!(png, PreviewFormat::default());
+        let svg: PreviewFormat = serde_json::from_str(r#"{"format": "svg"}"#).unwrap();
+        assert_eq!(svg.mime_type(), "image/svg+xml");
+
+        let error = render_preview(&document(), PreviewFormat::Png { dpi: 5000.0 }).unwrap_err();
+        assert!(error.to_string().contains("Preview DPI"));
+    }
+}
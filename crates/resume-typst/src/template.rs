@@ -1,30 +1,163 @@
-//! Template rendering utilities for Typst resume generation
+//! Template data preparation for Typst resume generation
 //!
-//! This module provides helper functions for preparing data and formatting
-//! content for injection into the Typst template.
+//! This module turns a `GenerationPayload` into the JSON document the Typst
+//! template reads as [`DATA_FILE`], plus the date and spacing helpers behind it.
 
+use crate::{fonts, metadata};
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
-use shared_types::{GenerationPayload, ScoredBullet};
+use shared_types::{DateStyle, GenerationPayload, PaperSize, RenderOptions, ScoredBullet};
 use std::collections::HashMap;
 
+/// Virtual path the template loads its data from (`json("data.json")`)
+pub const DATA_FILE: &str = "data.json";
+
+/// Version of the [`DATA_FILE`] shape; bumped on breaking changes so
+/// user-supplied templates can declare which one they were written against
+pub const DATA_VERSION: u32 = 1;
+
+/// JSON Schema for [`DATA_FILE`], published at
+/// `schemas/template-data.schema.json`
+pub const DATA_SCHEMA: &str = include_str!("../../../schemas/template-data.schema.json");
+
+/// Vertical density of the rendered layout
+///
+/// `Normal` is the standard layout. The fit-to-page loop steps through
+/// tighter levels before it starts dropping content.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Spacing {
+    #[default]
+    Normal,
+    Compact,
+    Tight,
+}
+
+impl Spacing {
+    /// All levels, loosest first
+    pub const ALL: [Spacing; 3] = [Spacing::Normal, Spacing::Compact, Spacing::Tight];
+
+    /// Page margin in inches (top, bottom and sides)
+    pub fn margin(self) -> f32 {
+        match self {
+            Spacing::Normal => 0.75,
+            Spacing::Compact => 0.6,
+            Spacing::Tight => 0.5,
+        }
+    }
+
+    /// Paragraph leading in em
+    pub fn leading(self) -> f32 {
+        match self {
+            Spacing::Normal => 0.55,
+            Spacing::Compact => 0.5,
+            Spacing::Tight => 0.45,
+        }
+    }
+
+    /// Gap between list items in em
+    pub fn list_spacing(self) -> f32 {
+        match self {
+            Spacing::Normal => 0.4,
+            Spacing::Compact => 0.3,
+            Spacing::Tight => 0.25,
+        }
+    }
+
+    /// Block spacing `(above, below)` in em for a heading level (1-3)
+    pub fn heading(self, level: u8) -> (f32, f32) {
+        match (self, level) {
+            (Spacing::Normal, 1) => (1.2, 0.6),
+            (Spacing::Normal, 2) => (0.8, 0.4),
+            (Spacing::Normal, _) => (0.5, 0.3),
+            (Spacing::Compact, 1) => (1.0, 0.5),
+            (Spacing::Compact, 2) => (0.6, 0.3),
+            (Spacing::Compact, _) => (0.4, 0.25),
+            (Spacing::Tight, 1) => (0.8, 0.4),
+            (Spacing::Tight, 2) => (0.5, 0.25),
+            (Spacing::Tight, _) => (0.3, 0.2),
+        }
+    }
+}
+
+/// Typst `page(paper: ..)` name for a paper size
+fn paper_name(paper: PaperSize) -> &'static str {
+    match paper {
+        PaperSize::Letter => "us-letter",
+        PaperSize::A4 => "a4",
+        PaperSize::Legal => "us-legal",
+    }
+}
+
+/// Layout values as exposed to the template (`data.layout`)
+///
+/// Spacing values are scaled by the line spacing multiplier; explicit
+/// margins shrink in proportion to the spacing level so the fit loop can
+/// still tighten them.
+fn layout_json(spacing: Spacing, options: &RenderOptions) -> JsonValue {
+    let headings: Vec<JsonValue> = (1..=3)
+        .map(|level| {
+            let (above, below) = spacing.heading(level);
+            serde_json::json!({ "above": above, "below": below })
+        })
+        .collect();
+    let scale = spacing.margin() / Spacing::Normal.margin();
+    let margins = options.margins.map(|m| {
+        serde_json::json!({
+            "top": m.top * scale,
+            "right": m.right * scale,
+            "bottom": m.bottom * scale,
+            "left": m.left * scale,
+        })
+    });
+    let sections = options
+        .sections
+        .as_ref()
+        .map(|sections| sections.iter().map(|s| s.name()).collect::<Vec<_>>());
+    let (lang, region) = options.language_parts();
+
+    serde_json::json!({
+        "spacing": spacing,
+        "paper": paper_name(options.paper),
+        "margin": spacing.margin(),
+        "margins": margins,
+        "font": fonts::family(options.font_set),
+        "lang": lang,
+        "region": region,
+        "font_size": options.font_size,
+        "leading": spacing.leading() * options.line_spacing,
+        "list_spacing": spacing.list_spacing() * options.line_spacing,
+        "headings": headings,
+        "sections": sections,
+    })
+}
+
 /// Format a date string for display in resume
 ///
-/// Converts dates from "YYYY-MM-DD" or "YYYY-MM" format to "Mon YYYY" format
-/// Examples:
+/// Converts dates from "YYYY-MM-DD" or "YYYY-MM" format to the given style
+/// Examples (short style):
 /// - "2020-01-15" → "Jan 2020"
 /// - "2020-01" → "Jan 2020"
 /// - "2020" → "2020"
 ///
-fn format_month_year(date_str: &str) -> String {
+fn format_month_year(date_str: &str, style: DateStyle) -> String {
+    let pattern = match style {
+        DateStyle::Short => "%b %Y",
+        DateStyle::Long => "%B %Y",
+        DateStyle::Numeric => "%m/%Y",
+        DateStyle::Year => "%Y",
+    };
+
     // Try parsing as full date (YYYY-MM-DD)
     if let Ok(date) = NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
-        return date.format("%b %Y").to_string();
+        return date.format(pattern).to_string();
     }
 
     // Try parsing as year-month (YYYY-MM)
     if let Ok(date) = NaiveDate::parse_from_str(&format!("{}-01", date_str), "%Y-%m-%d") {
-        return date.format("%b %Y").to_string();
+        return date.format(pattern).to_string();
     }
 
     // Fallback: return as-is (could be just year "2020")
@@ -39,15 +172,32 @@ fn format_month_year(date_str: &str) -> String {
 /// - ("2020-01", "present") → "Jan 2020 - Present"
 ///
 pub fn format_date_range(start: Option<&str>, end: Option<&str>) -> String {
+    format_date_range_with_style(start, end, DateStyle::Short)
+}
+
+/// Format a date range in a given [`DateStyle`]
+///
+/// A range that starts and ends on the same printed date collapses to one
+/// date, so the year style prints "2021" rather than "2021 - 2021".
+pub fn format_date_range_with_style(
+    start: Option<&str>,
+    end: Option<&str>,
+    style: DateStyle,
+) -> String {
     match (start, end) {
         (Some(s), Some(e)) if e.is_empty() || e.eq_ignore_ascii_case("present") => {
-            format!("{} - Present", format_month_year(s))
+            format!("{} - Present", format_month_year(s, style))
         }
         (Some(s), Some(e)) => {
-            format!("{} - {}", format_month_year(s), format_month_year(e))
+            let (start, end) = (format_month_year(s, style), format_month_year(e, style));
+            if start == end {
+                start
+            } else {
+                format!("{} - {}", start, end)
+            }
         }
         (Some(s), None) => {
-            format!("{} - Present", format_month_year(s))
+            format!("{} - Present", format_month_year(s, style))
         }
         _ => String::new(),
     }
@@ -132,12 +282,136 @@ fn group_bullets_by_hierarchy(bullets: &[ScoredBullet]) -> Vec<CompanyData> {
     companies
 }
 
-/// Prepare data for Typst template injection
+/// Prefix bare domains with `https://`
+fn normalize_url(link: &str) -> String {
+    if link.starts_with("http://") || link.starts_with("https://") {
+        link.to_string()
+    } else {
+        format!("https://{}", link)
+    }
+}
+
+/// Title-case a kebab-case skill category ("machine-learning" → "Machine Learning")
+fn category_display_name(category: &str) -> String {
+    category
+        .split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Contact line entries, in display order: `{ "text", "url" }`
+fn contacts_json(payload: &GenerationPayload) -> Vec<JsonValue> {
+    let personal = &payload.personal;
+    let present = |value: &Option<String>| value.clone().filter(|v| !v.is_empty());
+    let mut contacts = Vec::new();
+
+    if let Some(email) = present(&personal.email) {
+        contacts.push(serde_json::json!({ "text": email, "url": format!("mailto:{}", email) }));
+    }
+    for text in [present(&personal.phone), present(&personal.location)]
+        .into_iter()
+        .flatten()
+    {
+        contacts.push(serde_json::json!({ "text": text, "url": null }));
+    }
+    if let Some(website) = present(&personal.website) {
+        contacts.push(serde_json::json!({ "text": website, "url": normalize_url(&website) }));
+    }
+    if let Some(linkedin) = present(&personal.linkedin) {
+        contacts.push(serde_json::json!({
+            "text": format!("linkedin.com/in/{}", linkedin),
+            "url": format!("https://linkedin.com/in/{}", linkedin),
+        }));
+    }
+    if let Some(github) = present(&personal.github) {
+        contacts.push(serde_json::json!({
+            "text": format!("github.com/{}", github),
+            "url": format!("https://github.com/{}", github),
+        }));
+    }
+
+    contacts
+}
+
+/// Meta footer with `{bullet_count}`/`{company_count}` filled in, split into paragraphs
+fn about_paragraphs(payload: &GenerationPayload) -> Option<Vec<String>> {
+    let meta_footer = payload.meta_footer.as_deref().filter(|f| !f.is_empty())?;
+
+    // Totals come from the full compendium, not the selected output
+    let footer_text = meta_footer
+        .replace(
+            "{bullet_count}",
+            &payload.total_bullets_available.unwrap_or(0).to_string(),
+        )
+        .replace(
+            "{company_count}",
+            &payload.total_companies_available.unwrap_or(0).to_string(),
+        );
+
+    Some(
+        format_footer_for_ats(&footer_text)
+            .split("\n\n")
+            .map(str::to_string)
+            .collect(),
+    )
+}
+
+/// Format meta footer text for optimal ATS/AI readability
+///
+/// Breaks long paragraph text into logical sections with line breaks between
+/// key concepts to help automated systems parse the information clearly.
+///
+fn format_footer_for_ats(text: &str) -> String {
+    // Break after key sentence endings to create logical paragraphs
+    text.replace(
+        ". A hierarchical scoring engine",
+        ".\n\nA hierarchical scoring engine",
+    )
+    .replace(". The system is built", ".\n\nThe system is built")
+    .replace(". Deployed on", ".\n\nDeployed on")
+    .replace(". Explore the full", ".\n\nExplore the full")
+}
+
+/// Prepare the data the Typst template reads from [`DATA_FILE`]
+///
+/// Rust does the data work (grouping, ordering, date formatting, link
+/// normalization, footer variables); the templates in `typst/templates/` own the
+/// layout. Every key below is always present, with `null` for missing values:
+///
+/// - `personal`: `name`, `email`, `phone`, `location`, `linkedin`, `github`, `website`
+/// - `contacts`: `[{ text, url }]` for the header line (`url` may be `null`)
+/// - `role_profile`: `name`, `description`, `tags` (highest-weighted first,
+///   used as PDF keywords)
+/// - `summary`
+/// - `companies`: `[{ name, description, link, url, location, date_start, date_end,
+///   date_range, position_count, positions: [{ title, description, date_start,
+///   date_end, date_range, show_dates, bullets: [{ description, score }] }] }]`
+/// - `education`: `[{ degree, degree_type, institution, location, year }]`
+/// - `skills`: `[{ category, items }]`, categories title-cased
+/// - `about`: meta footer paragraphs, or `null`
+/// - `layout`: `spacing`, `paper` (Typst paper name), `margin` (in, the
+///   spacing level's default), `margins` (`{ top, right, bottom, left }` in
+///   or `null`), `font` (family name), `lang`/`region` (document language, region or
+///   `null`), `font_size` (pt or `null`), `leading`/`list_spacing` (em),
+///   `headings: [{ above, below }]` (em, levels 1-3), `sections` (names in
+///   order, or `null` for the template's default)
+/// - `dev`: build metadata when `options.debug` is set, otherwise `null`;
+///   `build_time` is the render clock (see [`RenderOptions::clock`])
 ///
-/// Converts GenerationPayload into a JSON structure suitable for
-/// string interpolation into the Typst template.
+/// `null` layout values mean the template picks its own default.
 ///
-pub fn prepare_template_data(payload: &GenerationPayload) -> JsonValue {
+pub fn prepare_template_data(
+    payload: &GenerationPayload,
+    options: &RenderOptions,
+    spacing: Spacing,
+) -> JsonValue {
     let companies = group_bullets_by_hierarchy(&payload.selected_bullets);
 
     // Convert companies to JSON
@@ -164,6 +438,7 @@ pub fn prepare_template_data(payload: &GenerationPayload) -> JsonValue {
                     let bullets_json: Vec<JsonValue> = pos
                         .bullets
                         .iter()
+                        .filter(|(description, _)| !description.is_empty())
                         .map(|(description, score)| {
                             serde_json::json!({
                                 "description": description,
@@ -177,10 +452,13 @@ pub fn prepare_template_data(payload: &GenerationPayload) -> JsonValue {
                         "description": pos.description, // Position context/summary - can be removed if too verbose
                         "date_start": &pos.date_start,
                         "date_end": pos.date_end.as_ref(),
-                        "date_range": format_date_range(
+                        "date_range": format_date_range_with_style(
                             Some(&pos.date_start),
-                            pos.date_end.as_deref()
+                            pos.date_end.as_deref(),
+                            options.date_style,
                         ),
+                        // Only show position dates if company has multiple positions (avoid redundancy)
+                        "show_dates": company.positions.len() > 1,
                         "bullets": bullets_json,
                     })
                 })
@@ -188,14 +466,16 @@ pub fn prepare_template_data(payload: &GenerationPayload) -> JsonValue {
 
             serde_json::json!({
                 "name": company.name,
-                "description": company.description,
+                "description": company.description.as_deref().filter(|d| !d.is_empty()),
                 "link": company.link,
+                "url": company.link.as_deref().filter(|l| !l.is_empty()).map(normalize_url),
                 "location": company.location,
                 "date_start": company.date_start,
                 "date_end": company.date_end,
-                "date_range": format_date_range(
+                "date_range": format_date_range_with_style(
                     Some(&company.date_start),
-                    company.date_end.as_deref()
+                    company.date_end.as_deref(),
+                    options.date_style,
                 ),
                 "positions": positions_json,
                 "position_count": company.positions.len(),
@@ -203,6 +483,60 @@ pub fn prepare_template_data(payload: &GenerationPayload) -> JsonValue {
         })
         .collect();
 
+    let education_json: Vec<JsonValue> = payload
+        .education
+        .iter()
+        .flatten()
+        .filter(|edu| !edu.degree.is_empty() || !edu.institution.is_empty())
+        .map(|edu| {
+            serde_json::json!({
+                "degree": edu.degree,
+                "degree_type": edu.degree_type,
+                "institution": edu.institution,
+                "location": edu.location,
+                "year": edu.year,
+            })
+        })
+        .collect();
+
+    // Sorted by category key so the order is stable across runs
+    let mut skill_groups: Vec<(&String, &Vec<String>)> = payload
+        .skills
+        .iter()
+        .flatten()
+        .filter(|(_, items)| !items.is_empty())
+        .collect();
+    skill_groups.sort_by(|a, b| a.0.cmp(b.0));
+    let skills_json: Vec<JsonValue> = skill_groups
+        .into_iter()
+        .map(|(category, items)| {
+            serde_json::json!({
+                "category": category_display_name(category),
+                "items": items,
+            })
+        })
+        .collect();
+
+    let dev_json = options.debug.then(|| {
+        let total_bullets: usize = companies
+            .iter()
+            .flat_map(|c| c.positions.values())
+            .map(|p| p.bullets.len())
+            .sum();
+        // A pinned clock keeps debug renders reproducible
+        let build_time = options
+            .clock(payload.metadata.as_ref())
+            .and_then(|timestamp| i64::try_from(timestamp).ok())
+            .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp, 0))
+            .unwrap_or_else(chrono::Utc::now);
+        serde_json::json!({
+            "build_time": build_time.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+            "version": env!("CARGO_PKG_VERSION"),
+            "companies": companies.len(),
+            "bullets": total_bullets,
+        })
+    });
+
     // Build final data structure
     serde_json::json!({
         "personal": {
@@ -214,17 +548,19 @@ pub fn prepare_template_data(payload: &GenerationPayload) -> JsonValue {
             "github": payload.personal.github,
             "website": payload.personal.website,
         },
-        "summary": payload.summary,
+        "contacts": contacts_json(payload),
+        "summary": payload.summary.as_deref().filter(|s| !s.is_empty()),
         "companies": companies_json,
-        "education": payload.education,
-        "skills": payload.skills,
+        "education": education_json,
+        "skills": skills_json,
         "role_profile": {
             "name": payload.role_profile.name,
             "description": payload.role_profile.description,
+            "tags": metadata::keywords(&payload.role_profile),
         },
-        "metaFooter": payload.meta_footer,
-        "totalBulletsAvailable": payload.total_bullets_available,
-        "totalCompaniesAvailable": payload.total_companies_available,
+        "about": about_paragraphs(payload),
+        "layout": layout_json(spacing, options),
+        "dev": dev_json,
     })
 }
 
@@ -259,22 +595,245 @@ struct PositionData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use shared_types::{Margins, PersonalInfo, ResumeSection, RoleProfile, ScoringWeights};
+
+    fn payload() -> GenerationPayload {
+        GenerationPayload {
+            personal: PersonalInfo {
+                name: "Test Person".to_string(),
+                nickname: None,
+                tagline: None,
+                email: Some("test@example.com".to_string()),
+                phone: Some(String::new()),
+                location: Some("Berlin".to_string()),
+                linkedin: None,
+                github: Some("tester".to_string()),
+                website: Some("example.com".to_string()),
+                twitter: None,
+            },
+            selected_bullets: vec![],
+            role_profile: RoleProfile {
+                id: "role".to_string(),
+                name: "Engineer".to_string(),
+                description: None,
+                tag_weights: HashMap::new(),
+                scoring_weights: ScoringWeights {
+                    tag_relevance: 0.6,
+                    priority: 0.4,
+                },
+                summary_id: None,
+            },
+            education: None,
+            skills: Some(HashMap::from([
+                ("machine-learning".to_string(), vec!["PyTorch".to_string()]),
+                ("languages".to_string(), vec!["Rust".to_string()]),
+                ("empty".to_string(), vec![]),
+            ])),
+            summary: Some(String::new()),
+            meta_footer: Some(
+                "Picked from {bullet_count} bullets. A hierarchical scoring engine ranks them."
+                    .to_string(),
+            ),
+            total_bullets_available: Some(42),
+            total_companies_available: None,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn test_template_data_contacts_and_skills() {
+        let data = prepare_template_data(&payload(), &RenderOptions::default(), Spacing::Normal);
+
+        let contacts: Vec<(&str, Option<&str>)> = data["contacts"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| (c["text"].as_str().unwrap(), c["url"].as_str()))
+            .collect();
+        assert_eq!(
+            contacts,
+            vec![
+                ("test@example.com", Some("mailto:test@example.com")),
+                ("Berlin", None),
+                ("example.com", Some("https://example.com")),
+                ("github.com/tester", Some("https://github.com/tester")),
+            ]
+        );
+
+        assert_eq!(
+            data["skills"],
+            serde_json::json!([
+                { "category": "Languages", "items": ["Rust"] },
+                { "category": "Machine Learning", "items": ["PyTorch"] },
+            ])
+        );
+        assert!(data["summary"].is_null());
+        assert!(data["dev"].is_null());
+    }
+
+    #[test]
+    fn test_template_data_about_and_layout() {
+        let options = RenderOptions {
+            debug: true,
+            ..RenderOptions::default()
+        };
+        let data = prepare_template_data(&payload(), &options, Spacing::Tight);
+
+        assert_eq!(
+            data["about"],
+            serde_json::json!([
+                "Picked from 42 bullets.",
+                "A hierarchical scoring engine ranks them."
+            ])
+        );
+        assert_eq!(data["layout"]["spacing"], "tight");
+        assert_eq!(data["layout"]["margin"], 0.5);
+        assert_eq!(data["layout"]["headings"].as_array().unwrap().len(), 3);
+        assert!(data["layout"]["margins"].is_null());
+        assert!(data["layout"]["sections"].is_null());
+        assert_eq!(data["dev"]["bullets"], 0);
+    }
+
+    #[test]
+    fn test_template_data_layout_from_render_options() {
+        let options = RenderOptions {
+            paper: PaperSize::A4,
+            margins: Some(Margins::uniform(1.0)),
+            font_size: Some(11.0),
+            line_spacing: 2.0,
+            sections: Some(vec![ResumeSection::Skills, ResumeSection::Experience]),
+            language: "de-AT".to_string(),
+            ..RenderOptions::default()
+        };
+        let normal = prepare_template_data(&payload(), &options, Spacing::Normal);
+        let tight = prepare_template_data(&payload(), &options, Spacing::Tight);
+
+        let layout = &normal["layout"];
+        assert_eq!(layout["paper"], "a4");
+        assert_eq!(layout["font"], "Liberation Serif");
+        assert_eq!(layout["font_size"], 11.0);
+        assert_eq!(layout["lang"], "de");
+        assert_eq!(layout["region"], "AT");
+        assert_eq!(layout["margins"]["left"], 1.0);
+        assert_eq!(
+            layout["leading"],
+            serde_json::json!(Spacing::Normal.leading() * 2.0)
+        );
+        assert_eq!(
+            layout["sections"],
+            serde_json::json!(["skills", "experience"])
+        );
+
+        // Explicit margins still tighten with the spacing level
+        let tight_left = tight["layout"]["margins"]["left"].as_f64().unwrap();
+        assert!(tight_left < 1.0);
+    }
+
+    /// Minimal JSON Schema check: types, required keys, closed objects, enums
+    fn check_schema(schema: &JsonValue, value: &JsonValue, at: &str) {
+        if let Some(options) = schema["enum"].as_array() {
+            assert!(options.contains(value), "{}: {} not in enum", at, value);
+        }
+        if let Some(types) = schema.get("type") {
+            let types: Vec<&str> = match types {
+                JsonValue::String(t) => vec![t.as_str()],
+                JsonValue::Array(ts) => ts.iter().filter_map(JsonValue::as_str).collect(),
+                _ => vec![],
+            };
+            let actual = match value {
+                JsonValue::Null => "null",
+                JsonValue::Bool(_) => "boolean",
+                JsonValue::Number(n) if n.is_u64() || n.is_i64() => "integer",
+                JsonValue::Number(_) => "number",
+                JsonValue::String(_) => "string",
+                JsonValue::Array(_) => "array",
+                JsonValue::Object(_) => "object",
+            };
+            let matches =
+                types.contains(&actual) || (actual == "integer" && types.contains(&"number"));
+            assert!(matches, "{}: {} is not {:?}", at, actual, types);
+        }
+        if let JsonValue::Object(map) = value {
+            for key in schema["required"].as_array().into_iter().flatten() {
+                let key = key.as_str().unwrap();
+                assert!(map.contains_key(key), "{}: missing {}", at, key);
+            }
+            if let Some(properties) = schema["properties"].as_object() {
+                for (key, child) in map {
+                    let child_schema = properties
+                        .get(key)
+                        .unwrap_or_else(|| panic!("{}: {} not in schema", at, key));
+                    check_schema(child_schema, child, &format!("{}.{}", at, key));
+                }
+            }
+        }
+        if let (JsonValue::Array(items), Some(item_schema)) = (value, schema.get("items")) {
+            for (i, item) in items.iter().enumerate() {
+                check_schema(item_schema, item, &format!("{}[{}]", at, i));
+            }
+        }
+    }
+
+    #[test]
+    fn test_template_data_matches_published_schema() {
+        let schema: JsonValue = serde_json::from_str(DATA_SCHEMA).unwrap();
+        assert_eq!(schema["x-data-version"], DATA_VERSION);
+
+        let options = RenderOptions {
+            debug: true,
+            margins: Some(Margins::uniform(0.8)),
+            font_size: Some(10.0),
+            sections: Some(ResumeSection::ALL.to_vec()),
+            ..RenderOptions::default()
+        };
+        for (options, spacing) in [
+            (RenderOptions::default(), Spacing::Normal),
+            (options, Spacing::Tight),
+        ] {
+            let data = prepare_template_data(&payload(), &options, spacing);
+            check_schema(&schema, &data, "data");
+        }
+    }
+
+    #[test]
+    fn test_format_date_range_styles() {
+        let range = |style| format_date_range_with_style(Some("2020-01"), Some("2022-09"), style);
+        assert_eq!(range(DateStyle::Short), "Jan 2020 - Sep 2022");
+        assert_eq!(range(DateStyle::Long), "January 2020 - September 2022");
+        assert_eq!(range(DateStyle::Numeric), "01/2020 - 09/2022");
+        assert_eq!(range(DateStyle::Year), "2020 - 2022");
+
+        assert_eq!(
+            format_date_range_with_style(Some("2021-02"), Some("2021-11"), DateStyle::Year),
+            "2021"
+        );
+        assert_eq!(
+            format_date_range_with_style(Some("2021-02"), None, DateStyle::Long),
+            "February 2021 - Present"
+        );
+    }
 
     #[test]
     fn test_format_month_year_full_date() {
-        assert_eq!(format_month_year("2020-01-15"), "Jan 2020");
-        assert_eq!(format_month_year("2022-12-31"), "Dec 2022");
+        assert_eq!(
+            format_month_year("2020-01-15", DateStyle::Short),
+            "Jan 2020"
+        );
+        assert_eq!(
+            format_month_year("2022-12-31", DateStyle::Short),
+            "Dec 2022"
+        );
     }
 
     #[test]
     fn test_format_month_year_month_only() {
-        assert_eq!(format_month_year("2020-01"), "Jan 2020");
-        assert_eq!(format_month_year("2022-12"), "Dec 2022");
+        assert_eq!(format_month_year("2020-01", DateStyle::Short), "Jan 2020");
+        assert_eq!(format_month_year("2022-12", DateStyle::Short), "Dec 2022");
     }
 
     #[test]
     fn test_format_month_year_year_only() {
-        assert_eq!(format_month_year("2020"), "2020");
+        assert_eq!(format_month_year("2020", DateStyle::Short), "2020");
     }
 
     #[test]
@@ -0,0 +1,186 @@
+//! Built-in resume templates
+//!
+//! Every template reads the same `data.json` (see
+//! [`crate::template::prepare_template_data`]), so any of them can render any
+//! payload. They are embedded at compile time and chosen by name through
+//! `RenderOptions::template`.
+
+use crate::TypstError;
+use serde::{Deserialize, Serialize};
+
+/// A template shipped with the crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BuiltinTemplate {
+    /// Single column, centred header; the original layout
+    #[default]
+    Classic,
+    /// Two columns with a tinted sidebar
+    Modern,
+    /// Dense single column for long histories
+    Compact,
+    /// Education-first CV with a date column
+    Academic,
+}
+
+impl BuiltinTemplate {
+    /// All built-in templates, in display order
+    pub const ALL: [BuiltinTemplate; 4] = [
+        BuiltinTemplate::Classic,
+        BuiltinTemplate::Modern,
+        BuiltinTemplate::Compact,
+        BuiltinTemplate::Academic,
+    ];
+
+    /// Name used to select the template in render options
+    pub fn name(self) -> &'static str {
+        match self {
+            BuiltinTemplate::Classic => "classic",
+            BuiltinTemplate::Modern => "modern",
+            BuiltinTemplate::Compact => "compact",
+            BuiltinTemplate::Academic => "academic",
+        }
+    }
+
+    /// Human-readable name for pickers
+    pub fn label(self) -> &'static str {
+        match self {
+            BuiltinTemplate::Classic => "Classic",
+            BuiltinTemplate::Modern => "Modern",
+            BuiltinTemplate::Compact => "Compact",
+            BuiltinTemplate::Academic => "Academic CV",
+        }
+    }
+
+    /// One-line description for pickers
+    pub fn description(self) -> &'static str {
+        match self {
+            BuiltinTemplate::Classic => "Single-column ATS-friendly layout with a centred header",
+            BuiltinTemplate::Modern => {
+                "Two columns: contact, skills and education in a sidebar beside experience"
+            }
+            BuiltinTemplate::Compact => {
+                "Dense single column with one line per role, for long histories"
+            }
+            BuiltinTemplate::Academic => {
+                "Education-first curriculum vitae with ruled headings and a date column"
+            }
+        }
+    }
+
+    /// Typst source of the template
+    pub fn source(self) -> &'static str {
+        match self {
+            BuiltinTemplate::Classic => include_str!("../../../typst/templates/resume.typ"),
+            BuiltinTemplate::Modern => include_str!("../../../typst/templates/modern.typ"),
+            BuiltinTemplate::Compact => include_str!("../../../typst/templates/compact.typ"),
+            BuiltinTemplate::Academic => include_str!("../../../typst/templates/academic.typ"),
+        }
+    }
+
+    /// Small SVG wireframe of the layout
+    pub fn thumbnail_svg(self) -> &'static str {
+        match self {
+            BuiltinTemplate::Classic => {
+                include_str!("../../../typst/templates/thumbnails/classic.svg")
+            }
+            BuiltinTemplate::Modern => {
+                include_str!("../../../typst/templates/thumbnails/modern.svg")
+            }
+            BuiltinTemplate::Compact => {
+                include_str!("../../../typst/templates/thumbnails/compact.svg")
+            }
+            BuiltinTemplate::Academic => {
+                include_str!("../../../typst/templates/thumbnails/academic.svg")
+            }
+        }
+    }
+
+    /// Look up a template by name (case-insensitive)
+    pub fn from_name(name: &str) -> Result<Self, TypstError> {
+        let wanted = name.trim();
+        Self::ALL
+            .into_iter()
+            .find(|template| template.name().eq_ignore_ascii_case(wanted))
+            .ok_or_else(|| {
+                let available: Vec<&str> = Self::ALL.iter().map(|t| t.name()).collect();
+                TypstError::TemplateError(format!(
+                    "Unknown template '{}' (available: {})",
+                    name,
+                    available.join(", ")
+                ))
+            })
+    }
+
+    /// Picker entry for this template
+    pub fn info(self) -> TemplateInfo {
+        TemplateInfo {
+            name: self.name().to_string(),
+            label: self.label().to_string(),
+            description: self.description().to_string(),
+            thumbnail: self.thumbnail_svg().to_string(),
+        }
+    }
+}
+
+/// Template metadata for a picker UI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateInfo {
+    pub name: String,
+    pub label: String,
+    pub description: String,
+    /// Inline SVG markup
+    pub thumbnail: String,
+}
+
+/// List every built-in template, in display order
+pub fn list_templates() -> Vec<TemplateInfo> {
+    BuiltinTemplate::ALL
+        .into_iter()
+        .map(BuiltinTemplate::info)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_name_round_trips() {
+        for template in BuiltinTemplate::ALL {
+            assert_eq!(
+                BuiltinTemplate::from_name(template.name()).unwrap(),
+                template
+            );
+        }
+        assert_eq!(
+            BuiltinTemplate::from_name(" Modern ").unwrap(),
+            BuiltinTemplate::Modern
+        );
+    }
+
+    #[test]
+    fn test_unknown_name_lists_available() {
+        let err = BuiltinTemplate::from_name("fancy").unwrap_err().to_string();
+        assert!(err.contains("Unknown template 'fancy'"));
+        assert!(err.contains("classic, modern, compact, academic"));
+    }
+
+    #[test]
+    fn test_default_matches_render_options_default() {
+        let options = shared_types::RenderOptions::default();
+        assert_eq!(
+            BuiltinTemplate::from_name(&options.template).unwrap(),
+            BuiltinTemplate::default()
+        );
+    }
+
+    #[test]
+    fn test_every_template_uses_data_contract() {
+        for info in list_templates() {
+            let source = BuiltinTemplate::from_name(&info.name).unwrap().source();
+            assert!(source.contains("json(\"data.json\")"), "{}", info.name);
+            assert!(source.contains("<resume-end>"), "{}", info.name);
+            assert!(info.thumbnail.starts_with("<svg"), "{}", info.name);
+        }
+    }
+}
@@ -63,6 +63,7 @@ impl TestDataBuilder {
                     tag_relevance: 0.6,
                     priority: 0.4,
                 },
+                summary_id: None,
             },
             education: None,
             skills: None,
@@ -132,6 +133,7 @@ impl TestDataBuilder {
                     tag_relevance: 0.5,
                     priority: 0.5,
                 },
+                summary_id: None,
             },
             education: None,
             skills: None,
@@ -233,6 +235,7 @@ impl TestDataBuilder {
                     tag_relevance: 0.6,
                     priority: 0.4,
                 },
+                summary_id: None,
             },
             education: Some(vec![Education {
                 degree: "Bachelor of Science in Computer Science".to_string(),
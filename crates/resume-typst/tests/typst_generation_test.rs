@@ -7,7 +7,10 @@
 mod common;
 
 use common::{PdfValidator, TestDataBuilder};
-use resume_typst::render_resume;
+use resume_typst::{list_templates, render_resume, render_resume_fit};
+use shared_types::{
+    DateStyle, GenerationMetadata, Margins, PaperSize, PdfStandard, RenderOptions, ResumeSection,
+};
 use std::fs;
 
 #[test]
@@ -15,7 +18,7 @@ fn test_minimal_data_generates_valid_pdf() {
     println!("\n=== Test: Minimal Data PDF Generation ===\n");
 
     let payload = TestDataBuilder::minimal_payload();
-    let result = render_resume(&payload, false);
+    let result = render_resume(&payload, &RenderOptions::default());
 
     assert!(
         result.is_ok(),
@@ -53,7 +56,7 @@ fn test_unicode_content_renders_correctly() {
     println!("\n=== Test: Unicode Content Rendering ===\n");
 
     let payload = TestDataBuilder::with_unicode();
-    let result = render_resume(&payload, false);
+    let result = render_resume(&payload, &RenderOptions::default());
 
     assert!(
         result.is_ok(),
@@ -87,7 +90,7 @@ fn test_long_text_wraps_correctly() {
     println!("\n=== Test: Long Text Wrapping ===\n");
 
     let payload = TestDataBuilder::with_long_text();
-    let result = render_resume(&payload, false);
+    let result = render_resume(&payload, &RenderOptions::default());
 
     assert!(
         result.is_ok(),
@@ -128,7 +131,7 @@ fn test_empty_fields_handled_gracefully() {
     println!("\n=== Test: Empty Optional Fields ===\n");
 
     let payload = TestDataBuilder::with_empty_fields();
-    let result = render_resume(&payload, false);
+    let result = render_resume(&payload, &RenderOptions::default());
 
     assert!(
         result.is_ok(),
@@ -158,33 +161,65 @@ fn test_empty_fields_handled_gracefully() {
 fn test_generation_is_deterministic() {
     println!("\n=== Test: Deterministic Generation ===\n");
 
-    let payload = TestDataBuilder::minimal_payload();
-
-    // Generate PDF twice
-    let result1 = render_resume(&payload, false);
-    let result2 = render_resume(&payload, false);
-
-    assert!(result1.is_ok(), "First generation should succeed");
-    assert!(result2.is_ok(), "Second generation should succeed");
-
-    let pdf1 = result1.unwrap();
-    let pdf2 = result2.unwrap();
+    let mut payload = TestDataBuilder::comprehensive_payload();
+    payload.metadata = Some(GenerationMetadata {
+        generation_id: "gen-deterministic".to_string(),
+        timestamp: 1_700_000_000,
+        selected_bullet_ids: vec![],
+        role_profile_id: payload.role_profile.id.clone(),
+        source_profiles: None,
+        selection_overrides: None,
+        render_options: None,
+    });
+
+    // The debug page and PDF/A history are the most time-dependent output
+    for info in list_templates() {
+        let options = RenderOptions {
+            template: info.name.clone(),
+            pdf_standard: PdfStandard::PdfA2b,
+            reader_hints: true,
+            debug: true,
+            ..RenderOptions::default()
+        };
+
+        let pdf1 = render_resume(&payload, &options).expect("First generation should succeed");
+        let pdf2 = render_resume(&payload, &options).expect("Second generation should succeed");
+        assert!(
+            pdf1 == pdf2,
+            "{} should render the same payload to identical bytes",
+            info.name
+        );
+    }
+
+    // The clock and document ID default to the generation metadata and can
+    // be pinned explicitly
+    let options = RenderOptions {
+        debug: true,
+        ..RenderOptions::default()
+    };
+    let pdf = render_resume(&payload, &options).unwrap();
+    let pinned = RenderOptions {
+        timestamp: Some(1_700_000_000),
+        document_id: Some("gen-deterministic".to_string()),
+        ..options.clone()
+    };
+    assert!(pdf == render_resume(&payload, &pinned).unwrap());
+
+    let later = RenderOptions {
+        timestamp: Some(1_800_000_000),
+        ..options.clone()
+    };
+    assert!(pdf != render_resume(&payload, &later).unwrap());
+    let other_id = RenderOptions {
+        document_id: Some("another-render".to_string()),
+        ..options
+    };
+    assert!(pdf != render_resume(&payload, &other_id).unwrap());
 
-    // PDFs should be identical
-    assert_eq!(
-        pdf1.len(),
-        pdf2.len(),
-        "Generated PDFs should have same size"
-    );
-
-    // Note: We can't do byte-for-byte comparison because Typst may include
-    // timestamps or other metadata. But size should be the same.
-    assert_eq!(
-        pdf1, pdf2,
-        "Generated PDFs should be identical for same input"
+    println!(
+        "✓ PDF generation is byte-for-byte deterministic ({} bytes)",
+        pdf.len()
     );
-
-    println!("✓ PDF generation is deterministic ({} bytes)", pdf1.len());
 }
 
 #[test]
@@ -192,7 +227,7 @@ fn test_comprehensive_payload_all_features() {
     println!("\n=== Test: Comprehensive Payload (All Features) ===\n");
 
     let payload = TestDataBuilder::comprehensive_payload();
-    let result = render_resume(&payload, false);
+    let result = render_resume(&payload, &RenderOptions::default());
 
     assert!(
         result.is_ok(),
@@ -233,12 +268,16 @@ fn test_dev_mode_includes_metadata() {
     let payload = TestDataBuilder::minimal_payload();
 
     // Generate in prod mode (no metadata)
-    let prod_result = render_resume(&payload, false);
+    let prod_result = render_resume(&payload, &RenderOptions::default());
     assert!(prod_result.is_ok(), "Prod mode generation should succeed");
     let prod_pdf = prod_result.unwrap();
 
     // Generate in dev mode (with metadata)
-    let dev_result = render_resume(&payload, true);
+    let debug = RenderOptions {
+        debug: true,
+        ..RenderOptions::default()
+    };
+    let dev_result = render_resume(&payload, &debug);
     assert!(dev_result.is_ok(), "Dev mode generation should succeed");
     let dev_pdf = dev_result.unwrap();
 
@@ -252,3 +291,160 @@ fn test_dev_mode_includes_metadata() {
     println!("  Prod size: {} bytes", prod_pdf.len());
     println!("  Dev size:  {} bytes", dev_pdf.len());
 }
+
+#[test]
+fn test_fit_to_one_page_keeps_comprehensive_payload_intact() {
+    println!("\n=== Test: Fit-to-Page Rendering ===\n");
+
+    let payload = TestDataBuilder::comprehensive_payload();
+    let result = render_resume_fit(&payload, &RenderOptions::default(), 1)
+        .expect("Fit render should succeed");
+
+    PdfValidator::validate_structure(&result.pdf).expect("PDF should have valid structure");
+    assert!(
+        result.report.fits,
+        "Comprehensive payload should fit on one page"
+    );
+    assert_eq!(result.report.pages, 1);
+    assert!(
+        result.report.dropped.is_empty(),
+        "Nothing should be dropped: {:?}",
+        result.report.dropped
+    );
+
+    println!("✓ Fit report: {:?}", result.report);
+}
+
+#[test]
+fn test_every_template_renders_all_test_payloads() {
+    println!("\n=== Test: Built-in Templates ===\n");
+
+    let payloads = [
+        TestDataBuilder::minimal_payload(),
+        TestDataBuilder::with_unicode(),
+        TestDataBuilder::with_long_text(),
+        TestDataBuilder::with_empty_fields(),
+        TestDataBuilder::comprehensive_payload(),
+    ];
+
+    for info in list_templates() {
+        let options = RenderOptions {
+            template: info.name.clone(),
+            ..RenderOptions::default()
+        };
+
+        for payload in &payloads {
+            let pdf_bytes = render_resume(payload, &options)
+                .unwrap_or_else(|e| panic!("{} failed: {}", info.name, e));
+            PdfValidator::validate_structure(&pdf_bytes)
+                .unwrap_or_else(|e| panic!("{} produced an invalid PDF: {}", info.name, e));
+        }
+
+        // Write the comprehensive rendering for manual inspection
+        let output_path = format!("/tmp/test_template_{}.pdf", info.name);
+        let pdf_bytes = render_resume(&payloads[4], &options).unwrap();
+        fs::write(&output_path, &pdf_bytes).expect("Failed to write test PDF");
+
+        println!("✓ {} renders every payload ({})", info.label, output_path);
+    }
+}
+
+#[test]
+fn test_render_options_change_layout() {
+    println!("\n=== Test: Render Options ===\n");
+
+    let payload = TestDataBuilder::comprehensive_payload();
+    let options = RenderOptions {
+        paper: PaperSize::A4,
+        margins: Some(Margins::uniform(0.5)),
+        font_size: Some(11.0),
+        line_spacing: 1.2,
+        sections: Some(vec![ResumeSection::Skills, ResumeSection::Experience]),
+        date_style: DateStyle::Long,
+        ..RenderOptions::default()
+    };
+
+    for info in list_templates() {
+        let options = RenderOptions {
+            template: info.name.clone(),
+            ..options.clone()
+        };
+        let pdf_bytes = render_resume(&payload, &options)
+            .unwrap_or_else(|e| panic!("{} failed: {}", info.name, e));
+        PdfValidator::validate_structure(&pdf_bytes).expect("PDF should have valid structure");
+
+        // A4 media box: 595.28 x 841.89 pt
+        assert!(
+            PdfValidator::contains_text(&pdf_bytes, "MediaBox [0 0 595.2"),
+            "{} should use A4 paper",
+            info.name
+        );
+    }
+
+    println!("✓ Every template honours paper, margins, font size, sections and dates");
+}
+
+#[test]
+fn test_pdfa_and_reader_hints_output() {
+    println!("\n=== Test: PDF/A and Reader Hints ===\n");
+
+    let payload = TestDataBuilder::comprehensive_payload();
+    let options = RenderOptions {
+        pdf_standard: PdfStandard::PdfA2b,
+        language: "en-GB".to_string(),
+        reader_hints: true,
+        ..RenderOptions::default()
+    };
+
+    for info in list_templates() {
+        let options = RenderOptions {
+            template: info.name.clone(),
+            ..options.clone()
+        };
+        let pdf_bytes = render_resume(&payload, &options)
+            .unwrap_or_else(|e| panic!("{} failed: {}", info.name, e));
+        PdfValidator::validate_structure(&pdf_bytes).expect("PDF should have valid structure");
+
+        // PDF/A-2b identification and sRGB output intent
+        for marker in [
+            "<pdfaid:part>2</pdfaid:part>",
+            "<pdfaid:conformance>B</pdfaid:conformance>",
+            "/S /GTS_PDFA1",
+            "/DestOutputProfile",
+        ] {
+            assert!(
+                PdfValidator::contains_text(&pdf_bytes, marker),
+                "{} should contain {}",
+                info.name,
+                marker
+            );
+        }
+
+        // Language, heading bookmarks, title display and link descriptions
+        for marker in [
+            "/Lang (en-GB)",
+            "/Outlines",
+            "/DisplayDocTitle true",
+            "/Subtype /Link\n  /Contents <FEFF",
+        ] {
+            assert!(
+                PdfValidator::contains_text(&pdf_bytes, marker),
+                "{} should contain {}",
+                info.name,
+                marker
+            );
+        }
+
+        // Hints only: no structure tree is claimed
+        assert!(!PdfValidator::contains_text(&pdf_bytes, "/StructTreeRoot"));
+        assert!(!PdfValidator::contains_text(&pdf_bytes, "/Marked true"));
+    }
+
+    // Plain output carries none of this
+    let plain = render_resume(&payload, &RenderOptions::default()).unwrap();
+    assert!(!PdfValidator::contains_text(&plain, "pdfaid:part"));
+    assert!(!PdfValidator::contains_text(&plain, "/DisplayDocTitle"));
+    assert!(PdfValidator::contains_text(&plain, "/Lang (en)"));
+
+    println!("✓ Every template exports as PDF/A-2b with reader hints");
+}
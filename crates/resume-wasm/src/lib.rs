@@ -36,7 +36,10 @@ pub fn build_info() -> String {
 ///
 /// # Arguments
 /// * `payload_json` - JSON string containing GenerationPayload
-/// * `dev_mode` - If true, includes build metadata in PDF
+/// * `options_json` - Optional RenderOptions JSON (template, paper, margins,
+///   font size, sections, date style, `debug` for the build metadata page).
+///   When omitted, the options recorded in `metadata.renderOptions` are
+///   reused so a stored payload renders the same PDF again.
 ///
 /// # Returns
 /// * `Result<Vec<u8>, JsValue>` - PDF bytes or error
@@ -49,24 +52,351 @@ pub fn build_info() -> String {
 ///   role_profile: {...},
 /// });
 /// const isDev = window.location.hostname === 'localhost';
-/// const pdfBytes = await generate_pdf_typst(payloadJson, isDev);
+/// const options = { template: 'modern', paper: 'a4', debug: isDev };
+/// const pdfBytes = await generate_pdf_typst(payloadJson, JSON.stringify(options));
 /// ```
 #[wasm_bindgen]
-pub fn generate_pdf_typst(payload_json: &str, dev_mode: bool) -> Result<Vec<u8>, JsValue> {
+pub fn generate_pdf_typst(
+    payload_json: &str,
+    options_json: Option<String>,
+) -> Result<Vec<u8>, JsValue> {
     // Parse JSON payload
     let payload: shared_types::GenerationPayload = serde_json::from_str(payload_json)
         .map_err(|e| JsValue::from_str(&format!("Invalid JSON payload: {}", e)))?;
+    let options = parse_render_options(&payload, options_json.as_deref())
+        .map_err(|e| JsValue::from_str(&e))?;
 
     // Validate payload
     validate_payload(&payload)?;
 
     // Generate PDF using Typst
-    let pdf_bytes = resume_typst::render_resume(&payload, dev_mode)
+    let pdf_bytes = resume_typst::render_resume(&payload, &options)
         .map_err(|e| JsValue::from_str(&format!("Typst PDF generation failed: {}", e)))?;
 
     Ok(pdf_bytes)
 }
 
+/// Parse optional RenderOptions JSON
+///
+/// Falls back to the options recorded in the payload's metadata, then to the
+/// defaults.
+fn parse_render_options(
+    payload: &shared_types::GenerationPayload,
+    options_json: Option<&str>,
+) -> Result<shared_types::RenderOptions, String> {
+    match options_json {
+        Some(json) => {
+            serde_json::from_str(json).map_err(|e| format!("Invalid options JSON: {}", e))
+        }
+        None => Ok(payload
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.render_options.clone())
+            .unwrap_or_default()),
+    }
+}
+
+/// Diagnostics logic (WASM-agnostic)
+fn typst_diagnostics_internal(
+    payload_json: &str,
+    options_json: Option<&str>,
+) -> Result<String, String> {
+    let payload: shared_types::GenerationPayload =
+        serde_json::from_str(payload_json).map_err(|e| format!("Invalid JSON payload: {}", e))?;
+    let options = parse_render_options(&payload, options_json)?;
+    validate_payload_internal(&payload)?;
+
+    let diagnostics = match resume_typst::render_resume_with_warnings(&payload, &options) {
+        Ok(output) => output.warnings,
+        Err(resume_typst::TypstError::CompilationError(errors)) => errors.0,
+        Err(e) => return Err(format!("Typst PDF generation failed: {}", e)),
+    };
+    serde_json::to_string(&diagnostics).map_err(|e| format!("Serialization failed: {}", e))
+}
+
+/// Compile a payload and report errors and warnings without returning a PDF
+///
+/// Returns a JSON array of `{ severity, message, hints?, span?, text?,
+/// fields? }`. `span` locates the problem in the template (`file`, `line`,
+/// `column`, `snippet`); `fields` names the payload fields to fix, e.g.
+/// `selected_bullets[4].bullet.description` for a character no font covers.
+/// Compile errors are returned as diagnostics rather than thrown.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const diagnostics = JSON.parse(typst_diagnostics(payloadJson));
+/// for (const d of diagnostics) console.warn(d.severity, d.message, d.fields ?? []);
+/// ```
+#[wasm_bindgen]
+pub fn typst_diagnostics(
+    payload_json: &str,
+    options_json: Option<String>,
+) -> Result<String, JsValue> {
+    typst_diagnostics_internal(payload_json, options_json.as_deref())
+        .map_err(|e| JsValue::from_str(&e))
+}
+
+/// Bundle rendering logic (WASM-agnostic)
+fn generate_pdf_typst_bundle_internal(
+    payload_json: &str,
+    bundle: &[u8],
+    options_json: Option<&str>,
+) -> Result<Vec<u8>, String> {
+    let payload: shared_types::GenerationPayload =
+        serde_json::from_str(payload_json).map_err(|e| format!("Invalid JSON payload: {}", e))?;
+    let options = parse_render_options(&payload, options_json)?;
+    validate_payload_internal(&payload)?;
+
+    let bundle = resume_typst::TemplateBundle::from_archive(bundle)
+        .map_err(|e| format!("Invalid template bundle: {}", e))?;
+    resume_typst::render_resume_with_bundle(&payload, &bundle, &options)
+        .map_err(|e| format!("Typst PDF generation failed: {}", e))
+}
+
+/// Generate PDF from a user-supplied template bundle
+///
+/// `bundle` is a `.zip`, `.tar` or `.tar.gz` archive holding the main `.typ`
+/// file plus any modules, images and fonts it uses. The template reads its
+/// data with `json("data.json")`; see `template_data_schema` for the shape.
+/// `template` in the options is ignored.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const bundle = new Uint8Array(await file.arrayBuffer());
+/// const pdfBytes = generate_pdf_typst_bundle(payloadJson, bundle, JSON.stringify({ paper: 'a4' }));
+/// ```
+#[wasm_bindgen]
+pub fn generate_pdf_typst_bundle(
+    payload_json: &str,
+    bundle: &[u8],
+    options_json: Option<String>,
+) -> Result<Vec<u8>, JsValue> {
+    generate_pdf_typst_bundle_internal(payload_json, bundle, options_json.as_deref())
+        .map_err(|e| JsValue::from_str(&e))
+}
+
+/// Bundle validation logic (WASM-agnostic)
+fn validate_template_bundle_internal(bundle: &[u8]) -> Result<String, String> {
+    let bundle = resume_typst::TemplateBundle::from_archive(bundle).map_err(|e| e.to_string())?;
+    bundle.validate().map_err(|e| e.to_string())?;
+
+    let manifest = bundle.manifest().map_err(|e| e.to_string())?;
+    let files: Vec<&str> = bundle.files().map(|(path, _)| path).collect();
+    let summary = serde_json::json!({
+        "name": manifest.and_then(|m| m.name),
+        "main": bundle.main(),
+        "files": files,
+    });
+    serde_json::to_string(&summary).map_err(|e| format!("Serialization failed: {}", e))
+}
+
+/// Check a template bundle archive before rendering with it
+///
+/// Reports every problem at once (unresolved imports, missing images,
+/// fields not in the data schema, unsupported data version, ...). On
+/// success returns JSON `{ name, main, files }` describing the bundle.
+#[wasm_bindgen]
+pub fn validate_template_bundle(bundle: &[u8]) -> Result<String, JsValue> {
+    validate_template_bundle_internal(bundle).map_err(|e| JsValue::from_str(&e))
+}
+
+/// JSON Schema of the `data.json` document templates read
+#[wasm_bindgen]
+pub fn template_data_schema() -> String {
+    resume_typst::template::DATA_SCHEMA.to_string()
+}
+
+/// Parse the payload, render options and preview format of a preview request
+fn parse_preview_request(
+    payload_json: &str,
+    options_json: Option<&str>,
+    preview_json: Option<&str>,
+) -> Result<
+    (
+        shared_types::GenerationPayload,
+        shared_types::RenderOptions,
+        resume_typst::PreviewFormat,
+    ),
+    String,
+> {
+    let payload: shared_types::GenerationPayload =
+        serde_json::from_str(payload_json).map_err(|e| format!("Invalid JSON payload: {}", e))?;
+    let options = parse_render_options(&payload, options_json)?;
+    let format: resume_typst::PreviewFormat = match preview_json {
+        Some(json) => serde_json::from_str(json)
+            .map_err(|e| format!("Invalid preview options JSON: {}", e))?,
+        None => resume_typst::PreviewFormat::default(),
+    };
+    validate_payload_internal(&payload)?;
+    Ok((payload, options, format))
+}
+
+/// Serialize previews as a JSON array of `{ width, height, dataUrl }`
+fn preview_pages_json(
+    pages: &[resume_typst::PagePreview],
+    format: resume_typst::PreviewFormat,
+) -> Result<String, String> {
+    use base64::Engine;
+
+    let pages: Vec<serde_json::Value> = pages
+        .iter()
+        .map(|page| {
+            serde_json::json!({
+                "width": page.width,
+                "height": page.height,
+                "dataUrl": format!(
+                    "data:{};base64,{}",
+                    format.mime_type(),
+                    base64::engine::general_purpose::STANDARD.encode(&page.data)
+                ),
+            })
+        })
+        .collect();
+    serde_json::to_string(&pages).map_err(|e| format!("Serialization failed: {}", e))
+}
+
+/// Preview rendering logic (WASM-agnostic)
+fn render_preview_internal(
+    payload_json: &str,
+    options_json: Option<&str>,
+    preview_json: Option<&str>,
+) -> Result<String, String> {
+    let (payload, options, format) =
+        parse_preview_request(payload_json, options_json, preview_json)?;
+    let pages = resume_typst::render_resume_preview(&payload, &options, format)
+        .map_err(|e| format!("Typst preview failed: {}", e))?;
+    preview_pages_json(&pages, format)
+}
+
+/// Render page images for an in-browser preview or thumbnail
+///
+/// `preview_json` picks the format: `{ "format": "png", "dpi": 144 }`
+/// (default 96 dpi) or `{ "format": "svg" }`. Returns a JSON array with one
+/// `{ width, height, dataUrl }` per page (size in points), ready for
+/// `<img src>`. Pages match what `generate_pdf_typst` produces with the same
+/// options.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const pages = JSON.parse(render_preview(payloadJson, null, JSON.stringify({ format: 'png', dpi: 72 })));
+/// thumbnail.src = pages[0].dataUrl;
+/// ```
+#[wasm_bindgen]
+pub fn render_preview(
+    payload_json: &str,
+    options_json: Option<String>,
+    preview_json: Option<String>,
+) -> Result<String, JsValue> {
+    render_preview_internal(
+        payload_json,
+        options_json.as_deref(),
+        preview_json.as_deref(),
+    )
+    .map_err(|e| JsValue::from_str(&e))
+}
+
+/// Combined PDF and preview rendering logic (WASM-agnostic)
+///
+/// Returns the PDF bytes and the previews JSON.
+fn generate_pdf_with_preview_internal(
+    payload_json: &str,
+    options_json: Option<&str>,
+    preview_json: Option<&str>,
+) -> Result<(Vec<u8>, String), String> {
+    let (payload, options, format) =
+        parse_preview_request(payload_json, options_json, preview_json)?;
+    let output = resume_typst::render_resume_with_preview(&payload, &options, format)
+        .map_err(|e| format!("Typst PDF generation failed: {}", e))?;
+    Ok((output.pdf, preview_pages_json(&output.pages, format)?))
+}
+
+/// A PDF and previews of its pages, from `generate_pdf_with_preview`
+#[wasm_bindgen]
+pub struct PdfWithPreview {
+    pdf: Vec<u8>,
+    pages: String,
+}
+
+#[wasm_bindgen]
+impl PdfWithPreview {
+    /// PDF bytes, as `generate_pdf_typst` returns them
+    #[wasm_bindgen(getter)]
+    pub fn pdf(&self) -> Vec<u8> {
+        self.pdf.clone()
+    }
+
+    /// Page previews JSON, as `render_preview` returns it
+    #[wasm_bindgen(getter)]
+    pub fn pages(&self) -> String {
+        self.pages.clone()
+    }
+}
+
+/// Generate the PDF and its page previews from a single compile
+///
+/// Takes the same arguments as `render_preview`. Use it instead of calling
+/// `generate_pdf_typst` and `render_preview` back to back, which compiles
+/// the resume twice.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const output = generate_pdf_with_preview(payloadJson, null, JSON.stringify({ format: 'png' }));
+/// thumbnail.src = JSON.parse(output.pages)[0].dataUrl;
+/// const blob = new Blob([output.pdf], { type: 'application/pdf' });
+/// output.free();
+/// ```
+#[wasm_bindgen]
+pub fn generate_pdf_with_preview(
+    payload_json: &str,
+    options_json: Option<String>,
+    preview_json: Option<String>,
+) -> Result<PdfWithPreview, JsValue> {
+    let (pdf, pages) = generate_pdf_with_preview_internal(
+        payload_json,
+        options_json.as_deref(),
+        preview_json.as_deref(),
+    )
+    .map_err(|e| JsValue::from_str(&e))?;
+    Ok(PdfWithPreview { pdf, pages })
+}
+
+/// PDF metadata reading logic (WASM-agnostic)
+fn read_pdf_metadata_internal(pdf: &[u8]) -> Result<String, String> {
+    let metadata = resume_typst::read_pdf_metadata(pdf).map_err(|e| e.to_string())?;
+    serde_json::to_string(&metadata).map_err(|e| format!("Serialization failed: {}", e))
+}
+
+/// Read the metadata of a downloaded resume PDF
+///
+/// Returns JSON `{ title, author, subject, keywords, created, generationId,
+/// bulletIds }`, tracing the file back to the generation that produced it.
+#[wasm_bindgen]
+pub fn read_pdf_metadata(pdf: &[u8]) -> Result<String, JsValue> {
+    read_pdf_metadata_internal(pdf).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Built-in template listing (WASM-agnostic)
+fn list_templates_internal() -> Result<String, String> {
+    serde_json::to_string(&resume_typst::list_templates())
+        .map_err(|e| format!("Serialization failed: {}", e))
+}
+
+/// List the built-in Typst templates for a template picker
+///
+/// Returns a JSON array of `{ name, label, description, thumbnail }`, where
+/// `thumbnail` is inline SVG markup. Pass `name` as `template` in the
+/// `generate_pdf_typst` options.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const templates = JSON.parse(list_templates());
+/// picker.innerHTML = templates.map(t => `<button title="${t.description}">${t.thumbnail}${t.label}</button>`).join('');
+/// ```
+#[wasm_bindgen]
+pub fn list_templates() -> Result<String, JsValue> {
+    list_templates_internal().map_err(|e| JsValue::from_str(&e))
+}
+
 /// Internal validation logic (WASM-agnostic)
 ///
 /// Returns String error messages instead of JsValue for testability
@@ -130,6 +460,313 @@ pub fn validate_payload_json(payload_json: &str) -> Result<(), JsValue> {
     Ok(())
 }
 
+/// Tag analytics over a compendium (WASM-agnostic)
+///
+/// Takes ResumeData JSON and returns `TagAnalytics` JSON.
+fn analyze_tags_internal(resume_json: &str) -> Result<String, String> {
+    let data: shared_types::ResumeData =
+        serde_json::from_str(resume_json).map_err(|e| format!("Invalid JSON: {}", e))?;
+
+    let analytics = resume_core::tag_analytics::analyze_tags(&data);
+    serde_json::to_string(&analytics).map_err(|e| format!("Serialization failed: {}", e))
+}
+
+/// Compute per-tag usage statistics for a compendium
+///
+/// Returns JSON with per-tag counts over companies, positions and bullets,
+/// priority weight, date span, companies and co-occurrence, plus unused
+/// role-profile tags and orphan tags (tags used exactly once).
+///
+/// # Example (JavaScript)
+/// ```js
+/// const analytics = JSON.parse(analyze_tags(JSON.stringify(resumeData)));
+/// analytics.orphanTags; // ["kafka", ...]
+/// ```
+#[wasm_bindgen]
+pub fn analyze_tags(resume_json: &str) -> Result<String, JsValue> {
+    analyze_tags_internal(resume_json).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Timeline checks over a compendium (WASM-agnostic)
+///
+/// Takes ResumeData JSON plus optional `TimelineOptions` JSON and returns
+/// `TimelineReport` JSON.
+fn analyze_timeline_internal(
+    resume_json: &str,
+    options_json: Option<&str>,
+) -> Result<String, String> {
+    let data: shared_types::ResumeData =
+        serde_json::from_str(resume_json).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let options: resume_core::timeline::TimelineOptions = match options_json {
+        Some(json) => {
+            serde_json::from_str(json).map_err(|e| format!("Invalid options JSON: {}", e))?
+        }
+        None => Default::default(),
+    };
+
+    let report = resume_core::timeline::analyze_timeline(&data, &options);
+    serde_json::to_string(&report).map_err(|e| format!("Serialization failed: {}", e))
+}
+
+/// Check a compendium's employment timeline
+///
+/// Returns JSON with gaps between companies, overlapping non-concurrent
+/// positions, tenure per company and any unparseable dates.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const report = JSON.parse(
+///   analyze_timeline(JSON.stringify(resumeData), JSON.stringify({ gapThresholdMonths: 6, asOf: "2025-01" }))
+/// );
+/// report.gaps; // [{ afterCompanyId, beforeCompanyId, start, end, months }]
+/// ```
+#[wasm_bindgen]
+pub fn analyze_timeline(
+    resume_json: &str,
+    options_json: Option<String>,
+) -> Result<String, JsValue> {
+    analyze_timeline_internal(resume_json, options_json.as_deref())
+        .map_err(|e| JsValue::from_str(&e))
+}
+
+/// Content lint over a compendium (WASM-agnostic)
+///
+/// Takes ResumeData JSON plus optional `LintConfig` JSON and returns a JSON
+/// array of `LintFinding`.
+fn lint_resume_internal(resume_json: &str, config_json: Option<&str>) -> Result<String, String> {
+    let data: shared_types::ResumeData =
+        serde_json::from_str(resume_json).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let config: resume_core::lint::LintConfig = match config_json {
+        Some(json) => {
+            serde_json::from_str(json).map_err(|e| format!("Invalid config JSON: {}", e))?
+        }
+        None => Default::default(),
+    };
+
+    let findings = resume_core::lint::lint_resume(&data, &config);
+    serde_json::to_string(&findings).map_err(|e| format!("Serialization failed: {}", e))
+}
+
+/// Lint bullet descriptions and summaries for the editor
+///
+/// Returns a JSON array of findings in document order, each with `rule`,
+/// `severity`, `path` (e.g. `experience[0].children[1].children[2].description`),
+/// `itemId`, `message` and, for duplicates and repeated openings, `relatedPath`.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const findings = JSON.parse(
+///   lint_resume(JSON.stringify(resumeData), JSON.stringify({ severities: { "missing-metric": "off" } }))
+/// );
+/// ```
+#[wasm_bindgen]
+pub fn lint_resume(resume_json: &str, config_json: Option<String>) -> Result<String, JsValue> {
+    lint_resume_internal(resume_json, config_json.as_deref()).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Summary selection for a role profile (WASM-agnostic)
+///
+/// Takes ResumeData JSON and RoleProfile JSON and returns `SelectedSummary`
+/// JSON, or `null` when the compendium has no summary at all.
+fn select_summary_internal(resume_json: &str, role_profile_json: &str) -> Result<String, String> {
+    let data: shared_types::ResumeData =
+        serde_json::from_str(resume_json).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let role_profile: shared_types::RoleProfile = serde_json::from_str(role_profile_json)
+        .map_err(|e| format!("Invalid role profile JSON: {}", e))?;
+
+    let selected = resume_core::summary::select_summary(&data, &role_profile);
+    serde_json::to_string(&selected).map_err(|e| format!("Serialization failed: {}", e))
+}
+
+/// Pick the summary candidate that best matches a role profile
+///
+/// Honours the profile's `summaryId` override, otherwise scores tagged
+/// candidates like bullets and falls back to `ResumeData.summary`.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const selected = JSON.parse(select_summary(JSON.stringify(resumeData), JSON.stringify(roleProfile)));
+/// payload.summary = selected?.text;
+/// ```
+#[wasm_bindgen]
+pub fn select_summary(resume_json: &str, role_profile_json: &str) -> Result<String, JsValue> {
+    select_summary_internal(resume_json, role_profile_json).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Tag-weight learning from bullet feedback (WASM-agnostic)
+///
+/// Takes ResumeData, RoleProfile and `BulletFeedback` JSON plus optional
+/// `LearnOptions` JSON and returns `LearnedProfile` JSON.
+fn learn_tag_weights_internal(
+    resume_json: &str,
+    role_profile_json: &str,
+    feedback_json: &str,
+    options_json: Option<&str>,
+) -> Result<String, String> {
+    let data: shared_types::ResumeData =
+        serde_json::from_str(resume_json).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let role_profile: shared_types::RoleProfile = serde_json::from_str(role_profile_json)
+        .map_err(|e| format!("Invalid role profile JSON: {}", e))?;
+    let feedback: resume_core::tag_learning::BulletFeedback =
+        serde_json::from_str(feedback_json).map_err(|e| format!("Invalid feedback JSON: {}", e))?;
+    let options: resume_core::tag_learning::LearnOptions = match options_json {
+        Some(json) => {
+            serde_json::from_str(json).map_err(|e| format!("Invalid options JSON: {}", e))?
+        }
+        None => Default::default(),
+    };
+
+    let learned =
+        resume_core::tag_learning::learn_tag_weights(&data, &role_profile, &feedback, &options);
+    serde_json::to_string(&learned).map_err(|e| format!("Serialization failed: {}", e))
+}
+
+/// Refit a role profile's tag weights from kept and swapped-out bullets
+///
+/// Returns JSON with the updated `profile`, the per-tag `changes` (largest
+/// first), example counts, training log loss and any unknown or conflicting
+/// IDs.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const learned = JSON.parse(
+///   learn_tag_weights(
+///     JSON.stringify(resumeData),
+///     JSON.stringify(roleProfile),
+///     JSON.stringify({ accepted: keptIds, rejected: swappedIds }),
+///   )
+/// );
+/// learned.changes; // [{ tag, before, after, coefficient }]
+/// ```
+#[wasm_bindgen]
+pub fn learn_tag_weights(
+    resume_json: &str,
+    role_profile_json: &str,
+    feedback_json: &str,
+    options_json: Option<String>,
+) -> Result<String, JsValue> {
+    learn_tag_weights_internal(
+        resume_json,
+        role_profile_json,
+        feedback_json,
+        options_json.as_deref(),
+    )
+    .map_err(|e| JsValue::from_str(&e))
+}
+
+/// Bullet search (WASM-agnostic)
+///
+/// Takes ResumeData JSON, a query string and optional `SearchOptions` JSON
+/// and returns a JSON array of `SearchHit`.
+fn search_bullets_internal(
+    resume_json: &str,
+    query: &str,
+    options_json: Option<&str>,
+) -> Result<String, String> {
+    let data: shared_types::ResumeData =
+        serde_json::from_str(resume_json).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let options: resume_core::search::SearchOptions = match options_json {
+        Some(json) => {
+            serde_json::from_str(json).map_err(|e| format!("Invalid options JSON: {}", e))?
+        }
+        None => Default::default(),
+    };
+
+    let hits = resume_core::search::search(&data, query, &options)
+        .map_err(|e| format!("Invalid query: {}", e))?;
+    serde_json::to_string(&hits).map_err(|e| format!("Serialization failed: {}", e))
+}
+
+/// Full-text search over bullets for the site's search box
+///
+/// Supports free text, `"quoted phrases"`, `tag:`, `company:`, `after:`,
+/// `before:` and `priority>=N`. Each hit carries its `company` and
+/// `position` (id + name), `path` and BM25 `score`.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const hits = JSON.parse(
+///   search_bullets(JSON.stringify(resumeData), 'tag:kafka after:2020 "migration"', JSON.stringify({ limit: 10 }))
+/// );
+/// ```
+#[wasm_bindgen]
+pub fn search_bullets(
+    resume_json: &str,
+    query: &str,
+    options_json: Option<String>,
+) -> Result<String, JsValue> {
+    search_bullets_internal(resume_json, query, options_json.as_deref())
+        .map_err(|e| JsValue::from_str(&e))
+}
+
+/// Ensemble bullet scoring (WASM-agnostic)
+///
+/// Takes ResumeData JSON, RoleProfile JSON, a JSON object of external scores
+/// keyed by bullet ID and optional `EnsembleWeights` JSON, and returns a JSON
+/// array of `ScoredBullet`.
+fn score_bullets_ensemble_internal(
+    resume_json: &str,
+    role_profile_json: &str,
+    external_scores_json: &str,
+    weights_json: Option<&str>,
+) -> Result<String, String> {
+    let data: shared_types::ResumeData =
+        serde_json::from_str(resume_json).map_err(|e| format!("Invalid JSON: {}", e))?;
+    let role_profile: shared_types::RoleProfile = serde_json::from_str(role_profile_json)
+        .map_err(|e| format!("Invalid role profile JSON: {}", e))?;
+    let external: std::collections::HashMap<String, f32> =
+        serde_json::from_str(external_scores_json)
+            .map_err(|e| format!("Invalid external scores JSON: {}", e))?;
+    let weights: resume_core::scorer::EnsembleWeights = match weights_json {
+        Some(json) => {
+            serde_json::from_str(json).map_err(|e| format!("Invalid weights JSON: {}", e))?
+        }
+        None => Default::default(),
+    };
+
+    let scorer = resume_core::scorer::EnsembleScorer::refine(
+        &role_profile,
+        resume_core::scorer::ExternalScores::from_pairs(external),
+        weights,
+    );
+    let scored = resume_core::scorer::score_candidates(&data, &scorer);
+    serde_json::to_string(&scored).map_err(|e| format!("Serialization failed: {}", e))
+}
+
+/// Blend heuristic scores with external (e.g. AI) scores
+///
+/// Each bullet gets the weighted mean of its normalized heuristic score and
+/// its external score, so bullets the AI skipped keep their heuristic score.
+/// Weights default to `{ heuristic: 0.3, external: 0.7 }`. Returns a JSON
+/// array of `ScoredBullet`, highest score first.
+///
+/// # Example (JavaScript)
+/// ```js
+/// const scored = JSON.parse(
+///   score_bullets_ensemble(
+///     JSON.stringify(resumeData),
+///     JSON.stringify(roleProfile),
+///     JSON.stringify({ b1: 0.9, b2: 0.4 }),
+///     JSON.stringify({ heuristic: 0.5, external: 0.5 }),
+///   )
+/// );
+/// ```
+#[wasm_bindgen]
+pub fn score_bullets_ensemble(
+    resume_json: &str,
+    role_profile_json: &str,
+    external_scores_json: &str,
+    weights_json: Option<String>,
+) -> Result<String, JsValue> {
+    score_bullets_ensemble_internal(
+        resume_json,
+        role_profile_json,
+        external_scores_json,
+        weights_json.as_deref(),
+    )
+    .map_err(|e| JsValue::from_str(&e))
+}
+
 /// Get estimated PDF size in bytes (for progress UI)
 #[wasm_bindgen]
 pub fn estimate_pdf_size(bullet_count: usize) -> usize {
@@ -196,6 +833,7 @@ mod tests {
                     tag_relevance: 0.6,
                     priority: 0.4,
                 },
+                summary_id: None,
             },
             education: None,
             skills: None,
@@ -372,7 +1010,7 @@ mod tests {
         let payload = create_test_payload();
         let json = serde_json::to_string(&payload).unwrap();
 
-        let result = generate_pdf_typst(&json, false);
+        let result = generate_pdf_typst(&json, None);
         assert!(result.is_ok());
 
         let pdf_bytes = result.unwrap();
@@ -388,7 +1026,7 @@ mod tests {
         let payload = create_test_payload();
         let json = serde_json::to_string(&payload).unwrap();
 
-        let result = generate_pdf_typst(&json, true);
+        let result = generate_pdf_typst(&json, Some(r#"{"debug": true}"#.to_string()));
         assert!(result.is_ok());
 
         let pdf_bytes = result.unwrap();
@@ -399,7 +1037,7 @@ mod tests {
     #[test]
     #[cfg(target_arch = "wasm32")]
     fn test_generate_pdf_typst_invalid_json() {
-        let result = generate_pdf_typst("{invalid}", false);
+        let result = generate_pdf_typst("{invalid}", None);
         assert!(result.is_err());
     }
 
@@ -410,7 +1048,7 @@ mod tests {
         payload.personal.name = "".to_string();
         let json = serde_json::to_string(&payload).unwrap();
 
-        let result = generate_pdf_typst(&json, false);
+        let result = generate_pdf_typst(&json, None);
         assert!(result.is_err());
     }
 
@@ -466,6 +1104,7 @@ mod tests {
                     tag_relevance: 0.65,
                     priority: 0.35,
                 },
+                summary_id: None,
             },
             education: Some(vec![shared_types::Education {
                 degree: "BSc Computer Science".to_string(),
@@ -496,7 +1135,7 @@ mod tests {
         };
 
         let json = serde_json::to_string(&payload).unwrap();
-        let result = generate_pdf_typst(&json, false);
+        let result = generate_pdf_typst(&json, None);
 
         assert!(result.is_ok(), "Should generate PDF with complex payload");
         let pdf_bytes = result.unwrap();
@@ -760,6 +1399,7 @@ mod tests {
                     tag_relevance: 0.65,
                     priority: 0.35,
                 },
+                summary_id: None,
             },
             education: Some(vec![shared_types::Education {
                 degree: "BSc Computer Science".to_string(),
@@ -801,4 +1441,481 @@ mod tests {
         let result = validate_payload_internal(&payload);
         assert!(result.is_ok());
     }
+
+    // ========== Tag Analytics Tests ==========
+
+    #[test]
+    fn test_analyze_tags_internal() {
+        let resume_json = r#"{
+            "personal": {"name": "Test Person"},
+            "experience": [{
+                "id": "acme",
+                "name": "Acme",
+                "dateStart": "2020-01",
+                "tags": [],
+                "priority": 8,
+                "children": [{
+                    "id": "acme-eng",
+                    "name": "Engineer",
+                    "dateStart": "2020-01",
+                    "tags": [],
+                    "priority": 8,
+                    "children": [
+                        {"id": "b1", "description": "Rust", "tags": ["rust", "backend"], "priority": 9},
+                        {"id": "b2", "description": "More Rust", "tags": ["rust"], "priority": 7}
+                    ]
+                }]
+            }]
+        }"#;
+
+        let json = analyze_tags_internal(resume_json).unwrap();
+        let analytics: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(analytics["tags"][0]["tag"], "rust");
+        assert_eq!(analytics["tags"][0]["bulletCount"], 2);
+        assert_eq!(analytics["orphanTags"], serde_json::json!(["backend"]));
+    }
+
+    #[test]
+    fn test_analyze_tags_internal_invalid_json() {
+        let result = analyze_tags_internal("{not json");
+        assert!(result.unwrap_err().starts_with("Invalid JSON"));
+    }
+
+    // ========== Timeline Tests ==========
+
+    #[test]
+    fn test_analyze_timeline_internal() {
+        let resume_json = r#"{
+            "personal": {"name": "Test Person"},
+            "experience": [
+                {
+                    "id": "old", "dateStart": "2018-01", "dateEnd": "2019-01", "tags": [], "priority": 5,
+                    "children": [{"id": "old-eng", "name": "Engineer", "dateStart": "2018-01", "dateEnd": "2019-01", "tags": [], "priority": 5, "children": []}]
+                },
+                {
+                    "id": "new", "dateStart": "2020-01", "tags": [], "priority": 5,
+                    "children": [
+                        {"id": "new-eng", "name": "Engineer", "dateStart": "2020-01", "tags": [], "priority": 5, "children": []},
+                        {"id": "new-adv", "name": "Advisor", "dateStart": "2020-01", "concurrent": true, "tags": [], "priority": 5, "children": []}
+                    ]
+                }
+            ]
+        }"#;
+
+        let json = analyze_timeline_internal(resume_json, Some(r#"{"asOf": "2020-12"}"#)).unwrap();
+        let report: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(report["gaps"][0]["months"], 11);
+        assert_eq!(report["overlaps"], serde_json::json!([]));
+        assert_eq!(report["tenure"][1]["months"], 12);
+
+        let defaults = analyze_timeline_internal(resume_json, None).unwrap();
+        assert!(defaults.contains("\"gaps\""));
+    }
+
+    #[test]
+    fn test_analyze_timeline_internal_invalid_options() {
+        let result = analyze_timeline_internal(
+            r#"{"personal": {"name": "X"}, "experience": []}"#,
+            Some(r#"{"gapThresholdMonths": -1}"#),
+        );
+        assert!(result.unwrap_err().starts_with("Invalid options JSON"));
+    }
+
+    // ========== Lint Tests ==========
+
+    #[test]
+    fn test_lint_resume_internal() {
+        let resume_json = r#"{
+            "personal": {"name": "Test Person"},
+            "experience": [{
+                "id": "acme", "dateStart": "2020-01", "tags": [], "priority": 5,
+                "children": [{
+                    "id": "acme-eng", "name": "Engineer", "dateStart": "2020-01", "tags": [], "priority": 5,
+                    "children": [
+                        {"id": "b1", "description": "Helped with the build system", "tags": [], "priority": 5}
+                    ]
+                }]
+            }]
+        }"#;
+
+        let json = lint_resume_internal(
+            resume_json,
+            Some(r#"{"severities": {"weak-opening": "error"}}"#),
+        )
+        .unwrap();
+        let findings: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(findings[0]["rule"], "missing-metric");
+        assert_eq!(findings[1]["rule"], "weak-opening");
+        assert_eq!(findings[1]["severity"], "error");
+        assert_eq!(
+            findings[1]["path"],
+            "experience[0].children[0].children[0].description"
+        );
+        assert_eq!(findings[1]["itemId"], "b1");
+    }
+
+    #[test]
+    fn test_lint_resume_internal_invalid_config() {
+        let result = lint_resume_internal(
+            r#"{"personal": {"name": "X"}, "experience": []}"#,
+            Some(r#"{"severities": {"no-such-rule": "off"}}"#),
+        );
+        assert!(result.unwrap_err().starts_with("Invalid config JSON"));
+    }
+
+    // ========== Summary Selection Tests ==========
+
+    #[test]
+    fn test_select_summary_internal() {
+        let resume_json = r#"{
+            "personal": {"name": "Test Person"},
+            "summary": "Generic summary",
+            "summaries": [
+                {"id": "ic", "text": "Hands-on Rust engineer", "tags": ["rust"], "priority": 5},
+                {"id": "lead", "text": "Engineering leader", "tags": ["leadership"], "priority": 5}
+            ],
+            "experience": []
+        }"#;
+        let role_json = r#"{
+            "id": "manager", "name": "Manager",
+            "tagWeights": {"leadership": 1.0},
+            "scoringWeights": {"tagRelevance": 0.6, "priority": 0.4}
+        }"#;
+
+        let json = select_summary_internal(resume_json, role_json).unwrap();
+        let selected: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(selected["id"], "lead");
+        assert_eq!(selected["text"], "Engineering leader");
+        assert_eq!(selected["source"], "scored");
+    }
+
+    #[test]
+    fn test_select_summary_internal_without_summary() {
+        let role_json = r#"{
+            "id": "r", "name": "R", "tagWeights": {},
+            "scoringWeights": {"tagRelevance": 0.6, "priority": 0.4}
+        }"#;
+        let json = select_summary_internal(
+            r#"{"personal": {"name": "X"}, "experience": []}"#,
+            role_json,
+        )
+        .unwrap();
+        assert_eq!(json, "null");
+
+        let result =
+            select_summary_internal(r#"{"personal": {"name": "X"}, "experience": []}"#, "{}");
+        assert!(result.unwrap_err().starts_with("Invalid role profile JSON"));
+    }
+
+    // ========== Tag Weight Learning Tests ==========
+
+    const LEARNING_RESUME: &str = r#"{
+        "personal": {"name": "Test Person"},
+        "experience": [{
+            "id": "acme", "dateStart": "2020-01", "tags": [], "priority": 8,
+            "children": [{
+                "id": "eng", "name": "Engineer", "dateStart": "2020-01", "tags": [], "priority": 8,
+                "children": [
+                    {"id": "b1", "description": "Built Rust services", "tags": ["rust"], "priority": 7},
+                    {"id": "b2", "description": "Shipped the redesign", "tags": ["frontend"], "priority": 7}
+                ]
+            }]
+        }]
+    }"#;
+
+    const LEARNING_ROLE: &str = r#"{
+        "id": "backend", "name": "Backend",
+        "tagWeights": {"rust": 0.5, "frontend": 0.5},
+        "scoringWeights": {"tagRelevance": 0.6, "priority": 0.4}
+    }"#;
+
+    #[test]
+    fn test_learn_tag_weights_internal() {
+        let json = learn_tag_weights_internal(
+            LEARNING_RESUME,
+            LEARNING_ROLE,
+            r#"{"accepted": ["b1"], "rejected": ["b2", "missing"]}"#,
+            None,
+        )
+        .unwrap();
+        let learned: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert!(learned["profile"]["tagWeights"]["rust"].as_f64().unwrap() > 0.5);
+        assert!(
+            learned["profile"]["tagWeights"]["frontend"]
+                .as_f64()
+                .unwrap()
+                < 0.5
+        );
+        assert_eq!(learned["changes"].as_array().unwrap().len(), 2);
+        assert_eq!(learned["unknownIds"], serde_json::json!(["missing"]));
+    }
+
+    #[test]
+    fn test_learn_tag_weights_internal_invalid_feedback() {
+        let result = learn_tag_weights_internal(
+            LEARNING_RESUME,
+            LEARNING_ROLE,
+            r#"{"accepted": "b1"}"#,
+            None,
+        );
+        assert!(result.unwrap_err().starts_with("Invalid feedback JSON"));
+    }
+
+    // ========== Search Tests ==========
+
+    #[test]
+    fn test_search_bullets_internal() {
+        let json = search_bullets_internal(
+            LEARNING_RESUME,
+            r#"tag:rust "rust services""#,
+            Some(r#"{"limit": 5}"#),
+        )
+        .unwrap();
+        let hits: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(hits.as_array().unwrap().len(), 1);
+        assert_eq!(hits[0]["bulletId"], "b1");
+        assert_eq!(hits[0]["company"]["id"], "acme");
+        assert_eq!(hits[0]["position"]["name"], "Engineer");
+    }
+
+    #[test]
+    fn test_search_bullets_internal_invalid_query() {
+        let result = search_bullets_internal(LEARNING_RESUME, "priority>=eleven", None);
+        assert!(result.unwrap_err().starts_with("Invalid query"));
+    }
+
+    // ========== Ensemble Scoring Tests ==========
+
+    #[test]
+    fn test_score_bullets_ensemble_internal() {
+        let role = r#"{
+            "id": "rust", "name": "Rust",
+            "tagWeights": {"rust": 1.0},
+            "scoringWeights": {"tagRelevance": 0.6, "priority": 0.4}
+        }"#;
+        let external = r#"{"b2": 1.0}"#;
+
+        let json = score_bullets_ensemble_internal(LEARNING_RESUME, role, external, None).unwrap();
+        let scored: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(scored.as_array().unwrap().len(), 2);
+        assert_eq!(scored[0]["bullet"]["id"], "b2");
+
+        let json = score_bullets_ensemble_internal(
+            LEARNING_RESUME,
+            role,
+            external,
+            Some(r#"{"heuristic": 1.0, "external": 0.0}"#),
+        )
+        .unwrap();
+        let scored: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(scored[0]["bullet"]["id"], "b1");
+    }
+
+    #[test]
+    fn test_score_bullets_ensemble_internal_invalid_scores() {
+        let result =
+            score_bullets_ensemble_internal(LEARNING_RESUME, LEARNING_ROLE, r#"["b1"]"#, None);
+        assert!(result
+            .unwrap_err()
+            .starts_with("Invalid external scores JSON"));
+    }
+
+    // ========== Template Tests ==========
+
+    #[test]
+    fn test_list_templates_internal() {
+        let json = list_templates_internal().unwrap();
+        let templates: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let names: Vec<&str> = templates
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["name"].as_str().unwrap())
+            .collect();
+
+        assert_eq!(names, ["classic", "modern", "compact", "academic"]);
+        assert!(templates[1]["thumbnail"]
+            .as_str()
+            .unwrap()
+            .starts_with("<svg"));
+    }
+
+    #[test]
+    fn test_parse_render_options() {
+        let mut payload = create_test_payload();
+        assert_eq!(
+            parse_render_options(&payload, None).unwrap(),
+            shared_types::RenderOptions::default()
+        );
+
+        let options = parse_render_options(&payload, Some(r#"{"template": "compact"}"#)).unwrap();
+        assert_eq!(options.template, "compact");
+
+        assert!(parse_render_options(&payload, Some("{invalid}"))
+            .unwrap_err()
+            .starts_with("Invalid options JSON"));
+
+        // Recorded options are reused when none are passed
+        payload.metadata = Some(shared_types::GenerationMetadata {
+            generation_id: "gen-1".to_string(),
+            timestamp: 1_700_000_000,
+            selected_bullet_ids: vec![],
+            role_profile_id: "test".to_string(),
+            source_profiles: None,
+            selection_overrides: None,
+            render_options: Some(options.clone()),
+        });
+        assert_eq!(parse_render_options(&payload, None).unwrap(), options);
+    }
+
+    // ========== Template Bundle Tests ==========
+
+    fn zip_bundle(files: &[(&str, &str)]) -> Vec<u8> {
+        use std::io::Write;
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for (path, data) in files {
+            writer
+                .start_file(*path, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(data.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_generate_pdf_typst_bundle_internal() {
+        let payload_json = serde_json::to_string(&create_test_payload()).unwrap();
+        let bundle = zip_bundle(&[
+            (
+                "acme/main.typ",
+                "#import \"style.typ\": title\n#let data = json(\"data.json\")\n#title(data.personal.name)",
+            ),
+            ("acme/style.typ", "#let title(name) = text(size: 20pt, name)"),
+        ]);
+
+        let pdf = generate_pdf_typst_bundle_internal(&payload_json, &bundle, None).unwrap();
+        assert_eq!(&pdf[0..4], b"%PDF");
+
+        let err = generate_pdf_typst_bundle_internal(&payload_json, b"nope", None).unwrap_err();
+        assert!(err.starts_with("Invalid template bundle"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_template_bundle_internal() {
+        let bundle = zip_bundle(&[
+            ("template.json", r#"{"name": "Acme", "main": "cv.typ"}"#),
+            ("cv.typ", "#let data = json(\"data.json\")\n#data.summary"),
+        ]);
+        let summary: serde_json::Value =
+            serde_json::from_str(&validate_template_bundle_internal(&bundle).unwrap()).unwrap();
+        assert_eq!(summary["name"], "Acme");
+        assert_eq!(summary["main"], "cv.typ");
+        assert_eq!(summary["files"].as_array().unwrap().len(), 2);
+
+        let bundle = zip_bundle(&[("main.typ", "#import \"@preview/cetz:0.3.0\": canvas")]);
+        let err = validate_template_bundle_internal(&bundle).unwrap_err();
+        assert!(err.contains("package '@preview/cetz:0.3.0'"), "{}", err);
+    }
+
+    #[test]
+    fn test_template_data_schema_is_json() {
+        let schema: serde_json::Value = serde_json::from_str(&template_data_schema()).unwrap();
+        assert_eq!(schema["title"], "TemplateData");
+    }
+
+    // ========== Diagnostics Tests ==========
+
+    #[test]
+    fn test_typst_diagnostics_internal_points_at_bullet() {
+        let mut payload = create_test_payload();
+        payload.selected_bullets[0].bullet.description = "Led \u{2603} migration".to_string();
+        let payload_json = serde_json::to_string(&payload).unwrap();
+
+        let json = typst_diagnostics_internal(&payload_json, None).unwrap();
+        let diagnostics: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let snowman = diagnostics
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|d| d["message"].as_str().unwrap().contains("U+2603"))
+            .unwrap_or_else(|| panic!("{}", json));
+
+        assert_eq!(snowman["severity"], "warning");
+        assert_eq!(
+            snowman["fields"],
+            serde_json::json!(["selected_bullets[0].bullet.description"])
+        );
+    }
+
+    // ========== PDF Metadata Tests ==========
+
+    #[test]
+    fn test_read_pdf_metadata_internal() {
+        let mut payload = create_test_payload();
+        payload.metadata = Some(shared_types::GenerationMetadata {
+            generation_id: "gen-7".to_string(),
+            timestamp: 1_700_000_000,
+            selected_bullet_ids: vec!["bullet-1".to_string()],
+            role_profile_id: payload.role_profile.id.clone(),
+            source_profiles: None,
+            selection_overrides: None,
+            render_options: None,
+        });
+        let pdf =
+            resume_typst::render_resume(&payload, &shared_types::RenderOptions::default()).unwrap();
+
+        let json = read_pdf_metadata_internal(&pdf).unwrap();
+        let metadata: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(metadata["generationId"], "gen-7");
+        assert_eq!(metadata["bulletIds"], serde_json::json!(["bullet-1"]));
+        assert_eq!(metadata["created"], 1_700_000_000);
+
+        assert!(read_pdf_metadata_internal(b"not a pdf").is_err());
+    }
+
+    // ========== Preview Tests ==========
+
+    #[test]
+    fn test_render_preview_internal() {
+        let payload_json = serde_json::to_string(&create_test_payload()).unwrap();
+
+        let json = render_preview_internal(&payload_json, None, None).unwrap();
+        let pages: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let first = &pages[0];
+        assert!(first["dataUrl"]
+            .as_str()
+            .unwrap()
+            .starts_with("data:image/png;base64,iVBORw0KGgo"));
+        assert_eq!(first["width"], 612.0);
+
+        let json =
+            render_preview_internal(&payload_json, None, Some(r#"{"format": "svg"}"#)).unwrap();
+        assert!(json.contains("data:image/svg+xml;base64,"));
+
+        let error =
+            render_preview_internal(&payload_json, None, Some(r#"{"format": "gif"}"#)).unwrap_err();
+        assert!(error.contains("Invalid preview options JSON"));
+    }
+
+    #[test]
+    fn test_generate_pdf_with_preview_internal() {
+        let payload_json = serde_json::to_string(&create_test_payload()).unwrap();
+
+        let (pdf, json) =
+            generate_pdf_with_preview_internal(&payload_json, None, Some(r#"{"format": "svg"}"#))
+                .unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+        assert_eq!(
+            json,
+            render_preview_internal(&payload_json, None, Some(r#"{"format": "svg"}"#)).unwrap()
+        );
+
+        let error = generate_pdf_with_preview_internal("{", None, None).unwrap_err();
+        assert!(error.contains("Invalid JSON payload"));
+    }
 }
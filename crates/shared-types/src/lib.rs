@@ -152,6 +152,15 @@ pub struct Position {
     ))]
     pub date_end: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(
+            description = "Held alongside other roles (advisory, board, part-time), so overlaps with it are expected (optional)"
+        )
+    )]
+    pub concurrent: Option<bool>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     #[cfg_attr(feature = "schema", schemars(
         description = "Brief role summary (optional)",
@@ -443,6 +452,15 @@ pub struct RoleProfile {
         schemars(description = "Weights for scoring algorithm components (required)")
     )]
     pub scoring_weights: ScoringWeights,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(
+            description = "ID of the summary candidate to use for this role, bypassing tag scoring (optional)"
+        )
+    )]
+    pub summary_id: Option<String>,
 }
 
 #[cfg(feature = "schema")]
@@ -530,6 +548,192 @@ impl ScoringWeights {
     }
 }
 
+impl RoleProfile {
+    /// Blend several role profiles into a hybrid profile
+    ///
+    /// For postings like "Staff Engineer / Developer Relations". Blend weights
+    /// are normalized to sum to 1.0, then:
+    /// - `tag_weights` become the weighted mean across profiles (a tag missing
+    ///   from a profile counts as 0.0 for that profile)
+    /// - `scoring_weights` become the weighted mean, passed through
+    ///   [`ScoringWeights::normalize`]
+    ///
+    /// The returned sources should be stored in
+    /// [`GenerationMetadata::source_profiles`] so the blend can be reproduced.
+    pub fn blend(sources: &[(RoleProfile, f32)]) -> Result<BlendedRoleProfile, String> {
+        if sources.is_empty() {
+            return Err("Cannot blend an empty list of role profiles".to_string());
+        }
+        if let Some((profile, weight)) = sources.iter().find(|(_, w)| !w.is_finite() || *w < 0.0) {
+            return Err(format!(
+                "Role profile '{}': blend weight must be a non-negative number, got {}",
+                profile.id, weight
+            ));
+        }
+
+        let total: f32 = sources.iter().map(|(_, w)| w).sum();
+        if total <= 0.0 {
+            return Err("Blend weights must not all be zero".to_string());
+        }
+
+        // Zero-weight profiles contribute nothing and are left out of the record
+        let contributing: Vec<(&RoleProfile, f32)> = sources
+            .iter()
+            .filter(|(_, weight)| *weight > 0.0)
+            .map(|(profile, weight)| (profile, weight / total))
+            .collect();
+
+        let mut tag_weights: HashMap<Tag, f32> = HashMap::new();
+        let mut tag_relevance = 0.0;
+        let mut priority = 0.0;
+        for (profile, share) in &contributing {
+            for (tag, tag_weight) in &profile.tag_weights {
+                *tag_weights.entry(tag.clone()).or_insert(0.0) += tag_weight * share;
+            }
+            tag_relevance += profile.scoring_weights.tag_relevance * share;
+            priority += profile.scoring_weights.priority * share;
+        }
+        tag_weights.retain(|_, weight| *weight > 0.0);
+
+        let (scoring_weights, _) = ScoringWeights {
+            tag_relevance,
+            priority,
+        }
+        .normalize();
+
+        let join = |part: fn(&RoleProfile) -> &str, separator: &str| {
+            contributing
+                .iter()
+                .map(|(profile, _)| part(profile))
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+        let description = contributing
+            .iter()
+            .map(|(profile, share)| format!("{} ({:.0}%)", profile.name, share * 100.0))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        // A summary override only survives when every contributor agrees on it
+        let shared_summary_id = contributing
+            .first()
+            .and_then(|(first, _)| first.summary_id.clone())
+            .filter(|id| {
+                contributing
+                    .iter()
+                    .all(|(profile, _)| profile.summary_id.as_ref() == Some(id))
+            });
+
+        Ok(BlendedRoleProfile {
+            profile: RoleProfile {
+                id: join(|p| &p.id, "+"),
+                name: join(|p| &p.name, " / "),
+                description: Some(format!("Blend of {}", description)),
+                tag_weights,
+                scoring_weights,
+                summary_id: shared_summary_id,
+            },
+            sources: contributing
+                .iter()
+                .map(|(profile, share)| ProfileBlendSource {
+                    profile_id: profile.id.clone(),
+                    weight: *share,
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Result of [`RoleProfile::blend`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlendedRoleProfile {
+    /// Hybrid profile ready for selection
+    pub profile: RoleProfile,
+    /// Contributing profiles with normalized blend weights
+    pub sources: Vec<ProfileBlendSource>,
+}
+
+/// One input to a blended role profile
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileBlendSource {
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "ID of the source role profile (required)")
+    )]
+    pub profile_id: String,
+
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Normalized blend weight 0.0-1.0 (required)")
+    )]
+    pub weight: f32,
+}
+
+// =============================================================================
+// SUMMARY CANDIDATES
+// =============================================================================
+
+/// Tagged professional summary
+///
+/// A compendium can hold several summaries aimed at different roles. The one
+/// shown on a generated resume is picked with the same tag-weight scoring as
+/// bullets, unless the role profile names one via `summaryId`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SummaryCandidate {
+    #[cfg_attr(feature = "schema", schemars(
+        description = "Unique identifier (required)",
+        example = summary_id_example()
+    ))]
+    pub id: String,
+
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Summary text, 2-3 sentences (required)")
+    )]
+    pub text: String,
+
+    #[cfg_attr(feature = "schema", schemars(
+        description = "Category tags for scoring against role profiles (required)",
+        example = tags_example()
+    ))]
+    pub tags: Vec<Tag>,
+
+    #[cfg_attr(feature = "schema", schemars(
+        description = "Fallback ranking 1-10 when tags tie, higher = preferred (required)",
+        range(min = 1, max = 10),
+        example = priority_example()
+    ))]
+    pub priority: u8,
+}
+
+#[cfg(feature = "schema")]
+fn summary_id_example() -> &'static str {
+    "summary-platform"
+}
+
+impl SummaryCandidate {
+    /// Validate summary has an ID, text and a 1-10 priority
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.is_empty() {
+            return Err("Summary ID cannot be empty".to_string());
+        }
+        if self.priority < 1 || self.priority > 10 {
+            return Err(format!(
+                "Summary '{}': priority must be 1-10, got {}",
+                self.id, self.priority
+            ));
+        }
+        if self.text.trim().is_empty() {
+            return Err(format!("Summary '{}': text cannot be empty", self.id));
+        }
+        Ok(())
+    }
+}
+
 // =============================================================================
 // TOP-LEVEL RESUME DATA
 // =============================================================================
@@ -555,6 +759,15 @@ pub struct ResumeData {
     )]
     pub summary: Option<String>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(
+            description = "Tagged summary candidates, scored per role like bullets; falls back to summary when absent (optional)"
+        )
+    )]
+    pub summaries: Option<Vec<SummaryCandidate>>,
+
     #[cfg_attr(
         feature = "schema",
         schemars(description = "List of Company objects - work experience (required)")
@@ -616,6 +829,20 @@ impl ResumeData {
                 .map_err(|e| format!("Experience[{}]: {}", i, e))?;
         }
 
+        // Validate summary candidates
+        let summaries = self.summaries.as_deref().unwrap_or_default();
+        for (i, candidate) in summaries.iter().enumerate() {
+            candidate
+                .validate()
+                .map_err(|e| format!("Summaries[{}]: {}", i, e))?;
+            if summaries[..i].iter().any(|c| c.id == candidate.id) {
+                return Err(format!(
+                    "Summaries[{}]: duplicate summary ID '{}'",
+                    i, candidate.id
+                ));
+            }
+        }
+
         // Validate role profiles
         if let Some(profiles) = &self.role_profiles {
             for (i, profile) in profiles.iter().enumerate() {
@@ -623,6 +850,14 @@ impl ResumeData {
                     .scoring_weights
                     .validate()
                     .map_err(|e| format!("Role profile[{}] '{}': {}", i, profile.id, e))?;
+                if let Some(summary_id) = &profile.summary_id {
+                    if !summaries.iter().any(|c| &c.id == summary_id) {
+                        return Err(format!(
+                            "Role profile[{}] '{}': unknown summary '{}'",
+                            i, profile.id, summary_id
+                        ));
+                    }
+                }
             }
         }
 
@@ -964,4 +1199,579 @@ pub struct GenerationMetadata {
         schemars(description = "Role profile ID used (required)")
     )]
     pub role_profile_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Source profiles when the role profile is a blend (optional)")
+    )]
+    pub source_profiles: Option<Vec<ProfileBlendSource>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(
+            description = "Pinned/excluded bullets and companies applied during selection (optional)"
+        )
+    )]
+    pub selection_overrides: Option<SelectionOverrides>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Render options the PDF was produced with (optional)")
+    )]
+    pub render_options: Option<RenderOptions>,
+}
+
+/// Manual selection overrides for one application
+///
+/// Pinned bullets are always selected; excluded bullets and companies never
+/// are. An empty `included_company_ids` means every company is eligible.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SelectionOverrides {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Bullets always selected, bypassing scoring (optional)")
+    )]
+    pub pinned_bullet_ids: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Bullets never selected (optional)")
+    )]
+    pub excluded_bullet_ids: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Only these companies are eligible when non-empty (optional)")
+    )]
+    pub included_company_ids: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Companies never selected from (optional)")
+    )]
+    pub excluded_company_ids: Vec<String>,
+}
+
+impl SelectionOverrides {
+    /// True when no override is set
+    pub fn is_empty(&self) -> bool {
+        self.pinned_bullet_ids.is_empty()
+            && self.excluded_bullet_ids.is_empty()
+            && self.included_company_ids.is_empty()
+            && self.excluded_company_ids.is_empty()
+    }
+
+    /// Whether a company's bullets may be selected
+    pub fn allows_company(&self, company_id: &str) -> bool {
+        !self.excluded_company_ids.iter().any(|id| id == company_id)
+            && (self.included_company_ids.is_empty()
+                || self.included_company_ids.iter().any(|id| id == company_id))
+    }
+
+    /// Whether a bullet may be selected (exclusions beat pins)
+    pub fn allows(&self, bullet_id: &str, company_id: &str) -> bool {
+        !self.excluded_bullet_ids.iter().any(|id| id == bullet_id)
+            && self.allows_company(company_id)
+    }
+
+    /// Check every referenced ID exists and no pin is also excluded
+    pub fn validate(&self, data: &ResumeData) -> Result<(), String> {
+        let mut bullet_ids = HashMap::new();
+        for company in &data.experience {
+            for position in &company.children {
+                for bullet in &position.children {
+                    bullet_ids.insert(bullet.id.as_str(), company.id.as_str());
+                }
+            }
+        }
+        let company_exists = |id: &String| data.experience.iter().any(|c| &c.id == id);
+
+        for id in self
+            .pinned_bullet_ids
+            .iter()
+            .chain(&self.excluded_bullet_ids)
+        {
+            if !bullet_ids.contains_key(id.as_str()) {
+                return Err(format!("Selection overrides: unknown bullet '{}'", id));
+            }
+        }
+        for id in self
+            .included_company_ids
+            .iter()
+            .chain(&self.excluded_company_ids)
+        {
+            if !company_exists(id) {
+                return Err(format!("Selection overrides: unknown company '{}'", id));
+            }
+        }
+        for id in &self.pinned_bullet_ids {
+            if !self.allows(id, bullet_ids[id.as_str()]) {
+                return Err(format!(
+                    "Selection overrides: pinned bullet '{}' is also excluded",
+                    id
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// =============================================================================
+// RENDER OPTIONS
+// =============================================================================
+
+/// Paper size for the rendered PDF
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum PaperSize {
+    /// US Letter, 8.5 x 11 in
+    #[default]
+    Letter,
+    /// ISO A4, 210 x 297 mm
+    A4,
+    /// US Legal, 8.5 x 14 in
+    Legal,
+}
+
+/// Page margins in inches
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct Margins {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl Margins {
+    /// The same margin on every side
+    pub fn uniform(inches: f32) -> Self {
+        Self {
+            top: inches,
+            right: inches,
+            bottom: inches,
+            left: inches,
+        }
+    }
+}
+
+/// A main resume section that can be reordered or hidden
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum ResumeSection {
+    Summary,
+    Experience,
+    Education,
+    Skills,
+}
+
+impl ResumeSection {
+    /// Order used when no template-specific order applies
+    pub const ALL: [ResumeSection; 4] = [
+        ResumeSection::Summary,
+        ResumeSection::Experience,
+        ResumeSection::Education,
+        ResumeSection::Skills,
+    ];
+
+    /// Serialized name, as used in `RenderOptions::sections`
+    pub fn name(self) -> &'static str {
+        match self {
+            ResumeSection::Summary => "summary",
+            ResumeSection::Experience => "experience",
+            ResumeSection::Education => "education",
+            ResumeSection::Skills => "skills",
+        }
+    }
+}
+
+/// How month-year dates are printed
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum DateStyle {
+    /// "Jan 2020"
+    #[default]
+    Short,
+    /// "January 2020"
+    Long,
+    /// "01/2020"
+    Numeric,
+    /// "2020"
+    Year,
+}
+
+/// Embedded font family the document is set in
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum FontSet {
+    /// Liberation Serif (Times New Roman metrics)
+    #[default]
+    Serif,
+    /// DejaVu Sans
+    Sans,
+}
+
+/// PDF standard the output conforms to
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub enum PdfStandard {
+    /// Plain PDF 1.7
+    #[default]
+    #[serde(rename = "1.7")]
+    Pdf17,
+    /// PDF/A-2b for long-term archiving: embedded fonts, sRGB output intent
+    /// and XMP identification
+    #[serde(rename = "a-2b")]
+    PdfA2b,
+}
+
+/// Presentation choices for rendering a payload
+///
+/// Kept separate from [`GenerationPayload`] so the same content can be
+/// rendered in different layouts. Unset optional fields fall back to the
+/// template's own defaults. Record the options used in
+/// [`GenerationMetadata::render_options`] to reproduce a render.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", default)]
+pub struct RenderOptions {
+    #[cfg_attr(
+        feature = "schema",
+        schemars(
+            description = "Built-in template name: classic, modern, compact or academic (default: classic)"
+        )
+    )]
+    pub template: String,
+
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Paper size (default: letter)")
+    )]
+    pub paper: PaperSize,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Page margins in inches (optional, template default)")
+    )]
+    pub margins: Option<Margins>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Base font size in points (optional, template default)")
+    )]
+    pub font_size: Option<f32>,
+
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Line spacing multiplier (default: 1.0)")
+    )]
+    pub line_spacing: f32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(
+            description = "Sections to show, in order; omitted sections are hidden (optional, template default)"
+        )
+    )]
+    pub sections: Option<Vec<ResumeSection>>,
+
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Date format (default: short)")
+    )]
+    pub date_style: DateStyle,
+
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Embedded font family: serif or sans (default: serif)")
+    )]
+    pub font_set: FontSet,
+
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "PDF standard: 1.7 or a-2b for PDF/A-2b (default: 1.7)")
+    )]
+    pub pdf_standard: PdfStandard,
+
+    #[cfg_attr(
+        feature = "schema",
+        schemars(
+            description = "Document language as a BCP 47 tag, e.g. en or en-GB (default: en)"
+        )
+    )]
+    pub language: String,
+
+    #[cfg_attr(
+        feature = "schema",
+        schemars(
+            description = "Reader hints: link descriptions for screen readers, the title shown instead of the file name, and the full language tag. Not a tagged PDF (default: false)"
+        )
+    )]
+    pub reader_hints: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(
+            description = "Unix timestamp to render at: PDF creation date, datetime.today() and the debug page build time (optional, defaults to metadata.timestamp)"
+        )
+    )]
+    pub timestamp: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(
+            description = "Stable identifier hashed into the PDF /ID (optional, defaults to metadata.generationId)"
+        )
+    )]
+    pub document_id: Option<String>,
+
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Append a build metadata page (default: false)")
+    )]
+    pub debug: bool,
+}
+
+impl RenderOptions {
+    /// Name of the default built-in template
+    pub const DEFAULT_TEMPLATE: &'static str = "classic";
+
+    /// Accepted base font size range in points
+    pub const FONT_SIZE_RANGE: (f32, f32) = (6.0, 16.0);
+
+    /// Accepted line spacing multiplier range
+    pub const LINE_SPACING_RANGE: (f32, f32) = (0.5, 3.0);
+
+    /// Largest accepted margin in inches
+    pub const MAX_MARGIN: f32 = 3.0;
+
+    /// Default document language
+    pub const DEFAULT_LANGUAGE: &'static str = "en";
+
+    /// Language and optional region of [`RenderOptions::language`], e.g.
+    /// `("en", Some("GB"))` for `en-GB`
+    pub fn language_parts(&self) -> (&str, Option<&str>) {
+        match self.language.split_once('-') {
+            Some((language, region)) => (language, Some(region)),
+            None => (&self.language, None),
+        }
+    }
+
+    /// Timestamp to render at: [`RenderOptions::timestamp`], else the
+    /// generation's timestamp
+    ///
+    /// With a pinned clock and document ID the same payload always renders
+    /// to the same bytes. `None` means the wall clock is used.
+    pub fn clock(&self, metadata: Option<&GenerationMetadata>) -> Option<u64> {
+        self.timestamp.or(metadata.map(|m| m.timestamp))
+    }
+
+    /// PDF document identifier: [`RenderOptions::document_id`], else the
+    /// generation ID
+    pub fn document_id<'a>(&'a self, metadata: Option<&'a GenerationMetadata>) -> Option<&'a str> {
+        self.document_id
+            .as_deref()
+            .or(metadata.map(|m| m.generation_id.as_str()))
+    }
+
+    /// Check numeric ranges, the language tag and that no section is listed
+    /// twice
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(margins) = &self.margins {
+            for (side, value) in [
+                ("top", margins.top),
+                ("right", margins.right),
+                ("bottom", margins.bottom),
+                ("left", margins.left),
+            ] {
+                if !value.is_finite() || !(0.0..=Self::MAX_MARGIN).contains(&value) {
+                    return Err(format!(
+                        "Render options: {} margin must be between 0 and {} inches, got {}",
+                        side,
+                        Self::MAX_MARGIN,
+                        value
+                    ));
+                }
+            }
+        }
+
+        if let Some(size) = self.font_size {
+            let (min, max) = Self::FONT_SIZE_RANGE;
+            if !size.is_finite() || !(min..=max).contains(&size) {
+                return Err(format!(
+                    "Render options: font size must be between {}pt and {}pt, got {}",
+                    min, max, size
+                ));
+            }
+        }
+
+        let (min, max) = Self::LINE_SPACING_RANGE;
+        if !self.line_spacing.is_finite() || !(min..=max).contains(&self.line_spacing) {
+            return Err(format!(
+                "Render options: line spacing must be between {} and {}, got {}",
+                min, max, self.line_spacing
+            ));
+        }
+
+        let (language, region) = self.language_parts();
+        let language_ok =
+            (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_lowercase());
+        let region_ok =
+            region.is_none_or(|r| r.len() == 2 && r.chars().all(|c| c.is_ascii_uppercase()));
+        if !language_ok || !region_ok {
+            return Err(format!(
+                "Render options: language must look like 'en' or 'en-GB', got '{}'",
+                self.language
+            ));
+        }
+
+        if let Some(sections) = &self.sections {
+            for (i, section) in sections.iter().enumerate() {
+                if sections[..i].contains(section) {
+                    return Err(format!(
+                        "Render options: section '{}' is listed more than once",
+                        section.name()
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            template: Self::DEFAULT_TEMPLATE.to_string(),
+            paper: PaperSize::default(),
+            margins: None,
+            font_size: None,
+            line_spacing: 1.0,
+            sections: None,
+            date_style: DateStyle::default(),
+            font_set: FontSet::default(),
+            pdf_standard: PdfStandard::default(),
+            language: Self::DEFAULT_LANGUAGE.to_string(),
+            reader_hints: false,
+            timestamp: None,
+            document_id: None,
+            debug: false,
+        }
+    }
+}
+
+// =============================================================================
+// AI SELECTION OUTPUT
+// =============================================================================
+
+/// ISO 4217 currency codes (common subset) accepted in AI salary extraction
+///
+/// Mirrors `ISO_4217_CURRENCIES` in `lib/ai/output-parser-salary.ts`.
+pub const ISO_4217_CURRENCIES: &[&str] = &[
+    "USD", "EUR", "GBP", "JPY", "CNY", "CAD", "AUD", "CHF", "HKD", "SGD", "SEK", "NOK", "DKK",
+    "NZD", "MXN", "BRL", "INR", "KRW", "PLN", "CZK", "ILS", "THB", "PHP", "MYR", "IDR", "ZAR",
+    "AED", "SAR", "TRY", "RUB",
+];
+
+/// Pay period for an extracted salary
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum SalaryPeriod {
+    Annual,
+    Monthly,
+    Hourly,
+    Daily,
+    Weekly,
+}
+
+impl SalaryPeriod {
+    /// All periods, in the order listed in error messages
+    pub const ALL: [SalaryPeriod; 5] = [
+        SalaryPeriod::Annual,
+        SalaryPeriod::Monthly,
+        SalaryPeriod::Hourly,
+        SalaryPeriod::Daily,
+        SalaryPeriod::Weekly,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SalaryPeriod::Annual => "annual",
+            SalaryPeriod::Monthly => "monthly",
+            SalaryPeriod::Hourly => "hourly",
+            SalaryPeriod::Daily => "daily",
+            SalaryPeriod::Weekly => "weekly",
+        }
+    }
+
+    /// Parse a period name (exact, lowercase match like the TS validator)
+    pub fn parse(value: &str) -> Option<SalaryPeriod> {
+        Self::ALL.into_iter().find(|p| p.as_str() == value)
+    }
+}
+
+/// Salary range extracted from a job description by the AI selector
+///
+/// Matches `SalaryInfo` in `lib/ai/providers/types.ts`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SalaryInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Lower bound of the range (optional)")
+    )]
+    pub min: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "Upper bound of the range (optional)")
+    )]
+    pub max: Option<f64>,
+
+    #[cfg_attr(
+        feature = "schema",
+        schemars(description = "ISO 4217 currency code, uppercase (required)")
+    )]
+    pub currency: String,
+
+    #[cfg_attr(feature = "schema", schemars(description = "Pay period (required)"))]
+    pub period: SalaryPeriod,
+}
+
+impl SalaryInfo {
+    /// Validate the currency is an uppercase ISO 4217 code
+    pub fn validate(&self) -> Result<(), String> {
+        if !ISO_4217_CURRENCIES.contains(&self.currency.as_str()) {
+            return Err(format!(
+                "salary.currency must be ISO 4217 (USD, GBP, EUR, etc.). Got: {}",
+                self.currency
+            ));
+        }
+        Ok(())
+    }
 }
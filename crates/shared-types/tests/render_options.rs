@@ -0,0 +1,150 @@
+//! RenderOptions defaults, validation and round-tripping through metadata
+
+use shared_types::{
+    DateStyle, FontSet, GenerationMetadata, Margins, PaperSize, PdfStandard, RenderOptions,
+    ResumeSection,
+};
+
+#[test]
+fn test_defaults_from_empty_json() {
+    let options: RenderOptions = serde_json::from_str("{}").unwrap();
+
+    assert_eq!(options, RenderOptions::default());
+    assert_eq!(options.template, "classic");
+    assert_eq!(options.paper, PaperSize::Letter);
+    assert_eq!(options.date_style, DateStyle::Short);
+    assert_eq!(options.font_set, FontSet::Serif);
+    assert_eq!(options.pdf_standard, PdfStandard::Pdf17);
+    assert_eq!(options.language, "en");
+    assert!(!options.reader_hints);
+    assert_eq!(options.line_spacing, 1.0);
+    assert!(options.margins.is_none());
+    assert!(options.sections.is_none());
+    assert!(!options.debug);
+    assert!(options.validate().is_ok());
+}
+
+#[test]
+fn test_parses_camel_case_fields() {
+    let options: RenderOptions = serde_json::from_str(
+        r#"{
+            "template": "modern",
+            "paper": "a4",
+            "margins": { "top": 0.5, "right": 0.6, "bottom": 0.5, "left": 0.6 },
+            "fontSize": 11,
+            "lineSpacing": 1.2,
+            "sections": ["experience", "skills"],
+            "dateStyle": "numeric",
+            "fontSet": "sans",
+            "pdfStandard": "a-2b",
+            "language": "en-GB",
+            "readerHints": true,
+            "debug": true
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(options.paper, PaperSize::A4);
+    assert_eq!(options.margins.unwrap().right, 0.6);
+    assert_eq!(options.font_size, Some(11.0));
+    assert_eq!(
+        options.sections,
+        Some(vec![ResumeSection::Experience, ResumeSection::Skills])
+    );
+    assert_eq!(options.date_style, DateStyle::Numeric);
+    assert_eq!(options.font_set, FontSet::Sans);
+    assert_eq!(options.pdf_standard, PdfStandard::PdfA2b);
+    assert_eq!(options.language_parts(), ("en", Some("GB")));
+    assert!(options.reader_hints);
+    assert!(options.debug);
+    assert!(options.validate().is_ok());
+}
+
+#[test]
+fn test_validate_rejects_out_of_range_values() {
+    let margins = RenderOptions {
+        margins: Some(Margins {
+            left: -0.1,
+            ..Margins::uniform(0.5)
+        }),
+        ..RenderOptions::default()
+    };
+    assert!(margins.validate().unwrap_err().contains("left margin"));
+
+    let font = RenderOptions {
+        font_size: Some(40.0),
+        ..RenderOptions::default()
+    };
+    assert!(font.validate().unwrap_err().contains("font size"));
+
+    let spacing = RenderOptions {
+        line_spacing: f32::NAN,
+        ..RenderOptions::default()
+    };
+    assert!(spacing.validate().unwrap_err().contains("line spacing"));
+
+    for language in ["english", "EN", "en-gb", "en_GB", ""] {
+        let options = RenderOptions {
+            language: language.to_string(),
+            ..RenderOptions::default()
+        };
+        assert!(options.validate().unwrap_err().contains("language"));
+    }
+
+    let sections = RenderOptions {
+        sections: Some(vec![ResumeSection::Skills, ResumeSection::Skills]),
+        ..RenderOptions::default()
+    };
+    assert!(sections
+        .validate()
+        .unwrap_err()
+        .contains("section 'skills' is listed more than once"));
+}
+
+#[test]
+fn test_generation_metadata_records_render_options() {
+    let metadata = GenerationMetadata {
+        generation_id: "gen-1".to_string(),
+        timestamp: 1_700_000_000,
+        selected_bullet_ids: vec!["b1".to_string()],
+        role_profile_id: "staff-engineer".to_string(),
+        source_profiles: None,
+        selection_overrides: None,
+        render_options: Some(RenderOptions {
+            paper: PaperSize::Legal,
+            sections: Some(vec![ResumeSection::Summary]),
+            ..RenderOptions::default()
+        }),
+    };
+
+    let json = serde_json::to_string(&metadata).unwrap();
+    assert!(json.contains(r#""renderOptions":{"template":"classic","paper":"legal""#));
+    assert!(!json.contains("fontSize"));
+
+    let parsed: GenerationMetadata = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, metadata);
+}
+
+#[test]
+fn test_clock_and_document_id_default_to_generation_metadata() {
+    let metadata = GenerationMetadata {
+        generation_id: "gen-1".to_string(),
+        timestamp: 1_700_000_000,
+        selected_bullet_ids: vec![],
+        role_profile_id: "staff-engineer".to_string(),
+        source_profiles: None,
+        selection_overrides: None,
+        render_options: None,
+    };
+
+    let options = RenderOptions::default();
+    assert_eq!(options.clock(Some(&metadata)), Some(1_700_000_000));
+    assert_eq!(options.document_id(Some(&metadata)), Some("gen-1"));
+    assert_eq!(options.clock(None), None);
+    assert_eq!(options.document_id(None), None);
+
+    let pinned: RenderOptions =
+        serde_json::from_str(r#"{"timestamp": 1600000000, "documentId": "cache-key"}"#).unwrap();
+    assert_eq!(pinned.clock(Some(&metadata)), Some(1_600_000_000));
+    assert_eq!(pinned.document_id(Some(&metadata)), Some("cache-key"));
+}
@@ -0,0 +1,152 @@
+//! Role profile blending tests
+//!
+//! These tests verify that:
+//! 1. Tag weights are merged as a weighted mean across profiles
+//! 2. Scoring weights stay normalized after blending
+//! 3. Source profiles are recorded for GenerationMetadata
+//! 4. Invalid blend inputs are rejected
+
+use shared_types::*;
+use std::collections::HashMap;
+
+fn profile(id: &str, name: &str, tags: &[(&str, f32)], tag_relevance: f32) -> RoleProfile {
+    RoleProfile {
+        id: id.to_string(),
+        name: name.to_string(),
+        description: None,
+        tag_weights: tags
+            .iter()
+            .map(|(tag, weight)| (tag.to_string(), *weight))
+            .collect::<HashMap<_, _>>(),
+        scoring_weights: ScoringWeights {
+            tag_relevance,
+            priority: 1.0 - tag_relevance,
+        },
+        summary_id: None,
+    }
+}
+
+fn staff_engineer() -> RoleProfile {
+    profile(
+        "staff-engineer",
+        "Staff Engineer",
+        &[("rust", 1.0), ("leadership", 0.6)],
+        0.7,
+    )
+}
+
+fn devrel() -> RoleProfile {
+    profile(
+        "developer-relations",
+        "Developer Relations",
+        &[("public-speaking", 1.0), ("leadership", 0.8)],
+        0.5,
+    )
+}
+
+#[test]
+fn test_blend_merges_tag_weights_as_weighted_mean() {
+    let blended = RoleProfile::blend(&[(staff_engineer(), 3.0), (devrel(), 1.0)]).unwrap();
+    let weights = &blended.profile.tag_weights;
+
+    assert!((weights["rust"] - 0.75).abs() < 0.0001);
+    assert!((weights["public-speaking"] - 0.25).abs() < 0.0001);
+    assert!((weights["leadership"] - (0.6 * 0.75 + 0.8 * 0.25)).abs() < 0.0001);
+}
+
+#[test]
+fn test_blend_scoring_weights_are_normalized() {
+    let blended = RoleProfile::blend(&[(staff_engineer(), 1.0), (devrel(), 1.0)]).unwrap();
+    let weights = &blended.profile.scoring_weights;
+
+    assert!(weights.validate().is_ok());
+    assert!((weights.tag_relevance - 0.6).abs() < 0.0001);
+}
+
+#[test]
+fn test_blend_normalizes_denormalized_inputs() {
+    let mut skewed = staff_engineer();
+    skewed.scoring_weights = ScoringWeights {
+        tag_relevance: 1.2,
+        priority: 0.8,
+    };
+
+    let blended = RoleProfile::blend(&[(skewed, 1.0)]).unwrap();
+    assert!(blended.profile.scoring_weights.validate().is_ok());
+    assert!((blended.profile.scoring_weights.tag_relevance - 0.6).abs() < 0.0001);
+}
+
+#[test]
+fn test_blend_records_sources() {
+    let blended = RoleProfile::blend(&[(staff_engineer(), 2.0), (devrel(), 2.0)]).unwrap();
+
+    assert_eq!(blended.profile.id, "staff-engineer+developer-relations");
+    assert_eq!(blended.profile.name, "Staff Engineer / Developer Relations");
+    assert_eq!(
+        blended.sources,
+        vec![
+            ProfileBlendSource {
+                profile_id: "staff-engineer".to_string(),
+                weight: 0.5,
+            },
+            ProfileBlendSource {
+                profile_id: "developer-relations".to_string(),
+                weight: 0.5,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_blend_skips_zero_weight_profiles() {
+    let blended = RoleProfile::blend(&[(staff_engineer(), 1.0), (devrel(), 0.0)]).unwrap();
+
+    assert_eq!(blended.profile.id, "staff-engineer");
+    assert_eq!(blended.sources.len(), 1);
+    assert!(!blended.profile.tag_weights.contains_key("public-speaking"));
+}
+
+#[test]
+fn test_blend_rejects_invalid_input() {
+    assert!(RoleProfile::blend(&[]).is_err());
+    assert!(RoleProfile::blend(&[(staff_engineer(), 0.0)]).is_err());
+    assert!(RoleProfile::blend(&[(staff_engineer(), -1.0), (devrel(), 2.0)]).is_err());
+    assert!(RoleProfile::blend(&[(staff_engineer(), f32::NAN)]).is_err());
+}
+
+#[test]
+fn test_generation_metadata_source_profiles_roundtrip() {
+    let blended = RoleProfile::blend(&[(staff_engineer(), 1.0), (devrel(), 1.0)]).unwrap();
+    let metadata = GenerationMetadata {
+        generation_id: "gen-1".to_string(),
+        timestamp: 1_700_000_000,
+        selected_bullet_ids: vec!["b1".to_string()],
+        role_profile_id: blended.profile.id.clone(),
+        source_profiles: Some(blended.sources),
+        selection_overrides: None,
+        render_options: None,
+    };
+
+    let json = serde_json::to_string(&metadata).unwrap();
+    assert!(json.contains("\"sourceProfiles\""));
+    assert!(json.contains("\"profileId\":\"staff-engineer\""));
+
+    let parsed: GenerationMetadata = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, metadata);
+}
+
+#[test]
+fn test_generation_metadata_without_source_profiles() {
+    let json = r#"{
+        "generationId": "gen-1",
+        "timestamp": 1700000000,
+        "selectedBulletIds": [],
+        "roleProfileId": "staff-engineer"
+    }"#;
+
+    let parsed: GenerationMetadata = serde_json::from_str(json).unwrap();
+    assert_eq!(parsed.source_profiles, None);
+    assert!(!serde_json::to_string(&parsed)
+        .unwrap()
+        .contains("sourceProfiles"));
+}
@@ -103,6 +103,7 @@ fn test_position_roundtrip() {
         name: "Senior Engineer".to_string(),
         date_start: "2022-01".to_string(),
         date_end: None,
+        concurrent: None,
         children: vec![bullet],
         description: Some("Role description".to_string()),
         priority: 9,
@@ -143,6 +144,7 @@ fn test_company_roundtrip() {
         name: "Senior Engineer".to_string(),
         date_start: "2022-01".to_string(),
         date_end: None,
+        concurrent: None,
         children: vec![bullet],
         description: None,
         priority: 8,
@@ -191,6 +193,7 @@ fn test_role_profile_roundtrip() {
             tag_relevance: 0.6,
             priority: 0.4,
         },
+        summary_id: None,
     };
 
     let json =
@@ -265,6 +268,7 @@ fn test_resume_data_roundtrip() {
         name: "Engineer".to_string(),
         date_start: "2022-01".to_string(),
         date_end: None,
+        concurrent: None,
         children: vec![bullet],
         description: None,
         priority: 8,
@@ -291,6 +295,7 @@ fn test_resume_data_roundtrip() {
     let resume = ResumeData {
         personal,
         summary: Some("Professional summary".to_string()),
+        summaries: None,
         experience: vec![company],
         skills: None,
         education: None,
@@ -0,0 +1,175 @@
+//! Selection override tests
+//!
+//! These tests verify that:
+//! 1. Company include/exclude lists gate eligibility
+//! 2. Exclusions beat pins
+//! 3. Validation catches unknown IDs and pin/exclude conflicts
+//! 4. Overrides roundtrip through GenerationMetadata
+
+use shared_types::*;
+
+fn bullet(id: &str) -> Bullet {
+    Bullet {
+        id: id.to_string(),
+        name: None,
+        location: None,
+        date_start: None,
+        date_end: None,
+        summary: None,
+        description: format!("Bullet {}", id),
+        tags: vec![],
+        priority: 5,
+        link: None,
+    }
+}
+
+fn company(id: &str, bullets: &[&str]) -> Company {
+    Company {
+        id: id.to_string(),
+        name: Some(id.to_string()),
+        location: None,
+        date_start: "2020-01".to_string(),
+        date_end: None,
+        summary: None,
+        description: None,
+        tags: vec![],
+        priority: 5,
+        link: None,
+        children: vec![Position {
+            id: format!("{}-eng", id),
+            name: "Engineer".to_string(),
+            location: None,
+            date_start: "2020-01".to_string(),
+            date_end: None,
+            concurrent: None,
+            summary: None,
+            description: None,
+            tags: vec![],
+            priority: 5,
+            link: None,
+            children: bullets.iter().map(|id| bullet(id)).collect(),
+        }],
+    }
+}
+
+fn resume() -> ResumeData {
+    ResumeData {
+        personal: PersonalInfo {
+            name: "Test Person".to_string(),
+            nickname: None,
+            tagline: None,
+            email: None,
+            phone: None,
+            location: None,
+            linkedin: None,
+            github: None,
+            website: None,
+            twitter: None,
+        },
+        summary: None,
+        summaries: None,
+        experience: vec![company("acme", &["a1", "a2"]), company("rival", &["r1"])],
+        education: None,
+        skills: None,
+        role_profiles: None,
+        meta_footer: None,
+    }
+}
+
+fn ids(ids: &[&str]) -> Vec<String> {
+    ids.iter().map(|id| id.to_string()).collect()
+}
+
+#[test]
+fn test_company_include_and_exclude() {
+    let exclude = SelectionOverrides {
+        excluded_company_ids: ids(&["rival"]),
+        ..SelectionOverrides::default()
+    };
+    assert!(exclude.allows_company("acme"));
+    assert!(!exclude.allows_company("rival"));
+
+    let include = SelectionOverrides {
+        included_company_ids: ids(&["acme"]),
+        ..SelectionOverrides::default()
+    };
+    assert!(include.allows_company("acme"));
+    assert!(!include.allows_company("rival"));
+    assert!(!include.allows("r1", "rival"));
+}
+
+#[test]
+fn test_validate_accepts_known_ids() {
+    let overrides = SelectionOverrides {
+        pinned_bullet_ids: ids(&["a1"]),
+        excluded_bullet_ids: ids(&["a2"]),
+        included_company_ids: ids(&["acme"]),
+        excluded_company_ids: ids(&["rival"]),
+    };
+    assert!(overrides.validate(&resume()).is_ok());
+    assert!(!overrides.is_empty());
+    assert!(SelectionOverrides::default().is_empty());
+}
+
+#[test]
+fn test_validate_rejects_unknown_ids() {
+    let unknown_bullet = SelectionOverrides {
+        pinned_bullet_ids: ids(&["nope"]),
+        ..SelectionOverrides::default()
+    };
+    assert!(unknown_bullet
+        .validate(&resume())
+        .unwrap_err()
+        .contains("unknown bullet 'nope'"));
+
+    let unknown_company = SelectionOverrides {
+        excluded_company_ids: ids(&["initech"]),
+        ..SelectionOverrides::default()
+    };
+    assert!(unknown_company
+        .validate(&resume())
+        .unwrap_err()
+        .contains("unknown company 'initech'"));
+}
+
+#[test]
+fn test_validate_rejects_excluded_pins() {
+    let pinned_and_excluded = SelectionOverrides {
+        pinned_bullet_ids: ids(&["a1"]),
+        excluded_bullet_ids: ids(&["a1"]),
+        ..SelectionOverrides::default()
+    };
+    assert!(pinned_and_excluded.validate(&resume()).is_err());
+
+    let pinned_in_excluded_company = SelectionOverrides {
+        pinned_bullet_ids: ids(&["r1"]),
+        excluded_company_ids: ids(&["rival"]),
+        ..SelectionOverrides::default()
+    };
+    assert!(pinned_in_excluded_company.validate(&resume()).is_err());
+}
+
+#[test]
+fn test_generation_metadata_records_overrides() {
+    let metadata = GenerationMetadata {
+        generation_id: "gen-1".to_string(),
+        timestamp: 1_700_000_000,
+        selected_bullet_ids: ids(&["a1"]),
+        role_profile_id: "staff-engineer".to_string(),
+        source_profiles: None,
+        selection_overrides: Some(SelectionOverrides {
+            pinned_bullet_ids: ids(&["a1"]),
+            excluded_company_ids: ids(&["rival"]),
+            ..SelectionOverrides::default()
+        }),
+        render_options: None,
+    };
+
+    let json = serde_json::to_string(&metadata).unwrap();
+    assert!(json.contains(
+        r#""selectionOverrides":{"pinnedBulletIds":["a1"],"excludedCompanyIds":["rival"]}"#
+    ));
+
+    let parsed: GenerationMetadata = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, metadata);
+}
@@ -0,0 +1,153 @@
+//! Summary candidate tests
+//!
+//! These tests verify that:
+//! 1. Summary candidates and the role profile override roundtrip through JSON
+//! 2. Validation rejects bad candidates and duplicate IDs
+//! 3. Validation rejects overrides naming an unknown candidate
+//! 4. Blending keeps an override only when all sources agree
+
+use shared_types::*;
+use std::collections::HashMap;
+
+fn candidate(id: &str, tags: &[&str]) -> SummaryCandidate {
+    SummaryCandidate {
+        id: id.to_string(),
+        text: format!("Summary for {}", id),
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        priority: 5,
+    }
+}
+
+fn role(id: &str, summary_id: Option<&str>) -> RoleProfile {
+    RoleProfile {
+        id: id.to_string(),
+        name: id.to_string(),
+        description: None,
+        tag_weights: HashMap::from([("rust".to_string(), 1.0)]),
+        scoring_weights: ScoringWeights {
+            tag_relevance: 0.6,
+            priority: 0.4,
+        },
+        summary_id: summary_id.map(str::to_string),
+    }
+}
+
+fn resume(summaries: Vec<SummaryCandidate>, profiles: Vec<RoleProfile>) -> ResumeData {
+    ResumeData {
+        personal: PersonalInfo {
+            name: "Test Person".to_string(),
+            nickname: None,
+            tagline: None,
+            email: None,
+            phone: None,
+            location: None,
+            linkedin: None,
+            github: None,
+            website: None,
+            twitter: None,
+        },
+        summary: None,
+        summaries: Some(summaries),
+        experience: vec![Company {
+            id: "acme".to_string(),
+            name: Some("Acme".to_string()),
+            location: None,
+            date_start: "2020-01".to_string(),
+            date_end: None,
+            summary: None,
+            description: None,
+            tags: vec![],
+            priority: 5,
+            link: None,
+            children: vec![Position {
+                id: "acme-eng".to_string(),
+                name: "Engineer".to_string(),
+                location: None,
+                date_start: "2020-01".to_string(),
+                date_end: None,
+                concurrent: None,
+                summary: None,
+                description: None,
+                tags: vec![],
+                priority: 5,
+                link: None,
+                children: vec![Bullet {
+                    id: "b1".to_string(),
+                    name: None,
+                    location: None,
+                    date_start: None,
+                    date_end: None,
+                    summary: None,
+                    description: "Shipped things".to_string(),
+                    tags: vec![],
+                    priority: 5,
+                    link: None,
+                }],
+            }],
+        }],
+        education: None,
+        skills: None,
+        role_profiles: Some(profiles),
+        meta_footer: None,
+    }
+}
+
+#[test]
+fn test_summaries_roundtrip() {
+    let data = resume(
+        vec![
+            candidate("ic", &["rust"]),
+            candidate("lead", &["leadership"]),
+        ],
+        vec![role("manager", Some("lead"))],
+    );
+
+    let json = serde_json::to_string(&data).unwrap();
+    assert!(json.contains(r#""summaries":[{"id":"ic","text":"Summary for ic""#));
+    assert!(json.contains(r#""summaryId":"lead""#));
+
+    let parsed: ResumeData = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, data);
+    assert!(parsed.validate().is_ok());
+}
+
+#[test]
+fn test_validate_rejects_bad_candidates() {
+    let mut empty_text = candidate("ic", &[]);
+    empty_text.text = "  ".to_string();
+    let err = resume(vec![empty_text], vec![]).validate().unwrap_err();
+    assert!(err.contains("Summaries[0]"), "{}", err);
+
+    let mut bad_priority = candidate("ic", &[]);
+    bad_priority.priority = 0;
+    assert!(resume(vec![bad_priority], vec![]).validate().is_err());
+
+    let err = resume(vec![candidate("ic", &[]), candidate("ic", &[])], vec![])
+        .validate()
+        .unwrap_err();
+    assert!(err.contains("duplicate summary ID 'ic'"), "{}", err);
+}
+
+#[test]
+fn test_validate_rejects_unknown_override() {
+    let err = resume(
+        vec![candidate("ic", &[])],
+        vec![role("manager", Some("lead"))],
+    )
+    .validate()
+    .unwrap_err();
+    assert!(err.contains("unknown summary 'lead'"), "{}", err);
+}
+
+#[test]
+fn test_blend_keeps_agreed_override_only() {
+    let a = role("a", Some("lead"));
+    let b = role("b", Some("lead"));
+    let c = role("c", Some("ic"));
+
+    let agreed = RoleProfile::blend(&[(a.clone(), 1.0), (b, 1.0)]).unwrap();
+    assert_eq!(agreed.profile.summary_id.as_deref(), Some("lead"));
+
+    let mixed = RoleProfile::blend(&[(a, 1.0), (c, 1.0)]).unwrap();
+    assert_eq!(mixed.profile.summary_id, None);
+}
@@ -0,0 +1,78 @@
+import { describe, it, expect } from "vitest";
+import { blendRoleProfiles } from "@/lib/role-profile-blend";
+import type { RoleProfile } from "@/types/resume";
+
+function profile(
+  id: string,
+  name: string,
+  tagWeights: Record<string, number>,
+  tagRelevance: number,
+): RoleProfile {
+  return { id, name, tagWeights, scoringWeights: { tagRelevance, priority: 1 - tagRelevance } };
+}
+
+const staffEngineer = profile("staff-engineer", "Staff Engineer", { rust: 1, leadership: 0.6 }, 0.7);
+const devrel = profile(
+  "developer-relations",
+  "Developer Relations",
+  { "public-speaking": 1, leadership: 0.8 },
+  0.5,
+);
+
+describe("blendRoleProfiles", () => {
+  it("merges tag weights as a weighted mean", () => {
+    const { profile: blended } = blendRoleProfiles([
+      { profile: staffEngineer, weight: 3 },
+      { profile: devrel, weight: 1 },
+    ]);
+
+    expect(blended.tagWeights.rust).toBeCloseTo(0.75);
+    expect(blended.tagWeights["public-speaking"]).toBeCloseTo(0.25);
+    expect(blended.tagWeights.leadership).toBeCloseTo(0.6 * 0.75 + 0.8 * 0.25);
+  });
+
+  it("keeps scoring weights normalized", () => {
+    const skewed = { ...staffEngineer, scoringWeights: { tagRelevance: 1.2, priority: 0.8 } };
+
+    const { profile: blended } = blendRoleProfiles([{ profile: skewed, weight: 1 }]);
+
+    expect(blended.scoringWeights.tagRelevance).toBeCloseTo(0.6);
+    expect(blended.scoringWeights.priority).toBeCloseTo(0.4);
+  });
+
+  it("records normalized sources and skips zero-weight profiles", () => {
+    const even = blendRoleProfiles([
+      { profile: staffEngineer, weight: 2 },
+      { profile: devrel, weight: 2 },
+    ]);
+    expect(even.profile.id).toBe("staff-engineer+developer-relations");
+    expect(even.profile.name).toBe("Staff Engineer / Developer Relations");
+    expect(even.sources).toEqual([
+      { profileId: "staff-engineer", weight: 0.5 },
+      { profileId: "developer-relations", weight: 0.5 },
+    ]);
+
+    const solo = blendRoleProfiles([
+      { profile: staffEngineer, weight: 1 },
+      { profile: devrel, weight: 0 },
+    ]);
+    expect(solo.sources).toEqual([{ profileId: "staff-engineer", weight: 1 }]);
+    expect(solo.profile.tagWeights).not.toHaveProperty("public-speaking");
+  });
+
+  it("rejects invalid input", () => {
+    expect(() => blendRoleProfiles([])).toThrow("empty list");
+    expect(() => blendRoleProfiles([{ profile: staffEngineer, weight: 0 }])).toThrow(
+      "must not all be zero",
+    );
+    expect(() =>
+      blendRoleProfiles([
+        { profile: staffEngineer, weight: -1 },
+        { profile: devrel, weight: 2 },
+      ]),
+    ).toThrow("non-negative");
+    expect(() => blendRoleProfiles([{ profile: staffEngineer, weight: NaN }])).toThrow(
+      "non-negative",
+    );
+  });
+});
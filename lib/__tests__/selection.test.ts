@@ -0,0 +1,306 @@
+import { describe, it, expect } from "vitest";
+import {
+  parseSelectionOptions,
+  selectBulletsWithConstraints,
+  type ScoredBullet,
+  type SelectionConfig,
+} from "@/lib/selection";
+import { validateSelectionOverrides } from "@/lib/selection-caps";
+import { applyConstraints, applyDiversityConstraints } from "@/lib/selection-diversity";
+import {
+  HEURISTIC_MAX_SCORE,
+  parseEnsembleWeights,
+  refineScores,
+} from "@/lib/selection-ensemble";
+import { similarity, stem } from "@/lib/text-similarity";
+import type { ResumeData } from "@/types/resume";
+
+// Mirrors the selector tests in crates/resume-core/src/selector.rs so the
+// two engines stay in step.
+
+function scored(
+  id: string,
+  score: number,
+  options: { description?: string; tags?: string[]; company?: string; position?: string } = {},
+): ScoredBullet {
+  const company = options.company ?? "acme";
+  return {
+    bullet: { id, description: options.description ?? id, tags: options.tags ?? [], priority: 5 },
+    score,
+    companyId: company,
+    companyName: company,
+    companyDescription: null,
+    companyLink: null,
+    companyDateStart: "2020-01",
+    companyDateEnd: null,
+    companyLocation: null,
+    positionId: options.position ?? `${company}-eng`,
+    positionName: "Engineer",
+    positionDescription: null,
+    positionDateStart: "2020-01",
+    positionDateEnd: null,
+  };
+}
+
+function unconstrained(maxBullets: number, extra: Partial<SelectionConfig> = {}): SelectionConfig {
+  return { maxBullets, ...extra };
+}
+
+const ids = (bullets: ScoredBullet[]) => bullets.map((b) => b.bullet.id);
+
+/** Three near-identical Kubernetes bullets outscore one distinct bullet */
+const duplicateHeavy = [
+  scored("k8s-1", 1.0, { description: "Migrated payments platform to Kubernetes" }),
+  scored("k8s-2", 1.0, { description: "Migrated the payments platform to Kubernetes clusters" }),
+  scored("k8s-3", 0.95, { description: "Payments platform migrated to Kubernetes" }),
+  scored("mentor", 0.9, { description: "Mentored four junior engineers" }),
+];
+
+const twoCompanies = [
+  scored("a1", 1.0, { tags: ["rust"] }),
+  scored("r1", 1.0, { tags: ["rust"], company: "rival" }),
+  scored("a2", 0.9, { tags: ["rust"] }),
+  scored("r2", 0.9, { tags: ["rust"], company: "rival" }),
+  scored("a3", 0.1),
+];
+
+describe("text similarity", () => {
+  it("stems like the Rust normalizer", () => {
+    expect(stem("systems")).toBe(stem("system"));
+    expect(stem("scaling")).toBe(stem("scale"));
+    expect(stem("technologies")).toBe("technology");
+    expect(stem("process")).toBe("process");
+  });
+
+  it("scores near-duplicates above unrelated text", () => {
+    const a = "Migrated the payments platform to Kubernetes, cutting deploy time by 80%";
+    const b = "Migrated payments platform to Kubernetes, leading the effort";
+    const c = "Organised a community meetup for 200 developers";
+    expect(similarity(a, b)).toBeGreaterThan(0.3);
+    expect(similarity(a, c)).toBeLessThan(0.05);
+    expect(similarity("the and of", "the and of")).toBe(0);
+  });
+});
+
+describe("applyDiversityConstraints", () => {
+  it("greedy takes the highest scores", () => {
+    expect(ids(applyDiversityConstraints(duplicateHeavy, unconstrained(2)))).toEqual([
+      "k8s-1",
+      "k8s-2",
+    ]);
+  });
+
+  it("mmr skips near-duplicates", () => {
+    const config = unconstrained(2, { mode: { type: "mmr", redundancyPenalty: 1 } });
+    expect(ids(applyDiversityConstraints(duplicateHeavy, config))).toEqual(["k8s-1", "mentor"]);
+  });
+
+  it("mmr with zero penalty matches greedy", () => {
+    const config = unconstrained(3, { mode: { type: "mmr", redundancyPenalty: 0 } });
+    expect(ids(applyDiversityConstraints(duplicateHeavy, config))).toEqual(
+      ids(applyDiversityConstraints(duplicateHeavy, unconstrained(3))),
+    );
+  });
+
+  it("pins bypass scoring and come first", () => {
+    const config = unconstrained(2, { pinnedBulletIds: ["a3"] });
+    expect(ids(applyDiversityConstraints(twoCompanies, config))).toEqual(["a3", "a1"]);
+  });
+
+  it("pins count against the caps and survive minPerCompany", () => {
+    const capped = unconstrained(10, { maxPerCompany: 1, pinnedBulletIds: ["a3"] });
+    expect(ids(applyDiversityConstraints(twoCompanies, capped))).toEqual(["a3", "r1"]);
+
+    const sparse = { ...capped, minPerCompany: 2 };
+    expect(ids(applyDiversityConstraints(twoCompanies, sparse))).toEqual(["a3"]);
+  });
+
+  it("drops excluded bullets and companies, and exclusions beat pins", () => {
+    const select = (extra: Partial<SelectionConfig>) =>
+      ids(applyDiversityConstraints(twoCompanies, unconstrained(10, extra)));
+
+    expect(select({ maxBullets: 2, excludedBulletIds: ["a1"] })).toEqual(["r1", "a2"]);
+    expect(select({ includedCompanyIds: ["rival"] })).toEqual(["r1", "r2"]);
+    expect(select({ pinnedBulletIds: ["a3"], excludedCompanyIds: ["acme"] })).toEqual([
+      "r1",
+      "r2",
+    ]);
+  });
+
+  it("mmr penalises similarity to pins", () => {
+    const config = unconstrained(2, {
+      mode: { type: "mmr", redundancyPenalty: 1 },
+      pinnedBulletIds: ["k8s-2"],
+    });
+    expect(ids(applyDiversityConstraints(duplicateHeavy, config))).toEqual(["k8s-2", "mentor"]);
+  });
+});
+
+describe("mustCoverTags", () => {
+  const coverage = [
+    scored("rust-1", 1.0, { tags: ["rust"] }),
+    scored("rust-2", 1.0, { tags: ["rust"] }),
+    scored("lead", 0.4, { tags: ["leadership"] }),
+    scored("lead-ds", 0.3, { tags: ["leadership", "distributed-systems"] }),
+  ];
+
+  it("picks evidence over score", () => {
+    const config = unconstrained(2, {
+      mustCoverTags: ["rust", "leadership", "distributed-systems"],
+    });
+    const selection = applyConstraints(coverage, config);
+
+    expect(ids(selection.bullets)).toEqual(["rust-1", "lead-ds"]);
+    expect(selection.coverage.every((c) => c.gap === undefined)).toBe(true);
+  });
+
+  it("weighs gain by score", () => {
+    // Unweighted max-gain would take lead-ds (two tags) for the only slot
+    const config = unconstrained(1, {
+      mustCoverTags: ["rust", "leadership", "distributed-systems"],
+    });
+    expect(ids(applyConstraints(coverage, config).bullets)).toEqual(["rust-1"]);
+  });
+
+  it("reports why requirements are uncovered", () => {
+    const config = unconstrained(1, {
+      mustCoverTags: ["kubernetes", "leadership", "rust"],
+      excludedBulletIds: ["lead", "lead-ds"],
+    });
+    const gaps = applyConstraints(coverage, config).coverage.map((c) => [c.tag, c.gap]);
+    expect(gaps).toEqual([
+      ["kubernetes", "noBullet"],
+      ["leadership", "excluded"],
+      ["rust", undefined],
+    ]);
+
+    const tight = unconstrained(1, { mustCoverTags: ["rust", "leadership"] });
+    const noRoom = applyConstraints(coverage, tight);
+    expect(noRoom.coverage[1]?.gap).toBe("noRoom");
+  });
+});
+
+describe("selectBulletsWithConstraints", () => {
+  const resumeData = {
+    experience: [
+      {
+        id: "acme",
+        dateStart: "2020-01",
+        priority: 8,
+        tags: [],
+        children: [
+          {
+            id: "acme-eng",
+            name: "Engineer",
+            dateStart: "2020-01",
+            priority: 8,
+            tags: [],
+            children: [
+              { id: "a1", description: "A1", tags: [], priority: 5 },
+              { id: "a2", description: "A2", tags: [], priority: 5 },
+            ],
+          },
+        ],
+      },
+    ],
+  } as unknown as ResumeData;
+
+  it("keeps pins the scores skipped, with score 0", () => {
+    const scores = new Map([["a1", 0.9]]);
+    const selection = selectBulletsWithConstraints(resumeData, scores, {
+      maxBullets: 2,
+      pinnedBulletIds: ["a2"],
+    });
+
+    expect(ids(selection.bullets)).toEqual(["a2", "a1"]);
+    expect(selection.bullets[0]?.score).toBe(0);
+  });
+});
+
+describe("validateSelectionOverrides", () => {
+  const resumeData = {
+    experience: [
+      { id: "acme", children: [{ id: "acme-eng", children: [{ id: "a1" }, { id: "a2" }] }] },
+    ],
+  } as unknown as ResumeData;
+
+  it("accepts overrides that match the compendium", () => {
+    const overrides = { pinnedBulletIds: ["a1"], excludedBulletIds: ["a2"] };
+    expect(validateSelectionOverrides(overrides, resumeData)).toBeNull();
+  });
+
+  it("rejects unknown IDs and pins that are also excluded", () => {
+    expect(validateSelectionOverrides({ pinnedBulletIds: ["nope"] }, resumeData)).toBe(
+      "Selection overrides: unknown bullet 'nope'",
+    );
+    expect(validateSelectionOverrides({ excludedCompanyIds: ["rival"] }, resumeData)).toBe(
+      "Selection overrides: unknown company 'rival'",
+    );
+    expect(
+      validateSelectionOverrides(
+        { pinnedBulletIds: ["a1"], excludedCompanyIds: ["acme"] },
+        resumeData,
+      ),
+    ).toBe("Selection overrides: pinned bullet 'a1' is also excluded");
+  });
+});
+
+describe("parseSelectionOptions", () => {
+  it("keeps well-formed fields and drops the rest", () => {
+    expect(
+      parseSelectionOptions({
+        maxBullets: 10,
+        mode: { type: "mmr", redundancyPenalty: 0.5 },
+        pinnedBulletIds: ["a1"],
+        excludedBulletIds: "a2",
+        includedCompanyIds: ["acme", 3],
+        mustCoverTags: ["rust"],
+      }),
+    ).toEqual({
+      mode: { type: "mmr", redundancyPenalty: 0.5 },
+      pinnedBulletIds: ["a1"],
+      mustCoverTags: ["rust"],
+    });
+
+    expect(parseSelectionOptions({ mode: { type: "mmr", redundancyPenalty: -1 } })).toEqual({});
+    expect(parseSelectionOptions(undefined)).toEqual({});
+  });
+});
+
+describe("refineScores", () => {
+  const heuristic = new Map([
+    ["rust", HEURISTIC_MAX_SCORE * 0.8],
+    ["talk", HEURISTIC_MAX_SCORE * 0.2],
+    ["misc", HEURISTIC_MAX_SCORE * 0.1],
+  ]);
+  const ai = new Map([
+    ["rust", 0.1],
+    ["talk", 1.0],
+  ]);
+
+  it("lets the AI refine the heuristic and keeps bullets it skipped", () => {
+    const blended = refineScores(heuristic, ai);
+
+    expect(blended.get("talk")).toBeCloseTo(0.3 * 0.2 + 0.7 * 1.0);
+    expect(blended.get("rust")).toBeCloseTo(0.3 * 0.8 + 0.7 * 0.1);
+    expect(blended.get("misc")).toBeCloseTo(0.1);
+  });
+
+  it("ignores zero-weight members", () => {
+    const blended = refineScores(heuristic, ai, { heuristic: 1, external: 0 });
+    expect(blended.get("talk")).toBeCloseTo(0.2);
+    expect(refineScores(new Map(), ai, { heuristic: 1, external: 0 }).size).toBe(0);
+  });
+
+  it("parses weights from a request body", () => {
+    expect(parseEnsembleWeights(undefined)).toEqual({ heuristic: 0.3, external: 0.7 });
+    expect(parseEnsembleWeights({ heuristic: 2, external: -1 })).toEqual({
+      heuristic: 2,
+      external: 0,
+    });
+    expect(parseEnsembleWeights({ heuristic: "high" })).toEqual({
+      heuristic: 0.3,
+      external: 0.7,
+    });
+  });
+});
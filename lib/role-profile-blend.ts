@@ -0,0 +1,103 @@
+/**
+ * Hybrid role profiles for postings like "Staff Engineer / Developer
+ * Relations". Port of `RoleProfile::blend` in `crates/shared-types`.
+ *
+ * Blend weights are normalized to sum to 1; `tagWeights` and
+ * `scoringWeights` become the weighted means (a tag missing from a profile
+ * counts as 0), and the scoring weights are renormalized to sum to 1.
+ *
+ * @module lib/role-profile-blend
+ */
+
+import type { RoleProfile } from "@/types/resume";
+
+/** A requested blend entry: a role profile ID and its unnormalized weight. */
+export interface BlendWeight {
+  roleProfileId: string;
+  weight: number;
+}
+
+/** One input to a blended profile, as recorded in `GenerationMetadata.sourceProfiles`. */
+export interface ProfileBlendSource {
+  profileId: string;
+  /** Normalized blend weight 0-1 */
+  weight: number;
+}
+
+/** Result of {@link blendRoleProfiles}. */
+export interface BlendedRoleProfile {
+  /** Hybrid profile ready for selection */
+  profile: RoleProfile;
+  /** Contributing profiles with normalized blend weights */
+  sources: ProfileBlendSource[];
+}
+
+/**
+ * Blend several role profiles into one.
+ *
+ * Zero-weight profiles contribute nothing and are left out of `sources`. A
+ * `summaryId` only survives when every contributor names the same one.
+ *
+ * @param sources - Profiles with their (unnormalized) blend weights.
+ * @returns The hybrid profile and the normalized sources.
+ * @throws If `sources` is empty, a weight is negative or not finite, or all
+ *   weights are zero. Messages match the Rust implementation.
+ */
+export function blendRoleProfiles(
+  sources: ReadonlyArray<{ profile: RoleProfile; weight: number }>,
+): BlendedRoleProfile {
+  if (sources.length === 0) {
+    throw new Error("Cannot blend an empty list of role profiles");
+  }
+  const invalid = sources.find((s) => !Number.isFinite(s.weight) || s.weight < 0);
+  if (invalid) {
+    throw new Error(
+      `Role profile '${invalid.profile.id}': blend weight must be a non-negative number, got ${invalid.weight}`,
+    );
+  }
+  const total = sources.reduce((sum, s) => sum + s.weight, 0);
+  if (total <= 0) {
+    throw new Error("Blend weights must not all be zero");
+  }
+
+  const contributing = sources
+    .filter((s) => s.weight > 0)
+    .map((s) => ({ profile: s.profile, share: s.weight / total }));
+
+  const tagWeights: Record<string, number> = {};
+  let tagRelevance = 0;
+  let priority = 0;
+  for (const { profile, share } of contributing) {
+    for (const [tag, weight] of Object.entries(profile.tagWeights)) {
+      tagWeights[tag] = (tagWeights[tag] ?? 0) + weight * share;
+    }
+    tagRelevance += profile.scoringWeights.tagRelevance * share;
+    priority += profile.scoringWeights.priority * share;
+  }
+  for (const [tag, weight] of Object.entries(tagWeights)) {
+    if (weight <= 0) delete tagWeights[tag];
+  }
+  const weightSum = tagRelevance + priority;
+  if (Math.abs(weightSum - 1) >= 0.001) {
+    tagRelevance /= weightSum;
+    priority /= weightSum;
+  }
+
+  const summaryId = contributing[0]?.profile.summaryId;
+  const description = contributing
+    .map(({ profile, share }) => `${profile.name} (${Math.round(share * 100)}%)`)
+    .join(", ");
+
+  return {
+    profile: {
+      id: contributing.map(({ profile }) => profile.id).join("+"),
+      name: contributing.map(({ profile }) => profile.name).join(" / "),
+      description: `Blend of ${description}`,
+      tagWeights,
+      scoringWeights: { tagRelevance, priority },
+      ...(summaryId &&
+        contributing.every(({ profile }) => profile.summaryId === summaryId) && { summaryId }),
+    },
+    sources: contributing.map(({ profile, share }) => ({ profileId: profile.id, weight: share })),
+  };
+}
@@ -0,0 +1,118 @@
+/**
+ * Per-company / per-position caps and override checks shared by the
+ * selection passes (pins, tag coverage, greedy, MMR).
+ *
+ * @module lib/selection-caps
+ */
+
+import type { ResumeData } from "@/types/resume";
+import type { ScoredBullet, SelectionConfig, SelectionOverrides } from "@/lib/selection";
+
+/**
+ * Running per-company / per-position counts checked against the config's
+ * `maxPerCompany` / `maxPerPosition` (0 or undefined = no limit).
+ */
+export class CapTracker {
+  private readonly company = new Map<string, number>();
+  private readonly position = new Map<string, number>();
+
+  constructor(private readonly config: SelectionConfig) {}
+
+  /** Whether one more bullet from this company and position fits. */
+  allows(bullet: ScoredBullet): boolean {
+    const under = (limit: number | undefined, count: number | undefined) =>
+      !limit || limit <= 0 || (count ?? 0) < limit;
+    return (
+      under(this.config.maxPerCompany, this.company.get(bullet.companyId)) &&
+      under(this.config.maxPerPosition, this.position.get(bullet.positionId))
+    );
+  }
+
+  /** Count a selected bullet against its company and position. */
+  record(bullet: ScoredBullet): void {
+    this.company.set(bullet.companyId, (this.company.get(bullet.companyId) ?? 0) + 1);
+    this.position.set(bullet.positionId, (this.position.get(bullet.positionId) ?? 0) + 1);
+  }
+}
+
+/** Whether a company's bullets may be selected. */
+export function allowsCompany(overrides: SelectionOverrides, companyId: string): boolean {
+  const included = overrides.includedCompanyIds ?? [];
+  return (
+    !(overrides.excludedCompanyIds ?? []).includes(companyId) &&
+    (included.length === 0 || included.includes(companyId))
+  );
+}
+
+/** Whether a bullet may be selected (exclusions beat pins). */
+export function allowsBullet(
+  overrides: SelectionOverrides,
+  bulletId: string,
+  companyId: string,
+): boolean {
+  return (
+    !(overrides.excludedBulletIds ?? []).includes(bulletId) && allowsCompany(overrides, companyId)
+  );
+}
+
+/**
+ * Check every referenced ID exists and no pin is also excluded. Port of
+ * `SelectionOverrides::validate` in `crates/shared-types/src/lib.rs`.
+ *
+ * @param overrides - Pins and exclusions from the request.
+ * @param resumeData - Compendium the IDs must refer to.
+ * @returns The first problem found, or `null` when the overrides are valid.
+ */
+export function validateSelectionOverrides(
+  overrides: SelectionOverrides,
+  resumeData: ResumeData,
+): string | null {
+  const bulletCompany = new Map<string, string>();
+  for (const company of resumeData.experience) {
+    for (const position of company.children) {
+      for (const bullet of position.children) {
+        bulletCompany.set(bullet.id, company.id);
+      }
+    }
+  }
+  const companyIds = new Set(resumeData.experience.map((company) => company.id));
+
+  for (const id of [
+    ...(overrides.pinnedBulletIds ?? []),
+    ...(overrides.excludedBulletIds ?? []),
+  ]) {
+    if (!bulletCompany.has(id)) return `Selection overrides: unknown bullet '${id}'`;
+  }
+  for (const id of [
+    ...(overrides.includedCompanyIds ?? []),
+    ...(overrides.excludedCompanyIds ?? []),
+  ]) {
+    if (!companyIds.has(id)) return `Selection overrides: unknown company '${id}'`;
+  }
+  for (const id of overrides.pinnedBulletIds ?? []) {
+    if (!allowsBullet(overrides, id, bulletCompany.get(id) ?? "")) {
+      return `Selection overrides: pinned bullet '${id}' is also excluded`;
+    }
+  }
+  return null;
+}
+
+/**
+ * Drop excluded bullets, then pull pins out of the candidates in pin order.
+ *
+ * @param sortedBullets - Scored bullets, sorted by score descending.
+ * @param overrides - Pins and exclusions to apply.
+ * @returns The pinned bullets and the remaining (still sorted) candidates.
+ */
+export function splitPinned<T extends ScoredBullet>(
+  sortedBullets: T[],
+  overrides: SelectionOverrides,
+): { pinned: T[]; candidates: T[] } {
+  const candidates = sortedBullets.filter((b) => allowsBullet(overrides, b.bullet.id, b.companyId));
+  const pinned: T[] = [];
+  for (const pin of overrides.pinnedBulletIds ?? []) {
+    const index = candidates.findIndex((b) => b.bullet.id === pin);
+    if (index >= 0) pinned.push(...candidates.splice(index, 1));
+  }
+  return { pinned, candidates };
+}
@@ -0,0 +1,103 @@
+/**
+ * `mustCoverTags` support for bullet selection.
+ *
+ * A weighted greedy set cover picks, step by step, the bullet with the
+ * highest `score × still-uncovered required tags it carries` until every
+ * requirement has evidence or no candidate helps. Port of
+ * `cover_requirements` in `crates/resume-core/src/selector.rs`.
+ *
+ * @module lib/selection-coverage
+ */
+
+import type { RequirementCoverage, ScoredBullet, SelectionConfig } from "@/lib/selection";
+import type { CapTracker } from "@/lib/selection-caps";
+
+/** Tags in first-seen order, duplicates dropped. */
+export function uniqueTags(tags: readonly string[]): string[] {
+  return [...new Set(tags)];
+}
+
+/** Every tag carried by any of the bullets. */
+export function tagsOf(bullets: readonly ScoredBullet[]): Set<string> {
+  return new Set(bullets.flatMap((b) => b.bullet.tags ?? []));
+}
+
+/**
+ * Weighted greedy set cover over `requirements`, seeded with what `already`
+ * covers. Candidates rank by `score × newly covered requirements`, so a weak
+ * bullet covering two tags does not beat a strong one covering one; equal
+ * values go to the larger gain, then the earlier, higher-scored bullet.
+ *
+ * Picks are removed from `candidates` and recorded in `caps`.
+ *
+ * @param candidates - Remaining candidates, sorted by score descending.
+ * @param already - Bullets selected before coverage runs (the pins).
+ * @param requirements - Required tags, deduplicated.
+ * @param config - Supplies the `maxBullets` ceiling.
+ * @param caps - Per-company / per-position counts so far.
+ * @returns The bullets picked for coverage, in pick order.
+ */
+export function coverRequirements<T extends ScoredBullet>(
+  candidates: T[],
+  already: readonly T[],
+  requirements: readonly string[],
+  config: SelectionConfig,
+  caps: CapTracker,
+): T[] {
+  const covered = tagsOf(already);
+  let uncovered = requirements.filter((tag) => !covered.has(tag));
+  const picks: T[] = [];
+
+  while (uncovered.length > 0 && already.length + picks.length < config.maxBullets) {
+    let best = -1;
+    let bestValue = 0;
+    let bestGain = 0;
+    candidates.forEach((candidate, index) => {
+      if (!caps.allows(candidate)) return;
+      const tags = candidate.bullet.tags ?? [];
+      const gain = uncovered.filter((tag) => tags.includes(tag)).length;
+      if (gain === 0) return;
+      const value = gain * candidate.score;
+      // Strictly greater keeps the earlier (higher-scored) bullet on ties
+      if (best < 0 || value > bestValue || (value === bestValue && gain > bestGain)) {
+        best = index;
+        bestValue = value;
+        bestGain = gain;
+      }
+    });
+
+    const [pick] = best >= 0 ? candidates.splice(best, 1) : [];
+    if (!pick) break;
+    const tags = pick.bullet.tags ?? [];
+    uncovered = uncovered.filter((tag) => !tags.includes(tag));
+    caps.record(pick);
+    picks.push(pick);
+  }
+
+  return picks;
+}
+
+/**
+ * One coverage entry per requirement, explaining any gap.
+ *
+ * @param requirements - Required tags, deduplicated.
+ * @param selected - Final selection.
+ * @param candidateTags - Tags on any scored bullet, before overrides.
+ * @param allowedTags - Tags on bullets the overrides allow.
+ */
+export function reportCoverage(
+  requirements: readonly string[],
+  selected: readonly ScoredBullet[],
+  candidateTags: Set<string>,
+  allowedTags: Set<string>,
+): RequirementCoverage[] {
+  return requirements.map((tag) => {
+    const coveredBy = selected
+      .filter((b) => (b.bullet.tags ?? []).includes(tag))
+      .map((b) => b.bullet.id);
+    if (coveredBy.length > 0) return { tag, coveredBy };
+    if (!candidateTags.has(tag)) return { tag, coveredBy, gap: "noBullet" };
+    if (!allowedTags.has(tag)) return { tag, coveredBy, gap: "excluded" };
+    return { tag, coveredBy, gap: "noRoom" };
+  });
+}
@@ -2,13 +2,18 @@
  * Diversity and ordering helpers for bullet selection.
  *
  * Extracted from `lib/selection.ts` to keep each file under the `max-lines`
- * guardrail. Logic is unchanged.
+ * guardrail. Pins/exclusions, tag coverage and MMR live in their own
+ * modules (`selection-caps`, `selection-coverage`, `selection-mmr`); this
+ * module chains them in the same order as `crates/resume-core/src/selector.rs`.
  *
  * @module lib/selection-diversity
  */
 
 import type { ResumeData } from "@/types/resume";
-import type { ScoredBullet, SelectedBullet, SelectionConfig } from "@/lib/selection";
+import type { ScoredBullet, SelectedBullet, Selection, SelectionConfig } from "@/lib/selection";
+import { CapTracker, splitPinned } from "@/lib/selection-caps";
+import { coverRequirements, reportCoverage, tagsOf, uniqueTags } from "@/lib/selection-coverage";
+import { selectMmr } from "@/lib/selection-mmr";
 
 /**
  * Applies diversity constraints to a pre-sorted list of scored bullets.
@@ -19,14 +24,16 @@ import type { ScoredBullet, SelectedBullet, SelectionConfig } from "@/lib/select
  * - Maximum bullets per position (prevents one role dominating)
  * - Minimum bullets per company (removes sparse single-bullet companies)
  *
+ * Overrides, `mustCoverTags` and the MMR mode are applied too; see
+ * {@link applyConstraints}.
+ *
  * @param sortedBullets - Pre-scored bullets, must be sorted by score descending
  * @param config - Diversity constraint configuration
- * @returns Filtered bullets respecting all constraints, maintaining score order
+ * @returns Filtered bullets respecting all constraints
  *
  * @example
  * ```ts
  * const scored = scoreBullets(resumeData, roleProfile)
- * scored.sort((a, b) => b.score - a.score)
  * const selected = applyDiversityConstraints(scored, { maxBullets: 24, maxPerCompany: 6 })
  * ```
  */
@@ -34,58 +41,114 @@ export function applyDiversityConstraints<T extends ScoredBullet>(
   sortedBullets: T[],
   config: SelectionConfig,
 ): T[] {
-  const { maxBullets, maxPerCompany, maxPerPosition, minPerCompany } = config;
+  return applyConstraints(sortedBullets, config).bullets;
+}
 
+/**
+ * Like {@link applyDiversityConstraints}, also reporting tag coverage.
+ *
+ * Passes, in order (matching the Rust selector):
+ * 1. Excluded bullets and companies are removed; pinned bullets are taken
+ *    unconditionally in pin order, using up `maxBullets` and the caps.
+ * 2. `mustCoverTags`: a score-weighted greedy set cover adds evidence for each
+ *    required tag.
+ * 3. The remaining slots are filled greedily by score, or by maximal marginal
+ *    relevance when `mode` is `mmr`.
+ * 4. Companies under `minPerCompany` are dropped, except those holding a pin
+ *    or coverage pick.
+ *
+ * @param sortedBullets - Pre-scored bullets, must be sorted by score descending
+ * @param config - Selection configuration
+ * @returns Pins first, then coverage picks, then score (greedy) or pick (MMR) order
+ */
+export function applyConstraints<T extends ScoredBullet>(
+  sortedBullets: T[],
+  config: SelectionConfig,
+): Selection<T> {
+  const requirements = uniqueTags(config.mustCoverTags ?? []);
+  const candidateTags = tagsOf(sortedBullets);
+
+  const { pinned, candidates } = splitPinned(sortedBullets, config);
+  const allowedTags = tagsOf([...pinned, ...candidates]);
+
+  const caps = new CapTracker(config);
+  pinned.forEach((bullet) => caps.record(bullet));
+  const required = [
+    ...pinned,
+    ...coverRequirements(candidates, pinned, requirements, config, caps),
+  ];
+  const remaining = Math.max(0, config.maxBullets - required.length);
+
+  const picks =
+    config.mode?.type === "mmr"
+      ? selectMmr(candidates, required, caps, remaining, config.mode.redundancyPenalty)
+      : selectGreedy(candidates, caps, remaining);
+
+  const requiredCompanies = new Set(required.map((bullet) => bullet.companyId));
+  const bullets = enforceMinPerCompany(
+    [...required, ...picks],
+    config.minPerCompany,
+    requiredCompanies,
+  );
+
+  return {
+    bullets,
+    coverage: reportCoverage(requirements, bullets, candidateTags, allowedTags),
+  };
+}
+
+/** Highest score first, skipping bullets over a cap. */
+function selectGreedy<T extends ScoredBullet>(
+  sortedBullets: readonly T[],
+  caps: CapTracker,
+  limit: number,
+): T[] {
   const selected: T[] = [];
-  const companyCount: Record<string, number> = {};
-  const positionCount: Record<string, number> = {};
 
   for (const bullet of sortedBullets) {
     // Check maxBullets limit (ceiling)
-    if (selected.length >= maxBullets) {
+    if (selected.length >= limit) {
       break;
     }
 
-    // Check per-company limit (0 or undefined = no limit)
-    if (maxPerCompany && maxPerCompany > 0) {
-      const count = companyCount[bullet.companyId] || 0;
-      if (count >= maxPerCompany) {
-        continue;
-      }
+    // Check per-company and per-position limits
+    if (!caps.allows(bullet)) {
+      continue;
     }
 
-    // Check per-position limit (0 or undefined = no limit)
-    if (maxPerPosition && maxPerPosition > 0) {
-      const count = positionCount[bullet.positionId] || 0;
-      if (count >= maxPerPosition) {
-        continue;
-      }
-    }
-
-    // Add bullet and increment counters
-    companyCount[bullet.companyId] = (companyCount[bullet.companyId] || 0) + 1;
-    positionCount[bullet.positionId] = (positionCount[bullet.positionId] || 0) + 1;
+    caps.record(bullet);
     selected.push(bullet);
   }
 
-  // Enforce minimum bullets per company (avoid single-bullet companies)
-  if (minPerCompany && minPerCompany > 1) {
-    // Count bullets per company in selected set
-    const finalCompanyCount: Record<string, number> = {};
-    for (const bullet of selected) {
-      finalCompanyCount[bullet.companyId] = (finalCompanyCount[bullet.companyId] || 0) + 1;
-    }
+  return selected;
+}
 
-    // Filter out companies with fewer than minimum bullets
-    const filtered = selected.filter((bullet) => {
-      const count = finalCompanyCount[bullet.companyId] || 0;
-      return count >= minPerCompany;
-    });
+/**
+ * Enforce minimum bullets per company (avoid single-bullet companies).
+ *
+ * Companies in `requiredCompanies` (holding a pin or coverage pick) are
+ * exempt, since those bullets must stay.
+ */
+function enforceMinPerCompany<T extends ScoredBullet>(
+  selected: T[],
+  minPerCompany: number | undefined,
+  requiredCompanies: Set<string>,
+): T[] {
+  if (!minPerCompany || minPerCompany <= 1) {
+    return selected;
+  }
 
-    return filtered;
+  // Count bullets per company in selected set
+  const finalCompanyCount: Record<string, number> = {};
+  for (const bullet of selected) {
+    finalCompanyCount[bullet.companyId] = (finalCompanyCount[bullet.companyId] || 0) + 1;
   }
 
-  return selected;
+  // Filter out companies with fewer than minimum bullets
+  return selected.filter((bullet) => {
+    const count = finalCompanyCount[bullet.companyId] || 0;
+    return count >= minPerCompany || requiredCompanies.has(bullet.companyId);
+  });
 }
 
 /**
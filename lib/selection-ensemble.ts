@@ -0,0 +1,74 @@
+/**
+ * Blend heuristic and AI bullet scores so the AI refines the heuristic
+ * instead of replacing it. Port of `EnsembleScorer::refine` in
+ * `crates/resume-core/src/scorer.rs`.
+ *
+ * Each member's score is divided by its largest possible value, then every
+ * bullet gets the weighted mean over the members that scored it. A bullet the
+ * AI skipped therefore keeps its heuristic score rather than dropping out.
+ *
+ * @module lib/selection-ensemble
+ */
+
+/** Relative member weights; they need not sum to 1. */
+export interface EnsembleWeights {
+  heuristic: number;
+  /** Weight of the external (AI) scores */
+  external: number;
+}
+
+/** External scores lead; the heuristic still ranks the bullets they skipped. */
+export const DEFAULT_ENSEMBLE_WEIGHTS: EnsembleWeights = { heuristic: 0.3, external: 0.7 };
+
+/** Largest heuristic score: base ≤ 1.0 × company ≤ 1.2 × position ≤ 1.2 × 1.1. */
+export const HEURISTIC_MAX_SCORE = 1.2 * 1.2 * 1.1;
+
+/**
+ * Parse ensemble weights from an untrusted request body. Missing or
+ * non-numeric weights take the defaults; negative ones count as 0.
+ */
+export function parseEnsembleWeights(raw: unknown): EnsembleWeights {
+  const weights = { ...DEFAULT_ENSEMBLE_WEIGHTS };
+  if (!raw || typeof raw !== "object") return weights;
+  for (const key of ["heuristic", "external"] as const) {
+    const value = (raw as Record<string, unknown>)[key];
+    if (typeof value === "number" && Number.isFinite(value)) {
+      weights[key] = Math.max(0, value);
+    }
+  }
+  return weights;
+}
+
+/**
+ * Blend heuristic scores with external (AI) scores.
+ *
+ * @param heuristic - Bullet ID → heuristic score (0 to {@link HEURISTIC_MAX_SCORE}).
+ * @param external - Bullet ID → external score (0-1).
+ * @param weights - Member weights; a zero-weight member is ignored.
+ * @returns Bullet ID → blended score on a 0-1 scale, for every bullet at
+ *   least one weighted member scored.
+ */
+export function refineScores(
+  heuristic: ReadonlyMap<string, number>,
+  external: ReadonlyMap<string, number>,
+  weights: EnsembleWeights = DEFAULT_ENSEMBLE_WEIGHTS,
+): Map<string, number> {
+  const members = [
+    { scores: heuristic, max: HEURISTIC_MAX_SCORE, weight: weights.heuristic },
+    { scores: external, max: 1, weight: weights.external },
+  ].filter((m) => m.weight > 0);
+
+  const blended = new Map<string, number>();
+  for (const id of new Set([...heuristic.keys(), ...external.keys()])) {
+    let weighted = 0;
+    let totalWeight = 0;
+    for (const { scores, max, weight } of members) {
+      const score = scores.get(id);
+      if (score === undefined) continue;
+      weighted += (score / max) * weight;
+      totalWeight += weight;
+    }
+    if (totalWeight > 0) blended.set(id, weighted / totalWeight);
+  }
+  return blended;
+}
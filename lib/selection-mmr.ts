@@ -0,0 +1,73 @@
+/**
+ * Maximal marginal relevance selection.
+ *
+ * Each pick maximises `score - redundancyPenalty × maxSimilarity(selected)`,
+ * so a bullet that restates an already-selected achievement loses out to a
+ * slightly lower-scored but distinct one. Port of `select_mmr` in
+ * `crates/resume-core/src/selector.rs`.
+ *
+ * @module lib/selection-mmr
+ */
+
+import type { ScoredBullet } from "@/lib/selection";
+import type { CapTracker } from "@/lib/selection-caps";
+import { jaccard, shingles } from "@/lib/text-similarity";
+
+/**
+ * Pick up to `limit` candidates by maximal marginal relevance.
+ *
+ * @param sortedBullets - Candidates, sorted by score descending.
+ * @param required - Bullets already selected (pins, coverage picks); their
+ *   similarity counts against candidates from the start.
+ * @param caps - Per-company / per-position counts so far.
+ * @param limit - Maximum picks.
+ * @param redundancyPenalty - Weight of the similarity penalty.
+ * @returns Picked bullets, in pick order.
+ */
+export function selectMmr<T extends ScoredBullet>(
+  sortedBullets: readonly T[],
+  required: readonly T[],
+  caps: CapTracker,
+  limit: number,
+  redundancyPenalty: number,
+): T[] {
+  const candidateShingles = sortedBullets.map((b) => shingles(b.bullet.description));
+  const requiredShingles = required.map((b) => shingles(b.bullet.description));
+
+  // Highest similarity of each candidate to anything selected so far
+  const maxSimilarity = candidateShingles.map((candidate) =>
+    requiredShingles.reduce((max, pin) => Math.max(max, jaccard(candidate, pin)), 0),
+  );
+  const taken = sortedBullets.map(() => false);
+  const picks: T[] = [];
+
+  while (picks.length < limit) {
+    let best = -1;
+    let bestValue = -Infinity;
+    sortedBullets.forEach((bullet, i) => {
+      if (taken[i] || !caps.allows(bullet)) return;
+      const value = bullet.score - redundancyPenalty * (maxSimilarity[i] ?? 0);
+      // Strictly greater keeps the earlier (higher-scored) bullet on ties
+      if (best < 0 || value > bestValue) {
+        best = i;
+        bestValue = value;
+      }
+    });
+
+    const pick = sortedBullets[best];
+    const pickShingles = candidateShingles[best];
+    if (!pick || !pickShingles) break;
+
+    taken[best] = true;
+    caps.record(pick);
+    picks.push(pick);
+
+    candidateShingles.forEach((candidate, i) => {
+      if (!taken[i]) {
+        maxSimilarity[i] = Math.max(maxSimilarity[i] ?? 0, jaccard(candidate, pickShingles));
+      }
+    });
+  }
+
+  return picks;
+}
@@ -13,12 +13,42 @@
  */
 
 import type { Bullet, ResumeData } from "@/types/resume";
-import { applyDiversityConstraints } from "@/lib/selection-diversity";
+import { applyConstraints } from "@/lib/selection-diversity";
+
+/**
+ * How bullets are picked from the scored list.
+ *
+ * - `greedy`: highest score first, constrained only by the diversity caps
+ * - `mmr`: maximal marginal relevance; each pick maximises
+ *   `score - redundancyPenalty × maxSimilarity(selected)`, so a bullet that
+ *   restates an already-selected achievement loses to a distinct one.
+ *   0 behaves like greedy; 0.5 is a reasonable starting point.
+ */
+export type SelectionMode = { type: "greedy" } | { type: "mmr"; redundancyPenalty: number };
+
+/**
+ * Manual overrides for one application. Pinned bullets are always selected
+ * (bypassing scoring, but using up `maxBullets` and the caps); excluded
+ * bullets and companies never are. Exclusions beat pins.
+ */
+export interface SelectionOverrides {
+  /** Bullets always selected, in pin order */
+  pinnedBulletIds?: string[];
+  /** Bullets never selected */
+  excludedBulletIds?: string[];
+  /** Only these companies are eligible when non-empty */
+  includedCompanyIds?: string[];
+  /** Companies never selected from */
+  excludedCompanyIds?: string[];
+}
 
 /**
  * Configuration for bullet selection diversity constraints.
+ *
+ * Mirrors the Rust `SelectionConfig` (`crates/resume-core/src/selector.rs`),
+ * including its flattened overrides.
  */
-export interface SelectionConfig {
+export interface SelectionConfig extends SelectionOverrides {
   /** Maximum total bullets to select (ceiling - may select fewer based on constraints) */
   maxBullets: number;
   /** Maximum bullets per company (for diversity across employers) */
@@ -27,6 +57,35 @@ export interface SelectionConfig {
   minPerCompany?: number;
   /** Maximum bullets per position (prevent one role dominating) */
   maxPerPosition?: number;
+  /** Selection strategy (default greedy) */
+  mode?: SelectionMode;
+  /** Tags that need at least one selected bullet as evidence */
+  mustCoverTags?: string[];
+}
+
+/** Why a required tag has no selected bullet. */
+export type CoverageGap =
+  /** No candidate bullet carries the tag */
+  | "noBullet"
+  /** Every bullet carrying the tag is excluded by the overrides */
+  | "excluded"
+  /** Candidates exist but `maxBullets` or a diversity cap left no room */
+  | "noRoom";
+
+/** Coverage of one `mustCoverTags` requirement. */
+export interface RequirementCoverage {
+  tag: string;
+  /** Selected bullets carrying the tag, in selection order */
+  coveredBy: string[];
+  /** Set when `coveredBy` is empty */
+  gap?: CoverageGap;
+}
+
+/** Selected bullets plus a coverage report for `mustCoverTags`. */
+export interface Selection<T extends ScoredBullet = ScoredBullet> {
+  bullets: T[];
+  /** One entry per required tag, in config order */
+  coverage: RequirementCoverage[];
 }
 
 /**
@@ -80,31 +139,85 @@ export const DEFAULT_SELECTION_CONFIG: SelectionConfig = {
   maxPerPosition: 4,
 };
 
+/** Fields of {@link SelectionConfig} beyond the numeric caps. */
+export type SelectionOptions = Pick<
+  SelectionConfig,
+  keyof SelectionOverrides | "mode" | "mustCoverTags"
+>;
+
+/**
+ * Read the mode, overrides and `mustCoverTags` from an untrusted request
+ * `config` object. Malformed fields are dropped rather than rejected, like
+ * the numeric caps falling back to their defaults.
+ *
+ * @param config - The request's `config` value (any shape).
+ * @returns Only the well-formed fields.
+ */
+export function parseSelectionOptions(config: unknown): SelectionOptions {
+  if (!config || typeof config !== "object") return {};
+  const input = config as Record<string, unknown>;
+  const ids = (value: unknown) =>
+    Array.isArray(value) && value.every((id) => typeof id === "string")
+      ? (value as string[])
+      : undefined;
+
+  const options: SelectionOptions = {};
+  for (const key of [
+    "pinnedBulletIds",
+    "excludedBulletIds",
+    "includedCompanyIds",
+    "excludedCompanyIds",
+    "mustCoverTags",
+  ] as const) {
+    const value = ids(input[key]);
+    if (value) options[key] = value;
+  }
+
+  const mode = input.mode as { type?: unknown; redundancyPenalty?: unknown } | undefined;
+  if (mode?.type === "greedy") options.mode = { type: "greedy" };
+  if (
+    mode?.type === "mmr" &&
+    typeof mode.redundancyPenalty === "number" &&
+    Number.isFinite(mode.redundancyPenalty) &&
+    mode.redundancyPenalty >= 0
+  ) {
+    options.mode = { type: "mmr", redundancyPenalty: mode.redundancyPenalty };
+  }
+  return options;
+}
+
 /**
  * Select top bullets applying diversity constraints
  *
  * Algorithm:
  * 1. Build full bullet objects from resume data + scores
  * 2. Sort by score descending
- * 3. Select bullets while respecting per-company and per-position limits
+ * 3. Apply overrides, `mustCoverTags`, then per-company and per-position
+ *    limits (see {@link applyConstraints})
  * 4. Remove companies with fewer than minPerCompany bullets
  *
+ * Pins bypass scoring, so a pinned bullet the scores skipped (e.g. the AI
+ * had no opinion on it) still competes, with score 0.
+ *
  * @param resumeData - Full resume data
  * @param scores - Bullet ID to score map
  * @param config - Selection configuration
+ * @returns Selected bullets plus the `mustCoverTags` coverage report
  */
 export function selectBulletsWithConstraints(
   resumeData: ResumeData,
   scores: Map<string, number>,
   config: SelectionConfig = DEFAULT_SELECTION_CONFIG,
-): SelectedBullet[] {
+): Selection<SelectedBullet> {
+  const pinned = new Set(config.pinnedBulletIds ?? []);
+
   // Step 1: Build all bullet candidates with scores
   const allBullets: SelectedBullet[] = [];
 
   for (const company of resumeData.experience) {
     for (const position of company.children) {
       for (const bullet of position.children) {
-        const score = scores.get(bullet.id);
+        const score = scores.get(bullet.id) ?? (pinned.has(bullet.id) ? 0 : undefined);
         if (score === undefined) continue; // Bullet wasn't scored
 
         allBullets.push({
@@ -130,8 +243,6 @@ export function selectBulletsWithConstraints(
   // Step 2: Sort by score descending
   allBullets.sort((a, b) => b.score - a.score);
 
-  // Step 3: Apply diversity constraints
-  const selected = applyDiversityConstraints(allBullets, config);
-
-  return selected;
+  // Step 3: Apply overrides, coverage and diversity constraints
+  return applyConstraints(allBullets, config);
 }
@@ -0,0 +1,101 @@
+/**
+ * Text normalization + near-duplicate similarity for bullet selection.
+ *
+ * Port of `crates/resume-core/src/text.rs` and `similarity.rs`: lowercase
+ * word tokens, a small stopword list, a light suffix stemmer, and Jaccard
+ * similarity over unigram + bigram shingles. Keep the two in step so both
+ * engines treat the same bullets as near-duplicates.
+ *
+ * @module lib/text-similarity
+ */
+
+/**
+ * Words that carry no signal for matching, including job-posting
+ * boilerplate ("experience", "team", "role").
+ */
+const STOPWORDS = new Set(
+  (
+    "a about across all also an and any are as at be been being both but by can could do does " +
+    "each either etc for from had has have having how i if in into is it its just may more most " +
+    "much must my no not of on one or other our out over per plus should so such than that the " +
+    "their them then there these they this those through to up us very via was we were what " +
+    "when where which while who whom why will with within would you your yours " +
+    "ability able candidate company day environment excellent experience ideal ideally including " +
+    "join looking new opportunity preferred year required requirement responsibility role skill " +
+    "strong team work working"
+  ).split(" "),
+);
+
+/**
+ * Lowercase and split text into raw word tokens, keeping `+` and `#` so
+ * `c++` and `c#` survive.
+ */
+export function words(text: string): string[] {
+  return text
+    .split(/[^\p{L}\p{N}+#]+/u)
+    .filter((word) => word.length > 0)
+    .map((word) => word.toLowerCase());
+}
+
+/**
+ * Reduce a lowercase word to a crude stem, so "systems"/"system" and
+ * "scaling"/"scaled"/"scale" collapse together. Short and non-ASCII words
+ * are left alone.
+ */
+export function stem(word: string): string {
+  const length = [...word].length;
+  if (length <= 3 || !/^[\x00-\x7f]*$/.test(word)) return word;
+
+  if (word.endsWith("ies")) return `${word.slice(0, -3)}y`;
+  if (length > 5 && word.endsWith("ing")) return word.slice(0, -3);
+  if (length > 4 && word.endsWith("ed")) return word.slice(0, -2);
+  if (word.endsWith("s") && !word.endsWith("ss") && !word.endsWith("us") && !word.endsWith("is")) {
+    return word.slice(0, -1);
+  }
+  if (length > 4 && word.endsWith("e")) return word.slice(0, -1);
+  return word;
+}
+
+/** Whether a lowercase word (or its stem) is a stopword. */
+export function isStopword(word: string): boolean {
+  return STOPWORDS.has(word) || STOPWORDS.has(stem(word));
+}
+
+/** Stemmed terms with stopwords and numbers removed. */
+export function keywords(text: string): string[] {
+  return words(text)
+    .filter((word) => !isStopword(word) && /\p{L}/u.test(word))
+    .map(stem);
+}
+
+/** Unigram + bigram shingles of a piece of text. */
+export function shingles(text: string): Set<string> {
+  const terms = keywords(text);
+  const result = new Set(terms);
+  for (let i = 0; i + 1 < terms.length; i++) {
+    result.add(`${terms[i]} ${terms[i + 1]}`);
+  }
+  return result;
+}
+
+/** Jaccard similarity in 0-1 (0 if either side is empty). */
+export function jaccard(a: Set<string>, b: Set<string>): number {
+  if (a.size === 0 || b.size === 0) return 0;
+  let intersection = 0;
+  for (const shingle of a) {
+    if (b.has(shingle)) intersection++;
+  }
+  return intersection / (a.size + b.size - intersection);
+}
+
+/**
+ * Similarity of two texts, see {@link jaccard}.
+ *
+ * @example
+ * ```ts
+ * similarity("Migrated payments to Kubernetes", "Payments migrated to Kubernetes") // ≈ 0.43
+ * ```
+ */
+export function similarity(a: string, b: string): number {
+  return jaccard(shingles(a), shingles(b));
+}
@@ -34,6 +34,10 @@ export interface ResumeData {
   skills?: {
     [k: string]: string[]
   } | null
+  /**
+   * Tagged summary candidates, scored per role like bullets; falls back to summary when absent (optional)
+   */
+  summaries?: SummaryCandidate[] | null
   /**
    * Professional summary 2-3 sentences (optional)
    */
@@ -138,6 +142,10 @@ export interface Position {
    * List of Bullet objects - achievements/responsibilities for this role (required)
    */
   children: Bullet[]
+  /**
+   * Held alongside other roles (advisory, board, part-time), so overlaps with it are expected (optional)
+   */
+  concurrent?: boolean | null
   /**
    * End date or null for Present (optional)
    */
@@ -295,6 +303,10 @@ export interface RoleProfile {
    */
   name: string
   scoringWeights: ScoringWeights
+  /**
+   * ID of the summary candidate to use for this role, bypassing tag scoring (optional)
+   */
+  summaryId?: string | null
   /**
    * Map of tag names to relevance weights 0.0-1.0, higher = more relevant (required)
    */
@@ -317,3 +329,29 @@ export interface ScoringWeights {
   tagRelevance: number
   [k: string]: unknown
 }
+/**
+ * Tagged professional summary
+ *
+ * A compendium can hold several summaries aimed at different roles. The one
+ * shown on a generated resume is picked with the same tag-weight scoring as
+ * bullets, unless the role profile names one via `summaryId`.
+ */
+export interface SummaryCandidate {
+  /**
+   * Unique identifier (required)
+   */
+  id: string
+  /**
+   * Fallback ranking 1-10 when tags tie, higher = preferred (required)
+   */
+  priority: number
+  /**
+   * Category tags for scoring against role profiles (required)
+   */
+  tags: string[]
+  /**
+   * Summary text, 2-3 sentences (required)
+   */
+  text: string
+  [k: string]: unknown
+}
@@ -1,6 +1,54 @@
 /* tslint:disable */
 /* eslint-disable */
 
+/**
+ * A PDF and previews of its pages, from `generate_pdf_with_preview`
+ */
+export class PdfWithPreview {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    /**
+     * Page previews JSON, as `render_preview` returns it
+     */
+    readonly pages: string;
+    /**
+     * PDF bytes, as `generate_pdf_typst` returns them
+     */
+    readonly pdf: Uint8Array;
+}
+
+/**
+ * Compute per-tag usage statistics for a compendium
+ *
+ * Returns JSON with per-tag counts over companies, positions and bullets,
+ * priority weight, date span, companies and co-occurrence, plus unused
+ * role-profile tags and orphan tags (tags used exactly once).
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const analytics = JSON.parse(analyze_tags(JSON.stringify(resumeData)));
+ * analytics.orphanTags; // ["kafka", ...]
+ * ```
+ */
+export function analyze_tags(resume_json: string): string;
+
+/**
+ * Check a compendium's employment timeline
+ *
+ * Returns JSON with gaps between companies, overlapping non-concurrent
+ * positions, tenure per company and any unparseable dates.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const report = JSON.parse(
+ *   analyze_timeline(JSON.stringify(resumeData), JSON.stringify({ gapThresholdMonths: 6, asOf: "2025-01" }))
+ * );
+ * report.gaps; // [{ afterCompanyId, beforeCompanyId, start, end, months }]
+ * ```
+ */
+export function analyze_timeline(resume_json: string, options_json?: string | null): string;
+
 /**
  * Get build timestamp (when WASM was compiled)
  */
@@ -19,7 +67,10 @@ export function estimate_pdf_size(bullet_count: number): number;
  *
  * # Arguments
  * * `payload_json` - JSON string containing GenerationPayload
- * * `dev_mode` - If true, includes build metadata in PDF
+ * * `options_json` - Optional RenderOptions JSON (template, paper, margins,
+ *   font size, sections, date style, `debug` for the build metadata page).
+ *   When omitted, the options recorded in `metadata.renderOptions` are
+ *   reused so a stored payload renders the same PDF again.
  *
  * # Returns
  * * `Result<Vec<u8>, JsValue>` - PDF bytes or error
@@ -32,13 +83,198 @@ export function estimate_pdf_size(bullet_count: number): number;
  *   role_profile: {...},
  * });
  * const isDev = window.location.hostname === 'localhost';
- * const pdfBytes = await generate_pdf_typst(payloadJson, isDev);
+ * const options = { template: 'modern', paper: 'a4', debug: isDev };
+ * const pdfBytes = await generate_pdf_typst(payloadJson, JSON.stringify(options));
+ * ```
+ */
+export function generate_pdf_typst(payload_json: string, options_json?: string | null): Uint8Array;
+
+/**
+ * Generate PDF from a user-supplied template bundle
+ *
+ * `bundle` is a `.zip`, `.tar` or `.tar.gz` archive holding the main `.typ`
+ * file plus any modules, images and fonts it uses. The template reads its
+ * data with `json("data.json")`; see `template_data_schema` for the shape.
+ * `template` in the options is ignored.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const bundle = new Uint8Array(await file.arrayBuffer());
+ * const pdfBytes = generate_pdf_typst_bundle(payloadJson, bundle, JSON.stringify({ paper: 'a4' }));
  * ```
  */
-export function generate_pdf_typst(payload_json: string, dev_mode: boolean): Uint8Array;
+export function generate_pdf_typst_bundle(payload_json: string, bundle: Uint8Array, options_json?: string | null): Uint8Array;
+
+/**
+ * Generate the PDF and its page previews from a single compile
+ *
+ * Takes the same arguments as `render_preview`. Use it instead of calling
+ * `generate_pdf_typst` and `render_preview` back to back, which compiles
+ * the resume twice.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const output = generate_pdf_with_preview(payloadJson, null, JSON.stringify({ format: 'png' }));
+ * thumbnail.src = JSON.parse(output.pages)[0].dataUrl;
+ * const blob = new Blob([output.pdf], { type: 'application/pdf' });
+ * output.free();
+ * ```
+ */
+export function generate_pdf_with_preview(payload_json: string, options_json?: string | null, preview_json?: string | null): PdfWithPreview;
 
 export function init_panic_hook(): void;
 
+/**
+ * Refit a role profile's tag weights from kept and swapped-out bullets
+ *
+ * Returns JSON with the updated `profile`, the per-tag `changes` (largest
+ * first), example counts, training log loss and any unknown or conflicting
+ * IDs.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const learned = JSON.parse(
+ *   learn_tag_weights(
+ *     JSON.stringify(resumeData),
+ *     JSON.stringify(roleProfile),
+ *     JSON.stringify({ accepted: keptIds, rejected: swappedIds }),
+ *   )
+ * );
+ * learned.changes; // [{ tag, before, after, coefficient }]
+ * ```
+ */
+export function learn_tag_weights(resume_json: string, role_profile_json: string, feedback_json: string, options_json?: string | null): string;
+
+/**
+ * Lint bullet descriptions and summaries for the editor
+ *
+ * Returns a JSON array of findings in document order, each with `rule`,
+ * `severity`, `path` (e.g. `experience[0].children[1].children[2].description`),
+ * `itemId`, `message` and, for duplicates and repeated openings, `relatedPath`.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const findings = JSON.parse(
+ *   lint_resume(JSON.stringify(resumeData), JSON.stringify({ severities: { "missing-metric": "off" } }))
+ * );
+ * ```
+ */
+export function lint_resume(resume_json: string, config_json?: string | null): string;
+
+/**
+ * List the built-in Typst templates for a template picker
+ *
+ * Returns a JSON array of `{ name, label, description, thumbnail }`, where
+ * `thumbnail` is inline SVG markup. Pass `name` as `template` in the
+ * `generate_pdf_typst` options.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const templates = JSON.parse(list_templates());
+ * picker.innerHTML = templates.map(t => `<button title="${t.description}">${t.thumbnail}${t.label}</button>`).join('');
+ * ```
+ */
+export function list_templates(): string;
+
+/**
+ * Read the metadata of a downloaded resume PDF
+ *
+ * Returns JSON `{ title, author, subject, keywords, created, generationId,
+ * bulletIds }`, tracing the file back to the generation that produced it.
+ */
+export function read_pdf_metadata(pdf: Uint8Array): string;
+
+/**
+ * Render page images for an in-browser preview or thumbnail
+ *
+ * `preview_json` picks the format: `{ "format": "png", "dpi": 144 }`
+ * (default 96 dpi) or `{ "format": "svg" }`. Returns a JSON array with one
+ * `{ width, height, dataUrl }` per page (size in points), ready for
+ * `<img src>`. Pages match what `generate_pdf_typst` produces with the same
+ * options.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const pages = JSON.parse(render_preview(payloadJson, null, JSON.stringify({ format: 'png', dpi: 72 })));
+ * thumbnail.src = pages[0].dataUrl;
+ * ```
+ */
+export function render_preview(payload_json: string, options_json?: string | null, preview_json?: string | null): string;
+
+/**
+ * Blend heuristic scores with external (e.g. AI) scores
+ *
+ * Each bullet gets the weighted mean of its normalized heuristic score and
+ * its external score, so bullets the AI skipped keep their heuristic score.
+ * Weights default to `{ heuristic: 0.3, external: 0.7 }`. Returns a JSON
+ * array of `ScoredBullet`, highest score first.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const scored = JSON.parse(
+ *   score_bullets_ensemble(
+ *     JSON.stringify(resumeData),
+ *     JSON.stringify(roleProfile),
+ *     JSON.stringify({ b1: 0.9, b2: 0.4 }),
+ *     JSON.stringify({ heuristic: 0.5, external: 0.5 }),
+ *   )
+ * );
+ * ```
+ */
+export function score_bullets_ensemble(resume_json: string, role_profile_json: string, external_scores_json: string, weights_json?: string | null): string;
+
+/**
+ * Full-text search over bullets for the site's search box
+ *
+ * Supports free text, `"quoted phrases"`, `tag:`, `company:`, `after:`,
+ * `before:` and `priority>=N`. Each hit carries its `company` and
+ * `position` (id + name), `path` and BM25 `score`.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const hits = JSON.parse(
+ *   search_bullets(JSON.stringify(resumeData), 'tag:kafka after:2020 "migration"', JSON.stringify({ limit: 10 }))
+ * );
+ * ```
+ */
+export function search_bullets(resume_json: string, query: string, options_json?: string | null): string;
+
+/**
+ * Pick the summary candidate that best matches a role profile
+ *
+ * Honours the profile's `summaryId` override, otherwise scores tagged
+ * candidates like bullets and falls back to `ResumeData.summary`.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const selected = JSON.parse(select_summary(JSON.stringify(resumeData), JSON.stringify(roleProfile)));
+ * payload.summary = selected?.text;
+ * ```
+ */
+export function select_summary(resume_json: string, role_profile_json: string): string;
+
+/**
+ * JSON Schema of the `data.json` document templates read
+ */
+export function template_data_schema(): string;
+
+/**
+ * Compile a payload and report errors and warnings without returning a PDF
+ *
+ * Returns a JSON array of `{ severity, message, hints?, span?, text?,
+ * fields? }`. `span` locates the problem in the template (`file`, `line`,
+ * `column`, `snippet`); `fields` names the payload fields to fix, e.g.
+ * `selected_bullets[4].bullet.description` for a character no font covers.
+ * Compile errors are returned as diagnostics rather than thrown.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const diagnostics = JSON.parse(typst_diagnostics(payloadJson));
+ * for (const d of diagnostics) console.warn(d.severity, d.message, d.fields ?? []);
+ * ```
+ */
+export function typst_diagnostics(payload_json: string, options_json?: string | null): string;
+
 /**
  * Validate JSON payload structure without generating
  *
@@ -46,6 +282,15 @@ export function init_panic_hook(): void;
  */
 export function validate_payload_json(payload_json: string): void;
 
+/**
+ * Check a template bundle archive before rendering with it
+ *
+ * Reports every problem at once (unresolved imports, missing images,
+ * fields not in the data schema, unsupported data version, ...). On
+ * success returns JSON `{ name, main, files }` describing the bundle.
+ */
+export function validate_template_bundle(bundle: Uint8Array): string;
+
 /**
  * Test export to validate WASM build pipeline
  */
@@ -55,11 +300,29 @@ export type InitInput = RequestInfo | URL | Response | BufferSource | WebAssembl
 
 export interface InitOutput {
     readonly memory: WebAssembly.Memory;
+    readonly __wbg_pdfwithpreview_free: (a: number, b: number) => void;
+    readonly analyze_tags: (a: number, b: number, c: number) => void;
+    readonly analyze_timeline: (a: number, b: number, c: number, d: number, e: number) => void;
     readonly build_info: (a: number) => void;
     readonly estimate_pdf_size: (a: number) => number;
-    readonly generate_pdf_typst: (a: number, b: number, c: number, d: number) => void;
+    readonly generate_pdf_typst: (a: number, b: number, c: number, d: number, e: number) => void;
+    readonly generate_pdf_typst_bundle: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+    readonly generate_pdf_with_preview: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
     readonly init_panic_hook: () => void;
+    readonly learn_tag_weights: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number) => void;
+    readonly lint_resume: (a: number, b: number, c: number, d: number, e: number) => void;
+    readonly list_templates: (a: number) => void;
+    readonly pdfwithpreview_pages: (a: number, b: number) => void;
+    readonly pdfwithpreview_pdf: (a: number, b: number) => void;
+    readonly read_pdf_metadata: (a: number, b: number, c: number) => void;
+    readonly render_preview: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+    readonly score_bullets_ensemble: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number) => void;
+    readonly search_bullets: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+    readonly select_summary: (a: number, b: number, c: number, d: number, e: number) => void;
+    readonly template_data_schema: (a: number) => void;
+    readonly typst_diagnostics: (a: number, b: number, c: number, d: number, e: number) => void;
     readonly validate_payload_json: (a: number, b: number, c: number) => void;
+    readonly validate_template_bundle: (a: number, b: number, c: number) => void;
     readonly version: (a: number) => void;
     readonly lut_inverse_interp16: (a: number, b: number, c: number) => number;
     readonly qcms_profile_precache_output_transform: (a: number) => void;
@@ -1,5 +1,154 @@
 /* @ts-self-types="./resume_wasm.d.ts" */
 
+/**
+ * A PDF and previews of its pages, from `generate_pdf_with_preview`
+ */
+export class PdfWithPreview {
+    static __wrap(ptr) {
+        ptr = ptr >>> 0;
+        const obj = Object.create(PdfWithPreview.prototype);
+        obj.__wbg_ptr = ptr;
+        PdfWithPreviewFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        PdfWithPreviewFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_pdfwithpreview_free(ptr, 0);
+    }
+    /**
+     * Page previews JSON, as `render_preview` returns it
+     * @returns {string}
+     */
+    get pages() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.pdfwithpreview_pages(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_export(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * PDF bytes, as `generate_pdf_typst` returns them
+     * @returns {Uint8Array}
+     */
+    get pdf() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.pdfwithpreview_pdf(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayU8FromWasm0(r0, r1).slice();
+            wasm.__wbindgen_export(r0, r1 * 1, 1);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+}
+if (Symbol.dispose) PdfWithPreview.prototype[Symbol.dispose] = PdfWithPreview.prototype.free;
+
+/**
+ * Compute per-tag usage statistics for a compendium
+ *
+ * Returns JSON with per-tag counts over companies, positions and bullets,
+ * priority weight, date span, companies and co-occurrence, plus unused
+ * role-profile tags and orphan tags (tags used exactly once).
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const analytics = JSON.parse(analyze_tags(JSON.stringify(resumeData)));
+ * analytics.orphanTags; // ["kafka", ...]
+ * ```
+ * @param {string} resume_json
+ * @returns {string}
+ */
+export function analyze_tags(resume_json) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(resume_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.analyze_tags(retptr, ptr0, len0);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr2 = r0;
+        var len2 = r1;
+        if (r3) {
+            ptr2 = 0; len2 = 0;
+            throw takeObject(r2);
+        }
+        deferred3_0 = ptr2;
+        deferred3_1 = len2;
+        return getStringFromWasm0(ptr2, len2);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_export(deferred3_0, deferred3_1, 1);
+    }
+}
+
+/**
+ * Check a compendium's employment timeline
+ *
+ * Returns JSON with gaps between companies, overlapping non-concurrent
+ * positions, tenure per company and any unparseable dates.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const report = JSON.parse(
+ *   analyze_timeline(JSON.stringify(resumeData), JSON.stringify({ gapThresholdMonths: 6, asOf: "2025-01" }))
+ * );
+ * report.gaps; // [{ afterCompanyId, beforeCompanyId, start, end, months }]
+ * ```
+ * @param {string} resume_json
+ * @param {string | null} [options_json]
+ * @returns {string}
+ */
+export function analyze_timeline(resume_json, options_json) {
+    let deferred4_0;
+    let deferred4_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(resume_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(options_json) ? 0 : passStringToWasm0(options_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.analyze_timeline(retptr, ptr0, len0, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr3 = r0;
+        var len3 = r1;
+        if (r3) {
+            ptr3 = 0; len3 = 0;
+            throw takeObject(r2);
+        }
+        deferred4_0 = ptr3;
+        deferred4_1 = len3;
+        return getStringFromWasm0(ptr3, len3);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_export(deferred4_0, deferred4_1, 1);
+    }
+}
+
 /**
  * Get build timestamp (when WASM was compiled)
  * @returns {string}
@@ -39,7 +188,10 @@ export function estimate_pdf_size(bullet_count) {
  *
  * # Arguments
  * * `payload_json` - JSON string containing GenerationPayload
- * * `dev_mode` - If true, includes build metadata in PDF
+ * * `options_json` - Optional RenderOptions JSON (template, paper, margins,
+ *   font size, sections, date style, `debug` for the build metadata page).
+ *   When omitted, the options recorded in `metadata.renderOptions` are
+ *   reused so a stored payload renders the same PDF again.
  *
  * # Returns
  * * `Result<Vec<u8>, JsValue>` - PDF bytes or error
@@ -52,18 +204,64 @@ export function estimate_pdf_size(bullet_count) {
  *   role_profile: {...},
  * });
  * const isDev = window.location.hostname === 'localhost';
- * const pdfBytes = await generate_pdf_typst(payloadJson, isDev);
+ * const options = { template: 'modern', paper: 'a4', debug: isDev };
+ * const pdfBytes = await generate_pdf_typst(payloadJson, JSON.stringify(options));
+ * ```
+ * @param {string} payload_json
+ * @param {string | null} [options_json]
+ * @returns {Uint8Array}
+ */
+export function generate_pdf_typst(payload_json, options_json) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(payload_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(options_json) ? 0 : passStringToWasm0(options_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.generate_pdf_typst(retptr, ptr0, len0, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v3 = getArrayU8FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export(r0, r1 * 1, 1);
+        return v3;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
+
+/**
+ * Generate PDF from a user-supplied template bundle
+ *
+ * `bundle` is a `.zip`, `.tar` or `.tar.gz` archive holding the main `.typ`
+ * file plus any modules, images and fonts it uses. The template reads its
+ * data with `json("data.json")`; see `template_data_schema` for the shape.
+ * `template` in the options is ignored.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const bundle = new Uint8Array(await file.arrayBuffer());
+ * const pdfBytes = generate_pdf_typst_bundle(payloadJson, bundle, JSON.stringify({ paper: 'a4' }));
  * ```
  * @param {string} payload_json
- * @param {boolean} dev_mode
+ * @param {Uint8Array} bundle
+ * @param {string | null} [options_json]
  * @returns {Uint8Array}
  */
-export function generate_pdf_typst(payload_json, dev_mode) {
+export function generate_pdf_typst_bundle(payload_json, bundle, options_json) {
     try {
         const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
         const ptr0 = passStringToWasm0(payload_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
         const len0 = WASM_VECTOR_LEN;
-        wasm.generate_pdf_typst(retptr, ptr0, len0, dev_mode);
+        const ptr1 = passArray8ToWasm0(bundle, wasm.__wbindgen_export2);
+        const len1 = WASM_VECTOR_LEN;
+        var ptr2 = isLikeNone(options_json) ? 0 : passStringToWasm0(options_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        var len2 = WASM_VECTOR_LEN;
+        wasm.generate_pdf_typst_bundle(retptr, ptr0, len0, ptr1, len1, ptr2, len2);
         var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
         var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
         var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
@@ -71,9 +269,50 @@ export function generate_pdf_typst(payload_json, dev_mode) {
         if (r3) {
             throw takeObject(r2);
         }
-        var v2 = getArrayU8FromWasm0(r0, r1).slice();
+        var v4 = getArrayU8FromWasm0(r0, r1).slice();
         wasm.__wbindgen_export(r0, r1 * 1, 1);
-        return v2;
+        return v4;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
+
+/**
+ * Generate the PDF and its page previews from a single compile
+ *
+ * Takes the same arguments as `render_preview`. Use it instead of calling
+ * `generate_pdf_typst` and `render_preview` back to back, which compiles
+ * the resume twice.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const output = generate_pdf_with_preview(payloadJson, null, JSON.stringify({ format: 'png' }));
+ * thumbnail.src = JSON.parse(output.pages)[0].dataUrl;
+ * const blob = new Blob([output.pdf], { type: 'application/pdf' });
+ * output.free();
+ * ```
+ * @param {string} payload_json
+ * @param {string | null} [options_json]
+ * @param {string | null} [preview_json]
+ * @returns {PdfWithPreview}
+ */
+export function generate_pdf_with_preview(payload_json, options_json, preview_json) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(payload_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(options_json) ? 0 : passStringToWasm0(options_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        var ptr2 = isLikeNone(preview_json) ? 0 : passStringToWasm0(preview_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        var len2 = WASM_VECTOR_LEN;
+        wasm.generate_pdf_with_preview(retptr, ptr0, len0, ptr1, len1, ptr2, len2);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return PdfWithPreview.__wrap(r0);
     } finally {
         wasm.__wbindgen_add_to_stack_pointer(16);
     }
@@ -83,6 +322,452 @@ export function init_panic_hook() {
     wasm.init_panic_hook();
 }
 
+/**
+ * Refit a role profile's tag weights from kept and swapped-out bullets
+ *
+ * Returns JSON with the updated `profile`, the per-tag `changes` (largest
+ * first), example counts, training log loss and any unknown or conflicting
+ * IDs.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const learned = JSON.parse(
+ *   learn_tag_weights(
+ *     JSON.stringify(resumeData),
+ *     JSON.stringify(roleProfile),
+ *     JSON.stringify({ accepted: keptIds, rejected: swappedIds }),
+ *   )
+ * );
+ * learned.changes; // [{ tag, before, after, coefficient }]
+ * ```
+ * @param {string} resume_json
+ * @param {string} role_profile_json
+ * @param {string} feedback_json
+ * @param {string | null} [options_json]
+ * @returns {string}
+ */
+export function learn_tag_weights(resume_json, role_profile_json, feedback_json, options_json) {
+    let deferred6_0;
+    let deferred6_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(resume_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(role_profile_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passStringToWasm0(feedback_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len2 = WASM_VECTOR_LEN;
+        var ptr3 = isLikeNone(options_json) ? 0 : passStringToWasm0(options_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        var len3 = WASM_VECTOR_LEN;
+        wasm.learn_tag_weights(retptr, ptr0, len0, ptr1, len1, ptr2, len2, ptr3, len3);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr5 = r0;
+        var len5 = r1;
+        if (r3) {
+            ptr5 = 0; len5 = 0;
+            throw takeObject(r2);
+        }
+        deferred6_0 = ptr5;
+        deferred6_1 = len5;
+        return getStringFromWasm0(ptr5, len5);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_export(deferred6_0, deferred6_1, 1);
+    }
+}
+
+/**
+ * Lint bullet descriptions and summaries for the editor
+ *
+ * Returns a JSON array of findings in document order, each with `rule`,
+ * `severity`, `path` (e.g. `experience[0].children[1].children[2].description`),
+ * `itemId`, `message` and, for duplicates and repeated openings, `relatedPath`.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const findings = JSON.parse(
+ *   lint_resume(JSON.stringify(resumeData), JSON.stringify({ severities: { "missing-metric": "off" } }))
+ * );
+ * ```
+ * @param {string} resume_json
+ * @param {string | null} [config_json]
+ * @returns {string}
+ */
+export function lint_resume(resume_json, config_json) {
+    let deferred4_0;
+    let deferred4_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(resume_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(config_json) ? 0 : passStringToWasm0(config_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.lint_resume(retptr, ptr0, len0, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr3 = r0;
+        var len3 = r1;
+        if (r3) {
+            ptr3 = 0; len3 = 0;
+            throw takeObject(r2);
+        }
+        deferred4_0 = ptr3;
+        deferred4_1 = len3;
+        return getStringFromWasm0(ptr3, len3);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_export(deferred4_0, deferred4_1, 1);
+    }
+}
+
+/**
+ * List the built-in Typst templates for a template picker
+ *
+ * Returns a JSON array of `{ name, label, description, thumbnail }`, where
+ * `thumbnail` is inline SVG markup. Pass `name` as `template` in the
+ * `generate_pdf_typst` options.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const templates = JSON.parse(list_templates());
+ * picker.innerHTML = templates.map(t => `<button title="${t.description}">${t.thumbnail}${t.label}</button>`).join('');
+ * ```
+ * @returns {string}
+ */
+export function list_templates() {
+    let deferred2_0;
+    let deferred2_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        wasm.list_templates(retptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr1 = r0;
+        var len1 = r1;
+        if (r3) {
+            ptr1 = 0; len1 = 0;
+            throw takeObject(r2);
+        }
+        deferred2_0 = ptr1;
+        deferred2_1 = len1;
+        return getStringFromWasm0(ptr1, len1);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_export(deferred2_0, deferred2_1, 1);
+    }
+}
+
+/**
+ * Read the metadata of a downloaded resume PDF
+ *
+ * Returns JSON `{ title, author, subject, keywords, created, generationId,
+ * bulletIds }`, tracing the file back to the generation that produced it.
+ * @param {Uint8Array} pdf
+ * @returns {string}
+ */
+export function read_pdf_metadata(pdf) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(pdf, wasm.__wbindgen_export2);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.read_pdf_metadata(retptr, ptr0, len0);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr2 = r0;
+        var len2 = r1;
+        if (r3) {
+            ptr2 = 0; len2 = 0;
+            throw takeObject(r2);
+        }
+        deferred3_0 = ptr2;
+        deferred3_1 = len2;
+        return getStringFromWasm0(ptr2, len2);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_export(deferred3_0, deferred3_1, 1);
+    }
+}
+
+/**
+ * Render page images for an in-browser preview or thumbnail
+ *
+ * `preview_json` picks the format: `{ "format": "png", "dpi": 144 }`
+ * (default 96 dpi) or `{ "format": "svg" }`. Returns a JSON array with one
+ * `{ width, height, dataUrl }` per page (size in points), ready for
+ * `<img src>`. Pages match what `generate_pdf_typst` produces with the same
+ * options.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const pages = JSON.parse(render_preview(payloadJson, null, JSON.stringify({ format: 'png', dpi: 72 })));
+ * thumbnail.src = pages[0].dataUrl;
+ * ```
+ * @param {string} payload_json
+ * @param {string | null} [options_json]
+ * @param {string | null} [preview_json]
+ * @returns {string}
+ */
+export function render_preview(payload_json, options_json, preview_json) {
+    let deferred5_0;
+    let deferred5_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(payload_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(options_json) ? 0 : passStringToWasm0(options_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        var ptr2 = isLikeNone(preview_json) ? 0 : passStringToWasm0(preview_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        var len2 = WASM_VECTOR_LEN;
+        wasm.render_preview(retptr, ptr0, len0, ptr1, len1, ptr2, len2);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr4 = r0;
+        var len4 = r1;
+        if (r3) {
+            ptr4 = 0; len4 = 0;
+            throw takeObject(r2);
+        }
+        deferred5_0 = ptr4;
+        deferred5_1 = len4;
+        return getStringFromWasm0(ptr4, len4);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_export(deferred5_0, deferred5_1, 1);
+    }
+}
+
+/**
+ * Blend heuristic scores with external (e.g. AI) scores
+ *
+ * Each bullet gets the weighted mean of its normalized heuristic score and
+ * its external score, so bullets the AI skipped keep their heuristic score.
+ * Weights default to `{ heuristic: 0.3, external: 0.7 }`. Returns a JSON
+ * array of `ScoredBullet`, highest score first.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const scored = JSON.parse(
+ *   score_bullets_ensemble(
+ *     JSON.stringify(resumeData),
+ *     JSON.stringify(roleProfile),
+ *     JSON.stringify({ b1: 0.9, b2: 0.4 }),
+ *     JSON.stringify({ heuristic: 0.5, external: 0.5 }),
+ *   )
+ * );
+ * ```
+ * @param {string} resume_json
+ * @param {string} role_profile_json
+ * @param {string} external_scores_json
+ * @param {string | null} [weights_json]
+ * @returns {string}
+ */
+export function score_bullets_ensemble(resume_json, role_profile_json, external_scores_json, weights_json) {
+    let deferred6_0;
+    let deferred6_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(resume_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(role_profile_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len1 = WASM_VECTOR_LEN;
+        const ptr2 = passStringToWasm0(external_scores_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len2 = WASM_VECTOR_LEN;
+        var ptr3 = isLikeNone(weights_json) ? 0 : passStringToWasm0(weights_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        var len3 = WASM_VECTOR_LEN;
+        wasm.score_bullets_ensemble(retptr, ptr0, len0, ptr1, len1, ptr2, len2, ptr3, len3);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr5 = r0;
+        var len5 = r1;
+        if (r3) {
+            ptr5 = 0; len5 = 0;
+            throw takeObject(r2);
+        }
+        deferred6_0 = ptr5;
+        deferred6_1 = len5;
+        return getStringFromWasm0(ptr5, len5);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_export(deferred6_0, deferred6_1, 1);
+    }
+}
+
+/**
+ * Full-text search over bullets for the site's search box
+ *
+ * Supports free text, `"quoted phrases"`, `tag:`, `company:`, `after:`,
+ * `before:` and `priority>=N`. Each hit carries its `company` and
+ * `position` (id + name), `path` and BM25 `score`.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const hits = JSON.parse(
+ *   search_bullets(JSON.stringify(resumeData), 'tag:kafka after:2020 "migration"', JSON.stringify({ limit: 10 }))
+ * );
+ * ```
+ * @param {string} resume_json
+ * @param {string} query
+ * @param {string | null} [options_json]
+ * @returns {string}
+ */
+export function search_bullets(resume_json, query, options_json) {
+    let deferred5_0;
+    let deferred5_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(resume_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(query, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len1 = WASM_VECTOR_LEN;
+        var ptr2 = isLikeNone(options_json) ? 0 : passStringToWasm0(options_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        var len2 = WASM_VECTOR_LEN;
+        wasm.search_bullets(retptr, ptr0, len0, ptr1, len1, ptr2, len2);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr4 = r0;
+        var len4 = r1;
+        if (r3) {
+            ptr4 = 0; len4 = 0;
+            throw takeObject(r2);
+        }
+        deferred5_0 = ptr4;
+        deferred5_1 = len4;
+        return getStringFromWasm0(ptr4, len4);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_export(deferred5_0, deferred5_1, 1);
+    }
+}
+
+/**
+ * Pick the summary candidate that best matches a role profile
+ *
+ * Honours the profile's `summaryId` override, otherwise scores tagged
+ * candidates like bullets and falls back to `ResumeData.summary`.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const selected = JSON.parse(select_summary(JSON.stringify(resumeData), JSON.stringify(roleProfile)));
+ * payload.summary = selected?.text;
+ * ```
+ * @param {string} resume_json
+ * @param {string} role_profile_json
+ * @returns {string}
+ */
+export function select_summary(resume_json, role_profile_json) {
+    let deferred4_0;
+    let deferred4_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(resume_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        const ptr1 = passStringToWasm0(role_profile_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len1 = WASM_VECTOR_LEN;
+        wasm.select_summary(retptr, ptr0, len0, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr3 = r0;
+        var len3 = r1;
+        if (r3) {
+            ptr3 = 0; len3 = 0;
+            throw takeObject(r2);
+        }
+        deferred4_0 = ptr3;
+        deferred4_1 = len3;
+        return getStringFromWasm0(ptr3, len3);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_export(deferred4_0, deferred4_1, 1);
+    }
+}
+
+/**
+ * JSON Schema of the `data.json` document templates read
+ * @returns {string}
+ */
+export function template_data_schema() {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        wasm.template_data_schema(retptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        deferred1_0 = r0;
+        deferred1_1 = r1;
+        return getStringFromWasm0(r0, r1);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_export(deferred1_0, deferred1_1, 1);
+    }
+}
+
+/**
+ * Compile a payload and report errors and warnings without returning a PDF
+ *
+ * Returns a JSON array of `{ severity, message, hints?, span?, text?,
+ * fields? }`. `span` locates the problem in the template (`file`, `line`,
+ * `column`, `snippet`); `fields` names the payload fields to fix, e.g.
+ * `selected_bullets[4].bullet.description` for a character no font covers.
+ * Compile errors are returned as diagnostics rather than thrown.
+ *
+ * # Example (JavaScript)
+ * ```js
+ * const diagnostics = JSON.parse(typst_diagnostics(payloadJson));
+ * for (const d of diagnostics) console.warn(d.severity, d.message, d.fields ?? []);
+ * ```
+ * @param {string} payload_json
+ * @param {string | null} [options_json]
+ * @returns {string}
+ */
+export function typst_diagnostics(payload_json, options_json) {
+    let deferred4_0;
+    let deferred4_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(payload_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(options_json) ? 0 : passStringToWasm0(options_json, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.typst_diagnostics(retptr, ptr0, len0, ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr3 = r0;
+        var len3 = r1;
+        if (r3) {
+            ptr3 = 0; len3 = 0;
+            throw takeObject(r2);
+        }
+        deferred4_0 = ptr3;
+        deferred4_1 = len3;
+        return getStringFromWasm0(ptr3, len3);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_export(deferred4_0, deferred4_1, 1);
+    }
+}
+
 /**
  * Validate JSON payload structure without generating
  *
@@ -105,6 +790,42 @@ export function validate_payload_json(payload_json) {
     }
 }
 
+/**
+ * Check a template bundle archive before rendering with it
+ *
+ * Reports every problem at once (unresolved imports, missing images,
+ * fields not in the data schema, unsupported data version, ...). On
+ * success returns JSON `{ name, main, files }` describing the bundle.
+ * @param {Uint8Array} bundle
+ * @returns {string}
+ */
+export function validate_template_bundle(bundle) {
+    let deferred3_0;
+    let deferred3_1;
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(bundle, wasm.__wbindgen_export2);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.validate_template_bundle(retptr, ptr0, len0);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        var ptr2 = r0;
+        var len2 = r1;
+        if (r3) {
+            ptr2 = 0; len2 = 0;
+            throw takeObject(r2);
+        }
+        deferred3_0 = ptr2;
+        deferred3_1 = len2;
+        return getStringFromWasm0(ptr2, len2);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+        wasm.__wbindgen_export(deferred3_0, deferred3_1, 1);
+    }
+}
+
 /**
  * Test export to validate WASM build pipeline
  * @returns {string}
@@ -177,6 +898,10 @@ function __wbg_get_imports() {
     };
 }
 
+const PdfWithPreviewFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_pdfwithpreview_free(ptr >>> 0, 1));
+
 function addHeapObject(obj) {
     if (heap_next === heap.length) heap.push(heap.length + 1);
     const idx = heap_next;
@@ -225,6 +950,17 @@ heap.push(undefined, null, true, false);
 
 let heap_next = heap.length;
 
+function isLikeNone(x) {
+    return x === undefined || x === null;
+}
+
+function passArray8ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 1, 1) >>> 0;
+    getUint8ArrayMemory0().set(arg, ptr / 1);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+}
+
 function passStringToWasm0(arg, malloc, realloc) {
     if (realloc === undefined) {
         const buf = cachedTextEncoder.encode(arg);
@@ -1,11 +1,29 @@
 /* tslint:disable */
 /* eslint-disable */
 export const memory: WebAssembly.Memory;
+export const __wbg_pdfwithpreview_free: (a: number, b: number) => void;
+export const analyze_tags: (a: number, b: number, c: number) => void;
+export const analyze_timeline: (a: number, b: number, c: number, d: number, e: number) => void;
 export const build_info: (a: number) => void;
 export const estimate_pdf_size: (a: number) => number;
-export const generate_pdf_typst: (a: number, b: number, c: number, d: number) => void;
+export const generate_pdf_typst: (a: number, b: number, c: number, d: number, e: number) => void;
+export const generate_pdf_typst_bundle: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const generate_pdf_with_preview: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
 export const init_panic_hook: () => void;
+export const learn_tag_weights: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number) => void;
+export const lint_resume: (a: number, b: number, c: number, d: number, e: number) => void;
+export const list_templates: (a: number) => void;
+export const pdfwithpreview_pages: (a: number, b: number) => void;
+export const pdfwithpreview_pdf: (a: number, b: number) => void;
+export const read_pdf_metadata: (a: number, b: number, c: number) => void;
+export const render_preview: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const score_bullets_ensemble: (a: number, b: number, c: number, d: number, e: number, f: number, g: number, h: number, i: number) => void;
+export const search_bullets: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const select_summary: (a: number, b: number, c: number, d: number, e: number) => void;
+export const template_data_schema: (a: number) => void;
+export const typst_diagnostics: (a: number, b: number, c: number, d: number, e: number) => void;
 export const validate_payload_json: (a: number, b: number, c: number) => void;
+export const validate_template_bundle: (a: number, b: number, c: number) => void;
 export const version: (a: number) => void;
 export const lut_inverse_interp16: (a: number, b: number, c: number) => number;
 export const qcms_profile_precache_output_transform: (a: number) => void;
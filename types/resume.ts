@@ -23,6 +23,7 @@ export type {
   Position,
   RoleProfile,
   ScoringWeights,
+  SummaryCandidate,
 } from "../lib/types/generated-resume";
 
 /**
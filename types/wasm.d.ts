@@ -6,9 +6,57 @@ declare module "/wasm/resume_wasm.js" {
   export function init_panic_hook(): void;
   export function version(): string;
   export function generate_pdf(payload_json: string): Uint8Array;
-  export function generate_pdf_typst(payload_json: string, dev_mode: boolean): Uint8Array;
+  export function generate_pdf_typst(payload_json: string, options_json?: string | null): Uint8Array;
+  export function generate_pdf_typst_bundle(
+    payload_json: string,
+    bundle: Uint8Array,
+    options_json?: string | null,
+  ): Uint8Array;
+  export function validate_template_bundle(bundle: Uint8Array): string;
+  export function template_data_schema(): string;
+  export function list_templates(): string;
+  export function typst_diagnostics(payload_json: string, options_json?: string | null): string;
+  export function read_pdf_metadata(pdf: Uint8Array): string;
+  export function render_preview(
+    payload_json: string,
+    options_json?: string | null,
+    preview_json?: string | null,
+  ): string;
+  export class PdfWithPreview {
+    private constructor();
+    free(): void;
+    readonly pdf: Uint8Array;
+    /** Same JSON as `render_preview` returns */
+    readonly pages: string;
+  }
+  export function generate_pdf_with_preview(
+    payload_json: string,
+    options_json?: string | null,
+    preview_json?: string | null,
+  ): PdfWithPreview;
   export function generate_docx(payload_json: string): Uint8Array;
   export function validate_payload_json(payload_json: string): void;
+  export function analyze_tags(resume_json: string): string;
+  export function analyze_timeline(resume_json: string, options_json?: string | null): string;
+  export function lint_resume(resume_json: string, config_json?: string | null): string;
+  export function select_summary(resume_json: string, role_profile_json: string): string;
+  export function learn_tag_weights(
+    resume_json: string,
+    role_profile_json: string,
+    feedback_json: string,
+    options_json?: string | null,
+  ): string;
+  export function search_bullets(
+    resume_json: string,
+    query: string,
+    options_json?: string | null,
+  ): string;
+  export function score_bullets_ensemble(
+    resume_json: string,
+    role_profile_json: string,
+    external_scores_json: string,
+    weights_json?: string | null,
+  ): string;
   export function estimate_pdf_size(bullet_count: number): number;
   export function estimate_docx_size(bullet_count: number): number;
 }
@@ -0,0 +1,208 @@
+// Academic CV Template for Resumate
+// Education-first curriculum vitae: ruled section headings and a date
+// column to the left of each entry
+//
+// Reads the same `data.json` as every built-in template; its shape is
+// documented on `prepare_template_data` in crates/resume-typst/src/template.rs.
+
+#let data = json("data.json")
+#let personal = data.personal
+#let layout = data.layout
+
+// Render options override these defaults when set
+#let default-size = 10.5pt
+#let base-size = if layout.font_size == none { default-size } else { layout.font_size * 1pt }
+#let scaled(size) = size * (base-size / default-size)
+#let margins = if layout.margins == none {
+  (top: layout.margin * 1in, bottom: layout.margin * 1in, x: layout.margin * 1.2in)
+} else {
+  let m = layout.margins
+  (top: m.top * 1in, right: m.right * 1in, bottom: m.bottom * 1in, left: m.left * 1in)
+}
+// Education first, as is usual for a CV
+#let sections = if layout.sections == none {
+  ("summary", "education", "experience", "skills")
+} else {
+  layout.sections
+}
+
+#let date-column = 1.25in
+
+// ====================
+// DOCUMENT SETTINGS
+// ====================
+
+#set document(
+  title: "Curriculum Vitae - " + personal.name,
+  author: (personal.name,),
+  keywords: data.role_profile.tags,
+)
+
+#set page(
+  paper: layout.paper,
+  margin: margins,
+  numbering: none,
+)
+
+#set text(
+  font: layout.font,
+  lang: layout.lang,
+  region: layout.region,
+  size: base-size,
+  hyphenate: false,
+)
+
+#set par(
+  leading: layout.leading * 1.1em,
+  justify: true,
+  first-line-indent: 0pt,
+)
+
+#let heading-gap(level) = {
+  let gap = layout.headings.at(level - 1)
+  (above: gap.above * 1.2em, below: gap.below * 1em)
+}
+
+#show heading.where(level: 1): it => block(..heading-gap(1), width: 100%, breakable: false)[
+  #set align(center)
+  #text(size: scaled(11pt), weight: "bold", tracking: 0.08em, upper(it.body))
+  #v(-0.5em)
+  #line(length: 100%, stroke: 0.5pt)
+]
+
+#set list(marker: [•], indent: 0em, body-indent: 0.5em, spacing: layout.list_spacing * 1em)
+
+// ====================
+// HELPERS
+// ====================
+
+#let present(value) = value != none and value != ""
+
+// Date in the left column, entry on the right
+#let entry(date, body) = grid(
+  columns: (date-column, 1fr),
+  column-gutter: 0.15in,
+  row-gutter: 0.3em,
+  align(right, text(size: scaled(9.5pt), date)),
+  body,
+)
+
+// ====================
+// HEADER
+// ====================
+
+#align(center)[
+  #text(size: scaled(18pt), weight: "bold", personal.name)
+  #if present(data.role_profile.name) [
+    \ #text(size: scaled(11pt), style: "italic", data.role_profile.name)
+  ]
+
+  #v(0.2em)
+
+  #text(size: scaled(9.5pt))[
+    #data.contacts.map(contact => {
+      if contact.url == none { contact.text } else { link(contact.url, contact.text) }
+    }).join([ #h(0.5em)·#h(0.5em) ])
+  ]
+]
+
+// ====================
+// SECTIONS
+// ====================
+// Each section renders nothing when it has no content; `sections` sets which
+// appear and in what order
+
+#let summary-section() = if present(data.summary) [
+  = Research Interests
+
+  #data.summary
+]
+
+#let education-section() = if data.education.len() > 0 [
+  = Education
+
+  #for edu in data.education {
+    block(below: 0.8em, entry(edu.year)[
+      *#edu.degree* \
+      #if present(edu.institution) { emph(edu.institution) }
+      #if present(edu.location) [, #edu.location]
+    ])
+  }
+]
+
+#let experience-section() = if data.companies.len() > 0 [
+  = Appointments
+
+  #for company in data.companies {
+    for position in company.positions {
+      let name = if company.url == none { company.name } else { link(company.url, company.name) }
+      let date = if position.show_dates { position.date_range } else { company.date_range }
+      block(below: 0.9em, breakable: true, entry(date)[
+        *#position.title*#if present(company.name) [, #emph(name)] \
+        #if company.description != none [#text(size: scaled(9.5pt), company.description) \ ]
+        #list(..position.bullets.map(bullet => bullet.description))
+      ])
+    }
+  }
+]
+
+#let skills-section() = if data.skills.len() > 0 [
+  = Skills
+
+  #for group in data.skills {
+    block(below: 0.5em, entry(group.category, group.items.join(", ")))
+  }
+]
+
+#let renderers = (
+  summary: summary-section,
+  experience: experience-section,
+  education: education-section,
+  skills: skills-section,
+)
+
+#for name in sections {
+  renderers.at(name)()
+}
+
+// Marks the end of the resume proper; appendix pages after it don't count
+// towards the page limit in `render_resume_fit`
+#metadata(none) <resume-end>
+
+// ====================
+// META FOOTER
+// ====================
+
+#if data.about != none [
+  #pagebreak(weak: true)
+
+  = About This CV
+
+  #for paragraph in data.about {
+    par(paragraph)
+  }
+]
+
+// ====================
+// DEV MODE METADATA
+// ====================
+
+#if data.dev != none [
+  #pagebreak()
+
+  = Build Metadata (Dev Mode)
+
+  *Build Time:* #data.dev.build_time
+
+  *Typst Version:* #data.dev.version
+
+  *Role Profile:* #data.role_profile.name
+
+  #if present(data.role_profile.description) [
+    *Description:* #data.role_profile.description
+  ]
+
+  *Companies:* #data.dev.companies
+
+  *Total Bullets:* #data.dev.bullets
+]
@@ -0,0 +1,200 @@
+// Compact Template for Resumate
+// Dense single-column layout: smaller type, narrow margins, one line per
+// company and position, for fitting long histories on one page
+//
+// Reads the same `data.json` as every built-in template; its shape is
+// documented on `prepare_template_data` in crates/resume-typst/src/template.rs.
+
+#let data = json("data.json")
+#let personal = data.personal
+#let layout = data.layout
+
+// Render options override these defaults when set; the default margin is
+// tighter than the layout value every other template uses
+#let default-size = 9pt
+#let base-size = if layout.font_size == none { default-size } else { layout.font_size * 1pt }
+#let scaled(size) = size * (base-size / default-size)
+#let margins = if layout.margins == none {
+  calc.min(layout.margin, 0.5) * 1in
+} else {
+  let m = layout.margins
+  (top: m.top * 1in, right: m.right * 1in, bottom: m.bottom * 1in, left: m.left * 1in)
+}
+#let sections = if layout.sections == none {
+  ("summary", "experience", "education", "skills")
+} else {
+  layout.sections
+}
+
+// ====================
+// DOCUMENT SETTINGS
+// ====================
+
+#set document(
+  title: "Resume - " + personal.name,
+  author: (personal.name,),
+  keywords: data.role_profile.tags,
+)
+
+#set page(
+  paper: layout.paper,
+  margin: margins,
+  numbering: none,
+)
+
+#set text(
+  font: layout.font,
+  lang: layout.lang,
+  region: layout.region,
+  size: base-size,
+  hyphenate: false,
+)
+
+#set par(
+  leading: layout.leading * 0.85em,
+  spacing: 0.6em,
+  justify: false,
+  first-line-indent: 0pt,
+)
+
+#let heading-gap(level) = {
+  let gap = layout.headings.at(level - 1)
+  (above: gap.above * 0.7em, below: gap.below * 0.7em)
+}
+
+#show heading.where(level: 1): it => block(..heading-gap(1), width: 100%)[
+  #text(size: scaled(10pt), weight: "bold", upper(it.body))
+  #box(width: 1fr, baseline: -0.3em, line(length: 100%, stroke: 0.4pt))
+]
+#show heading.where(level: 2): set text(size: scaled(9pt), weight: "bold")
+#show heading.where(level: 2): set block(..heading-gap(2), breakable: false)
+
+#set list(marker: [–], indent: 0.3em, body-indent: 0.4em, spacing: layout.list_spacing * 0.7em)
+
+// ====================
+// HELPERS
+// ====================
+
+#let present(value) = value != none and value != ""
+
+#let separator = [ #h(0.4em)|#h(0.4em) ]
+
+// ====================
+// HEADER
+// ====================
+
+#text(size: scaled(15pt), weight: "bold", personal.name)
+#if present(data.role_profile.name) {
+  h(0.4em)
+  text(size: scaled(11pt), style: "italic", "– " + data.role_profile.name)
+}
+#h(1fr)
+#text(size: scaled(8.5pt))[
+  #data.contacts.map(contact => {
+    if contact.url == none { contact.text } else { link(contact.url, contact.text) }
+  }).join(separator)
+]
+
+// ====================
+// SECTIONS
+// ====================
+// Each section renders nothing when it has no content; `sections` sets which
+// appear and in what order
+
+#let summary-section() = if present(data.summary) [
+  = Summary
+
+  #data.summary
+]
+
+#let experience-section() = if data.companies.len() > 0 [
+  = Experience
+
+  #for company in data.companies {
+    for (index, position) in company.positions.enumerate() {
+      // Company and position share one line; later positions at the same
+      // company repeat only the title
+      let title = if index == 0 and present(company.name) {
+        let name = if company.url == none { company.name } else { link(company.url, company.name) }
+        if present(position.title) [#name #text(weight: "regular")[— #emph(position.title)]] else { name }
+      } else {
+        text(weight: "regular", emph(position.title))
+      }
+      let date = if position.show_dates { position.date_range } else { company.date_range }
+      heading(level: 2)[#title #h(1fr) #text(weight: "regular", size: scaled(8.5pt), date)]
+      list(..position.bullets.map(bullet => bullet.description))
+    }
+  }
+]
+
+#let education-section() = if data.education.len() > 0 [
+  = Education
+
+  #for edu in data.education {
+    par[
+      *#edu.degree*#if present(edu.institution) [, #edu.institution]
+      #h(1fr) #text(size: scaled(8.5pt), edu.year)
+    ]
+  }
+]
+
+#let skills-section() = if data.skills.len() > 0 [
+  = Skills
+
+  #for group in data.skills {
+    par[#strong(group.category + ":") #group.items.join(", ")]
+  }
+]
+
+#let renderers = (
+  summary: summary-section,
+  experience: experience-section,
+  education: education-section,
+  skills: skills-section,
+)
+
+#for name in sections {
+  renderers.at(name)()
+}
+
+// Marks the end of the resume proper; appendix pages after it don't count
+// towards the page limit in `render_resume_fit`
+#metadata(none) <resume-end>
+
+// ====================
+// META FOOTER
+// ====================
+
+#if data.about != none [
+  #pagebreak(weak: true)
+
+  = About This Resume
+
+  #for paragraph in data.about {
+    par(paragraph)
+  }
+]
+
+// ====================
+// DEV MODE METADATA
+// ====================
+
+#if data.dev != none [
+  #pagebreak()
+
+  = Build Metadata (Dev Mode)
+
+  *Build Time:* #data.dev.build_time
+
+  *Typst Version:* #data.dev.version
+
+  *Role Profile:* #data.role_profile.name
+
+  #if present(data.role_profile.description) [
+    *Description:* #data.role_profile.description
+  ]
+
+  *Companies:* #data.dev.companies
+
+  *Total Bullets:* #data.dev.bullets
+]
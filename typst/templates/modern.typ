@@ -0,0 +1,216 @@
+// Modern Template for Resumate
+// Two-column layout: tinted sidebar (contact, skills, education) beside the
+// main column (summary, experience). Section order applies within each column.
+//
+// Reads the same `data.json` as every built-in template; its shape is
+// documented on `prepare_template_data` in crates/resume-typst/src/template.rs.
+
+#let data = json("data.json")
+#let personal = data.personal
+#let layout = data.layout
+
+// Render options override these defaults when set
+#let default-size = 9.5pt
+#let base-size = if layout.font_size == none { default-size } else { layout.font_size * 1pt }
+#let scaled(size) = size * (base-size / default-size)
+#let margins = if layout.margins == none {
+  (top: layout.margin * 1in, bottom: layout.margin * 1in, x: layout.margin * 0.8in)
+} else {
+  let m = layout.margins
+  (top: m.top * 1in, right: m.right * 1in, bottom: m.bottom * 1in, left: m.left * 1in)
+}
+#let sections = if layout.sections == none {
+  ("summary", "experience", "skills", "education")
+} else {
+  layout.sections
+}
+
+#let accent = rgb("#2b4c7e")
+#let sidebar-fill = rgb("#eef2f7")
+
+// ====================
+// DOCUMENT SETTINGS
+// ====================
+
+#set document(
+  title: "Resume - " + personal.name,
+  author: (personal.name,),
+  keywords: data.role_profile.tags,
+)
+
+#set page(
+  paper: layout.paper,
+  margin: margins,
+  numbering: none,
+)
+
+#set text(
+  font: layout.font,
+  lang: layout.lang,
+  region: layout.region,
+  size: base-size,
+  hyphenate: false,
+)
+
+#set par(
+  leading: layout.leading * 1em,
+  justify: false,
+  first-line-indent: 0pt,
+)
+
+#let heading-gap(level) = {
+  let gap = layout.headings.at(level - 1)
+  (above: gap.above * 1em, below: gap.below * 1em)
+}
+
+#show heading.where(level: 1): it => block(..heading-gap(1), width: 100%)[
+  #text(size: scaled(10.5pt), weight: "bold", fill: accent, upper(it.body))
+  #v(-0.6em)
+  #line(length: 100%, stroke: 0.6pt + accent)
+]
+#show heading.where(level: 2): set text(size: scaled(10pt), weight: "bold")
+#show heading.where(level: 2): set block(..heading-gap(2), breakable: false)
+#show heading.where(level: 3): set text(size: scaled(9.5pt), weight: "regular", style: "italic")
+#show heading.where(level: 3): set block(..heading-gap(3))
+
+#set list(marker: text(fill: accent)[▪], indent: 0.4em, body-indent: 0.5em, spacing: layout.list_spacing * 1em)
+
+// ====================
+// HELPERS
+// ====================
+
+#let present(value) = value != none and value != ""
+
+#let dated(title, date) = {
+  title
+  if present(date) {
+    h(1fr)
+    text(weight: "regular", style: "italic", size: scaled(9pt), date)
+  }
+}
+
+// ====================
+// SIDEBAR
+// ====================
+
+#let sidebar = {
+  set par(leading: 0.5em)
+
+  text(size: scaled(18pt), weight: "bold", fill: accent, personal.name)
+  if present(data.role_profile.name) {
+    linebreak()
+    text(size: scaled(11pt), style: "italic", data.role_profile.name)
+  }
+
+  v(0.8em)
+  for contact in data.contacts {
+    let body = if contact.url == none { contact.text } else { link(contact.url, contact.text) }
+    block(below: 0.45em, text(size: scaled(8.5pt), body))
+  }
+
+  for name in sections {
+    if name == "skills" and data.skills.len() > 0 {
+      heading(level: 1, "Skills")
+      for group in data.skills {
+        block(below: 0.6em)[
+          #text(weight: "bold", group.category) \
+          #text(size: scaled(8.5pt), group.items.join(", "))
+        ]
+      }
+    }
+
+    if name == "education" and data.education.len() > 0 {
+      heading(level: 1, "Education")
+      for edu in data.education {
+        block(below: 0.6em)[
+          #text(weight: "bold", edu.degree) \
+          #if present(edu.institution) [#edu.institution \ ]
+          #if present(edu.year) [#text(size: scaled(8.5pt), style: "italic", edu.year)]
+        ]
+      }
+    }
+  }
+}
+
+// ====================
+// MAIN COLUMN
+// ====================
+
+#let main = {
+  for name in sections {
+    if name == "summary" and present(data.summary) {
+      heading(level: 1, "Profile")
+      par(data.summary)
+    }
+
+    if name == "experience" and data.companies.len() > 0 {
+      heading(level: 1, "Experience")
+      for company in data.companies {
+        if present(company.name) {
+          let name = if company.url == none { company.name } else { link(company.url, company.name) }
+          heading(level: 2, dated(name, company.date_range))
+          if company.description != none {
+            par(text(size: scaled(8.5pt), style: "italic", company.description))
+          }
+        }
+
+        for position in company.positions {
+          if present(position.title) {
+            let date = if position.show_dates { position.date_range }
+            heading(level: 3, dated(position.title, date))
+          }
+          list(..position.bullets.map(bullet => bullet.description))
+        }
+      }
+    }
+  }
+}
+
+#grid(
+  columns: (2.1in, 1fr),
+  column-gutter: 0.25in,
+  grid.cell(fill: sidebar-fill, inset: 0.15in, sidebar),
+  main,
+)
+
+// Marks the end of the resume proper; appendix pages after it don't count
+// towards the page limit in `render_resume_fit`
+#metadata(none) <resume-end>
+
+// ====================
+// META FOOTER
+// ====================
+
+#if data.about != none [
+  #pagebreak(weak: true)
+
+  = About This Resume
+
+  #for paragraph in data.about {
+    par(paragraph)
+  }
+]
+
+// ====================
+// DEV MODE METADATA
+// ====================
+
+#if data.dev != none [
+  #pagebreak()
+
+  = Build Metadata (Dev Mode)
+
+  *Build Time:* #data.dev.build_time
+
+  *Typst Version:* #data.dev.version
+
+  *Role Profile:* #data.role_profile.name
+
+  #if present(data.role_profile.description) [
+    *Description:* #data.role_profile.description
+  ]
+
+  *Companies:* #data.dev.companies
+
+  *Total Bullets:* #data.dev.bullets
+]
@@ -1,69 +1,219 @@
 // Resume Template for Resumate
 // Professional ATS-optimized resume template using Typst
 //
-// This template will receive data injected from Rust via JSON
-// The data structure matches GenerationPayload from resume-core
+// This template owns the whole layout. Rust prepares the data (grouping,
+// ordering, date formatting, link normalization) and serves it as the
+// virtual file `data.json`; its shape is documented on
+// `prepare_template_data` in crates/resume-typst/src/template.rs.
+//
+// Every value read from the JSON is a string or number, never markup, so
+// characters like #, $, @ and [ ] need no escaping.
+
+#let data = json("data.json")
+#let personal = data.personal
+#let layout = data.layout
+
+// Render options override these defaults when set
+#let default-size = 10pt
+#let base-size = if layout.font_size == none { default-size } else { layout.font_size * 1pt }
+#let scaled(size) = size * (base-size / default-size)
+#let margins = if layout.margins == none {
+  (top: layout.margin * 1in, bottom: layout.margin * 1in, x: layout.margin * 1in)
+} else {
+  let m = layout.margins
+  (top: m.top * 1in, right: m.right * 1in, bottom: m.bottom * 1in, left: m.left * 1in)
+}
+#let sections = if layout.sections == none {
+  ("summary", "experience", "education", "skills")
+} else {
+  layout.sections
+}
 
 // ====================
 // DOCUMENT SETTINGS
 // ====================
 
 #set document(
-  title: "Resume",
-  author: ("Resume Author",),
+  title: "Resume - " + personal.name,
+  author: (personal.name,),
+  keywords: data.role_profile.tags,
 )
 
 #set page(
-  paper: "us-letter",
-  margin: (top: 0.75in, bottom: 0.75in, x: 0.75in),
+  paper: layout.paper,
+  margin: margins,
   numbering: none,  // No page numbers for single-page resumes
 )
 
 #set text(
-  font: "Liberation Serif",  // Fallback: Typst built-in serif font
-  size: 10pt,
+  font: layout.font,
+  lang: layout.lang,
+  region: layout.region,
+  size: base-size,
   hyphenate: false,  // Disable hyphenation for ATS compatibility
 )
 
 #set par(
-  leading: 0.55em,
+  leading: layout.leading * 1em,
   justify: false,
   first-line-indent: 0pt,
 )
 
+// Heading styles (section headers) - LARGER for better hierarchy
+#let heading-gap(level) = {
+  let gap = layout.headings.at(level - 1)
+  (above: gap.above * 1em, below: gap.below * 1em)
+}
+
+#show heading.where(level: 1): set text(size: scaled(12pt), weight: "bold")
+#show heading.where(level: 1): set block(..heading-gap(1))
+#show heading.where(level: 2): set text(size: scaled(10.5pt), weight: "bold")
+#show heading.where(level: 2): set block(..heading-gap(2), breakable: false)
+#show heading.where(level: 3): set text(size: scaled(10pt), weight: "regular", style: "italic")
+#show heading.where(level: 3): set block(..heading-gap(3))
+
+// List styling (bullets)
+#set list(marker: [•], indent: 1em, body-indent: 0.5em, spacing: layout.list_spacing * 1em)
+
+// ====================
+// HELPERS
+// ====================
+
+#let present(value) = value != none and value != ""
+
+// Right-aligned italic date on a heading line
+#let dated(title, date) = {
+  title
+  if present(date) {
+    h(1fr)
+    emph(date)
+  }
+}
+
 // ====================
-// HELPER FUNCTIONS
+// HEADER
 // ====================
 
-// Format date range (will be called from Rust)
-#let format-date-range(start, end) = {
-  if end == none or end == "" or end == "present" [
-    #start -- Present
-  ] else [
-    #start -- #end
+#align(center)[
+  #text(size: scaled(20pt), weight: "bold", personal.name)
+  #if present(data.role_profile.name) {
+    h(0.5em)
+    text(size: scaled(14pt), style: "italic", "– " + data.role_profile.name)
+  }
+
+  #v(0.4em)
+
+  #text(size: scaled(10pt))[
+    #data.contacts.map(contact => {
+      if contact.url == none { contact.text } else { link(contact.url, contact.text) }
+    }).join([ #h(0.6em)•#h(0.6em) ])
   ]
+]
+
+#v(0.5em)
+
+// ====================
+// SECTIONS
+// ====================
+// Each section renders nothing when it has no content; `sections` sets which
+// appear and in what order
+
+#let summary-section() = if present(data.summary) [
+  = PROFESSIONAL SUMMARY
+
+  #data.summary
+]
+
+#let experience-section() = if data.companies.len() > 0 [
+  = EXPERIENCE
+
+  #for company in data.companies {
+    if present(company.name) {
+      let name = if company.url == none { company.name } else { link(company.url, company.name) }
+      heading(level: 2, dated(name, company.date_range))
+      if company.description != none {
+        par(emph(company.description))
+      }
+    }
+
+    for position in company.positions {
+      if present(position.title) {
+        let date = if position.show_dates { position.date_range }
+        heading(level: 3, dated(position.title, date))
+      }
+      list(..position.bullets.map(bullet => bullet.description))
+    }
+  }
+]
+
+#let education-section() = if data.education.len() > 0 [
+  = EDUCATION
+
+  #for edu in data.education {
+    heading(level: 2, dated(edu.degree, edu.year))
+    if present(edu.institution) {
+      par(emph(edu.institution))
+    }
+  }
+]
+
+#let skills-section() = if data.skills.len() > 0 [
+  = SKILLS
+
+  #for group in data.skills {
+    par[#strong(group.category + ":") #group.items.join(", ")]
+  }
+]
+
+#let renderers = (
+  summary: summary-section,
+  experience: experience-section,
+  education: education-section,
+  skills: skills-section,
+)
+
+#for name in sections {
+  renderers.at(name)()
 }
 
+// Marks the end of the resume proper; appendix pages after it don't count
+// towards the page limit in `render_resume_fit` (see fit::RESUME_END_LABEL)
+#metadata(none) <resume-end>
+
+// ====================
+// META FOOTER (dedicated page with formatting)
+// ====================
+
+#if data.about != none [
+  #pagebreak(weak: true)
+
+  = ABOUT THIS RESUME
+
+  #for paragraph in data.about {
+    par(paragraph)
+  }
+]
+
 // ====================
-// TEMPLATE CONTENT
+// DEV MODE METADATA
 // ====================
 
-// This is a placeholder template.
-// The actual content will be injected via Rust string interpolation
-// in the render_resume() function.
+#if data.dev != none [
+  #pagebreak()
+
+  = BUILD METADATA (DEV MODE)
 
-// For now, this shows the structure we'll use:
+  *Build Time:* #data.dev.build_time
 
-= Resume Template
+  *Typst Version:* #data.dev.version
 
-This is a placeholder template that will be populated with data from Rust.
+  *Role Profile:* #data.role_profile.name
+
+  #if present(data.role_profile.description) [
+    *Description:* #data.role_profile.description
+  ]
 
-The final template will include:
-- Header with contact information
-- Professional summary
-- Experience section (company → position → bullets hierarchy)
-- Education section
-- Skills section
-- Optional dev mode metadata
+  *Companies:* #data.dev.companies
 
-_Template to be completed in Phase 3 (Rust Integration)_
+  *Total Bullets:* #data.dev.bullets
+]